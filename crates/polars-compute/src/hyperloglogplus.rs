@@ -20,6 +20,7 @@
 use std::hash::{BuildHasher, Hash};
 use std::marker::PhantomData;
 
+use polars_error::{PolarsResult, polars_ensure};
 use polars_utils::aliases::PlFixedStateQuality;
 
 /// The greater is P, the smaller the error.
@@ -29,6 +30,13 @@ const HLL_Q: usize = 64_usize - HLL_P;
 const NUM_REGISTERS: usize = 1_usize << HLL_P;
 /// Mask to obtain index into the registers
 const HLL_P_MASK: u64 = (NUM_REGISTERS as u64) - 1;
+/// Version of the serialized format, stored as its first byte.
+///
+/// Registers are only comparable between sketches whose values were hashed in the same way, so
+/// the hash of the values is part of this format. Serialized sketches are built with
+/// [`HyperLogLog::add_hash`] from the xxh64 hash (seed 0) of the little-endian bytes of the
+/// values, and the version must be bumped whenever that hash changes.
+const FORMAT_VERSION: u8 = 1;
 
 #[derive(Clone, Debug)]
 pub struct HyperLogLog<T>
@@ -84,6 +92,13 @@ where
     /// Adds an element to the HyperLogLog.
     pub fn add(&mut self, obj: &T) {
         let hash = self.hash_value(obj);
+        self.add_hash(hash);
+    }
+
+    /// Adds an element to the HyperLogLog by its (well distributed) hash.
+    ///
+    /// The hashes of all elements must be computed in the same way.
+    pub fn add_hash(&mut self, hash: u64) {
         let index = (hash & HLL_P_MASK) as usize;
         let p = ((hash >> HLL_P) | (1_u64 << HLL_Q)).trailing_zeros() + 1;
         self.registers[index] = self.registers[index].max(p as u8);
@@ -126,6 +141,27 @@ where
         z += m * hll_sigma(histogram[0] as f64 / m);
         (0.5 / 2_f64.ln() * m * m / z).round() as usize
     }
+
+    /// Serializes the registers of the HyperLogLog, see `FORMAT_VERSION`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(1 + NUM_REGISTERS);
+        out.push(FORMAT_VERSION);
+        out.extend_from_slice(&self.registers);
+        out
+    }
+
+    /// Deserializes a HyperLogLog from the representation created by
+    /// [`HyperLogLog::to_bytes`], validating the registers.
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() == 1 + NUM_REGISTERS
+                && bytes[0] == FORMAT_VERSION
+                && bytes[1..].iter().all(|r| *r as usize <= HLL_Q + 1),
+            ComputeError: "invalid HyperLogLog sketch"
+        );
+        let registers = bytes[1..].try_into().unwrap();
+        Ok(Self::new_with_registers(registers))
+    }
 }
 
 /// Helper function sigma as defined in
@@ -315,6 +351,23 @@ mod tests {
         compare_with_delta(hll.count(), 1000);
     }
 
+    #[test]
+    fn test_serialize_merge() {
+        let mut hll = HyperLogLog::<u64>::new();
+        hll.extend(0..1000u64);
+        let mut other = HyperLogLog::<u64>::new();
+        other.extend(500..2000u64);
+
+        let mut merged = HyperLogLog::<u64>::from_bytes(&hll.to_bytes()).unwrap();
+        merged.merge(&HyperLogLog::from_bytes(&other.to_bytes()).unwrap());
+        compare_with_delta(merged.count(), 2000);
+
+        assert!(HyperLogLog::<u64>::from_bytes(&[]).is_err());
+        let mut bytes = hll.to_bytes();
+        bytes[1] = u8::MAX;
+        assert!(HyperLogLog::<u64>::from_bytes(&bytes).is_err());
+    }
+
     #[test]
     fn test_empty_merge() {
         let mut hll = HyperLogLog::<u64>::new();
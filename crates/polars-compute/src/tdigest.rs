@@ -0,0 +1,340 @@
+//! # t-digest
+//!
+//! `tdigest` module contains a merging t-digest, a mergeable sketch for estimating
+//! quantiles with a bounded amount of memory. It backs the `approx_quantile` and
+//! `quantile_sketch` expressions.
+//!
+//! The sketch clusters values into centroids, keeping the clusters near the tails small
+//! so that extreme quantiles stay accurate. See Dunning & Ertl, "Computing Extremely
+//! Accurate Quantiles Using t-Digests".
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_compute::tdigest::*;
+//!     let mut digest = TDigest::new();
+//!     for i in 0..=100 {
+//!         digest.add(i as f64);
+//!     }
+//!
+//!     let median = digest.quantile(0.5).unwrap();
+//!     assert!((median - 50.0).abs() < 1.0);
+//!
+//!     let bytes = digest.to_bytes();
+//!     let mut other = TDigest::from_bytes(&bytes).unwrap();
+//!     other.merge(&digest);
+//!     assert_eq!(other.count(), 202.0);
+//! ```
+
+use std::f64::consts::PI;
+
+use polars_error::{PolarsResult, polars_ensure};
+
+/// The greater the compression, the more centroids are kept and the smaller the error.
+const COMPRESSION: f64 = 100.0;
+/// Number of centroids that are buffered before they get merged.
+const BUFFER_SIZE: usize = 5 * COMPRESSION as usize;
+/// Version of the serialized format, stored as its first byte.
+const FORMAT_VERSION: u8 = 1;
+/// Size of the serialized header: the version, min, max and the number of centroids.
+const HEADER_SIZE: usize = 1 + 8 + 8 + 8;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    /// Merged centroids, sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Centroids that have not yet been merged into `centroids`.
+    buffer: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The scale function, mapping a quantile to the index of its (fractional) centroid.
+#[inline]
+fn scale(q: f64) -> f64 {
+    COMPRESSION / (2.0 * PI) * (2.0 * q.clamp(0.0, 1.0) - 1.0).asin()
+}
+
+impl TDigest {
+    /// Creates a new, empty t-digest.
+    pub fn new() -> Self {
+        Self {
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Adds a value to the t-digest. NaN values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+    }
+
+    /// Merge the other [`TDigest`] into this one.
+    pub fn merge(&mut self, other: &TDigest) {
+        for c in other.centroids.iter().chain(&other.buffer) {
+            self.push(*c);
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    #[inline]
+    fn push(&mut self, centroid: Centroid) {
+        self.min = self.min.min(centroid.mean);
+        self.max = self.max.max(centroid.mean);
+        self.buffer.push(centroid);
+        if self.buffer.len() >= BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    /// The total weight, i.e. the number of values added to the t-digest.
+    pub fn count(&self) -> f64 {
+        self.centroids
+            .iter()
+            .chain(&self.buffer)
+            .map(|c| c.weight)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.buffer.is_empty()
+    }
+
+    /// Merges the buffered centroids into the sorted centroids.
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let mut all = std::mem::take(&mut self.centroids);
+        all.append(&mut self.buffer);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total: f64 = all.iter().map(|c| c.weight).sum();
+
+        let mut merged = Vec::with_capacity(COMPRESSION as usize);
+        let mut all = all.into_iter();
+        let mut current = all.next().unwrap();
+        let mut weight_so_far = 0.0;
+        let mut k_lower = scale(0.0);
+        for c in all {
+            let q = (weight_so_far + current.weight + c.weight) / total;
+            if scale(q) - k_lower <= 1.0 {
+                current.weight += c.weight;
+                current.mean += (c.mean - current.mean) * c.weight / current.weight;
+            } else {
+                weight_so_far += current.weight;
+                k_lower = scale(weight_so_far / total);
+                merged.push(current);
+                current = c;
+            }
+        }
+        merged.push(current);
+
+        self.centroids = merged;
+        self.buffer = Vec::new();
+    }
+
+    /// Estimate the value at quantile `q` (between 0.0 and 1.0).
+    ///
+    /// Returns `None` if no values were added.
+    pub fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.compress();
+        let centroids = &self.centroids;
+        let (first, last) = (centroids.first()?, centroids.last()?);
+        if centroids.len() == 1 {
+            return Some(first.mean);
+        }
+
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let target = q.clamp(0.0, 1.0) * total;
+
+        // Interpolate between the centers of the centroids, and towards the min and max
+        // in the outer halves of the first and last centroid.
+        let out = if target <= first.weight / 2.0 {
+            self.min + (first.mean - self.min) * target / (first.weight / 2.0)
+        } else if target >= total - last.weight / 2.0 {
+            let remaining = total - target;
+            self.max - (self.max - last.mean) * remaining / (last.weight / 2.0)
+        } else {
+            let mut center = first.weight / 2.0;
+            let mut out = last.mean;
+            for w in centroids.windows(2) {
+                let next_center = center + (w[0].weight + w[1].weight) / 2.0;
+                if target <= next_center {
+                    let t = (target - center) / (next_center - center);
+                    out = w[0].mean + (w[1].mean - w[0].mean) * t;
+                    break;
+                }
+                center = next_center;
+            }
+            out
+        };
+        Some(out.clamp(self.min, self.max))
+    }
+
+    /// Serializes the t-digest into a compact binary representation.
+    pub fn to_bytes(&mut self) -> Vec<u8> {
+        self.compress();
+        let mut out = Vec::with_capacity(HEADER_SIZE + 16 * self.centroids.len());
+        out.push(FORMAT_VERSION);
+        out.extend_from_slice(&self.min.to_le_bytes());
+        out.extend_from_slice(&self.max.to_le_bytes());
+        out.extend_from_slice(&(self.centroids.len() as u64).to_le_bytes());
+        for c in &self.centroids {
+            out.extend_from_slice(&c.mean.to_le_bytes());
+            out.extend_from_slice(&c.weight.to_le_bytes());
+        }
+        out
+    }
+
+    /// Deserializes a t-digest from the representation created by [`TDigest::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        let read_f64 =
+            |offset: usize| f64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+
+        polars_ensure!(
+            bytes.len() >= HEADER_SIZE && bytes[0] == FORMAT_VERSION,
+            ComputeError: "invalid quantile sketch"
+        );
+        let min = read_f64(1);
+        let max = read_f64(9);
+        let len = u64::from_le_bytes(bytes[17..25].try_into().unwrap()) as usize;
+        polars_ensure!(
+            len.checked_mul(16).and_then(|l| l.checked_add(HEADER_SIZE)) == Some(bytes.len()),
+            ComputeError: "invalid quantile sketch"
+        );
+
+        let centroids = (0..len)
+            .map(|i| {
+                let offset = HEADER_SIZE + 16 * i;
+                Centroid {
+                    mean: read_f64(offset),
+                    weight: read_f64(offset + 8),
+                }
+            })
+            .collect::<Vec<_>>();
+        polars_ensure!(
+            centroids.is_sorted_by(|a, b| a.mean <= b.mean)
+                && centroids.iter().all(|c| c.weight > 0.0),
+            ComputeError: "invalid quantile sketch"
+        );
+
+        Ok(Self {
+            centroids,
+            buffer: Vec::new(),
+            min,
+            max,
+        })
+    }
+}
+
+impl Extend<f64> for TDigest {
+    fn extend<S: IntoIterator<Item = f64>>(&mut self, iter: S) {
+        for value in iter {
+            self.add(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TDigest;
+
+    fn exact_quantile(sorted: &[f64], q: f64) -> f64 {
+        sorted[((sorted.len() - 1) as f64 * q).round() as usize]
+    }
+
+    fn assert_close(digest: &mut TDigest, sorted: &[f64]) {
+        for q in [
+            0.0, 0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999, 1.0,
+        ] {
+            let got = digest.quantile(q).unwrap();
+            let expected = exact_quantile(sorted, q);
+            let range = sorted[sorted.len() - 1] - sorted[0];
+            assert!(
+                (got - expected).abs() <= 0.01 * range,
+                "quantile {q}: got {got}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut digest = TDigest::new();
+        assert!(digest.is_empty());
+        assert_eq!(digest.quantile(0.5), None);
+        digest.add(f64::NAN);
+        assert_eq!(digest.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let mut digest = TDigest::new();
+        digest.extend((0..10_000).map(|i| ((i * 7919) % 10_000) as f64));
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some(9999.0));
+    }
+
+    #[test]
+    fn test_accuracy() {
+        let values = (0..100_000)
+            .map(|i| (((i * 7919) % 100_000) as f64).powi(2))
+            .collect::<Vec<_>>();
+        let mut digest = TDigest::new();
+        digest.extend(values.iter().copied());
+
+        let mut sorted = values;
+        sorted.sort_by(f64::total_cmp);
+        assert_close(&mut digest, &sorted);
+    }
+
+    #[test]
+    fn test_merge_serialized() {
+        let values = (0..100_000)
+            .map(|i| ((i * 7919) % 100_000) as f64)
+            .collect::<Vec<_>>();
+
+        let mut merged = TDigest::new();
+        for chunk in values.chunks(1_000) {
+            let mut digest = TDigest::new();
+            digest.extend(chunk.iter().copied());
+            let digest = TDigest::from_bytes(&digest.to_bytes()).unwrap();
+            merged.merge(&digest);
+        }
+        assert_eq!(merged.count(), values.len() as f64);
+
+        let mut sorted = values;
+        sorted.sort_by(f64::total_cmp);
+        assert_close(&mut merged, &sorted);
+    }
+
+    #[test]
+    fn test_invalid_bytes() {
+        assert!(TDigest::from_bytes(&[]).is_err());
+        let mut digest = TDigest::new();
+        digest.add(1.0);
+        let bytes = digest.to_bytes();
+        assert!(TDigest::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -79,6 +79,15 @@ const DEFAULT_OOC_SPILL_MIN_BYTES: u64 = 64 * 1024; // 64 KB
 const OOC_LOG_METRICS: &str = "POLARS_OOC_LOG_METRICS";
 const DEFAULT_OOC_LOG_METRICS: bool = false;
 
+/// The (estimated) number of bytes each pipeline of a streaming sort buffers before
+/// sorting them into a run.
+const OOC_SORT_RUN_SIZE_BYTES: &str = "POLARS_OOC_SORT_RUN_SIZE_BYTES";
+const DEFAULT_OOC_SORT_RUN_SIZE_BYTES: u64 = 64 * 1024 * 1024; // 64 MiB
+
+/// The maximum number of runs a streaming sort merges at once (at least 2).
+const OOC_SORT_MAX_MERGE_FAN_IN: &str = "POLARS_OOC_SORT_MAX_MERGE_FAN_IN";
+const DEFAULT_OOC_SORT_MAX_MERGE_FAN_IN: u64 = 64;
+
 const JOIN_SAMPLE_LIMIT: &str = "POLARS_JOIN_SAMPLE_LIMIT";
 const DEFAULT_JOIN_SAMPLE_LIMIT: u64 = 10_000_000;
 
@@ -137,6 +146,8 @@ static KNOWN_OPTIONS: &[&str] = &[
     OOC_MEMORY_BUDGET_MB,
     OOC_SPILL_MIN_BYTES,
     OOC_LOG_METRICS,
+    OOC_SORT_RUN_SIZE_BYTES,
+    OOC_SORT_MAX_MERGE_FAN_IN,
     JOIN_SAMPLE_LIMIT,
     PROJECTION_PUSHDOWN_PRUNE_STRICT_HCONCAT_INPUTS,
 ];
@@ -164,6 +175,8 @@ pub struct Config {
     ooc_memory_budget_bytes: AtomicU64,
     ooc_spill_min_bytes: AtomicU64,
     ooc_log_metrics: AtomicBool,
+    ooc_sort_run_size_bytes: AtomicU64,
+    ooc_sort_max_merge_fan_in: AtomicU64,
     join_sample_limit: AtomicU64,
     projection_pushdown_prune_strict_hconcat_inputs: AtomicBool,
 }
@@ -198,6 +211,8 @@ impl Config {
             ),
             ooc_spill_min_bytes: AtomicU64::new(DEFAULT_OOC_SPILL_MIN_BYTES),
             ooc_log_metrics: AtomicBool::new(false),
+            ooc_sort_run_size_bytes: AtomicU64::new(DEFAULT_OOC_SORT_RUN_SIZE_BYTES),
+            ooc_sort_max_merge_fan_in: AtomicU64::new(DEFAULT_OOC_SORT_MAX_MERGE_FAN_IN),
             join_sample_limit: AtomicU64::new(DEFAULT_JOIN_SAMPLE_LIMIT),
             projection_pushdown_prune_strict_hconcat_inputs: AtomicBool::new(
                 DEFAULT_PROJECTION_PUSHDOWN_PRUNE_STRICT_HCONCAT_INPUTS,
@@ -332,6 +347,17 @@ impl Config {
                     .unwrap_or(DEFAULT_OOC_LOG_METRICS),
                 Ordering::Relaxed,
             ),
+            OOC_SORT_RUN_SIZE_BYTES => self.ooc_sort_run_size_bytes.store(
+                val.and_then(|x| parse::parse_u64(var, x))
+                    .unwrap_or(DEFAULT_OOC_SORT_RUN_SIZE_BYTES),
+                Ordering::Relaxed,
+            ),
+            OOC_SORT_MAX_MERGE_FAN_IN => self.ooc_sort_max_merge_fan_in.store(
+                val.and_then(|x| parse::parse_u64(var, x))
+                    .filter(|&x| x >= 2)
+                    .unwrap_or(DEFAULT_OOC_SORT_MAX_MERGE_FAN_IN),
+                Ordering::Relaxed,
+            ),
             JOIN_SAMPLE_LIMIT => self.join_sample_limit.store(
                 val.and_then(|x| parse::parse_u64(var, x))
                     .unwrap_or(DEFAULT_JOIN_SAMPLE_LIMIT),
@@ -465,6 +491,16 @@ impl Config {
         self.ooc_log_metrics.load(Ordering::Relaxed)
     }
 
+    #[inline(always)]
+    pub fn ooc_sort_run_size_bytes(&self) -> u64 {
+        self.ooc_sort_run_size_bytes.load(Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    pub fn ooc_sort_max_merge_fan_in(&self) -> u64 {
+        self.ooc_sort_max_merge_fan_in.load(Ordering::Relaxed)
+    }
+
     pub fn ooc_spill_dir(&self) -> std::path::PathBuf {
         if let Ok(dir) = std::env::var("POLARS_OOC_SPILL_DIR") {
             std::path::PathBuf::from(dir)
@@ -35,6 +35,46 @@ pub(super) fn approx_n_unique(s: &Column) -> PolarsResult<Column> {
         .map(|v| Column::new_scalar(s.name().clone(), Scalar::new(IDX_DTYPE, v.into()), 1))
 }
 
+#[cfg(feature = "approx_unique")]
+pub(super) fn hll_sketch(s: &Column) -> PolarsResult<Column> {
+    use crate::reduce::{new_hll_sketch_reduction, reduce_column};
+    reduce_column(new_hll_sketch_reduction(s.dtype().clone())?, s)
+}
+
+#[cfg(feature = "approx_unique")]
+pub(super) fn hll_merge(s: &Column) -> PolarsResult<Column> {
+    use crate::reduce::{new_hll_merge_reduction, reduce_column};
+    reduce_column(new_hll_merge_reduction(s.dtype().clone())?, s)
+}
+
+#[cfg(feature = "approx_quantile")]
+pub(super) fn approx_quantile(s: &Column, quantile: f64) -> PolarsResult<Column> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0"
+    );
+    let mut digest = crate::reduce::column_to_tdigest(s, "approx_quantile")?;
+    let value = digest
+        .quantile(quantile)
+        .map_or(AnyValue::Null, AnyValue::Float64);
+    Ok(Column::new_scalar(
+        s.name().clone(),
+        Scalar::new(DataType::Float64, value),
+        1,
+    ))
+}
+
+#[cfg(feature = "approx_quantile")]
+pub(super) fn quantile_sketch(s: &Column) -> PolarsResult<Column> {
+    let mut digest = crate::reduce::column_to_tdigest(s, "quantile_sketch")?;
+    let value = AnyValue::BinaryOwned(digest.to_bytes());
+    Ok(Column::new_scalar(
+        s.name().clone(),
+        Scalar::new(DataType::Binary, value),
+        1,
+    ))
+}
+
 #[cfg(feature = "diff")]
 pub(super) fn diff(s: &[Column], null_behavior: NullBehavior) -> PolarsResult<Column> {
     let s1 = s[0].as_materialized_series();
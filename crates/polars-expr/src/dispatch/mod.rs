@@ -338,6 +338,16 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Reverse => map!(misc::reverse),
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => map!(misc::approx_n_unique),
+        #[cfg(feature = "approx_unique")]
+        F::HllSketch => map!(misc::hll_sketch),
+        #[cfg(feature = "approx_unique")]
+        F::HllMerge => map!(misc::hll_merge),
+        #[cfg(feature = "approx_unique")]
+        F::HllEstimate => map!(crate::reduce::hll_estimate),
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantile { quantile } => map!(misc::approx_quantile, quantile),
+        #[cfg(feature = "approx_quantile")]
+        F::QuantileSketch => map!(misc::quantile_sketch),
         F::Coalesce => map_as_slice!(misc::coalesce),
         #[cfg(feature = "diff")]
         F::Diff(null_behavior) => map_as_slice!(misc::diff, null_behavior),
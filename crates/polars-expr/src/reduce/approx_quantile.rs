@@ -0,0 +1,198 @@
+use polars_compute::tdigest::TDigest;
+
+use super::*;
+
+pub fn new_approx_quantile_reduction(
+    dtype: DataType,
+    quantile: f64,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0"
+    );
+    new_sketch_reduction(dtype, SketchOutput::Quantile(quantile), "approx_quantile")
+}
+
+pub fn new_quantile_sketch_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
+    new_sketch_reduction(dtype, SketchOutput::Sketch, "quantile_sketch")
+}
+
+fn new_sketch_reduction(
+    dtype: DataType,
+    output: SketchOutput,
+    name: &str,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    use VecGroupedReduction as VGR;
+    Ok(match dtype {
+        DataType::Binary => Box::new(VGR::new(dtype, SketchMergeReducer { output })),
+        _ if is_sketchable(&dtype) => Box::new(VGR::new(dtype, TDigestReducer { output })),
+        _ => {
+            polars_bail!(InvalidOperation: "`{name}` operation not supported for dtype `{dtype}`")
+        },
+    })
+}
+
+fn is_sketchable(dtype: &DataType) -> bool {
+    dtype.is_primitive_numeric() || dtype.is_decimal() || dtype.is_null()
+}
+
+/// Builds a t-digest from numeric values, or by merging the serialized sketches of a
+/// binary column.
+pub(crate) fn column_to_tdigest(s: &Column, name: &str) -> PolarsResult<TDigest> {
+    let mut digest = TDigest::new();
+    match s.dtype() {
+        DataType::Binary => {
+            for bytes in s.binary()?.iter().flatten() {
+                digest.merge(&TDigest::from_bytes(bytes)?);
+            }
+        },
+        dtype if is_sketchable(dtype) => {
+            let s = s.cast(&DataType::Float64)?;
+            digest.extend(s.f64()?.iter().flatten());
+        },
+        dtype => {
+            polars_bail!(InvalidOperation: "`{name}` operation not supported for dtype `{dtype}`")
+        },
+    }
+    Ok(digest)
+}
+
+#[derive(Clone, Copy)]
+enum SketchOutput {
+    /// The estimated quantile of each group.
+    Quantile(f64),
+    /// The serialized sketch of each group.
+    Sketch,
+}
+
+#[derive(Clone, Default)]
+struct SketchState {
+    digest: TDigest,
+    /// Whether an invalid serialized sketch was encountered, which is only reported when
+    /// finishing as reducing can't fail.
+    invalid: bool,
+}
+
+impl SketchState {
+    fn combine(&mut self, other: &Self) {
+        self.digest.merge(&other.digest);
+        self.invalid |= other.invalid;
+    }
+}
+
+impl SketchOutput {
+    fn finish(&self, v: Vec<SketchState>) -> PolarsResult<Series> {
+        polars_ensure!(
+            !v.iter().any(|s| s.invalid),
+            ComputeError: "invalid quantile sketch"
+        );
+        Ok(match self {
+            Self::Quantile(quantile) => {
+                let ca: Float64Chunked = v
+                    .into_iter()
+                    .map(|mut s| s.digest.quantile(*quantile))
+                    .collect_ca(PlSmallStr::EMPTY);
+                ca.into_series()
+            },
+            Self::Sketch => {
+                let ca: BinaryChunked = v
+                    .into_iter()
+                    .map(|mut s| Some(s.digest.to_bytes()))
+                    .collect_ca(PlSmallStr::EMPTY);
+                ca.into_series()
+            },
+        })
+    }
+}
+
+#[derive(Clone)]
+struct TDigestReducer {
+    output: SketchOutput,
+}
+
+impl Reducer for TDigestReducer {
+    type Dtype = Float64Type;
+    type Value = SketchState;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        SketchState::default()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        Cow::Owned(s.cast(&DataType::Float64).unwrap())
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<f64>, _seq_id: u64) {
+        if let Some(b) = b {
+            a.digest.add(b);
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &Float64Chunked, _seq_id: u64) {
+        v.digest.extend(ca.iter().flatten());
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        _dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        self.output.finish(v)
+    }
+}
+
+/// Merges serialized sketches.
+#[derive(Clone)]
+struct SketchMergeReducer {
+    output: SketchOutput,
+}
+
+impl Reducer for SketchMergeReducer {
+    type Dtype = BinaryType;
+    type Value = SketchState;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        SketchState::default()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<&[u8]>, _seq_id: u64) {
+        if let Some(b) = b {
+            match TDigest::from_bytes(b) {
+                Ok(digest) => a.digest.merge(&digest),
+                Err(_) => a.invalid = true,
+            }
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &BinaryChunked, seq_id: u64) {
+        for bytes in ca.iter() {
+            self.reduce_one(v, bytes, seq_id);
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        _dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        self.output.finish(v)
+    }
+}
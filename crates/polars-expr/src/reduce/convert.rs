@@ -6,6 +6,10 @@ use super::*;
 use crate::reduce::any_all::{new_all_reduction, new_any_reduction};
 #[cfg(feature = "approx_unique")]
 use crate::reduce::approx_n_unique::new_approx_n_unique_reduction;
+#[cfg(feature = "approx_quantile")]
+use crate::reduce::approx_quantile::{
+    new_approx_quantile_reduction, new_quantile_sketch_reduction,
+};
 #[cfg(feature = "bitwise")]
 use crate::reduce::bitwise::{
     new_bitwise_and_reduction, new_bitwise_or_reduction, new_bitwise_xor_reduction,
@@ -16,6 +20,13 @@ use crate::reduce::cov::{new_cov_reduction, new_pearson_corr_reduction};
 use crate::reduce::first_last::{new_first_reduction, new_item_reduction, new_last_reduction};
 use crate::reduce::first_last_nonnull::{new_first_nonnull_reduction, new_last_nonnull_reduction};
 use crate::reduce::has_nulls::HasNullsReduce;
+#[cfg(feature = "approx_unique")]
+use crate::reduce::hll::{new_hll_merge_reduction, new_hll_sketch_reduction};
+#[cfg(feature = "mode")]
+use crate::reduce::holistic::new_mode_reduction;
+use crate::reduce::holistic::{
+    new_median_reduction, new_n_unique_reduction, new_quantile_reduction,
+};
 use crate::reduce::implode::new_unordered_implode_reduction;
 use crate::reduce::is_empty::IsEmptyReduce;
 use crate::reduce::mean::new_mean_reduction;
@@ -77,9 +88,24 @@ pub fn into_reduction(
             IRAggExpr::Implode {
                 input,
                 maintain_order: false,
-            } => (new_unordered_implode_reduction(get_dt(*input)?), *input),
-            IRAggExpr::Median(_) => todo!(),
-            IRAggExpr::NUnique(_) => todo!(),
+            } => match expr_arena.get(*input) {
+                // The implicit implode of a mode in a group_by.
+                #[cfg(feature = "mode")]
+                AExpr::Function {
+                    input: inner_exprs,
+                    function:
+                        IRFunctionExpr::Mode {
+                            maintain_order: false,
+                        },
+                    ..
+                } => {
+                    let input = inner_exprs[0].node();
+                    (new_mode_reduction(get_dt(input)?)?, input)
+                },
+                _ => (new_unordered_implode_reduction(get_dt(*input)?), *input),
+            },
+            IRAggExpr::Median(input) => (new_median_reduction(get_dt(*input)?)?, *input),
+            IRAggExpr::NUnique(input) => (new_n_unique_reduction(get_dt(*input)?)?, *input),
             IRAggExpr::Implode { .. } => todo!(),
             IRAggExpr::AggGroups(_) => todo!(),
         },
@@ -132,6 +158,74 @@ pub fn into_reduction(
             (out, input)
         },
 
+        #[cfg(feature = "approx_unique")]
+        AExpr::Function {
+            input: inner_exprs,
+            function: IRFunctionExpr::HllSketch,
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let out = new_hll_sketch_reduction(get_dt(input)?)?;
+            (out, input)
+        },
+
+        #[cfg(feature = "approx_unique")]
+        AExpr::Function {
+            input: inner_exprs,
+            function: IRFunctionExpr::HllMerge,
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let out = new_hll_merge_reduction(get_dt(input)?)?;
+            (out, input)
+        },
+
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input: inner_exprs,
+            function: IRFunctionExpr::ApproxQuantile { quantile },
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let out = new_approx_quantile_reduction(get_dt(input)?, *quantile)?;
+            (out, input)
+        },
+
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input: inner_exprs,
+            function: IRFunctionExpr::QuantileSketch,
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let out = new_quantile_sketch_reduction(get_dt(input)?)?;
+            (out, input)
+        },
+
+        AExpr::Function {
+            input: inner_exprs,
+            function: IRFunctionExpr::Quantile { method },
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 2);
+            let input = inner_exprs[0].node();
+            let quantile = match expr_arena.get(inner_exprs[1].node()) {
+                AExpr::Literal(lv) if lv.is_scalar() => {
+                    lv.to_any_value().and_then(|av| av.extract::<f64>())
+                },
+                _ => None,
+            };
+            let Some(quantile) = quantile else {
+                polars_bail!(InvalidOperation: "streaming quantile requires a literal quantile")
+            };
+            let out = new_quantile_reduction(get_dt(input)?, quantile, *method)?;
+            (out, input)
+        },
+
         #[cfg(feature = "bitwise")]
         AExpr::Function {
             input: inner_exprs,
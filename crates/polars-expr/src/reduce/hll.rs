@@ -0,0 +1,295 @@
+use std::marker::PhantomData;
+
+use polars_compute::hyperloglogplus::HyperLogLog;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::float16::pf16;
+use polars_utils::total_ord::{canonical_f16, canonical_f32, canonical_f64};
+use xxhash_rust::xxh64::xxh64;
+
+use super::*;
+
+/// A HyperLogLog over the hashes of the values, see [`SketchHash`].
+type HllSketch = HyperLogLog<u64>;
+
+/// The hash of the values added to serialized sketches.
+///
+/// Sketches are merged across processes and versions, so this hash is part of the sketch format
+/// (see `FORMAT_VERSION` of [`HyperLogLog`]). It is the xxh64 hash with seed 0 of the
+/// little-endian bytes of numbers, of a single byte for booleans and of the bytes of strings and
+/// binary values. Floats are canonicalized first, so that -0.0 and 0.0, and all NaNs, are equal.
+trait SketchHash {
+    fn sketch_hash(&self) -> u64;
+}
+
+const SKETCH_HASH_SEED: u64 = 0;
+
+macro_rules! impl_sketch_hash_int {
+    ($($T:ty),*) => {
+        $(
+            impl SketchHash for $T {
+                #[inline(always)]
+                fn sketch_hash(&self) -> u64 {
+                    xxh64(&self.to_le_bytes(), SKETCH_HASH_SEED)
+                }
+            }
+        )*
+    };
+}
+
+impl_sketch_hash_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl SketchHash for pf16 {
+    #[inline(always)]
+    fn sketch_hash(&self) -> u64 {
+        xxh64(&canonical_f16(*self).to_le_bytes(), SKETCH_HASH_SEED)
+    }
+}
+
+impl SketchHash for f32 {
+    #[inline(always)]
+    fn sketch_hash(&self) -> u64 {
+        xxh64(&canonical_f32(*self).to_le_bytes(), SKETCH_HASH_SEED)
+    }
+}
+
+impl SketchHash for f64 {
+    #[inline(always)]
+    fn sketch_hash(&self) -> u64 {
+        xxh64(&canonical_f64(*self).to_le_bytes(), SKETCH_HASH_SEED)
+    }
+}
+
+impl SketchHash for bool {
+    #[inline(always)]
+    fn sketch_hash(&self) -> u64 {
+        xxh64(&[*self as u8], SKETCH_HASH_SEED)
+    }
+}
+
+impl SketchHash for &str {
+    #[inline(always)]
+    fn sketch_hash(&self) -> u64 {
+        xxh64(self.as_bytes(), SKETCH_HASH_SEED)
+    }
+}
+
+impl SketchHash for &[u8] {
+    #[inline(always)]
+    fn sketch_hash(&self) -> u64 {
+        xxh64(self, SKETCH_HASH_SEED)
+    }
+}
+
+pub fn new_hll_sketch_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
+    use DataType::*;
+    use VecGroupedReduction as VGR;
+    Ok(match dtype {
+        Boolean => Box::new(VGR::new(dtype, HllSketchReducer::<BooleanType>::new(false))),
+        _ if dtype.is_primitive_numeric() || dtype.is_temporal() => {
+            with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
+                Box::new(VGR::new(dtype, HllSketchReducer::<$T>::new(false)))
+            })
+        },
+        String => Box::new(VGR::new(dtype, HllSketchReducer::<StringType>::new(false))),
+        Binary => Box::new(VGR::new(dtype, HllSketchReducer::<BinaryType>::new(false))),
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => Box::new(VGR::new(dtype, HllSketchReducer::<Int128Type>::new(false))),
+        // The physical representation of categoricals differs between sessions, so these
+        // are hashed by their string values.
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Enum(_, _) | DataType::Categorical(_, _) => {
+            Box::new(VGR::new(dtype, HllSketchReducer::<StringType>::new(true)))
+        },
+        _ => {
+            polars_bail!(InvalidOperation: "`hll_sketch` operation not supported for dtype `{dtype}`")
+        },
+    })
+}
+
+pub fn new_hll_merge_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
+    polars_ensure!(
+        dtype == DataType::Binary,
+        InvalidOperation: "`hll_merge` expects a binary column of sketches, got dtype `{dtype}`"
+    );
+    Ok(Box::new(VecGroupedReduction::new(dtype, HllMergeReducer)))
+}
+
+/// Evaluates a reduction on a single group containing all values of the column.
+pub(crate) fn reduce_column(
+    mut reduction: Box<dyn GroupedReduction>,
+    s: &Column,
+) -> PolarsResult<Column> {
+    reduction.resize(1);
+    reduction.update_group(&[s], 0, 0)?;
+    Ok(reduction
+        .finalize()?
+        .with_name(s.name().clone())
+        .into_column())
+}
+
+/// Estimates the number of unique values of each sketch in a binary column.
+pub(crate) fn hll_estimate(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    let out: UInt64Chunked = ca.try_apply_nonnull_values_generic(|bytes| {
+        HllSketch::from_bytes(bytes).map(|hll| hll.count() as u64)
+    })?;
+    Ok(out.into_column())
+}
+
+#[derive(Clone)]
+struct HllState {
+    sketch: HllSketch,
+    /// Whether an invalid serialized sketch was encountered, which is only reported when
+    /// finishing as reducing can't fail.
+    invalid: bool,
+}
+
+impl HllState {
+    fn new() -> Self {
+        Self {
+            sketch: HllSketch::new(),
+            invalid: false,
+        }
+    }
+
+    fn combine(&mut self, other: &Self) {
+        self.sketch.merge(&other.sketch);
+        self.invalid |= other.invalid;
+    }
+}
+
+fn finish_sketches(v: Vec<HllState>) -> PolarsResult<Series> {
+    polars_ensure!(
+        !v.iter().any(|s| s.invalid),
+        ComputeError: "invalid HyperLogLog sketch"
+    );
+    let ca: BinaryChunked = v
+        .into_iter()
+        .map(|s| Some(s.sketch.to_bytes()))
+        .collect_ca(PlSmallStr::EMPTY);
+    Ok(ca.into_series())
+}
+
+struct HllSketchReducer<T> {
+    /// Whether to hash the values as strings rather than their physical representation.
+    as_string: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T> HllSketchReducer<T> {
+    fn new(as_string: bool) -> Self {
+        Self {
+            as_string,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for HllSketchReducer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            as_string: self.as_string,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Reducer for HllSketchReducer<T>
+where
+    T: PolarsPhysicalType,
+    for<'a> T::Physical<'a>: SketchHash,
+{
+    type Dtype = T;
+    type Value = HllState;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        HllState::new()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        if self.as_string {
+            Cow::Owned(s.cast(&DataType::String).unwrap())
+        } else {
+            s.to_physical_repr()
+        }
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(
+        &self,
+        a: &mut Self::Value,
+        b: Option<<Self::Dtype as PolarsDataType>::Physical<'_>>,
+        _seq_id: u64,
+    ) {
+        if let Some(b) = b {
+            a.sketch.add_hash(b.sketch_hash());
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for val in ca.iter().flatten() {
+            v.sketch.add_hash(val.sketch_hash());
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        _dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        finish_sketches(v)
+    }
+}
+
+/// Merges serialized sketches.
+#[derive(Clone)]
+struct HllMergeReducer;
+
+impl Reducer for HllMergeReducer {
+    type Dtype = BinaryType;
+    type Value = HllState;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        HllState::new()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<&[u8]>, _seq_id: u64) {
+        if let Some(b) = b {
+            match HllSketch::from_bytes(b) {
+                Ok(sketch) => a.sketch.merge(&sketch),
+                Err(_) => a.invalid = true,
+            }
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &BinaryChunked, seq_id: u64) {
+        for bytes in ca.iter() {
+            self.reduce_one(v, bytes, seq_id);
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        _dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        finish_sketches(v)
+    }
+}
@@ -0,0 +1,313 @@
+//! Holistic reductions (median, quantile, n_unique and mode).
+//!
+//! These cannot be computed from a fixed-size state, so the values of each group are
+//! collected with an unordered implode and only reduced when finalizing. If a spill context
+//! is set, collected values are regularly flushed into `SpillFrame`s registered in it, so
+//! that they can be spilled to disk when memory runs low.
+#[cfg(feature = "ooc")]
+use std::pin::Pin;
+
+#[cfg(feature = "ooc")]
+use polars_ooc::{MostRecentSpillContext, ParameterFreeSpillContext, SpillFrame};
+
+use super::implode::new_unordered_implode_reduction;
+use super::*;
+
+pub fn new_median_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
+    HolisticGroupedReduction::new(dtype, HolisticKind::Median)
+}
+
+pub fn new_quantile_reduction(
+    dtype: DataType,
+    quantile: f64,
+    method: QuantileMethod,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0"
+    );
+    HolisticGroupedReduction::new(dtype, HolisticKind::Quantile { quantile, method })
+}
+
+pub fn new_n_unique_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
+    HolisticGroupedReduction::new(dtype, HolisticKind::NUnique)
+}
+
+/// The modes of each group, as a list per group.
+#[cfg(feature = "mode")]
+pub fn new_mode_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
+    HolisticGroupedReduction::new(dtype, HolisticKind::Mode)
+}
+
+#[derive(Clone, Copy)]
+enum HolisticKind {
+    Median,
+    Quantile {
+        quantile: f64,
+        method: QuantileMethod,
+    },
+    NUnique,
+    #[cfg(feature = "mode")]
+    Mode,
+}
+
+impl HolisticKind {
+    /// Reduces the values of a single group.
+    fn reduce(&self, s: &Series) -> PolarsResult<Scalar> {
+        Ok(match self {
+            Self::Median => s.median_reduce()?,
+            Self::Quantile { quantile, method } => s.quantile_reduce(*quantile, *method)?,
+            Self::NUnique => Scalar::new_idxsize(s.n_unique()? as IdxSize),
+            #[cfg(feature = "mode")]
+            Self::Mode => {
+                let modes = polars_ops::prelude::mode::mode(s, false)?;
+                Scalar::new(modes.dtype().clone().implode(), AnyValue::List(modes))
+            },
+        })
+    }
+}
+
+/// The values of a group that are not held by the in-memory implode.
+#[derive(Clone, Default)]
+struct GroupState {
+    /// Number of values of this group held by the in-memory implode.
+    num_buffered: usize,
+    /// Flushed values of this group, as the frame and the row of this group in its list column.
+    #[cfg(feature = "ooc")]
+    flushed: Vec<(Arc<SpillFrame>, IdxSize)>,
+}
+
+impl GroupState {
+    /// Appends the flushed values of this group, from the loaded flushed frames.
+    #[cfg(feature = "ooc")]
+    fn append_flushed(
+        &self,
+        s: &mut Series,
+        loaded: &PlHashMap<usize, ListChunked>,
+    ) -> PolarsResult<()> {
+        for (frame, row) in &self.flushed {
+            let flushed = loaded
+                .get(&(Arc::as_ptr(frame) as usize))
+                .expect("spilled state must be loaded before finalizing")
+                .get_as_series(*row as usize);
+            s.append_owned(flushed.unwrap())?;
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "ooc"))]
+    fn append_flushed(
+        &self,
+        _s: &mut Series,
+        _loaded: &PlHashMap<usize, ListChunked>,
+    ) -> PolarsResult<()> {
+        Ok(())
+    }
+}
+
+struct HolisticGroupedReduction {
+    /// Collects the values of each group in memory, until they are flushed.
+    values: Box<dyn GroupedReduction>,
+    groups: Vec<GroupState>,
+    evicted_groups: Vec<GroupState>,
+    /// Total number of values held by the in-memory implode.
+    num_buffered: usize,
+    kind: HolisticKind,
+    out_dtype: DataType,
+    /// The context the flushed frames are registered in. Values are only flushed if set.
+    #[cfg(feature = "ooc")]
+    spill_ctx: Option<Arc<MostRecentSpillContext>>,
+    /// The list columns of the flushed frames once loaded, by the address of the frame.
+    loaded: PlHashMap<usize, ListChunked>,
+}
+
+impl HolisticGroupedReduction {
+    fn new(in_dtype: DataType, kind: HolisticKind) -> PolarsResult<Box<dyn GroupedReduction>> {
+        // Reducing an empty group also checks that the dtype is supported.
+        let empty = Series::new_empty(PlSmallStr::EMPTY, &in_dtype);
+        let out_dtype = kind.reduce(&empty)?.dtype().clone();
+        Ok(Box::new(Self {
+            values: new_unordered_implode_reduction(in_dtype),
+            groups: Vec::new(),
+            evicted_groups: Vec::new(),
+            num_buffered: 0,
+            kind,
+            out_dtype,
+            #[cfg(feature = "ooc")]
+            spill_ctx: None,
+            loaded: PlHashMap::new(),
+        }))
+    }
+
+    /// Flushes the values held in memory into a spillable frame once there are enough of them.
+    /// The frame is only registered for spilling; the spilling itself is left to the caller.
+    ///
+    /// Flushing touches every group, so it waits for at least as many values as there are groups.
+    #[cfg(feature = "ooc")]
+    fn maybe_flush(&mut self) -> PolarsResult<()> {
+        let Some(spill_ctx) = &self.spill_ctx else {
+            return Ok(());
+        };
+        let threshold =
+            (polars_config::config().ideal_morsel_size() as usize).max(self.groups.len());
+        if self.num_buffered < threshold {
+            return Ok(());
+        }
+
+        let lists = self.values.finalize()?;
+        self.values.resize(self.groups.len() as IdxSize);
+        let frame = Arc::new(SpillFrame::new_unregistered(lists.into_frame()));
+        spill_ctx.register(&*frame);
+        for (i, group) in self.groups.iter_mut().enumerate() {
+            if group.num_buffered > 0 {
+                group.flushed.push((frame.clone(), i as IdxSize));
+                group.num_buffered = 0;
+            }
+        }
+        self.num_buffered = 0;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "ooc"))]
+    fn maybe_flush(&mut self) -> PolarsResult<()> {
+        Ok(())
+    }
+}
+
+impl GroupedReduction for HolisticGroupedReduction {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            values: self.values.new_empty(),
+            groups: Vec::new(),
+            evicted_groups: Vec::new(),
+            num_buffered: 0,
+            kind: self.kind,
+            out_dtype: self.out_dtype.clone(),
+            #[cfg(feature = "ooc")]
+            spill_ctx: self.spill_ctx.clone(),
+            loaded: PlHashMap::new(),
+        })
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+        self.groups.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.values.resize(num_groups);
+        self.groups
+            .resize_with(num_groups as usize, GroupState::default);
+    }
+
+    fn update_group(
+        &mut self,
+        values: &[&Column],
+        group_idx: IdxSize,
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        self.values.update_group(values, group_idx, seq_id)?;
+        self.groups[group_idx as usize].num_buffered += values[0].len();
+        self.num_buffered += values[0].len();
+        self.maybe_flush()
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &[&Column],
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        self.values
+            .update_groups_while_evicting(values, subset, group_idxs, seq_id)?;
+        for g in group_idxs {
+            let group = self.groups.get_unchecked_mut(g.idx());
+            if g.should_evict() {
+                self.num_buffered -= group.num_buffered;
+                self.evicted_groups.push(core::mem::take(group));
+            }
+            group.num_buffered += 1;
+        }
+        self.num_buffered += subset.len();
+        self.maybe_flush()
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        self.values
+            .combine_subset(&*other.values, subset, group_idxs)?;
+        for (i, g) in subset.iter().zip(group_idxs) {
+            let other_group = other.groups.get_unchecked(*i as usize);
+            let group = self.groups.get_unchecked_mut(*g as usize);
+            group.num_buffered += other_group.num_buffered;
+            #[cfg(feature = "ooc")]
+            group.flushed.extend(other_group.flushed.iter().cloned());
+            self.num_buffered += other_group.num_buffered;
+        }
+        self.maybe_flush()
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        let groups = core::mem::take(&mut self.evicted_groups);
+        Box::new(Self {
+            values: self.values.take_evictions(),
+            num_buffered: groups.iter().map(|g| g.num_buffered).sum(),
+            groups,
+            evicted_groups: Vec::new(),
+            kind: self.kind,
+            out_dtype: self.out_dtype.clone(),
+            #[cfg(feature = "ooc")]
+            spill_ctx: self.spill_ctx.clone(),
+            loaded: PlHashMap::new(),
+        })
+    }
+
+    #[cfg(feature = "ooc")]
+    fn set_spill_context(&mut self, spill_ctx: &Arc<MostRecentSpillContext>) {
+        self.spill_ctx = Some(spill_ctx.clone());
+    }
+
+    #[cfg(feature = "ooc")]
+    fn load_spilled(&mut self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            // Load every flushed frame once, frames are shared by many groups.
+            for (frame, _) in self.groups.iter().flat_map(|g| &g.flushed) {
+                let key = Arc::as_ptr(frame) as usize;
+                if !self.loaded.contains_key(&key) {
+                    let df = frame.get().await;
+                    let lists = df.columns()[0].list().unwrap().clone();
+                    self.loaded.insert(key, lists);
+                }
+            }
+        })
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let lists = self.values.finalize()?;
+        let lists = lists.list()?;
+        let groups = core::mem::take(&mut self.groups);
+        self.num_buffered = 0;
+        let loaded = core::mem::take(&mut self.loaded);
+
+        let values = groups
+            .iter()
+            .enumerate()
+            .map(|(i, group)| {
+                let mut s = lists.get_as_series(i).unwrap();
+                group.append_flushed(&mut s, &loaded)?;
+                Ok(self.kind.reduce(&s)?.into_value())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, &self.out_dtype, true)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -2,6 +2,8 @@
 mod any_all;
 #[cfg(feature = "approx_unique")]
 mod approx_n_unique;
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
 #[cfg(feature = "bitwise")]
 mod bitwise;
 mod convert;
@@ -11,6 +13,9 @@ mod cov;
 mod first_last;
 mod first_last_nonnull;
 mod has_nulls;
+#[cfg(feature = "approx_unique")]
+mod hll;
+mod holistic;
 mod implode;
 mod is_empty;
 mod mean;
@@ -24,12 +29,22 @@ mod var_std;
 use std::any::Any;
 use std::borrow::Cow;
 use std::marker::PhantomData;
+#[cfg(feature = "ooc")]
+use std::pin::Pin;
 
+#[cfg(feature = "approx_quantile")]
+pub(crate) use approx_quantile::column_to_tdigest;
 use arrow::array::{Array, PrimitiveArray, StaticArray};
 use arrow::bitmap::{Bitmap, BitmapBuilder, MutableBitmap};
 pub use convert::into_reduction;
+#[cfg(feature = "approx_unique")]
+pub(crate) use hll::{
+    hll_estimate, new_hll_merge_reduction, new_hll_sketch_reduction, reduce_column,
+};
 pub use min_max::{new_max_reduction, new_min_reduction};
 use polars_core::prelude::*;
+#[cfg(feature = "ooc")]
+use polars_ooc::MostRecentSpillContext;
 
 use crate::EvictIdx;
 
@@ -116,6 +131,20 @@ pub trait GroupedReduction: Any + Send + Sync {
     /// Take the accumulated evicted groups.
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction>;
 
+    /// Sets the context in which this GroupedReduction registers the state it
+    /// spills. Without one, all state is kept in memory.
+    #[cfg(feature = "ooc")]
+    fn set_spill_context(&mut self, spill_ctx: &Arc<MostRecentSpillContext>) {
+        let _ = spill_ctx;
+    }
+
+    /// Loads the spilled state of this GroupedReduction back into memory. This
+    /// must be awaited before finalizing if a spill context was set.
+    #[cfg(feature = "ooc")]
+    fn load_spilled(&mut self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(std::future::ready(()))
+    }
+
     /// Returns the finalized value per group as a Series.
     ///
     /// After this operation the number of groups is reset to 0.
@@ -130,3 +130,38 @@ where
         )
     }
 }
+
+/// Read the Avro file header from the start of `byte_source`. Avro headers have no length prefix,
+/// so progressively larger prefixes are fetched until the header decodes.
+///
+/// Returns the file metadata and the byte offset of the first data block.
+#[cfg(any(feature = "async", feature = "cloud"))]
+pub async fn read_metadata_from_byte_source(
+    byte_source: &crate::utils::byte_source::DynByteSource,
+) -> PolarsResult<(avro::avro_schema::file::FileMetadata, usize)> {
+    use crate::utils::byte_source::{ByteSource, DynByteSource};
+
+    const INITIAL_FETCH: usize = 64 * 1024;
+
+    let file_size = byte_source.get_size().await?;
+
+    let mut fetch_size = if let DynByteSource::Buffer(_) = byte_source {
+        // Mmapped or in-memory, reads are free.
+        file_size
+    } else {
+        INITIAL_FETCH.min(file_size)
+    };
+
+    loop {
+        let bytes = byte_source.get_range(0..fetch_size).await?;
+        let mut cursor = std::io::Cursor::new(bytes.as_ref());
+
+        match avro::avro_schema::read::read_metadata(&mut cursor) {
+            Ok(metadata) => return Ok((metadata, cursor.position() as usize)),
+            Err(_) if fetch_size < file_size => {
+                fetch_size = fetch_size.saturating_mul(4).min(file_size);
+            },
+            Err(e) => return Err(to_compute_err(e)),
+        }
+    }
+}
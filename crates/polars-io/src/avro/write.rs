@@ -6,9 +6,41 @@ use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Block compression
+    pub compression: Option<AvroCodec>,
+    /// Name of the record in the written Avro schema
+    pub name: PlSmallStr,
+}
+
+/// Compression codec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCodec {
+    /// Deflate
+    Deflate,
+    /// Snappy, with a CRC32 checksum per block
+    Snappy,
+}
+
+impl From<AvroCodec> for AvroCompression {
+    fn from(value: AvroCodec) -> Self {
+        match value {
+            AvroCodec::Deflate => AvroCompression::Deflate,
+            AvroCodec::Snappy => AvroCompression::Snappy,
+        }
+    }
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -4,7 +4,7 @@ use polars_error::{PolarsResult, polars_bail, to_compute_err};
 
 use super::models::{CatalogInfo, NamespaceInfo, TableCredentials, TableInfo};
 use super::schema::schema_to_column_info_list;
-use super::utils::{PageWalker, do_request};
+use super::utils::{PageWalker, do_request, do_request_opt};
 use crate::catalog::unity::models::{ColumnInfo, DataSourceFormat, TableType};
 use crate::cloud::USER_AGENT;
 use crate::impl_page_walk;
@@ -62,6 +62,37 @@ impl CatalogClient {
         namespace: &str,
         table_name: &str,
     ) -> PolarsResult<TableInfo> {
+        let bytes =
+            do_request(self.get_table_info_request(catalog_name, namespace, table_name)).await?;
+
+        let out: TableInfo = decode_json_response(&bytes)?;
+
+        Ok(out)
+    }
+
+    /// Like [`Self::get_table_info`], but returns `None` if the table does not exist.
+    pub async fn try_get_table_info(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> PolarsResult<Option<TableInfo>> {
+        let Some(bytes) =
+            do_request_opt(self.get_table_info_request(catalog_name, namespace, table_name))
+                .await?
+        else {
+            return Ok(None);
+        };
+
+        decode_json_response(&bytes).map(Some)
+    }
+
+    fn get_table_info_request(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> reqwest::RequestBuilder {
         let full_table_name = format!(
             "{}.{}.{}",
             catalog_name.replace('/', "%2F"),
@@ -69,19 +100,12 @@ impl CatalogClient {
             table_name.replace('/', "%2F")
         );
 
-        let bytes = do_request(
-            self.http_client
-                .get(format!(
-                    "{}{}{}",
-                    &self.workspace_url, "/api/2.1/unity-catalog/tables/", full_table_name
-                ))
-                .query(&[("full_name", full_table_name)]),
-        )
-        .await?;
-
-        let out: TableInfo = decode_json_response(&bytes)?;
-
-        Ok(out)
+        self.http_client
+            .get(format!(
+                "{}{}{}",
+                &self.workspace_url, "/api/2.1/unity-catalog/tables/", full_table_name
+            ))
+            .query(&[("full_name", full_table_name)])
     }
 
     pub async fn get_table_credentials(
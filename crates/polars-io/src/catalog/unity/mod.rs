@@ -2,3 +2,5 @@ pub mod client;
 pub mod models;
 pub mod schema;
 pub(crate) mod utils;
+#[cfg(feature = "delta")]
+pub mod write;
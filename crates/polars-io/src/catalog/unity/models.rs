@@ -200,7 +200,7 @@ pub struct ColumnTypeJson {
     pub nullable: Option<bool>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<PlHashMap<String, String>>,
+    pub metadata: Option<PlHashMap<String, serde_json::Value>>,
 
     // Used for List types
     #[serde(
@@ -1,5 +1,5 @@
 use polars_core::prelude::{DataType, Field};
-use polars_core::schema::{Schema, SchemaRef};
+use polars_core::schema::{Schema, SchemaExt, SchemaRef};
 use polars_error::{PolarsResult, polars_bail, polars_err, to_compute_err};
 use polars_utils::error::TruncateErrorDetail;
 use polars_utils::format_pl_smallstr;
@@ -395,6 +395,13 @@ fn dtype_to_type_name(dtype: &DataType) -> PolarsResult<PlSmallStr> {
     Ok(out)
 }
 
+/// Creates the `type_json` of a struct with the columns of `schema`. This is also the format of
+/// the `schemaString` of Delta tables.
+pub fn schema_to_type_json_str(schema: &Schema) -> PolarsResult<String> {
+    let dtype = DataType::Struct(schema.iter_fields().collect());
+    serde_json::to_string(&dtype_to_type_json(&dtype)?).map_err(to_compute_err)
+}
+
 /// Creates the `type_json` field.
 fn field_to_type_json(name: PlSmallStr, dtype: &DataType) -> PolarsResult<ColumnTypeJson> {
     Ok(ColumnTypeJson {
@@ -6,6 +6,23 @@ use reqwest::RequestBuilder;
 /// Performs the request and attaches the response body to any error messages.
 pub(super) async fn do_request(request: reqwest::RequestBuilder) -> PolarsResult<bytes::Bytes> {
     let resp = request.send().await.map_err(to_compute_err)?;
+    read_response(resp).await
+}
+
+/// Like [`do_request`], but returns `None` if the requested resource does not exist.
+pub(super) async fn do_request_opt(
+    request: reqwest::RequestBuilder,
+) -> PolarsResult<Option<bytes::Bytes>> {
+    let resp = request.send().await.map_err(to_compute_err)?;
+
+    if resp.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    read_response(resp).await.map(Some)
+}
+
+async fn read_response(resp: reqwest::Response) -> PolarsResult<bytes::Bytes> {
     let opt_err = resp.error_for_status_ref().map(|_| ());
     let resp_bytes = resp.bytes().await.map_err(to_compute_err)?;
 
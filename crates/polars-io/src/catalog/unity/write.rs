@@ -0,0 +1,336 @@
+//! Writing of data to Unity catalog tables.
+
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_utils::pl_path::PlRefPath;
+
+use super::client::CatalogClient;
+use super::models::{
+    DataSourceFormat, TableCredentials, TableCredentialsAws, TableCredentialsAzure,
+    TableCredentialsGcp, TableCredentialsVariants, TableInfo, TableType,
+};
+use crate::cloud::CloudOptions;
+use crate::delta::{DeltaWrite, DeltaWriteOptions};
+
+/// Arguments used to create the table if it does not exist.
+#[derive(Debug)]
+pub struct CreateTableArgs {
+    pub table_type: TableType,
+    /// Location of the data of the table. Required for external tables.
+    pub storage_location: Option<String>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct WriteTableOptions {
+    pub delta: DeltaWriteOptions,
+    /// Options for the storage of the table. If `None`, temporary write credentials are requested
+    /// from the catalog.
+    pub cloud_options: Option<CloudOptions>,
+    /// Creates the table with the schema of the data if it does not exist.
+    pub create_table: Option<CreateTableArgs>,
+}
+
+impl CatalogClient {
+    /// Writes `df` to a catalog table, see [`CatalogClient::prepare_write_table`].
+    ///
+    /// Returns the committed version, see [`write_delta`](crate::delta::write_delta).
+    pub async fn write_table(
+        &self,
+        df: &mut DataFrame,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        options: &WriteTableOptions,
+    ) -> PolarsResult<Option<u64>> {
+        let Some(write) = self
+            .prepare_write_table(catalog_name, namespace, table_name, df.schema(), options)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let path = write
+            .write_data_file(df, &options.delta.parquet_options)
+            .await?;
+        write.commit(&[path]).await.map(Some)
+    }
+
+    /// Prepares a write of data with `schema` to a catalog table, by resolving the storage
+    /// location and write credentials of the table through the catalog and resolving the state
+    /// of the Delta table at that location.
+    ///
+    /// Only tables with the `DELTA` data source format are supported. Returns `None` if nothing
+    /// is to be written, see [`DeltaWrite::try_new`].
+    pub async fn prepare_write_table(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        schema: &SchemaRef,
+        options: &WriteTableOptions,
+    ) -> PolarsResult<Option<DeltaWrite>> {
+        let table_info = match (
+            self.try_get_table_info(catalog_name, namespace, table_name)
+                .await?,
+            &options.create_table,
+        ) {
+            (Some(table_info), _) => table_info,
+            (None, Some(args)) => {
+                self.create_table(
+                    catalog_name,
+                    namespace,
+                    table_name,
+                    Some(schema.as_ref()),
+                    &args.table_type,
+                    Some(&DataSourceFormat::Delta),
+                    args.comment.as_deref(),
+                    args.storage_location.as_deref(),
+                    &mut std::iter::empty(),
+                )
+                .await?
+            },
+            (None, None) => polars_bail!(
+                ComputeError:
+                "catalog table not found: {}.{}.{}", catalog_name, namespace, table_name
+            ),
+        };
+
+        let TableInfo {
+            table_id,
+            data_source_format,
+            storage_location,
+            ..
+        } = table_info;
+
+        if !matches!(data_source_format, Some(DataSourceFormat::Delta)) {
+            polars_bail!(
+                nyi = "writing to catalog tables with data_source_format {:?}",
+                data_source_format
+            )
+        }
+
+        let storage_location = PlRefPath::new(storage_location.ok_or_else(|| {
+            polars_err!(ComputeError: "cannot write to catalog table: no storage_location found")
+        })?);
+
+        let cloud_options = match &options.cloud_options {
+            Some(cloud_options) => cloud_options.clone(),
+            None => credentials_to_cloud_options(
+                self.get_table_credentials(&table_id, true).await?,
+                &storage_location,
+            )?,
+        };
+
+        DeltaWrite::try_new(
+            storage_location,
+            Some(cloud_options),
+            schema.clone(),
+            options.delta.mode,
+        )
+        .await
+    }
+}
+
+fn credentials_to_cloud_options(
+    credentials: TableCredentials,
+    storage_location: &PlRefPath,
+) -> PolarsResult<CloudOptions> {
+    let expiration_time = credentials.expiration_time;
+
+    let config = match credentials.into_enum() {
+        Some(TableCredentialsVariants::Aws(TableCredentialsAws {
+            access_key_id,
+            secret_access_key,
+            session_token,
+            access_point,
+        })) => {
+            let mut config = vec![
+                ("aws_access_key_id", access_key_id),
+                ("aws_secret_access_key", secret_access_key),
+            ];
+            config.extend(session_token.map(|x| ("aws_session_token", x)));
+            config.extend(access_point.map(|x| ("aws_endpoint_url", x)));
+            config
+        },
+        Some(TableCredentialsVariants::Azure(TableCredentialsAzure { sas_token })) => {
+            vec![("sas_token", sas_token)]
+        },
+        Some(TableCredentialsVariants::Gcp(TableCredentialsGcp { oauth_token })) => {
+            #[cfg(feature = "gcp")]
+            {
+                return Ok(gcp_cloud_options(oauth_token, expiration_time));
+            }
+            #[cfg(not(feature = "gcp"))]
+            {
+                let _ = (oauth_token, expiration_time);
+                polars_bail!(ComputeError: "'gcp' feature is not enabled");
+            }
+        },
+        None => vec![],
+    };
+
+    CloudOptions::from_untyped_config(storage_location.scheme(), config)
+}
+
+/// GCP bearer tokens are not accepted as configuration, so they are passed through a credential
+/// provider instead.
+#[cfg(feature = "gcp")]
+fn gcp_cloud_options(oauth_token: String, expiration_time_ms: i64) -> CloudOptions {
+    use crate::cloud::credential_provider::{
+        GcpCredential, ObjectStoreCredential, PlCredentialProvider,
+    };
+
+    let credential = Arc::new(GcpCredential {
+        bearer: oauth_token,
+    });
+    let expiry = u64::try_from(expiration_time_ms / 1000).unwrap_or(0);
+
+    CloudOptions::default().with_credential_provider(Some(PlCredentialProvider::from_func(
+        move || {
+            let credential = credential.clone();
+            Box::pin(async move { Ok((ObjectStoreCredential::Gcp(credential), expiry)) })
+        },
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use polars_core::prelude::*;
+    use polars_core::runtime::ASYNC;
+    use polars_utils::pl_path::PlRefPath;
+
+    use super::{CreateTableArgs, WriteTableOptions};
+    use crate::catalog::unity::client::CatalogClientBuilder;
+    use crate::catalog::unity::models::TableType;
+    use crate::delta::{DeltaSnapshot, DeltaTimeTravel, DeltaWriteMode, DeltaWriteOptions};
+
+    /// Serves the catalog endpoints used by `write_table`, for a catalog that holds at most the
+    /// single table `main.default.t`.
+    fn serve_catalog() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        std::thread::spawn(move || {
+            let mut table: Option<String> = None;
+
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+
+                let (request_line, body) = {
+                    let mut reader = BufReader::new(&stream);
+                    let mut request_line = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if line.trim().is_empty() {
+                            break;
+                        }
+                        if let Some((k, v)) = line.split_once(':')
+                            && k.eq_ignore_ascii_case("content-length")
+                        {
+                            content_length = v.trim().parse().unwrap();
+                        }
+                    }
+
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+                    (request_line, body)
+                };
+
+                let mut parts = request_line.split(' ');
+                let method = parts.next().unwrap();
+                let path = parts.next().unwrap().split('?').next().unwrap();
+
+                let (status, response) = match (method, path) {
+                    ("GET", "/api/2.1/unity-catalog/tables/main.default.t") => match &table {
+                        Some(table) => ("200 OK", table.clone()),
+                        None => ("404 Not Found", "{}".to_string()),
+                    },
+                    ("POST", "/api/2.1/unity-catalog/tables") => {
+                        let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+                        let response = serde_json::json!({
+                            "name": request["name"],
+                            "table_id": "1",
+                            "table_type": request["table_type"],
+                            "data_source_format": request["data_source_format"],
+                            "storage_location": request["storage_location"],
+                            "columns": request["columns"],
+                            "created_at": null,
+                            "created_by": null,
+                            "updated_at": null,
+                            "updated_by": null,
+                        })
+                        .to_string();
+                        table = Some(response.clone());
+                        ("200 OK", response)
+                    },
+                    ("POST", "/api/2.1/unity-catalog/temporary-table-credentials") => {
+                        ("200 OK", r#"{"expiration_time":0}"#.to_string())
+                    },
+                    _ => ("404 Not Found", "{}".to_string()),
+                };
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                )
+                .unwrap();
+            }
+        });
+
+        url
+    }
+
+    #[test]
+    fn test_write_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage_location = dir.path().join("t").to_str().unwrap().to_string();
+        let client = CatalogClientBuilder::new()
+            .with_workspace_url(serve_catalog())
+            .build()
+            .unwrap();
+
+        let write = |mode, create_table| {
+            let options = WriteTableOptions {
+                delta: DeltaWriteOptions {
+                    mode,
+                    ..Default::default()
+                },
+                cloud_options: None,
+                create_table,
+            };
+            let mut df = df!("a" => [1i64, 2]).unwrap();
+            ASYNC.block_on(client.write_table(&mut df, "main", "default", "t", &options))
+        };
+        let create_table = || {
+            Some(CreateTableArgs {
+                table_type: TableType::External,
+                storage_location: Some(storage_location.clone()),
+                comment: None,
+            })
+        };
+
+        // The table does not exist and is not created.
+        assert!(write(DeltaWriteMode::Error, None).is_err());
+        assert_eq!(
+            write(DeltaWriteMode::Error, create_table()).unwrap(),
+            Some(0)
+        );
+        assert_eq!(write(DeltaWriteMode::Append, None).unwrap(), Some(1));
+
+        let snapshot =
+            DeltaSnapshot::try_new(&PlRefPath::new(&storage_location), DeltaTimeTravel::Latest)
+                .unwrap();
+        assert_eq!(snapshot.version, 1);
+        assert_eq!(snapshot.row_count(), Some((4, 0)));
+    }
+}
@@ -0,0 +1,403 @@
+//! Range-granular cache of data fetched through [`PolarsObjectStore`].
+//!
+//! Blocks are keyed by the URL of the object, its version (ETag) and the exact byte range that was
+//! requested, so repeated queries that fetch the same footers and column chunks of a file are
+//! served locally for as long as the object is not modified. Blocks are held in memory, and
+//! optionally in a disk tier that is shared between processes.
+//!
+//! The cache is disabled by default. It is enabled by setting a size in bytes for either tier
+//! through `POLARS_BLOCK_CACHE_MEMORY_SIZE` or `POLARS_BLOCK_CACHE_DISK_SIZE`.
+//!
+//! [`PolarsObjectStore`]: super::PolarsObjectStore
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use polars_buffer::Buffer;
+use polars_core::config;
+use polars_core::runtime::ASYNC;
+use polars_error::polars_warn;
+use polars_utils::aliases::PlHashMap;
+use tokio::task::JoinHandle;
+
+use crate::path_utils::{POLARS_TEMP_DIR_BASE_PATH, ensure_directory_init};
+
+pub(super) static BLOCK_CACHE: LazyLock<Option<BlockCache>> = LazyLock::new(|| {
+    let memory_size = get_env_size("POLARS_BLOCK_CACHE_MEMORY_SIZE");
+    let disk_size = get_env_size("POLARS_BLOCK_CACHE_DISK_SIZE");
+
+    if memory_size == 0 && disk_size == 0 {
+        return None;
+    }
+
+    let disk_dir = (disk_size > 0).then(|| {
+        let dir = POLARS_TEMP_DIR_BASE_PATH.join("block-cache");
+
+        if let Err(err) = ensure_directory_init(&dir) {
+            panic!(
+                "failed to create block cache directory: path = {}, err = {}",
+                dir.display(),
+                err
+            )
+        }
+
+        dir
+    });
+
+    if config::verbose() {
+        eprintln!(
+            "[BlockCache]: memory_size = {memory_size}, disk_size = {disk_size}, disk_dir = {disk_dir:?}"
+        );
+    }
+
+    Some(BlockCache::new(
+        memory_size,
+        disk_dir.map(|dir| (dir, disk_size)),
+    ))
+});
+
+fn get_env_size(name: &str) -> u64 {
+    let Ok(value) = std::env::var(name) else {
+        return 0;
+    };
+
+    value.parse::<u64>().unwrap_or_else(|_| {
+        polars_warn!(
+            "ignoring invalid value {} for {}, expected a size in bytes",
+            value,
+            name
+        );
+        0
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct BlockKey {
+    pub url: String,
+    /// Version of the object, used to invalidate the blocks of modified objects.
+    pub version: String,
+    pub range: Range<usize>,
+}
+
+impl BlockKey {
+    fn file_name(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.url.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(self.version.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(&(self.range.start as u64).to_le_bytes());
+        hasher.update(&(self.range.end as u64).to_le_bytes());
+        hasher.finalize().to_hex().to_string()
+    }
+}
+
+/// Maximum number of object versions that are remembered, see [`BlockCache::recent_version`].
+const MAX_VERSIONS: usize = 1 << 16;
+
+pub(super) struct BlockCache {
+    memory: Mutex<MemoryBlocks>,
+    disk: Option<Arc<DiskBlocks>>,
+    /// Versions of objects by URL and the time at which they were observed. These are shared by
+    /// all object stores, so that queries reading the same objects in quick succession don't
+    /// each request the version first.
+    versions: Mutex<PlHashMap<String, (String, Instant)>>,
+}
+
+impl BlockCache {
+    /// `disk` is the directory and the maximum size in bytes of the disk tier.
+    pub(super) fn new(memory_size: u64, disk: Option<(PathBuf, u64)>) -> Self {
+        Self {
+            memory: Mutex::new(MemoryBlocks {
+                max_size: memory_size as usize,
+                ..Default::default()
+            }),
+            disk: disk.map(|(dir, max_size)| Arc::new(DiskBlocks::new(dir, max_size))),
+            versions: Default::default(),
+        }
+    }
+
+    /// Returns the last observed version of the object at `url`, if it was observed within
+    /// `max_age`.
+    pub(super) fn recent_version(&self, url: &str, max_age: Duration) -> Option<String> {
+        let versions = self.versions.lock().unwrap();
+        let (version, observed_at) = versions.get(url)?;
+        (observed_at.elapsed() <= max_age).then(|| version.clone())
+    }
+
+    pub(super) fn set_version(&self, url: String, version: String) {
+        let mut versions = self.versions.lock().unwrap();
+
+        // Versions are only used for a short time after they are observed, so the map is cleared
+        // rather than evicted in order.
+        if versions.len() >= MAX_VERSIONS {
+            versions.clear();
+        }
+
+        versions.insert(url, (version, Instant::now()));
+    }
+
+    pub(super) async fn get(&self, key: &BlockKey) -> Option<Buffer<u8>> {
+        if let Some(bytes) = self.memory.lock().unwrap().get(key) {
+            return Some(bytes);
+        }
+
+        let disk = self.disk.clone()?;
+        let file_name = key.file_name();
+        let bytes = ASYNC
+            .spawn_blocking(move || disk.get(&file_name))
+            .await
+            .unwrap()?;
+
+        // Blocks on disk could only be corrupted by external modification, but a block of the
+        // wrong length would break readers.
+        if bytes.len() != key.range.len() {
+            return None;
+        }
+
+        let bytes = Buffer::from_vec(bytes);
+        self.memory.lock().unwrap().insert(key, bytes.clone());
+        Some(bytes)
+    }
+
+    /// Inserts a block. The block is written to the disk tier in the background, the returned
+    /// handle completes once it is written.
+    pub(super) fn insert(&self, key: &BlockKey, bytes: &Buffer<u8>) -> Option<JoinHandle<()>> {
+        self.memory.lock().unwrap().insert(key, bytes.clone());
+
+        let disk = self.disk.clone()?;
+        let file_name = key.file_name();
+        let bytes = bytes.clone();
+        Some(ASYNC.spawn_blocking(move || disk.insert(&file_name, &bytes)))
+    }
+}
+
+impl std::fmt::Debug for BlockCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockCache").finish_non_exhaustive()
+    }
+}
+
+/// In-memory blocks, evicted in least recently used order.
+#[derive(Default)]
+struct MemoryBlocks {
+    max_size: usize,
+    size: usize,
+    /// Incremented on every access, used to order the blocks by their last access.
+    tick: u64,
+    blocks: PlHashMap<BlockKey, (Buffer<u8>, u64)>,
+    lru: BTreeMap<u64, BlockKey>,
+}
+
+impl MemoryBlocks {
+    fn get(&mut self, key: &BlockKey) -> Option<Buffer<u8>> {
+        let (bytes, tick) = self.blocks.get_mut(key)?;
+
+        self.tick += 1;
+        let key = self.lru.remove(&*tick).unwrap();
+        self.lru.insert(self.tick, key);
+        *tick = self.tick;
+
+        Some(bytes.clone())
+    }
+
+    fn insert(&mut self, key: &BlockKey, bytes: Buffer<u8>) {
+        if bytes.len() > self.max_size || self.blocks.contains_key(key) {
+            return;
+        }
+
+        self.tick += 1;
+        self.size += bytes.len();
+        self.blocks.insert(key.clone(), (bytes, self.tick));
+        self.lru.insert(self.tick, key.clone());
+
+        while self.size > self.max_size {
+            let (_, key) = self.lru.pop_first().unwrap();
+            let (bytes, _) = self.blocks.remove(&key).unwrap();
+            self.size -= bytes.len();
+        }
+    }
+}
+
+/// Blocks stored as files in a directory. The directory can be shared by multiple processes, so
+/// the blocks are evicted in order of the access times of the files.
+struct DiskBlocks {
+    dir: PathBuf,
+    max_size: u64,
+    /// Size of the directory as of the last eviction, plus the blocks inserted since.
+    size: AtomicU64,
+}
+
+impl DiskBlocks {
+    fn new(dir: PathBuf, max_size: u64) -> Self {
+        let this = Self {
+            dir,
+            max_size,
+            size: AtomicU64::new(0),
+        };
+        this.evict();
+        this
+    }
+
+    fn get(&self, file_name: &str) -> Option<Vec<u8>> {
+        let path = self.dir.join(file_name);
+        let bytes = std::fs::read(&path).ok()?;
+
+        if let Ok(file) = std::fs::File::options().write(true).open(&path) {
+            let _ = file.set_times(std::fs::FileTimes::new().set_accessed(SystemTime::now()));
+        }
+
+        Some(bytes)
+    }
+
+    fn insert(&self, file_name: &str, bytes: &[u8]) {
+        if bytes.len() as u64 > self.max_size {
+            return;
+        }
+
+        let path = self.dir.join(file_name);
+
+        if path.exists() {
+            return;
+        }
+
+        // Blocks are renamed into place, so that other processes never read a partial block.
+        let tmp_path = self.dir.join(format!(
+            "{}.{}.tmp",
+            file_name,
+            polars_utils::unique_id::UniqueId::new()
+        ));
+
+        if std::fs::write(&tmp_path, bytes).is_err() || std::fs::rename(&tmp_path, &path).is_err() {
+            let _ = std::fs::remove_file(&tmp_path);
+            return;
+        }
+
+        if self.size.fetch_add(bytes.len() as u64, Ordering::Relaxed) + bytes.len() as u64
+            > self.max_size
+        {
+            self.evict();
+        }
+    }
+
+    /// Removes the least recently accessed blocks until the directory is below its maximum size.
+    /// Evicts down to 90% of the maximum size, so that this doesn't run on every insert.
+    fn evict(&self) {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+
+        let mut files = entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                // Blocks that are still being written by this or another process.
+                if entry.file_name().to_str()?.ends_with(".tmp") {
+                    return None;
+                }
+                let metadata = entry.metadata().ok()?;
+                let last_accessed = metadata.accessed().or_else(|_| metadata.modified()).ok()?;
+                Some((entry.path(), metadata.len(), last_accessed))
+            })
+            .collect::<Vec<_>>();
+
+        let mut size = files.iter().map(|(_, size, _)| size).sum::<u64>();
+        let target_size = self.max_size / 10 * 9;
+
+        if size > self.max_size {
+            files.sort_unstable_by_key(|(_, _, last_accessed)| *last_accessed);
+
+            for (path, file_size, _) in files {
+                if size <= target_size {
+                    break;
+                }
+
+                if std::fs::remove_file(&path).is_ok() {
+                    size -= file_size;
+                }
+            }
+        }
+
+        self.size.store(size, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_buffer::Buffer;
+    use polars_core::runtime::ASYNC;
+    use polars_error::polars_warn;
+
+    use super::{BlockCache, BlockKey};
+
+    fn key(version: &str, range: std::ops::Range<usize>) -> BlockKey {
+        BlockKey {
+            url: "s3://bucket/file.parquet".into(),
+            version: version.into(),
+            range,
+        }
+    }
+
+    fn block(len: usize) -> Buffer<u8> {
+        Buffer::from_vec((0..len).map(|x| x as u8).collect())
+    }
+
+    /// Inserts a block and waits for it to be written to disk.
+    async fn insert(cache: &BlockCache, key: &BlockKey, bytes: &Buffer<u8>) {
+        if let Some(handle) = cache.insert(key, bytes) {
+            handle.await.unwrap();
+        }
+    }
+
+    #[test]
+    fn test_block_cache_memory() {
+        let cache = BlockCache::new(25, None);
+
+        ASYNC.block_on(async {
+            insert(&cache, &key("v1", 0..10), &block(10)).await;
+            insert(&cache, &key("v1", 10..20), &block(10)).await;
+            assert!(cache.get(&key("v1", 0..10)).await.is_some());
+            // A different version of the object is a miss.
+            assert!(cache.get(&key("v2", 0..10)).await.is_none());
+
+            // Evicts the least recently used block, which is `10..20` after the access above.
+            insert(&cache, &key("v1", 20..30), &block(10)).await;
+            assert_eq!(cache.get(&key("v1", 0..10)).await, Some(block(10)));
+            assert!(cache.get(&key("v1", 10..20)).await.is_none());
+            assert!(cache.get(&key("v1", 20..30)).await.is_some());
+
+            // Blocks larger than the cache are not stored.
+            insert(&cache, &key("v1", 0..30), &block(30)).await;
+            assert!(cache.get(&key("v1", 0..30)).await.is_none());
+        });
+    }
+
+    #[test]
+    fn test_block_cache_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let new_cache = || BlockCache::new(0, Some((dir.path().to_path_buf(), 100)));
+
+        ASYNC.block_on(async {
+            let cache = new_cache();
+            insert(&cache, &key("v1", 0..30), &block(30)).await;
+            insert(&cache, &key("v1", 30..60), &block(30)).await;
+
+            // Blocks are shared with other caches using the same directory.
+            let cache = new_cache();
+            assert_eq!(cache.get(&key("v1", 30..60)).await, Some(block(30)));
+            assert!(cache.get(&key("v2", 30..60)).await.is_none());
+
+            // Partially written blocks are not evicted.
+            std::fs::write(dir.path().join("block.0.tmp"), [0; 30]).unwrap();
+
+            for i in 2..5 {
+                insert(&cache, &key("v1", i * 30..(i + 1) * 30), &block(30)).await;
+            }
+            assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 4);
+            assert!(dir.path().join("block.0.tmp").exists());
+        });
+    }
+}
@@ -1,5 +1,7 @@
 //! Interface with cloud storage through the object_store crate.
 
+#[cfg(feature = "cloud")]
+mod block_cache;
 #[cfg(feature = "cloud")]
 mod glob;
 #[cfg(feature = "cloud")]
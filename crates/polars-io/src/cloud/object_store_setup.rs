@@ -390,6 +390,10 @@ impl PolarsObjectStoreBuilder {
     pub(crate) fn is_azure(&self) -> bool {
         matches!(&self.cloud_type, CloudType::Azure)
     }
+
+    pub(crate) fn is_local(&self) -> bool {
+        matches!(&self.cloud_type, CloudType::File)
+    }
 }
 
 /// Build an [`ObjectStore`] based on the URL and passed in url. Return the cloud location and an implementation of the object store.
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 use std::ops::Range;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use futures::{Stream, StreamExt as _, TryStreamExt as _};
 use hashbrown::hash_map::RawEntryMut;
@@ -13,6 +13,7 @@ use polars_error::{PolarsError, PolarsResult};
 use polars_utils::pl_path::PlRefPath;
 use tokio::io::AsyncWriteExt;
 
+use super::block_cache::{BlockCache, BlockKey};
 use super::concurrency::IoSample;
 use super::concurrency_config::{ConcurrencyStrategy, FetchConfig, get_download_chunk_size};
 use crate::pl_async::{
@@ -71,12 +72,15 @@ mod inner {
     use std::borrow::Cow;
     use std::future::Future;
     use std::sync::Arc;
+    use std::time::Duration;
 
     use object_store::ObjectStore;
+    use object_store::path::Path;
     use polars_core::config;
     use polars_error::{PolarsError, PolarsResult};
     use polars_utils::relaxed_cell::RelaxedCell;
 
+    use crate::cloud::block_cache::{BLOCK_CACHE, BlockCache};
     use crate::cloud::concurrency::{ConcurrencyController, ControllerConfig};
     use crate::cloud::{ObjectStoreErrorContext, PolarsObjectStoreBuilder};
     use crate::metrics::{IOMetrics, OptIOMetrics};
@@ -95,6 +99,8 @@ mod inner {
         /// Avoid contending the Mutex `lock()` until the first re-build.
         initial_store: std::sync::Arc<dyn ObjectStore>,
         io_metrics: OptIOMetrics,
+        /// The block cache, if it is enabled. Local files are never cached.
+        block_cache: Option<&'static BlockCache>,
         /// In-flight concurrency control using the (new) BDP model.
         concurrency: Arc<std::sync::OnceLock<Arc<ConcurrencyController>>>,
     }
@@ -105,6 +111,7 @@ mod inner {
             builder: PolarsObjectStoreBuilder,
         ) -> Self {
             let initial_store = store.clone();
+            let block_cache = BLOCK_CACHE.as_ref().filter(|_| !builder.is_local());
             Self {
                 inner: Arc::new(Inner {
                     store: tokio::sync::RwLock::new(store),
@@ -113,6 +120,7 @@ mod inner {
                 }),
                 initial_store,
                 io_metrics: OptIOMetrics(None),
+                block_cache,
                 concurrency: Arc::new(std::sync::OnceLock::new()), // Arc::new(ConcurrencyController::new(ControllerConfig::default())),
             }
         }
@@ -218,9 +226,56 @@ and use the storage account keys from Azure CLI to authenticate"
         pub fn error_context(&self) -> ObjectStoreErrorContext {
             ObjectStoreErrorContext::new(self.inner.builder.path().clone())
         }
+
+        /// Returns the block cache if it is enabled. Local files are never cached.
+        pub(super) fn block_cache(&self) -> Option<&'static BlockCache> {
+            self.block_cache
+        }
+
+        #[cfg(test)]
+        pub(super) fn set_block_cache(&mut self, block_cache: Option<&'static BlockCache>) {
+            self.block_cache = block_cache;
+        }
+
+        pub(super) fn object_url(&self, path: &Path) -> String {
+            let base = self.inner.builder.path();
+            format!(
+                "{}/{}",
+                &base.as_str()[..base.authority_end_position()],
+                path
+            )
+        }
+
+        /// Returns the last observed version of the object at `path`, if it was observed within
+        /// `max_age`. Versions are shared through the block cache with other object stores.
+        pub(super) fn recent_object_version(
+            &self,
+            path: &Path,
+            max_age: Duration,
+        ) -> Option<String> {
+            self.block_cache()?
+                .recent_version(&self.object_url(path), max_age)
+        }
+
+        pub(super) fn set_object_version(&self, path: &Path, version: String) {
+            if let Some(block_cache) = self.block_cache() {
+                block_cache.set_version(self.object_url(path), version);
+            }
+        }
     }
 }
 
+/// Maximum age of an observed object version for it to be used to serve blocks from the block
+/// cache. Older versions are revalidated with a HEAD request first.
+const MAX_OBJECT_VERSION_AGE: Duration = Duration::from_secs(10);
+
+/// Version of an object, used to invalidate its cached blocks once it is modified.
+fn object_version(meta: &ObjectMeta) -> String {
+    meta.e_tag
+        .clone()
+        .unwrap_or_else(|| format!("{}-{}", meta.last_modified.timestamp_millis(), meta.size))
+}
+
 #[derive(Clone)]
 pub struct ObjectStoreErrorContext {
     path: PlRefPath,
@@ -251,6 +306,16 @@ impl PolarsObjectStore {
         path: &'a Path,
         ranges: T,
         strategy: ConcurrencyStrategy,
+    ) -> impl Stream<Item = PolarsResult<Buffer<u8>>> + use<'a, T> {
+        self.build_buffered_ranges_stream_impl(path, ranges, strategy, true)
+    }
+
+    fn build_buffered_ranges_stream_impl<'a, T: Iterator<Item = Range<usize>>>(
+        &'a self,
+        path: &'a Path,
+        ranges: T,
+        strategy: ConcurrencyStrategy,
+        use_block_cache: bool,
     ) -> impl Stream<Item = PolarsResult<Buffer<u8>>> + use<'a, T> {
         let controller = match strategy {
             ConcurrencyStrategy::BytesBased => Some(self.get_or_init_concurrency().clone()),
@@ -275,6 +340,19 @@ impl PolarsObjectStore {
                 if range.is_empty() {
                     return Ok(Buffer::new());
                 }
+
+                let block_key = if use_block_cache {
+                    self.block_key(path, &range).await
+                } else {
+                    None
+                };
+
+                if let Some((block_cache, key)) = &block_key
+                    && let Some(bytes) = block_cache.get(key).await
+                {
+                    return Ok(bytes);
+                }
+
                 let bytes_req = range.len() as u64;
 
                 // Held until end of block to bound in-flight bytes.
@@ -283,7 +361,7 @@ impl PolarsObjectStore {
                     None => None,
                 };
 
-                let (out, ttfb) = self
+                let (out, ttfb, version) = self
                     .io_metrics()
                     .record_io_read(
                         bytes_req,
@@ -299,9 +377,10 @@ impl PolarsObjectStore {
                                 )
                                 .await?;
                             let ttfb = t0.elapsed();
+                            let version = object_version(&response.meta);
                             let out = response.bytes().await?;
 
-                            Ok((out, ttfb))
+                            Ok((out, ttfb, version))
                         }),
                     )
                     .await?;
@@ -314,12 +393,63 @@ impl PolarsObjectStore {
                     });
                 }
 
-                Ok(Buffer::from_owner(out))
+                let out = Buffer::from_owner(out);
+
+                if let Some((block_cache, mut key)) = block_key {
+                    // The object was modified since its version was last observed.
+                    if key.version != version {
+                        self.set_object_version(path, version.clone());
+                        key.version = version;
+                    }
+
+                    // Not awaiting the disk write, the block is already cached in memory.
+                    drop(block_cache.insert(&key, &out));
+                }
+
+                Ok(out)
             }
         }))
         .buffered(n_buffered)
     }
 
+    /// Returns the block cache and the key of `range` of the object at `path`, if the block cache
+    /// is enabled. Returns `None` if the version of the object cannot be determined.
+    async fn block_key(
+        &self,
+        path: &Path,
+        range: &Range<usize>,
+    ) -> Option<(&'static BlockCache, BlockKey)> {
+        let block_cache = self.block_cache()?;
+
+        let version = match self.recent_object_version(path, MAX_OBJECT_VERSION_AGE) {
+            Some(version) => version,
+            None => {
+                // Not using `head()`, as it takes from the concurrency budget that may already be
+                // held by the caller.
+                let meta = self
+                    .io_metrics()
+                    .record_io_read(
+                        0,
+                        self.exec_with_rebuild_retry_on_err(|s| async move { s.head(path).await }),
+                    )
+                    .await
+                    .ok()?;
+                let version = object_version(&meta);
+                self.set_object_version(path, version.clone());
+                version
+            },
+        };
+
+        Some((
+            block_cache,
+            BlockKey {
+                url: self.object_url(path),
+                version,
+                range: range.clone(),
+            },
+        ))
+    }
+
     pub async fn get_range(
         &self,
         path: &Path,
@@ -487,8 +617,13 @@ impl PolarsObjectStore {
         tune_with_concurrency_budget(
             parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
             || async {
-                let mut stream =
-                    self.build_buffered_ranges_stream(path, parts, ConcurrencyStrategy::Unbounded);
+                // Whole files are cached by the file cache instead.
+                let mut stream = self.build_buffered_ranges_stream_impl(
+                    path,
+                    parts,
+                    ConcurrencyStrategy::Unbounded,
+                    false,
+                );
                 let mut len = 0;
                 while let Some(bytes) = stream.try_next().await? {
                     len += bytes.len();
@@ -566,6 +701,10 @@ impl PolarsObjectStore {
 
                     let out = head_result?;
 
+                    if self.block_cache().is_some() {
+                        self.set_object_version(path, object_version(&out));
+                    }
+
                     Ok(out)
                 }
             })
@@ -807,4 +946,64 @@ mod tests {
             [(0..80 * 1024 * 1024, 2)]
         );
     }
+
+    #[tokio::test]
+    async fn test_block_cache() -> polars_error::PolarsResult<()> {
+        use std::sync::Arc;
+
+        use object_store::memory::InMemory;
+        use object_store::{ObjectStore, ObjectStoreExt};
+        use polars_error::PolarsResult;
+        use polars_utils::pl_path::PlRefPath;
+
+        use crate::cloud::block_cache::BlockCache;
+        use crate::cloud::concurrency_config::{ConcurrencyStrategy, FetchConfig};
+        use crate::cloud::{
+            CloudOptions, ExtObjectStoreBuilder, build_object_store,
+            deregister_object_store_builder, object_path_from_str, register_object_store_builder,
+        };
+
+        struct TestBuilder(Arc<InMemory>);
+
+        impl ExtObjectStoreBuilder for TestBuilder {
+            fn build(
+                &self,
+                _url: &PlRefPath,
+                _options: Option<&CloudOptions>,
+            ) -> PolarsResult<Arc<dyn ObjectStore + Send + Sync>> {
+                Ok(self.0.clone())
+            }
+        }
+
+        let memory = Arc::new(InMemory::new());
+        polars_utils::pl_path::_allow_ext_scheme("pl-test-block-cache")?;
+        register_object_store_builder("pl-test-block-cache", Arc::new(TestBuilder(memory.clone())))
+            .unwrap();
+
+        let (cloud_location, mut store) = build_object_store(
+            PlRefPath::new("pl-test-block-cache://host/data/file.parquet"),
+            None,
+            false,
+        )
+        .await?;
+        store.set_block_cache(Some(Box::leak(Box::new(BlockCache::new(1024, None)))));
+        let path = object_path_from_str(&cloud_location.prefix)?;
+
+        memory.put(&path, b"v1-data".to_vec().into()).await.unwrap();
+        let get = || store.get_range(&path, 0..7, FetchConfig::random_access());
+        assert_eq!(&*get().await?, b"v1-data");
+
+        // Modified without observing the new version, the block is served from the cache.
+        memory.put(&path, b"v2-data".to_vec().into()).await.unwrap();
+        assert_eq!(&*get().await?, b"v1-data");
+
+        // Observing the new version invalidates the cached block.
+        store.head(&path, ConcurrencyStrategy::BytesBased).await?;
+        assert_eq!(&*get().await?, b"v2-data");
+        assert_eq!(&*get().await?, b"v2-data");
+
+        deregister_object_store_builder("pl-test-block-cache");
+        polars_utils::pl_path::_disallow_ext_scheme("pl-test-block-cache");
+        Ok(())
+    }
 }
@@ -14,6 +14,7 @@ use super::CsvParseOptions;
 use super::builder::Builder;
 use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
+use crate::metrics::IOMetrics;
 use crate::prelude::CsvReadOptions;
 use crate::prelude::streaming::read_until_start_and_infer_schema;
 use crate::utils::compression::ByteSourceReader;
@@ -32,6 +33,7 @@ pub fn count_rows(
     skip_rows_before_header: usize,
     skip_rows_after_header: usize,
     raise_if_empty: bool,
+    io_metrics: Option<Arc<IOMetrics>>,
 ) -> PolarsResult<usize> {
     let file = if path.has_scheme() || polars_config::config().force_async() {
         feature_gated!("cloud", {
@@ -39,7 +41,7 @@ pub fn count_rows(
                 .get_entry(path)
                 // Safety: This was initialized by schema inference.
                 .unwrap()
-                .try_open_assume_latest()?
+                .try_open_assume_latest(&crate::metrics::OptIOMetrics(io_metrics))?
         })
     } else {
         polars_utils::open_file(path.as_std_path())?
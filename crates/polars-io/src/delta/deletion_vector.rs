@@ -0,0 +1,406 @@
+//! Decoding of Delta deletion vectors.
+//!
+//! Reference: <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vector-format>
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::pl_path::PlRefPath;
+
+use super::models::DeletionVectorDescriptor;
+
+/// Magic number at the start of a serialized `RoaringBitmapArray`.
+const PORTABLE_ROARING_BITMAP_MAGIC: u32 = 1681511377;
+/// Cookie of a serialized roaring bitmap that contains run containers.
+const SERIAL_COOKIE: u32 = 12347;
+/// Cookie of a serialized roaring bitmap without run containers.
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+/// Containers with a cardinality up to this are serialized as sorted arrays.
+const MAX_ARRAY_CONTAINER_CARDINALITY: usize = 4096;
+/// Below this number of containers, the offset header is omitted if run containers exist.
+const NO_OFFSET_THRESHOLD: usize = 4;
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// The deletion vector of a data file, with the location resolved against the table root.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum DeletionVector {
+    /// Serialized bitmap stored inline in the transaction log.
+    Inline(Vec<u8>),
+    /// Serialized bitmap stored at `offset` in a deletion vector file.
+    File {
+        path: String,
+        offset: u64,
+        size_in_bytes: u64,
+    },
+}
+
+impl DeletionVector {
+    pub(super) fn try_resolve(
+        descriptor: &DeletionVectorDescriptor,
+        table_root: &PlRefPath,
+    ) -> PolarsResult<Self> {
+        let size_in_bytes = u64::try_from(descriptor.size_in_bytes).map_err(|_| invalid_dv())?;
+        let offset = u64::try_from(descriptor.offset.unwrap_or(1)).map_err(|_| invalid_dv())?;
+
+        Ok(match descriptor.storage_type.as_str() {
+            "i" => {
+                let mut data = z85_decode(&descriptor.path_or_inline_dv)?;
+                polars_ensure!(data.len() as u64 >= size_in_bytes, ComputeError: invalid_dv_msg());
+                data.truncate(size_in_bytes as usize);
+                Self::Inline(data)
+            },
+            "u" => {
+                let encoded = descriptor.path_or_inline_dv.as_str();
+                let Some(split) = encoded.len().checked_sub(20) else {
+                    return Err(invalid_dv());
+                };
+                let (prefix, uuid) = encoded.split_at(split);
+                let uuid = format_uuid(&z85_decode(uuid)?);
+                let file_name = format!("deletion_vector_{uuid}.bin");
+                let path = if prefix.is_empty() {
+                    table_root.join(file_name)
+                } else {
+                    table_root.join(prefix).join(file_name)
+                };
+
+                Self::File {
+                    path: path.as_str().to_string(),
+                    offset,
+                    size_in_bytes,
+                }
+            },
+            "p" => Self::File {
+                path: super::log::decode_log_path(&descriptor.path_or_inline_dv, table_root)?
+                    .as_str()
+                    .to_string(),
+                offset,
+                size_in_bytes,
+            },
+            v => polars_bail!(
+                ComputeError:
+                "unknown storage type of Delta deletion vector: {v}"
+            ),
+        })
+    }
+
+    /// Loads the deletion vector as a selection mask, i.e. `false` for deleted rows.
+    ///
+    /// The mask ends at the last deleted row, rows past its end are not deleted.
+    pub fn load_selection_mask(&self) -> PolarsResult<Bitmap> {
+        let deleted_rows = match self {
+            Self::Inline(data) => decode_roaring_bitmap_array(data)?,
+            Self::File {
+                path,
+                offset,
+                size_in_bytes,
+            } => {
+                let path = PlRefPath::new(path.as_str());
+                polars_ensure!(
+                    path.scheme().is_none_or(|x| x.is_file()),
+                    nyi = "reading Delta deletion vectors from cloud storage"
+                );
+                let bytes = std::fs::read(path.strip_scheme())
+                    .map_err(|e| polars_err!(ComputeError: "failed to read Delta deletion vector {}: {}", path, e))?;
+                decode_file_contents(&bytes, *offset as usize, *size_in_bytes as usize)?
+            },
+        };
+
+        let len = deleted_rows.iter().max().map_or(0, |x| *x as usize + 1);
+        let mut mask = MutableBitmap::from_len_set(len);
+        for row in deleted_rows {
+            mask.set(row as usize, false);
+        }
+        Ok(mask.freeze())
+    }
+}
+
+fn invalid_dv_msg() -> &'static str {
+    "invalid Delta deletion vector"
+}
+
+fn invalid_dv() -> polars_error::PolarsError {
+    polars_err!(ComputeError: invalid_dv_msg())
+}
+
+/// Reads the serialized bitmap at `offset` of a deletion vector file. The data is prefixed by its
+/// big-endian size and followed by a checksum.
+fn decode_file_contents(
+    bytes: &[u8],
+    offset: usize,
+    size_in_bytes: usize,
+) -> PolarsResult<Vec<u64>> {
+    polars_ensure!(bytes.first() == Some(&1), ComputeError: invalid_dv_msg());
+    let data = bytes
+        .get(offset..)
+        .and_then(|x| x.split_first_chunk::<4>())
+        .filter(|(size, _)| u32::from_be_bytes(**size) as usize == size_in_bytes)
+        .and_then(|(_, data)| data.get(..size_in_bytes))
+        .ok_or_else(invalid_dv)?;
+    decode_roaring_bitmap_array(data)
+}
+
+/// Decodes the row indices of a serialized `RoaringBitmapArray`, which consists of 32-bit
+/// roaring bitmaps for each of the high 32 bits of the row indices.
+fn decode_roaring_bitmap_array(data: &[u8]) -> PolarsResult<Vec<u64>> {
+    let mut reader = Reader(data);
+    polars_ensure!(
+        reader.u32()? == PORTABLE_ROARING_BITMAP_MAGIC,
+        ComputeError: invalid_dv_msg()
+    );
+
+    let num_bitmaps = reader.u64()?;
+    let mut out = vec![];
+    for _ in 0..num_bitmaps {
+        let high = (reader.u32()? as u64) << 32;
+        decode_roaring_bitmap(&mut reader, |low| out.push(high | low as u64))?;
+    }
+    Ok(out)
+}
+
+/// Decodes a roaring bitmap in the portable serialization format.
+///
+/// Reference: <https://github.com/RoaringBitmap/RoaringFormatSpec>
+fn decode_roaring_bitmap(reader: &mut Reader, mut f: impl FnMut(u32)) -> PolarsResult<()> {
+    let cookie = reader.u32()?;
+    let (num_containers, run_flags) = if cookie & 0xFFFF == SERIAL_COOKIE {
+        let num_containers = (cookie >> 16) as usize + 1;
+        (
+            num_containers,
+            Some(reader.bytes(num_containers.div_ceil(8))?),
+        )
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None)
+    } else {
+        return Err(invalid_dv());
+    };
+
+    let header = reader.bytes(4 * num_containers)?;
+    if run_flags.is_none() || num_containers >= NO_OFFSET_THRESHOLD {
+        reader.bytes(4 * num_containers)?;
+    }
+
+    for (i, key_and_cardinality) in header.chunks_exact(4).enumerate() {
+        let key =
+            (u16::from_le_bytes([key_and_cardinality[0], key_and_cardinality[1]]) as u32) << 16;
+        let cardinality =
+            u16::from_le_bytes([key_and_cardinality[2], key_and_cardinality[3]]) as usize + 1;
+        let is_run = run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+
+        if is_run {
+            let num_runs = reader.u16()?;
+            for _ in 0..num_runs {
+                let start = reader.u16()? as u32;
+                let len = reader.u16()? as u32;
+                (start..=start + len).for_each(|x| f(key | x));
+            }
+        } else if cardinality <= MAX_ARRAY_CONTAINER_CARDINALITY {
+            for _ in 0..cardinality {
+                f(key | reader.u16()? as u32);
+            }
+        } else {
+            let words = reader.bytes(8192)?;
+            for (w, word) in words.chunks_exact(8).enumerate() {
+                let mut word = u64::from_le_bytes(word.try_into().unwrap());
+                while word != 0 {
+                    f(key | (w as u32 * 64 + word.trailing_zeros()));
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        let (out, rest) = self.0.split_at_checked(n).ok_or_else(invalid_dv)?;
+        self.0 = rest;
+        Ok(out)
+    }
+
+    fn u16(&mut self) -> PolarsResult<u16> {
+        Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> PolarsResult<u64> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+}
+
+/// Decodes Z85 (a base85 variant), which encodes every 4 bytes as 5 characters.
+fn z85_decode(s: &str) -> PolarsResult<Vec<u8>> {
+    polars_ensure!(s.len() % 5 == 0, ComputeError: invalid_dv_msg());
+
+    let mut out = Vec::with_capacity(s.len() / 5 * 4);
+    for chunk in s.as_bytes().chunks_exact(5) {
+        let mut value: u64 = 0;
+        for c in chunk {
+            let digit = Z85_ALPHABET
+                .iter()
+                .position(|x| x == c)
+                .ok_or_else(invalid_dv)?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value).map_err(|_| invalid_dv())?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(out)
+}
+
+fn format_uuid(bytes: &[u8]) -> String {
+    let hex = bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    pub(crate) fn z85_encode(bytes: &[u8]) -> String {
+        assert!(bytes.len() % 4 == 0);
+        let mut out = String::new();
+        for chunk in bytes.chunks_exact(4) {
+            let mut value = u32::from_be_bytes(chunk.try_into().unwrap()) as u64;
+            let mut digits = [0u8; 5];
+            for d in digits.iter_mut().rev() {
+                *d = Z85_ALPHABET[(value % 85) as usize];
+                value /= 85;
+            }
+            out.push_str(std::str::from_utf8(&digits).unwrap());
+        }
+        out
+    }
+
+    /// Serializes row indices below 2^32 as a `RoaringBitmapArray` with array containers.
+    pub(crate) fn encode_roaring_bitmap_array(rows: &[u32]) -> Vec<u8> {
+        let mut out = PORTABLE_ROARING_BITMAP_MAGIC.to_le_bytes().to_vec();
+        out.extend_from_slice(&1u64.to_le_bytes());
+        out.extend_from_slice(&0u32.to_le_bytes());
+
+        let mut containers = std::collections::BTreeMap::<u16, Vec<u16>>::new();
+        for row in rows {
+            containers
+                .entry((row >> 16) as u16)
+                .or_default()
+                .push(*row as u16);
+        }
+        out.extend_from_slice(&SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        out.extend_from_slice(&(containers.len() as u32).to_le_bytes());
+        for (key, values) in &containers {
+            out.extend_from_slice(&key.to_le_bytes());
+            out.extend_from_slice(&(values.len() as u16 - 1).to_le_bytes());
+        }
+        let mut offset = 8 + 8 * containers.len() as u32;
+        for values in containers.values() {
+            out.extend_from_slice(&offset.to_le_bytes());
+            offset += 2 * values.len() as u32;
+        }
+        for values in containers.values() {
+            for v in values {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_z85_round_trip() {
+        let bytes = (0..=255).collect::<Vec<u8>>();
+        assert_eq!(z85_decode(&z85_encode(&bytes)).unwrap(), bytes);
+        // Example from the Z85 specification.
+        assert_eq!(
+            z85_decode("HelloWorld").unwrap(),
+            [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]
+        );
+        assert!(z85_decode("Hell").is_err());
+    }
+
+    #[test]
+    fn test_decode_array_containers() {
+        let rows = [0, 3, 4, 70_000, 70_001];
+        let data = encode_roaring_bitmap_array(&rows);
+        let decoded = decode_roaring_bitmap_array(&data).unwrap();
+        assert_eq!(decoded, rows.map(|x| x as u64));
+    }
+
+    #[test]
+    fn test_decode_run_and_bitmap_containers() {
+        let mut data = PORTABLE_ROARING_BITMAP_MAGIC.to_le_bytes().to_vec();
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        // Two containers, of which the first is a run container.
+        data.extend_from_slice(&(SERIAL_COOKIE | (1 << 16)).to_le_bytes());
+        data.push(0b01);
+        data.extend_from_slice(&[0, 0, 4, 0]);
+        data.extend_from_slice(&[1, 0, 0xFF, 0x1F]);
+        // Run container: [10, 14].
+        data.extend_from_slice(&[1, 0, 10, 0, 4, 0]);
+        // Bitmap container with all even values set.
+        data.extend((0..1024).flat_map(|_| 0x5555_5555_5555_5555u64.to_le_bytes()));
+
+        let decoded = decode_roaring_bitmap_array(&data).unwrap();
+        let high = 1u64 << 32;
+        let expected = (10..15)
+            .map(|x| high | x)
+            .chain((0..65536).step_by(2).map(|x| high | (1 << 16) | x))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn test_inline_selection_mask() {
+        let mut data = encode_roaring_bitmap_array(&[1, 3]);
+        let size_in_bytes = data.len();
+        data.resize(data.len().next_multiple_of(4), 0);
+
+        let descriptor = DeletionVectorDescriptor {
+            storage_type: "i".into(),
+            path_or_inline_dv: z85_encode(&data),
+            offset: None,
+            size_in_bytes: size_in_bytes as i32,
+            cardinality: 2,
+        };
+        let dv = DeletionVector::try_resolve(&descriptor, &PlRefPath::new("/table")).unwrap();
+        let mask = dv.load_selection_mask().unwrap();
+        assert_eq!(mask.iter().collect::<Vec<_>>(), [true, false, true, false]);
+
+        assert!(decode_roaring_bitmap_array(&data[..10]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_uuid_path() {
+        let uuid = (0..16).collect::<Vec<u8>>();
+        let descriptor = DeletionVectorDescriptor {
+            storage_type: "u".into(),
+            path_or_inline_dv: format!("ab{}", z85_encode(&uuid)),
+            offset: Some(1),
+            size_in_bytes: 10,
+            cardinality: 1,
+        };
+        let DeletionVector::File { path, .. } =
+            DeletionVector::try_resolve(&descriptor, &PlRefPath::new("/table")).unwrap()
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            path,
+            "/table/ab/deletion_vector_00010203-0405-0607-0809-0a0b0c0d0e0f.bin"
+        );
+    }
+}
@@ -0,0 +1,850 @@
+//! Replay of the Delta transaction log into the snapshot of a table version.
+//!
+//! Reference: <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#action-reconciliation>
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err, to_compute_err};
+use polars_utils::pl_path::PlRefPath;
+
+use super::deletion_vector::DeletionVector;
+use super::models::{Action, Add, DeletionVectorDescriptor, Metadata, Protocol, Stats};
+use crate::SerReader;
+use crate::catalog::unity::schema::parse_type_json_str;
+use crate::parquet::read::ParquetReader;
+
+/// Reader features of the Delta protocol that are supported.
+const SUPPORTED_READER_FEATURES: &[&str] = &[
+    "columnMapping",
+    "deletionVectors",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+/// The version of a Delta table to read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeltaTimeTravel {
+    #[default]
+    Latest,
+    /// Read the table as of this version.
+    Version(u64),
+    /// Read the latest version committed at or before this timestamp, in milliseconds since
+    /// the Unix epoch.
+    Timestamp(i64),
+}
+
+/// A data file of a Delta table snapshot.
+#[derive(Debug, Clone)]
+pub struct DeltaFile {
+    pub path: PlRefPath,
+    /// Number of rows in the file, if recorded in its statistics.
+    pub num_records: Option<u64>,
+    pub deletion_vector: Option<DeletionVector>,
+    /// Number of rows deleted by the deletion vector.
+    pub num_deleted_rows: u64,
+    /// Serialized values of the partition columns, as recorded in the log.
+    pub partition_values: BTreeMap<String, Option<String>>,
+}
+
+/// The state of a Delta table at a specific version.
+#[derive(Debug, Clone)]
+pub struct DeltaSnapshot {
+    pub version: u64,
+    /// Schema of the table, including the partition columns.
+    pub schema: SchemaRef,
+    pub partition_columns: Vec<PlSmallStr>,
+    pub files: Vec<DeltaFile>,
+}
+
+impl DeltaSnapshot {
+    /// Loads the snapshot of the Delta table at `table_root` by replaying its transaction log.
+    ///
+    /// Only tables on the local filesystem are supported.
+    pub fn try_new(table_root: &PlRefPath, time_travel: DeltaTimeTravel) -> PolarsResult<Self> {
+        polars_ensure!(
+            table_root.scheme().is_none_or(|x| x.is_file()),
+            nyi = "native scan of Delta tables in cloud storage"
+        );
+        let table_root = table_root.to_absolute_path()?.into_owned();
+        let log = LogFiles::try_new(&Path::new(table_root.strip_scheme()).join("_delta_log"))?;
+        let version = log.resolve_version(time_travel)?;
+
+        let mut state = ReplayState::default();
+        let (checkpoint, commits) = log.replay_files(version)?;
+        for part in checkpoint {
+            state.apply_checkpoint(&ParquetReader::new(File::open(part)?).finish()?)?;
+        }
+        for path in commits {
+            for action in read_commit(path)? {
+                state.apply(action);
+            }
+        }
+
+        state.finish(version, &table_root)
+    }
+
+    /// Returns the schema of the data files, which excludes the partition columns.
+    pub fn file_schema(&self) -> SchemaRef {
+        if self.partition_columns.is_empty() {
+            return self.schema.clone();
+        }
+
+        Arc::new(
+            self.schema
+                .iter()
+                .filter(|(name, _)| !self.partition_columns.contains(name))
+                .map(|(name, dtype)| (name.clone(), dtype.clone()))
+                .collect(),
+        )
+    }
+
+    /// Returns the values of the partition columns for each file, as recorded in the log, or
+    /// `None` if the table is not partitioned.
+    pub fn partition_values(&self) -> PolarsResult<Option<DataFrame>> {
+        if self.partition_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let columns = self
+            .partition_columns
+            .iter()
+            .map(|name| {
+                let dtype = self.schema.get(name).unwrap();
+                let values = StringChunked::from_iter_options(
+                    name.clone(),
+                    self.files.iter().map(|file| {
+                        file.partition_values
+                            .get(name.as_str())
+                            .and_then(|x| x.as_deref())
+                            .filter(|x| {
+                                *x != "__HIVE_DEFAULT_PARTITION__"
+                                    && (!x.is_empty() || dtype == &DataType::String)
+                            })
+                    }),
+                );
+
+                parse_partition_values(&values, dtype)
+                    .map(Column::from)
+                    .map_err(|e| {
+                        e.wrap_msg(|msg| format!("invalid Delta partition value for {name}: {msg}"))
+                    })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        DataFrame::new(self.files.len(), columns).map(Some)
+    }
+
+    /// Returns the `(physical, deleted)` row counts, if they are recorded for all files.
+    pub fn row_count(&self) -> Option<(u64, u64)> {
+        self.files
+            .iter()
+            .try_fold((0, 0), |(physical, deleted), file| {
+                Some((
+                    physical + file.num_records?,
+                    deleted + file.num_deleted_rows,
+                ))
+            })
+    }
+}
+
+/// Decodes a path from the log, which is a URI that is either absolute or relative to the table
+/// root.
+pub(super) fn decode_log_path(path: &str, table_root: &PlRefPath) -> PolarsResult<PlRefPath> {
+    let decoded = percent_encoding::percent_decode_str(path)
+        .decode_utf8()
+        .map_err(to_compute_err)?;
+    let decoded = PlRefPath::new(decoded.as_ref());
+
+    Ok(match decoded.scheme() {
+        Some(scheme) if scheme.is_file() => PlRefPath::new(decoded.strip_scheme()),
+        Some(_) => decoded,
+        None => table_root.join(decoded.as_str()),
+    })
+}
+
+/// The files of the Delta log, grouped by version.
+pub(super) struct LogFiles<P> {
+    commits: BTreeMap<u64, P>,
+    /// Complete checkpoints, with the paths of all of their parts.
+    checkpoints: BTreeMap<u64, Vec<P>>,
+}
+
+impl<P> LogFiles<P> {
+    /// Groups the `(file_name, path)` entries of the log directory by version. Entries that are
+    /// not commits or checkpoints are ignored.
+    pub(super) fn from_files<S: AsRef<str>>(files: impl IntoIterator<Item = (S, P)>) -> Self {
+        let mut commits = BTreeMap::new();
+        let mut checkpoints = BTreeMap::new();
+        // (version, num_parts) -> [(part, path)]
+        let mut multi_part_checkpoints = BTreeMap::<(u64, u64), Vec<(u64, P)>>::new();
+
+        for (name, path) in files {
+            let Some((version, suffix)) = name.as_ref().split_once('.') else {
+                continue;
+            };
+            let Some(version) = (version.len() == 20)
+                .then(|| version.parse::<u64>().ok())
+                .flatten()
+            else {
+                continue;
+            };
+
+            match suffix {
+                "json" => {
+                    commits.insert(version, path);
+                },
+                "checkpoint.parquet" => {
+                    checkpoints.insert(version, vec![path]);
+                },
+                _ => {
+                    // e.g. 00000000000000000010.checkpoint.0000000001.0000000002.parquet
+                    let parts = suffix
+                        .strip_prefix("checkpoint.")
+                        .and_then(|x| x.strip_suffix(".parquet"))
+                        .and_then(|x| x.split_once('.'))
+                        .and_then(|(part, n)| Some((part.parse().ok()?, n.parse().ok()?)));
+                    if let Some((part, num_parts)) = parts {
+                        multi_part_checkpoints
+                            .entry((version, num_parts))
+                            .or_default()
+                            .push((part, path));
+                    }
+                },
+            }
+        }
+
+        for ((version, num_parts), mut parts) in multi_part_checkpoints {
+            if parts.len() as u64 == num_parts {
+                parts.sort_by_key(|(part, _)| *part);
+                checkpoints
+                    .entry(version)
+                    .or_insert_with(|| parts.into_iter().map(|(_, path)| path).collect());
+            }
+        }
+
+        Self {
+            commits,
+            checkpoints,
+        }
+    }
+
+    /// Returns the latest version of the table, or `None` if the log is empty.
+    pub(super) fn latest_version(&self) -> Option<u64> {
+        self.commits
+            .keys()
+            .chain(self.checkpoints.keys())
+            .max()
+            .copied()
+    }
+
+    /// Returns the parts of the latest checkpoint at or before `version`, and the commits after
+    /// it that must be replayed to reconstruct `version`.
+    pub(super) fn replay_files(&self, version: u64) -> PolarsResult<(&[P], Vec<&P>)> {
+        let checkpoint = self.checkpoints.range(..=version).next_back();
+        let start = checkpoint.map_or(0, |(v, _)| v + 1);
+        let commits = (start..=version)
+            .map(|v| {
+                self.commits.get(&v).ok_or_else(
+                    || polars_err!(ComputeError: "Delta log is missing the commit of version {}", v),
+                )
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        Ok((checkpoint.map_or(&[], |(_, parts)| parts), commits))
+    }
+}
+
+impl LogFiles<PathBuf> {
+    fn try_new(log_dir: &Path) -> PolarsResult<Self> {
+        let entries = std::fs::read_dir(log_dir).map_err(|e| {
+            polars_err!(
+                ComputeError:
+                "failed to list the Delta log at {}: {}", log_dir.display(), e
+            )
+        })?;
+
+        let mut files = vec![];
+        for entry in entries {
+            let path = entry?.path();
+            if let Some(name) = path.file_name().and_then(|x| x.to_str()) {
+                files.push((name.to_string(), path));
+            }
+        }
+
+        let log = Self::from_files(files);
+        polars_ensure!(
+            log.latest_version().is_some(),
+            ComputeError: "no Delta table found: {} contains no commits", log_dir.display()
+        );
+
+        Ok(log)
+    }
+
+    fn resolve_version(&self, time_travel: DeltaTimeTravel) -> PolarsResult<u64> {
+        let latest = self.latest_version().unwrap();
+
+        Ok(match time_travel {
+            DeltaTimeTravel::Latest => latest,
+            DeltaTimeTravel::Version(version) => {
+                polars_ensure!(
+                    self.commits.contains_key(&version) || self.checkpoints.contains_key(&version),
+                    ComputeError:
+                    "version {} of the Delta table is not available, the latest version is {}",
+                    version, latest
+                );
+                version
+            },
+            DeltaTimeTravel::Timestamp(timestamp) => {
+                for (version, path) in self.commits.iter().rev() {
+                    if commit_timestamp(path)? <= timestamp {
+                        return Ok(*version);
+                    }
+                }
+                polars_bail!(
+                    ComputeError:
+                    "timestamp {} is before the earliest available commit of the Delta table",
+                    timestamp
+                )
+            },
+        })
+    }
+}
+
+/// The timestamp of a commit in milliseconds. This is the in-commit timestamp if enabled, or
+/// otherwise the modification time of the commit file.
+fn commit_timestamp(path: &Path) -> PolarsResult<i64> {
+    // If enabled, the in-commit timestamp is stored in the first action.
+    if let Some(timestamp) = read_commit(path)?
+        .first()
+        .and_then(|x| x.commit_info.as_ref())
+        .and_then(|x| x.in_commit_timestamp)
+    {
+        return Ok(timestamp);
+    }
+
+    let modified = std::fs::metadata(path)?.modified()?;
+    let since_epoch = modified
+        .duration_since(UNIX_EPOCH)
+        .map_err(to_compute_err)?;
+    Ok(since_epoch.as_millis() as i64)
+}
+
+fn read_commit(path: &Path) -> PolarsResult<Vec<Action>> {
+    parse_commit(&std::fs::read(path)?, &path.display())
+}
+
+/// Parses the actions of a commit file, which contains one JSON action per line.
+pub(super) fn parse_commit(
+    bytes: &[u8],
+    path: &dyn std::fmt::Display,
+) -> PolarsResult<Vec<Action>> {
+    bytes
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.trim_ascii().is_empty())
+        .map(|line| {
+            serde_json::from_slice::<Action>(line).map_err(
+                |e| polars_err!(ComputeError: "failed to parse Delta commit {}: {}", path, e),
+            )
+        })
+        .collect()
+}
+
+#[derive(Default)]
+pub(super) struct ReplayState {
+    pub protocol: Option<Protocol>,
+    pub metadata: Option<Metadata>,
+    /// Active files, keyed by their path and deletion vector id, with the order in which they
+    /// were added.
+    files: PlHashMap<(String, Option<String>), (usize, Add)>,
+    num_added: usize,
+}
+
+impl ReplayState {
+    pub(super) fn apply(&mut self, action: Action) {
+        if let Some(protocol) = action.protocol {
+            self.protocol = Some(protocol);
+        }
+        if let Some(metadata) = action.meta_data {
+            self.metadata = Some(metadata);
+        }
+        if let Some(add) = action.add {
+            self.add(add);
+        }
+        if let Some(remove) = action.remove {
+            let dv_id = remove.deletion_vector.as_ref().map(|x| x.unique_id());
+            self.files.remove(&(remove.path, dv_id));
+        }
+    }
+
+    fn add(&mut self, add: Add) {
+        let dv_id = add.deletion_vector.as_ref().map(|x| x.unique_id());
+        self.files
+            .insert((add.path.clone(), dv_id), (self.num_added, add));
+        self.num_added += 1;
+    }
+
+    /// Applies the actions of a checkpoint. The `remove` actions in checkpoints are tombstones
+    /// of files that are no longer part of the table, so these are skipped.
+    pub(super) fn apply_checkpoint(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let checkpoint = CheckpointReader { df };
+
+        if let Some(protocol) = checkpoint.actions("protocol")? {
+            for i in protocol.valid_rows() {
+                self.protocol = Some(Protocol {
+                    min_reader_version: protocol.i64("minReaderVersion", i)?.unwrap_or(1) as i32,
+                    min_writer_version: protocol.i64("minWriterVersion", i)?.unwrap_or(2) as i32,
+                    reader_features: protocol.str_list("readerFeatures", i)?,
+                    writer_features: protocol.str_list("writerFeatures", i)?,
+                });
+            }
+        }
+
+        if let Some(metadata) = checkpoint.actions("metaData")? {
+            for i in metadata.valid_rows() {
+                self.metadata = Some(Metadata {
+                    schema_string: metadata.str("schemaString", i)?.unwrap_or_default(),
+                    partition_columns: metadata
+                        .str_list("partitionColumns", i)?
+                        .unwrap_or_default(),
+                    configuration: metadata.str_map("configuration", i)?,
+                });
+            }
+        }
+
+        if let Some(add) = checkpoint.actions("add")? {
+            let dv = add
+                .field("deletionVector")?
+                .map(|x| ActionColumn::new(&x))
+                .transpose()?;
+
+            for i in add.valid_rows() {
+                let deletion_vector = match &dv {
+                    Some(dv) if dv.is_valid(i) => Some(DeletionVectorDescriptor {
+                        storage_type: dv.str("storageType", i)?.unwrap_or_default(),
+                        path_or_inline_dv: dv.str("pathOrInlineDv", i)?.unwrap_or_default(),
+                        offset: dv.i64("offset", i)?.map(|x| x as i32),
+                        size_in_bytes: dv.i64("sizeInBytes", i)?.unwrap_or_default() as i32,
+                        cardinality: dv.i64("cardinality", i)?.unwrap_or_default(),
+                    }),
+                    _ => None,
+                };
+
+                self.add(Add {
+                    path: add.str("path", i)?.unwrap_or_default(),
+                    partition_values: add.str_map("partitionValues", i)?,
+                    stats: add.str("stats", i)?,
+                    deletion_vector,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the active files, in the order in which they were added.
+    pub(super) fn into_files(self) -> Vec<Add> {
+        sorted_files(self.files)
+    }
+
+    fn finish(self, version: u64, table_root: &PlRefPath) -> PolarsResult<DeltaSnapshot> {
+        let (Some(protocol), Some(metadata)) = (self.protocol, self.metadata) else {
+            polars_bail!(
+                ComputeError:
+                "Delta log is missing the protocol or metadata of version {}", version
+            )
+        };
+        check_protocol(&protocol, &metadata)?;
+
+        let DataType::Struct(fields) = parse_type_json_str(&metadata.schema_string)? else {
+            polars_bail!(ComputeError: "Delta table schema is not a struct")
+        };
+        let schema = Schema::from_iter(fields);
+        let partition_columns = metadata
+            .partition_columns
+            .iter()
+            .map(|name| {
+                polars_ensure!(
+                    schema.contains(name),
+                    ComputeError: "Delta partition column {} is not in the table schema", name
+                );
+                Ok(PlSmallStr::from_str(name))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let files = sorted_files(self.files)
+            .into_iter()
+            .map(|add| {
+                let path = decode_log_path(&add.path, table_root)?;
+                let num_records = add
+                    .stats
+                    .as_deref()
+                    .and_then(|x| serde_json::from_str::<Stats>(x).ok())
+                    .and_then(|x| x.num_records);
+                let (deletion_vector, num_deleted_rows) = match &add.deletion_vector {
+                    Some(dv) => (
+                        Some(DeletionVector::try_resolve(dv, table_root)?),
+                        dv.cardinality as u64,
+                    ),
+                    None => (None, 0),
+                };
+
+                Ok(DeltaFile {
+                    path,
+                    num_records,
+                    deletion_vector,
+                    num_deleted_rows,
+                    partition_values: add.partition_values,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(DeltaSnapshot {
+            version,
+            schema: Arc::new(schema),
+            partition_columns,
+            files,
+        })
+    }
+}
+
+/// Parses the serialized values of a partition column.
+///
+/// Reference: <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#partition-value-serialization>
+fn parse_partition_values(values: &StringChunked, dtype: &DataType) -> PolarsResult<Series> {
+    match dtype {
+        DataType::Boolean => values
+            .iter()
+            .map(|x| {
+                x.map(|x| match x {
+                    "true" => Ok(true),
+                    "false" => Ok(false),
+                    _ => polars_bail!(ComputeError: "expected a boolean, got '{}'", x),
+                })
+                .transpose()
+            })
+            .collect::<PolarsResult<BooleanChunked>>()
+            .map(|ca| ca.with_name(values.name().clone()).into_series()),
+        DataType::Datetime(time_unit, _) => values
+            .iter()
+            .map(|x| x.map(|x| parse_timestamp(x, *time_unit)).transpose())
+            .collect::<PolarsResult<Int64Chunked>>()?
+            .with_name(values.name().clone())
+            .cast(dtype),
+        _ => values.cast_with_options(dtype, CastOptions::Strict),
+    }
+}
+
+/// Parses a serialized timestamp, which is either `{date} {time}` in UTC or in ISO 8601 format.
+fn parse_timestamp(value: &str, time_unit: TimeUnit) -> PolarsResult<i64> {
+    let datetime = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(value).map(|x| x.naive_utc()))
+        .map_err(|_| polars_err!(ComputeError: "expected a timestamp, got '{}'", value))?
+        .and_utc();
+
+    Ok(match time_unit {
+        TimeUnit::Nanoseconds => datetime
+            .timestamp_nanos_opt()
+            .ok_or_else(|| polars_err!(ComputeError: "timestamp '{}' is out of range", value))?,
+        TimeUnit::Microseconds => datetime.timestamp_micros(),
+        TimeUnit::Milliseconds => datetime.timestamp_millis(),
+    })
+}
+
+fn sorted_files(files: PlHashMap<(String, Option<String>), (usize, Add)>) -> Vec<Add> {
+    let mut files = files.into_values().collect::<Vec<_>>();
+    files.sort_unstable_by_key(|(i, _)| *i);
+    files.into_iter().map(|(_, add)| add).collect()
+}
+
+fn check_protocol(protocol: &Protocol, metadata: &Metadata) -> PolarsResult<()> {
+    polars_ensure!(
+        protocol.min_reader_version <= 3,
+        nyi = "Delta reader version {}",
+        protocol.min_reader_version
+    );
+
+    for feature in protocol.reader_features.iter().flatten() {
+        polars_ensure!(
+            SUPPORTED_READER_FEATURES.contains(&feature.as_str()),
+            nyi = "Delta reader feature '{}'",
+            feature
+        );
+    }
+
+    if let Some(Some(mode)) = metadata.configuration.get("delta.columnMapping.mode") {
+        polars_ensure!(mode == "none", nyi = "Delta column mapping mode '{}'", mode);
+    }
+
+    Ok(())
+}
+
+/// Reads the actions of a checkpoint, which stores each action type as a struct column.
+struct CheckpointReader<'a> {
+    df: &'a DataFrame,
+}
+
+impl CheckpointReader<'_> {
+    fn actions(&self, name: &str) -> PolarsResult<Option<ActionColumn>> {
+        self.df
+            .column(name)
+            .ok()
+            .map(|c| ActionColumn::new(c.as_materialized_series()))
+            .transpose()
+    }
+}
+
+struct ActionColumn {
+    fields: StructChunked,
+    validity: BooleanChunked,
+}
+
+impl ActionColumn {
+    fn new(s: &Series) -> PolarsResult<Self> {
+        Ok(Self {
+            fields: s.struct_()?.clone(),
+            validity: s.is_not_null(),
+        })
+    }
+
+    fn valid_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.validity
+            .iter()
+            .enumerate()
+            .filter_map(|(i, valid)| valid.unwrap_or(false).then_some(i))
+    }
+
+    fn is_valid(&self, i: usize) -> bool {
+        self.validity.get(i).unwrap_or(false)
+    }
+
+    fn field(&self, name: &str) -> PolarsResult<Option<Series>> {
+        Ok(self.fields.field_by_name(name).ok())
+    }
+
+    fn str(&self, name: &str, i: usize) -> PolarsResult<Option<String>> {
+        let Some(s) = self.field(name)? else {
+            return Ok(None);
+        };
+        Ok(s.str()?.get(i).map(|x| x.to_string()))
+    }
+
+    fn i64(&self, name: &str, i: usize) -> PolarsResult<Option<i64>> {
+        let Some(s) = self.field(name)? else {
+            return Ok(None);
+        };
+        Ok(s.cast(&DataType::Int64)?.i64()?.get(i))
+    }
+
+    fn str_list(&self, name: &str, i: usize) -> PolarsResult<Option<Vec<String>>> {
+        let Some(s) = self.field(name)? else {
+            return Ok(None);
+        };
+        let Some(values) = s.list()?.get_as_series(i) else {
+            return Ok(None);
+        };
+        Ok(Some(
+            values
+                .str()?
+                .iter()
+                .flatten()
+                .map(|x| x.to_string())
+                .collect(),
+        ))
+    }
+
+    /// Reads a `map<string, string>`, which is stored as a list of key-value structs.
+    fn str_map(&self, name: &str, i: usize) -> PolarsResult<BTreeMap<String, Option<String>>> {
+        let Some(s) = self.field(name)? else {
+            return Ok(BTreeMap::new());
+        };
+        let Some(entries) = s.list()?.get_as_series(i) else {
+            return Ok(BTreeMap::new());
+        };
+        let entries = entries.struct_()?;
+        let keys = entries.field_by_name("key")?;
+        let values = entries.field_by_name("value")?;
+
+        Ok(keys
+            .str()?
+            .iter()
+            .zip(values.str()?.iter())
+            .filter_map(|(k, v)| Some((k?.to_string(), v.map(|v| v.to_string()))))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use polars_core::prelude::*;
+    use polars_utils::pl_path::PlRefPath;
+
+    use super::{DeltaSnapshot, DeltaTimeTravel};
+    use crate::delta::deletion_vector::tests::{encode_roaring_bitmap_array, z85_encode};
+
+    const PROTOCOL: &str = r#"{"protocol":{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["deletionVectors"],"writerFeatures":["deletionVectors"]}}"#;
+    const METADATA: &str = r#"{"metaData":{"id":"1","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"p\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":["p"],"configuration":{}}}"#;
+
+    fn add(path: &str, num_records: u64) -> String {
+        format!(
+            r#"{{"add":{{"path":"{path}","partitionValues":{{"p":"x"}},"size":1,"modificationTime":0,"dataChange":true,"stats":"{{\"numRecords\":{num_records}}}"}}}}"#
+        )
+    }
+
+    fn commit_info(timestamp: i64) -> String {
+        format!(r#"{{"commitInfo":{{"inCommitTimestamp":{timestamp}}}}}"#)
+    }
+
+    fn write_commit(root: &Path, version: u64, actions: &[&str]) {
+        let log_dir = root.join("_delta_log");
+        std::fs::create_dir_all(&log_dir).unwrap();
+        std::fs::write(
+            log_dir.join(format!("{version:020}.json")),
+            actions.join("\n"),
+        )
+        .unwrap();
+    }
+
+    fn file_names(snapshot: &DeltaSnapshot) -> Vec<&str> {
+        snapshot
+            .files
+            .iter()
+            .map(|x| x.path.as_str().rsplit_once("/p=x/").unwrap().1)
+            .collect()
+    }
+
+    #[test]
+    fn test_delta_log_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let mut dv_data = encode_roaring_bitmap_array(&[1, 3]);
+        let size_in_bytes = dv_data.len();
+        dv_data.resize(dv_data.len().next_multiple_of(4), 0);
+        let dv = format!(
+            r#"{{"add":{{"path":"p=x/1.parquet","partitionValues":{{"p":"x"}},"size":1,"modificationTime":0,"dataChange":true,"stats":"{{\"numRecords\":5}}","deletionVector":{{"storageType":"i","pathOrInlineDv":"{}","sizeInBytes":{size_in_bytes},"cardinality":2}}}}}}"#,
+            z85_encode(&dv_data)
+        );
+        let remove_1 = r#"{"remove":{"path":"p=x/1.parquet","dataChange":false}}"#;
+        let remove_0 = r#"{"remove":{"path":"p=x/0.parquet","dataChange":true}}"#;
+
+        write_commit(
+            root,
+            0,
+            &[
+                &commit_info(1000),
+                PROTOCOL,
+                METADATA,
+                &add("p=x/0.parquet", 3),
+            ],
+        );
+        write_commit(root, 1, &[&commit_info(2000), &add("p=x/1.parquet", 5)]);
+        write_commit(root, 2, &[&commit_info(3000), remove_1, &dv, remove_0]);
+
+        let table_root = PlRefPath::new(root.to_str().unwrap());
+        let latest = DeltaSnapshot::try_new(&table_root, DeltaTimeTravel::Latest).unwrap();
+        assert_eq!(latest.version, 2);
+        assert_eq!(file_names(&latest), ["1.parquet"]);
+        assert!(latest.files[0].deletion_vector.is_some());
+        assert_eq!(latest.row_count(), Some((5, 2)));
+
+        assert_eq!(
+            latest.file_schema().as_ref(),
+            &Schema::from_iter([Field::new("a".into(), DataType::Int64)])
+        );
+        assert!(
+            latest
+                .partition_values()
+                .unwrap()
+                .unwrap()
+                .equals(&df!("p" => ["x"]).unwrap())
+        );
+
+        let v1 = DeltaSnapshot::try_new(&table_root, DeltaTimeTravel::Version(1)).unwrap();
+        assert_eq!(file_names(&v1), ["0.parquet", "1.parquet"]);
+        assert!(v1.files.iter().all(|x| x.deletion_vector.is_none()));
+        assert_eq!(v1.row_count(), Some((8, 0)));
+
+        let at = |ts| DeltaSnapshot::try_new(&table_root, DeltaTimeTravel::Timestamp(ts));
+        assert_eq!(at(1999).unwrap().version, 0);
+        assert_eq!(at(2000).unwrap().version, 1);
+        assert!(at(999).is_err());
+        assert!(DeltaSnapshot::try_new(&table_root, DeltaTimeTravel::Version(3)).is_err());
+    }
+
+    #[test]
+    fn test_delta_partition_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let metadata = r#"{"metaData":{"id":"1","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"n\",\"type\":\"integer\",\"nullable\":true,\"metadata\":{}},{\"name\":\"b\",\"type\":\"boolean\",\"nullable\":true,\"metadata\":{}},{\"name\":\"d\",\"type\":\"date\",\"nullable\":true,\"metadata\":{}},{\"name\":\"ts\",\"type\":\"timestamp_ntz\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":["n","b","d","ts"],"configuration":{}}}"#;
+        let add = |path: &str, partition_values: &str| {
+            format!(
+                r#"{{"add":{{"path":"{path}","partitionValues":{{{partition_values}}},"size":1,"modificationTime":0,"dataChange":true}}}}"#
+            )
+        };
+        write_commit(
+            dir.path(),
+            0,
+            &[
+                PROTOCOL,
+                metadata,
+                // Partition values need not be part of the file path.
+                &add(
+                    "0.parquet",
+                    r#""n":"1","b":"true","d":"2021-01-02","ts":"2021-01-02 03:04:05.000006""#,
+                ),
+                &add(
+                    "n=__HIVE_DEFAULT_PARTITION__/1.parquet",
+                    r#""n":"__HIVE_DEFAULT_PARTITION__","b":null,"d":"","ts":"2021-01-02T03:04:05Z""#,
+                ),
+            ],
+        );
+
+        let table_root = PlRefPath::new(dir.path().to_str().unwrap());
+        let snapshot = DeltaSnapshot::try_new(&table_root, DeltaTimeTravel::Latest).unwrap();
+        let expected = DataFrame::new(
+            2,
+            vec![
+                Column::new("n".into(), [Some(1i32), None]),
+                Column::new("b".into(), [Some(true), None]),
+                Column::new("d".into(), [Some(18629i32), None])
+                    .cast(&DataType::Date)
+                    .unwrap(),
+                Column::new("ts".into(), [1609556645000006i64, 1609556645000000])
+                    .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+                    .unwrap(),
+            ],
+        )
+        .unwrap();
+        assert!(
+            snapshot
+                .partition_values()
+                .unwrap()
+                .unwrap()
+                .equals_missing(&expected)
+        );
+
+        write_commit(dir.path(), 1, &[&add("2.parquet", r#""n":"x""#)]);
+        let snapshot = DeltaSnapshot::try_new(&table_root, DeltaTimeTravel::Latest).unwrap();
+        let err = snapshot.partition_values().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid Delta partition value for n")
+        );
+    }
+
+    #[test]
+    fn test_delta_unsupported_reader_feature() {
+        let dir = tempfile::tempdir().unwrap();
+        let protocol = r#"{"protocol":{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["v2Checkpoint"]}}"#;
+        write_commit(dir.path(), 0, &[protocol, METADATA]);
+
+        let table_root = PlRefPath::new(dir.path().to_str().unwrap());
+        let err = DeltaSnapshot::try_new(&table_root, DeltaTimeTravel::Latest).unwrap_err();
+        assert!(err.to_string().contains("v2Checkpoint"));
+    }
+}
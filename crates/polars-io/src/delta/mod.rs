@@ -0,0 +1,16 @@
+//! Native reading of Delta Lake tables.
+//!
+//! The transaction log of a table is replayed to resolve the data files of a table version,
+//! which can then be scanned as parquet files. Data is written by adding parquet files and
+//! committing the next version of the log.
+//!
+//! Reference: <https://github.com/delta-io/delta/blob/master/PROTOCOL.md>
+
+mod deletion_vector;
+mod log;
+mod models;
+mod write;
+
+pub use deletion_vector::DeletionVector;
+pub use log::{DeltaFile, DeltaSnapshot, DeltaTimeTravel};
+pub use write::{DeltaWrite, DeltaWriteMode, DeltaWriteOptions, write_delta};
@@ -0,0 +1,92 @@
+//! Actions of the Delta transaction log.
+//!
+//! Reference: <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#actions>
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A single line of a JSON commit file. Actions that are irrelevant for reading are ignored.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Action {
+    pub add: Option<Add>,
+    pub remove: Option<Remove>,
+    pub meta_data: Option<Metadata>,
+    pub protocol: Option<Protocol>,
+    pub commit_info: Option<CommitInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Add {
+    /// URI encoded path, relative to the table root.
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: BTreeMap<String, Option<String>>,
+    /// JSON encoded statistics of the file.
+    pub stats: Option<String>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Remove {
+    pub path: String,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Metadata {
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: BTreeMap<String, Option<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Protocol {
+    pub min_reader_version: i32,
+    pub min_writer_version: i32,
+    pub reader_features: Option<Vec<String>>,
+    pub writer_features: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CommitInfo {
+    pub in_commit_timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct DeletionVectorDescriptor {
+    /// One of `u` (relative path derived from a UUID), `i` (inline) or `p` (absolute path).
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    /// Number of deleted rows.
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    /// Identifies the deletion vector, files are identified by their path and this id.
+    pub fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{offset}", self.storage_type, self.path_or_inline_dv),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+}
+
+/// The subset of the file statistics that is used.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Stats {
+    pub num_records: Option<u64>,
+}
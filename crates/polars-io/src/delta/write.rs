@@ -0,0 +1,583 @@
+//! Native writing of Delta Lake tables.
+//!
+//! The data is written as parquet files, which are then added to the table by committing the next
+//! version of the transaction log. Commits are atomic as the commit file is only created if it
+//! does not exist yet.
+//!
+//! Reference: <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#writer-requirements>
+
+use std::io::Cursor;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use futures::TryStreamExt;
+use object_store::{PutMode, PutPayload};
+use polars_core::prelude::*;
+use polars_core::runtime::ASYNC;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err, to_compute_err};
+use polars_utils::pl_path::PlRefPath;
+use polars_utils::unique_id::UniqueId;
+use serde_json::json;
+
+use super::log::{LogFiles, ReplayState, parse_commit};
+use super::models::Add;
+use crate::SerReader;
+use crate::catalog::unity::schema::{parse_type_json_str, schema_to_type_json_str};
+use crate::cloud::cloud_writer::{CloudWriter, CloudWriterIoTraitWrap};
+use crate::cloud::concurrency_config::ConcurrencyStrategy;
+use crate::cloud::{
+    CloudOptions, ObjectStorePath, PolarsObjectStore, build_object_store, object_path_from_str,
+};
+use crate::configs::{upload_chunk_size, upload_concurrency};
+use crate::parquet::read::{ParquetObjectStore, ParquetReader};
+use crate::parquet::write::ParquetWriteOptions;
+use crate::utils::file::WriteableTrait;
+
+/// Writer features of the Delta protocol that are supported. Tables that require other writer
+/// features can not be written to.
+const SUPPORTED_WRITER_FEATURES: &[&str] = &[
+    "appendOnly",
+    "changeDataFeed",
+    "deletionVectors",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+/// How to handle existing data when writing to a Delta table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeltaWriteMode {
+    /// Raise an error if the table already exists.
+    #[default]
+    Error,
+    Append,
+    /// Replace the data of the table. The schema of the data must match the table.
+    Overwrite,
+    /// Don't write anything if the table already exists.
+    Ignore,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DeltaWriteOptions {
+    pub mode: DeltaWriteMode,
+    pub parquet_options: ParquetWriteOptions,
+}
+
+/// Writes `df` to the Delta table at `table_root`, creating the table if it does not exist.
+///
+/// Returns the committed version, or `None` if nothing was written as the table already exists
+/// and the mode is [`DeltaWriteMode::Ignore`].
+pub async fn write_delta(
+    df: &mut DataFrame,
+    table_root: &PlRefPath,
+    cloud_options: Option<&CloudOptions>,
+    options: &DeltaWriteOptions,
+) -> PolarsResult<Option<u64>> {
+    let Some(write) = DeltaWrite::try_new(
+        table_root.clone(),
+        cloud_options.cloned(),
+        df.schema().clone(),
+        options.mode,
+    )
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let path = write.write_data_file(df, &options.parquet_options).await?;
+    write.commit(&[path]).await.map(Some)
+}
+
+/// A write to a Delta table, for which the state of the table was resolved.
+///
+/// Data files are written to paths from [`DeltaWrite::new_data_file_path`], and are added to the
+/// table with [`DeltaWrite::commit`]. The commit fails if another version of the table was
+/// committed in the meantime.
+pub struct DeltaWrite {
+    table_root: PlRefPath,
+    cloud_options: Option<CloudOptions>,
+    store: PolarsObjectStore,
+    /// The table root within `store`.
+    root: String,
+    mode: DeltaWriteMode,
+    version: u64,
+    schema: SchemaRef,
+    schema_string: String,
+    removed_files: Vec<Add>,
+}
+
+impl DeltaWrite {
+    /// Resolves the state of the table at `table_root` and checks that data with `schema` can be
+    /// written to it.
+    ///
+    /// Returns `None` if the table already exists and the mode is [`DeltaWriteMode::Ignore`].
+    pub async fn try_new(
+        table_root: PlRefPath,
+        cloud_options: Option<CloudOptions>,
+        schema: SchemaRef,
+        mode: DeltaWriteMode,
+    ) -> PolarsResult<Option<Self>> {
+        let (cloud_location, store) =
+            build_object_store(table_root.clone(), cloud_options.as_ref(), false).await?;
+        let root = cloud_location.prefix.trim_end_matches('/').to_string();
+        let log = list_log(
+            &store,
+            &object_path_from_str(&format!("{root}/_delta_log"))?,
+        )
+        .await?;
+        let schema_string = schema_to_type_json_str(&schema)?;
+
+        let (version, removed_files) = match (log.latest_version(), mode) {
+            (None, _) => (0, vec![]),
+            (Some(_), DeltaWriteMode::Error) => {
+                polars_bail!(ComputeError: "Delta table already exists at {}", table_root)
+            },
+            (Some(_), DeltaWriteMode::Ignore) => return Ok(None),
+            (Some(latest), mode) => {
+                let state = replay(&store, &log, latest).await?;
+                check_writable(&state, &schema_string, mode)?;
+                let removed_files = match mode {
+                    DeltaWriteMode::Overwrite => state.into_files(),
+                    _ => vec![],
+                };
+                (latest + 1, removed_files)
+            },
+        };
+
+        Ok(Some(Self {
+            table_root,
+            cloud_options,
+            store,
+            root,
+            mode,
+            version,
+            schema,
+            schema_string,
+            removed_files,
+        }))
+    }
+
+    pub fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.cloud_options.as_ref()
+    }
+
+    /// Returns a new, unique, path for a data file in the table root.
+    pub fn new_data_file_path(&self) -> PlRefPath {
+        self.table_root
+            .join(format!("part-{}.parquet", UniqueId::new()))
+    }
+
+    /// Writes `df` to a new data file, which is streamed to the store as it is encoded.
+    pub async fn write_data_file(
+        &self,
+        df: &DataFrame,
+        options: &ParquetWriteOptions,
+    ) -> PolarsResult<PlRefPath> {
+        let path = self.new_data_file_path();
+        let mut writer = CloudWriter::new(
+            self.store.clone(),
+            self.object_path(&path)?,
+            upload_chunk_size(),
+            upload_concurrency(),
+            None,
+        );
+        writer.start().await?;
+        let mut writer = CloudWriterIoTraitWrap::from(writer);
+
+        let mut df = df.clone();
+        let options = options.clone();
+        ASYNC
+            .spawn_blocking(move || {
+                options.to_writer(&mut writer).finish(&mut df)?;
+                writer.close()?;
+                PolarsResult::Ok(())
+            })
+            .await
+            .unwrap()?;
+
+        Ok(path)
+    }
+
+    /// Commits the next version of the table, which adds the parquet files at `data_file_paths`
+    /// and, when overwriting, removes the existing files.
+    pub async fn commit(&self, data_file_paths: &[PlRefPath]) -> PolarsResult<u64> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(to_compute_err)?
+            .as_millis() as i64;
+
+        let mut actions = vec![json!({
+            "commitInfo": {
+                "timestamp": timestamp,
+                "operation": "WRITE",
+                "operationParameters": { "mode": format!("{:?}", self.mode) },
+                "engineInfo": "polars",
+            }
+        })];
+        if self.version == 0 {
+            actions.push(new_table_protocol(&self.schema));
+            actions.push(json!({
+                "metaData": {
+                    "id": UniqueId::new().to_string(),
+                    "format": { "provider": "parquet", "options": {} },
+                    "schemaString": self.schema_string,
+                    "partitionColumns": [],
+                    "configuration": {},
+                    "createdTime": timestamp,
+                }
+            }));
+        }
+        actions.extend(
+            self.removed_files
+                .iter()
+                .map(|file| remove_action(file, timestamp)),
+        );
+        for path in data_file_paths {
+            let file_name = self.file_name(path)?;
+            let object_path = self.object_path(path)?;
+            let size = self
+                .store
+                .head(&object_path, ConcurrencyStrategy::Unbounded)
+                .await?
+                .size;
+            let num_records =
+                ParquetObjectStore::from_uri(path.clone(), self.cloud_options(), None)
+                    .await?
+                    .num_rows_only()
+                    .await?;
+
+            actions.push(json!({
+                "add": {
+                    "path": file_name,
+                    "partitionValues": {},
+                    "size": size,
+                    "modificationTime": timestamp,
+                    "dataChange": true,
+                    "stats": json!({ "numRecords": num_records }).to_string(),
+                }
+            }));
+        }
+
+        let commit = actions
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let version = self.version;
+        let commit_path =
+            object_path_from_str(&format!("{}/_delta_log/{version:020}.json", self.root))?;
+
+        if !put_if_absent(&self.store, &commit_path, Bytes::from(commit)).await? {
+            polars_bail!(
+                ComputeError:
+                "failed to commit version {} of the Delta table at {}: the version was committed by a concurrent writer",
+                version, self.table_root
+            )
+        }
+
+        Ok(version)
+    }
+
+    /// Returns the name of a data file, which must be located directly in the table root.
+    fn file_name<'a>(&self, path: &'a PlRefPath) -> PolarsResult<&'a str> {
+        path.as_str()
+            .strip_prefix(self.table_root.as_str())
+            .map(|x| x.trim_start_matches('/'))
+            .filter(|x| !x.is_empty() && !x.contains('/'))
+            .ok_or_else(|| {
+                polars_err!(
+                    ComputeError:
+                    "data file {} is not located in the root of the Delta table at {}",
+                    path, self.table_root
+                )
+            })
+    }
+
+    fn object_path(&self, path: &PlRefPath) -> PolarsResult<ObjectStorePath> {
+        object_path_from_str(&format!("{}/{}", self.root, self.file_name(path)?))
+    }
+}
+
+async fn list_log(
+    store: &PolarsObjectStore,
+    log_dir: &ObjectStorePath,
+) -> PolarsResult<LogFiles<ObjectStorePath>> {
+    let files = store
+        .exec_with_rebuild_retry_on_err(|s| async move {
+            s.list(Some(log_dir))
+                .map_ok(|x| {
+                    (
+                        x.location.filename().unwrap_or_default().to_string(),
+                        x.location,
+                    )
+                })
+                .try_collect::<Vec<_>>()
+                .await
+        })
+        .await?;
+
+    Ok(LogFiles::from_files(files))
+}
+
+async fn get(store: &PolarsObjectStore, path: &ObjectStorePath) -> PolarsResult<Bytes> {
+    store
+        .exec_with_rebuild_retry_on_err(|s| async move { s.get(path).await?.bytes().await })
+        .await
+}
+
+async fn replay(
+    store: &PolarsObjectStore,
+    log: &LogFiles<ObjectStorePath>,
+    version: u64,
+) -> PolarsResult<ReplayState> {
+    let mut state = ReplayState::default();
+    let (checkpoint, commits) = log.replay_files(version)?;
+
+    for part in checkpoint {
+        let df = ParquetReader::new(Cursor::new(get(store, part).await?)).finish()?;
+        state.apply_checkpoint(&df)?;
+    }
+    for path in commits {
+        for action in parse_commit(&get(store, path).await?, path)? {
+            state.apply(action);
+        }
+    }
+
+    Ok(state)
+}
+
+/// Checks that the table can be written to with `mode`, and that data with `schema_string` can be
+/// added to it.
+fn check_writable(
+    state: &ReplayState,
+    schema_string: &str,
+    mode: DeltaWriteMode,
+) -> PolarsResult<()> {
+    let (Some(protocol), Some(metadata)) = (&state.protocol, &state.metadata) else {
+        polars_bail!(ComputeError: "Delta log is missing the protocol or metadata of the table")
+    };
+
+    // Writer versions 3 to 6 require features that are not supported, such as check constraints
+    // and generated columns.
+    polars_ensure!(
+        protocol.min_writer_version <= 2 || protocol.min_writer_version == 7,
+        nyi = "writing to Delta tables with writer version {}",
+        protocol.min_writer_version
+    );
+    for feature in protocol.writer_features.iter().flatten() {
+        polars_ensure!(
+            SUPPORTED_WRITER_FEATURES.contains(&feature.as_str()),
+            nyi = "writing to Delta tables with writer feature '{}'",
+            feature
+        );
+    }
+    polars_ensure!(
+        !metadata.schema_string.contains("delta.invariants"),
+        nyi = "writing to Delta tables with column invariants"
+    );
+    polars_ensure!(
+        metadata.partition_columns.is_empty(),
+        nyi = "writing to partitioned Delta tables"
+    );
+
+    let append_only = metadata
+        .configuration
+        .get("delta.appendOnly")
+        .is_some_and(|x| x.as_deref() == Some("true"));
+    polars_ensure!(
+        !(append_only && mode == DeltaWriteMode::Overwrite),
+        ComputeError: "cannot overwrite the Delta table: the table is append-only"
+    );
+
+    let table_dtype = parse_type_json_str(&metadata.schema_string)?;
+    let dtype = parse_type_json_str(schema_string)?;
+    polars_ensure!(
+        dtype == table_dtype,
+        SchemaMismatch: "schema of the data does not match the Delta table: expected {}, got {}",
+        table_dtype, dtype
+    );
+
+    Ok(())
+}
+
+/// The protocol of a new table. Timestamps without a time zone require the `timestampNtz`
+/// feature, which is only available in the table features protocol.
+fn new_table_protocol(schema: &Schema) -> serde_json::Value {
+    fn contains_datetime(dtype: &DataType) -> bool {
+        match dtype {
+            DataType::Datetime(..) => true,
+            DataType::List(inner) => contains_datetime(inner),
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) => fields.iter().any(|x| contains_datetime(x.dtype())),
+            _ => false,
+        }
+    }
+
+    if schema.iter_values().any(contains_datetime) {
+        json!({
+            "protocol": {
+                "minReaderVersion": 3,
+                "minWriterVersion": 7,
+                "readerFeatures": ["timestampNtz"],
+                "writerFeatures": ["timestampNtz"],
+            }
+        })
+    } else {
+        json!({ "protocol": { "minReaderVersion": 1, "minWriterVersion": 2 } })
+    }
+}
+
+fn remove_action(file: &Add, timestamp: i64) -> serde_json::Value {
+    let mut remove = json!({
+        "path": file.path,
+        "deletionTimestamp": timestamp,
+        "dataChange": true,
+        "partitionValues": file.partition_values,
+    });
+    if let Some(dv) = &file.deletion_vector {
+        remove["deletionVector"] = json!(dv);
+    }
+
+    json!({ "remove": remove })
+}
+
+/// Creates the object at `path`. Returns `false` if it already exists.
+///
+/// A put is retried when the store is rebuilt, and its first attempt may have created the object
+/// even though it failed. An existing object is therefore only taken to be created by another
+/// writer if its contents differ, which they do for commits as they add uniquely named files.
+async fn put_if_absent(
+    store: &PolarsObjectStore,
+    path: &ObjectStorePath,
+    bytes: Bytes,
+) -> PolarsResult<bool> {
+    let created = store
+        .exec_with_rebuild_retry_on_err(|s| {
+            let payload = PutPayload::from(bytes.clone());
+            async move {
+                match s.put_opts(path, payload, PutMode::Create.into()).await {
+                    Ok(_) => Ok(true),
+                    Err(object_store::Error::AlreadyExists { .. }) => Ok(false),
+                    Err(e) => Err(e),
+                }
+            }
+        })
+        .await?;
+
+    Ok(created || get(store, path).await? == bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use polars_core::prelude::*;
+    use polars_core::runtime::ASYNC;
+    use polars_core::utils::accumulate_dataframes_vertical;
+    use polars_utils::pl_path::PlRefPath;
+
+    use super::{DeltaWrite, DeltaWriteMode, DeltaWriteOptions, put_if_absent, write_delta};
+    use crate::SerReader;
+    use crate::cloud::{build_object_store, object_path_from_str};
+    use crate::delta::{DeltaSnapshot, DeltaTimeTravel};
+    use crate::parquet::read::ParquetReader;
+
+    fn read_delta(table_root: &PlRefPath) -> DataFrame {
+        let snapshot = DeltaSnapshot::try_new(table_root, DeltaTimeTravel::Latest).unwrap();
+        accumulate_dataframes_vertical(snapshot.files.iter().map(|file| {
+            let file = std::fs::File::open(file.path.as_str()).unwrap();
+            ParquetReader::new(file).finish().unwrap()
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_write_delta() {
+        let dir = tempfile::tempdir().unwrap();
+        let table_root = PlRefPath::new(dir.path().join("table").to_str().unwrap());
+        let write = |mut df: DataFrame, mode| {
+            let options = DeltaWriteOptions {
+                mode,
+                ..Default::default()
+            };
+            ASYNC.block_on(write_delta(&mut df, &table_root, None, &options))
+        };
+
+        let df1 = df!("a" => [1i64, 2], "b" => ["x", "y"]).unwrap();
+        let df2 = df!("a" => [3i64], "b" => ["z"]).unwrap();
+
+        assert_eq!(write(df1.clone(), DeltaWriteMode::Error).unwrap(), Some(0));
+        assert!(write(df1.clone(), DeltaWriteMode::Error).is_err());
+        assert_eq!(write(df1.clone(), DeltaWriteMode::Ignore).unwrap(), None);
+        assert_eq!(write(df2.clone(), DeltaWriteMode::Append).unwrap(), Some(1));
+        assert_eq!(read_delta(&table_root), df1.vstack(&df2).unwrap());
+
+        let other_schema = df!("a" => [1i32]).unwrap();
+        assert!(write(other_schema, DeltaWriteMode::Append).is_err());
+
+        assert_eq!(
+            write(df2.clone(), DeltaWriteMode::Overwrite).unwrap(),
+            Some(2)
+        );
+        assert_eq!(read_delta(&table_root), df2);
+
+        let snapshot = DeltaSnapshot::try_new(&table_root, DeltaTimeTravel::Version(1)).unwrap();
+        assert_eq!(snapshot.files.len(), 2);
+        assert_eq!(snapshot.row_count(), Some((3, 0)));
+    }
+
+    #[test]
+    fn test_concurrent_commit() {
+        let dir = tempfile::tempdir().unwrap();
+        let table_root = PlRefPath::new(dir.path().join("table").to_str().unwrap());
+        let df = df!("a" => [1i64, 2]).unwrap();
+
+        let prepare = || {
+            ASYNC
+                .block_on(DeltaWrite::try_new(
+                    table_root.clone(),
+                    None,
+                    df.schema().clone(),
+                    DeltaWriteMode::Append,
+                ))
+                .unwrap()
+                .unwrap()
+        };
+        let (w1, w2) = (prepare(), prepare());
+
+        let commit = |write: &DeltaWrite| {
+            ASYNC.block_on(async {
+                let path = write.write_data_file(&df, &Default::default()).await?;
+                write.commit(&[path]).await
+            })
+        };
+
+        assert_eq!(commit(&w1).unwrap(), 0);
+        // The second writer prepared the same version, which may not be overwritten.
+        assert!(commit(&w2).is_err());
+
+        let snapshot = DeltaSnapshot::try_new(&table_root, DeltaTimeTravel::Latest).unwrap();
+        assert_eq!(snapshot.version, 0);
+        assert_eq!(snapshot.row_count(), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_put_if_absent() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = PlRefPath::new(dir.path().to_str().unwrap());
+
+        ASYNC
+            .block_on(async {
+                let (cloud_location, store) = build_object_store(root, None, false).await?;
+                let path = object_path_from_str(&format!(
+                    "{}/00000000000000000000.json",
+                    cloud_location.prefix.trim_end_matches('/')
+                ))?;
+
+                assert!(put_if_absent(&store, &path, Bytes::from_static(b"a")).await?);
+                // A retried put finds the object it created.
+                assert!(put_if_absent(&store, &path, Bytes::from_static(b"a")).await?);
+                assert!(!put_if_absent(&store, &path, Bytes::from_static(b"b")).await?);
+                PolarsResult::Ok(())
+            })
+            .unwrap();
+    }
+}
@@ -2,9 +2,10 @@ use std::sync::atomic::AtomicU64;
 use std::sync::{Arc, LazyLock, RwLock};
 
 use polars_core::config;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_warn};
 use polars_utils::aliases::PlHashMap;
 use polars_utils::pl_path::PlRefPath;
+use polars_utils::relaxed_cell::RelaxedCell;
 
 use super::entry::{DATA_PREFIX, FileCacheEntry, METADATA_PREFIX};
 use super::eviction::EvictionManager;
@@ -21,6 +22,8 @@ pub static FILE_CACHE: LazyLock<FileCache> = LazyLock::new(|| {
 
     let min_ttl = Arc::new(AtomicU64::from(get_env_file_cache_ttl()));
     let notify_ttl_updated = Arc::new(tokio::sync::Notify::new());
+    let max_size = Arc::new(AtomicU64::from(get_env_file_cache_max_size().unwrap_or(0)));
+    let stats = Arc::new(FileCacheStats::default());
 
     let metadata_dir = prefix.join(std::str::from_utf8(&[METADATA_PREFIX]).unwrap());
     if let Err(err) = ensure_directory_init(metadata_dir.as_std_path()) {
@@ -45,11 +48,13 @@ pub static FILE_CACHE: LazyLock<FileCache> = LazyLock::new(|| {
         files_to_remove: None,
         min_ttl: min_ttl.clone(),
         notify_ttl_updated: notify_ttl_updated.clone(),
+        max_size: max_size.clone(),
+        stats: stats.clone(),
     }
     .run_in_background();
 
     // Safety: We have created the data and metadata directories.
-    unsafe { FileCache::new_unchecked(prefix, min_ttl, notify_ttl_updated) }
+    unsafe { FileCache::new_unchecked(prefix, min_ttl, notify_ttl_updated, max_size, stats) }
 });
 
 pub struct FileCache {
@@ -57,6 +62,20 @@ pub struct FileCache {
     entries: Arc<RwLock<PlHashMap<PlRefPath, Arc<FileCacheEntry>>>>,
     min_ttl: Arc<AtomicU64>,
     notify_ttl_updated: Arc<tokio::sync::Notify>,
+    /// Maximum total size of the data files in bytes, or 0 if the size is unbounded.
+    max_size: Arc<AtomicU64>,
+    stats: Arc<FileCacheStats>,
+}
+
+/// Statistics of the file cache since the start of the process.
+#[derive(Debug, Default)]
+pub struct FileCacheStats {
+    /// Number of files opened from the cache without downloading them.
+    pub hits: RelaxedCell<u64>,
+    /// Number of files that were downloaded into the cache.
+    pub misses: RelaxedCell<u64>,
+    /// Number of bytes evicted from the cache because it exceeded its maximum size.
+    pub bytes_evicted: RelaxedCell<u64>,
 }
 
 impl FileCache {
@@ -68,12 +87,16 @@ impl FileCache {
         prefix: PlRefPath,
         min_ttl: Arc<AtomicU64>,
         notify_ttl_updated: Arc<tokio::sync::Notify>,
+        max_size: Arc<AtomicU64>,
+        stats: Arc<FileCacheStats>,
     ) -> Self {
         Self {
             prefix,
             entries: Default::default(),
             min_ttl,
             notify_ttl_updated,
+            max_size,
+            stats,
         }
     }
 
@@ -150,6 +173,8 @@ impl FileCache {
                 self.prefix.clone(),
                 get_file_fetcher()?,
                 ttl,
+                self.max_size.clone(),
+                self.stats.clone(),
             ));
             entries.insert(uri.clone(), entry.clone());
             Ok(entry)
@@ -166,6 +191,26 @@ impl FileCache {
             self.entries.read().unwrap().get(&p).cloned()
         }
     }
+
+    /// Sets the maximum total size of the cached files in bytes. Once the cache exceeds this size,
+    /// the least recently accessed files are evicted, regardless of their TTL. Files that are
+    /// currently opened by any process are never evicted.
+    ///
+    /// `None` removes the bound, in which case files are only evicted after their TTL expires.
+    pub fn set_max_size(&self, max_size: Option<u64>) {
+        self.max_size.store(
+            max_size.map_or(0, |x| x.max(1)),
+            std::sync::atomic::Ordering::Relaxed,
+        );
+    }
+
+    pub fn max_size(&self) -> Option<u64> {
+        Some(self.max_size.load(std::sync::atomic::Ordering::Relaxed)).filter(|x| *x > 0)
+    }
+
+    pub fn stats(&self) -> &FileCacheStats {
+        &self.stats
+    }
 }
 
 pub fn get_env_file_cache_ttl() -> u64 {
@@ -173,3 +218,20 @@ pub fn get_env_file_cache_ttl() -> u64 {
         .map(|x| x.parse::<u64>().expect("integer"))
         .unwrap_or(60 * 60)
 }
+
+/// Maximum size of the file cache in bytes, set through `POLARS_FILE_CACHE_MAX_SIZE`. Invalid
+/// values are ignored with a warning, leaving the size of the cache unbounded.
+pub fn get_env_file_cache_max_size() -> Option<u64> {
+    let value = std::env::var("POLARS_FILE_CACHE_MAX_SIZE").ok()?;
+
+    match value.parse::<u64>() {
+        Ok(max_size) => Some(max_size).filter(|x| *x > 0),
+        Err(_) => {
+            polars_warn!(
+                "ignoring invalid value {} for POLARS_FILE_CACHE_MAX_SIZE, expected a size in bytes",
+                value
+            );
+            None
+        },
+    }
+}
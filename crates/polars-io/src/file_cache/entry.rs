@@ -8,11 +8,14 @@ use polars_core::config;
 use polars_error::{PolarsError, PolarsResult, polars_bail, to_compute_err};
 use polars_utils::pl_path::PlRefPath;
 
+use super::cache::FileCacheStats;
 use super::cache_lock::{self, GLOBAL_FILE_CACHE_LOCK};
+use super::eviction::evict_to_max_size;
 use super::file_fetcher::{FileFetcher, RemoteMetadata};
 use super::file_lock::{FileLock, FileLockAnyGuard};
 use super::metadata::{EntryMetadata, FileVersion};
 use super::utils::update_last_accessed;
+use crate::metrics::OptIOMetrics;
 
 pub(super) const DATA_PREFIX: u8 = b'd';
 pub(super) const METADATA_PREFIX: u8 = b'm';
@@ -31,6 +34,8 @@ struct Inner {
     cached_data: Option<CachedData>,
     ttl: Arc<AtomicU64>,
     file_fetcher: Arc<dyn FileFetcher>,
+    max_size: Arc<AtomicU64>,
+    stats: Arc<FileCacheStats>,
 }
 
 struct EntryData {
@@ -48,7 +53,7 @@ impl EntryMetadata {
 }
 
 impl Inner {
-    fn try_open_assume_latest(&mut self) -> PolarsResult<std::fs::File> {
+    fn try_open_assume_latest(&mut self, io_metrics: &OptIOMetrics) -> PolarsResult<std::fs::File> {
         let verbose = config::verbose();
 
         {
@@ -68,6 +73,8 @@ impl Inner {
                             self.uri.clone()
                         );
                     }
+                    self.stats.hits.fetch_add(1);
+                    io_metrics.add_file_cache_hit();
                     return Ok(finish_open(data_file_path, metadata_file));
                 }
             }
@@ -80,10 +87,10 @@ impl Inner {
             );
         }
 
-        self.try_open_check_latest()
+        self.try_open_check_latest(io_metrics)
     }
 
-    fn try_open_check_latest(&mut self) -> PolarsResult<std::fs::File> {
+    fn try_open_check_latest(&mut self, io_metrics: &OptIOMetrics) -> PolarsResult<std::fs::File> {
         let verbose = config::verbose();
         let remote_metadata = &self.file_fetcher.fetch_metadata()?;
         let cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_shared();
@@ -103,6 +110,8 @@ impl Inner {
                                 self.uri.clone()
                             );
                         }
+                        self.stats.hits.fetch_add(1);
+                        io_metrics.add_file_cache_hit();
                         return Ok(finish_open(data_file_path, metadata_file));
                     }
                 }
@@ -130,6 +139,8 @@ impl Inner {
                         self.uri.clone()
                     );
                 }
+                self.stats.hits.fetch_add(1);
+                io_metrics.add_file_cache_hit();
                 return Ok(finish_open(data_file_path, metadata_file));
             }
         }
@@ -197,6 +208,8 @@ impl Inner {
             }
         }
         self.file_fetcher.fetch(data_file_path)?;
+        self.stats.misses.fetch_add(1);
+        io_metrics.add_file_cache_miss();
 
         // Don't do this on windows as it will break setting last accessed times.
         #[cfg(target_family = "unix")]
@@ -234,6 +247,15 @@ impl Inner {
             .try_write(&mut **metadata_file)
             .map_err(to_compute_err)?;
 
+        // The fetched file is opened with a shared lock, so it is not evicted.
+        drop(cache_guard);
+        evict_after_fetch(
+            &self.path_prefix,
+            self.max_size.load(std::sync::atomic::Ordering::Relaxed),
+            &self.stats,
+            io_metrics,
+        );
+
         Ok(data_file)
     }
 
@@ -319,6 +341,8 @@ impl FileCacheEntry {
         path_prefix: PlRefPath,
         file_fetcher: Arc<dyn FileFetcher>,
         file_cache_ttl: u64,
+        max_size: Arc<AtomicU64>,
+        stats: Arc<FileCacheStats>,
     ) -> Self {
         let metadata = FileLock::from(get_metadata_file_path(
             path_prefix.as_bytes(),
@@ -342,6 +366,8 @@ impl FileCacheEntry {
                 cached_data: None,
                 ttl: ttl.clone(),
                 file_fetcher,
+                max_size,
+                stats,
             }),
             ttl,
         })
@@ -354,14 +380,22 @@ impl FileCacheEntry {
     /// Directly returns the cached file if it finds one without checking if
     /// there is a newer version on the remote. This does not make any API calls
     /// if it finds a cached file, otherwise it simply downloads the file.
-    pub fn try_open_assume_latest(&self) -> PolarsResult<std::fs::File> {
-        self.0.inner.lock().unwrap().try_open_assume_latest()
+    pub fn try_open_assume_latest(&self, io_metrics: &OptIOMetrics) -> PolarsResult<std::fs::File> {
+        self.0
+            .inner
+            .lock()
+            .unwrap()
+            .try_open_assume_latest(io_metrics)
     }
 
     /// Returns the cached file after ensuring it is up to date against the remote
     /// This will always perform at least 1 API call for fetching metadata.
-    pub fn try_open_check_latest(&self) -> PolarsResult<std::fs::File> {
-        self.0.inner.lock().unwrap().try_open_check_latest()
+    pub fn try_open_check_latest(&self, io_metrics: &OptIOMetrics) -> PolarsResult<std::fs::File> {
+        self.0
+            .inner
+            .lock()
+            .unwrap()
+            .try_open_check_latest(io_metrics)
     }
 
     pub fn update_ttl(&self, ttl: u64) {
@@ -398,6 +432,28 @@ fn finish_open<F: FileLockAnyGuard>(data_file_path: &Path, _metadata_guard: &F)
     file
 }
 
+/// Evicts the least recently accessed files if the cache exceeds `max_size` after a fetch. This is
+/// skipped if the cache is locked by other users, in which case the size is enforced by the
+/// background eviction task instead.
+fn evict_after_fetch(
+    path_prefix: &PlRefPath,
+    max_size: u64,
+    stats: &FileCacheStats,
+    io_metrics: &OptIOMetrics,
+) {
+    if max_size == 0 {
+        return;
+    }
+
+    if let Some(guard) = GLOBAL_FILE_CACHE_LOCK.try_lock_eviction() {
+        let data_dir = path_prefix
+            .as_std_path()
+            .join(std::str::from_utf8(&[DATA_PREFIX]).unwrap());
+        let bytes_evicted = evict_to_max_size(&data_dir, max_size, stats, &guard);
+        io_metrics.add_file_cache_bytes_evicted(bytes_evicted);
+    }
+}
+
 /// `[prefix]/d/[uri hash][last modified]`
 fn get_data_file_path(
     path_prefix: &[u8],
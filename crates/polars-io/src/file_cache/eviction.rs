@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
@@ -6,6 +7,7 @@ use polars_core::runtime::ASYNC;
 use polars_error::{PolarsError, PolarsResult};
 use polars_utils::pl_path::PlRefPath;
 
+use super::cache::FileCacheStats;
 use super::cache_lock::{GLOBAL_FILE_CACHE_LOCK, GlobalFileCacheGuardExclusive};
 use super::metadata::EntryMetadata;
 
@@ -23,6 +25,8 @@ pub(super) struct EvictionManager {
     pub(super) files_to_remove: Option<Vec<EvictionCandidate>>,
     pub(super) min_ttl: Arc<AtomicU64>,
     pub(super) notify_ttl_updated: Arc<tokio::sync::Notify>,
+    pub(super) max_size: Arc<AtomicU64>,
+    pub(super) stats: Arc<FileCacheStats>,
 }
 
 impl EvictionCandidate {
@@ -175,8 +179,12 @@ impl EvictionManager {
 
                 last_eviction_time = Instant::now();
 
+                let max_size = self.max_size.load(std::sync::atomic::Ordering::Relaxed);
+                let nothing_to_evict =
+                    max_size == 0 && self.files_to_remove.as_ref().is_some_and(|x| x.is_empty());
+
                 match result {
-                    Ok(_) if self.files_to_remove.as_ref().unwrap().is_empty() => {},
+                    Ok(_) if nothing_to_evict => {},
                     Ok(_) => loop {
                         if let Some(guard) = GLOBAL_FILE_CACHE_LOCK.try_lock_eviction() {
                             if verbose {
@@ -186,7 +194,18 @@ impl EvictionManager {
                                 );
                             }
 
-                            ASYNC.block_in_place(|| self.evict_files(&guard));
+                            ASYNC.block_in_place(|| {
+                                self.evict_files(&guard);
+
+                                if max_size > 0 {
+                                    evict_to_max_size(
+                                        self.data_dir.as_std_path(),
+                                        max_size,
+                                        &self.stats,
+                                        &guard,
+                                    );
+                                }
+                            });
                             break;
                         }
                         tokio::time::sleep(Duration::from_secs(7)).await;
@@ -329,3 +348,137 @@ impl EvictionManager {
         }
     }
 }
+
+/// Evicts the least recently accessed data files until the total size of the data files in
+/// `data_dir` is at most `max_size`. Files that are opened by any process hold a shared lock and
+/// are skipped.
+///
+/// Access times are tracked on the data files themselves, so this evicts in LRU order across all
+/// processes that share the cache directory. Returns the number of bytes that were evicted.
+pub(super) fn evict_to_max_size(
+    data_dir: &Path,
+    max_size: u64,
+    stats: &FileCacheStats,
+    _guard: &GlobalFileCacheGuardExclusive,
+) -> u64 {
+    let verbose = false;
+
+    let Ok(data_files_iter) = std::fs::read_dir(data_dir) else {
+        return 0;
+    };
+
+    let mut files = data_files_iter
+        .filter_map(|file| {
+            let file = file.ok()?;
+            let metadata = file.metadata().ok()?;
+
+            // Files of local sources are cached as symlinks, which don't take up space.
+            if metadata.is_symlink() {
+                return None;
+            }
+
+            let last_accessed = metadata.accessed().or_else(|_| metadata.modified()).ok()?;
+            Some((file.path(), metadata.len(), last_accessed))
+        })
+        .collect::<Vec<_>>();
+
+    let mut total_size = files.iter().map(|(_, size, _)| size).sum::<u64>();
+
+    if total_size <= max_size {
+        return 0;
+    }
+
+    files.sort_unstable_by_key(|(_, _, last_accessed)| *last_accessed);
+
+    let mut bytes_evicted = 0;
+
+    for (path, size, _) in files {
+        if total_size <= max_size {
+            break;
+        }
+
+        {
+            let Ok(file) = std::fs::OpenOptions::new().read(true).open(&path) else {
+                continue;
+            };
+
+            if file.try_lock().is_err() {
+                if verbose {
+                    eprintln!(
+                        "[EvictionManager] evict_to_max_size: skipping {} (file is locked)",
+                        path.display()
+                    );
+                }
+                continue;
+            }
+        }
+
+        match std::fs::remove_file(&path) {
+            Ok(()) => {
+                if verbose {
+                    eprintln!(
+                        "[EvictionManager] evict_to_max_size: removed file at {} ({} bytes)",
+                        path.display(),
+                        size
+                    );
+                }
+                total_size -= size;
+                bytes_evicted += size;
+            },
+            Err(err) => {
+                if verbose {
+                    eprintln!(
+                        "[EvictionManager] evict_to_max_size: error removing file: {} ({})",
+                        path.display(),
+                        err
+                    );
+                }
+            },
+        }
+    }
+
+    stats.bytes_evicted.fetch_add(bytes_evicted);
+    bytes_evicted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::*;
+
+    #[test]
+    fn test_evict_to_max_size() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let files = ["a", "b", "c", "d"].map(|name| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, [0u8; 10]).unwrap();
+            path
+        });
+
+        // Access order from least to most recent: b, a, c, d.
+        for (path, secs) in files.iter().zip([20, 10, 30, 40]) {
+            let file = std::fs::OpenOptions::new().write(true).open(path).unwrap();
+            file.set_times(
+                std::fs::FileTimes::new().set_accessed(UNIX_EPOCH + Duration::from_secs(secs)),
+            )
+            .unwrap();
+        }
+
+        // Opened files are locked and must not be evicted.
+        let opened = std::fs::File::open(&files[1]).unwrap();
+        opened.lock_shared().unwrap();
+
+        let stats = FileCacheStats::default();
+        let guard = GLOBAL_FILE_CACHE_LOCK.try_lock_eviction().unwrap();
+
+        assert_eq!(evict_to_max_size(dir.path(), 40, &stats, &guard), 0);
+        assert_eq!(evict_to_max_size(dir.path(), 25, &stats, &guard), 20);
+        drop(guard);
+
+        let exists = files.each_ref().map(|path| path.exists());
+        assert_eq!(exists, [false, true, false, true]);
+        assert_eq!(stats.bytes_evicted.load(), 20);
+    }
+}
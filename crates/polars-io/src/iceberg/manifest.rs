@@ -0,0 +1,286 @@
+//! Reading of Iceberg manifest lists and manifests, which are stored as Avro files.
+//!
+//! Reference: <https://iceberg.apache.org/spec/#manifests>
+
+use std::fs::File;
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_err};
+
+use crate::SerReader;
+use crate::avro::AvroReader;
+
+/// The content of a data or manifest file.
+pub(super) const CONTENT_DATA: i32 = 0;
+pub(super) const CONTENT_POSITION_DELETES: i32 = 1;
+
+/// The status of a manifest entry of a file that was deleted from the table.
+const STATUS_DELETED: i32 = 2;
+
+/// Reserved field ID of the `file_path` column of position delete files.
+pub(super) const POSITION_DELETE_FILE_PATH_ID: u32 = 2147483546;
+
+#[derive(Debug)]
+pub(super) struct ManifestFile {
+    pub path: String,
+    /// Whether the manifest tracks data or delete files.
+    pub content: i32,
+    pub partition_spec_id: i32,
+    pub sequence_number: i64,
+    /// Number of rows in the live files of the manifest, if recorded.
+    pub row_count: Option<u64>,
+    /// Summaries of the partition values of the files in the manifest, in the order of the fields
+    /// of the partition spec. Empty if they are not recorded.
+    pub partitions: Vec<FieldSummary>,
+}
+
+/// Summary of the values of a partition field over the files of a manifest.
+#[derive(Debug)]
+pub(super) struct FieldSummary {
+    pub contains_null: bool,
+    /// `None` if it is not recorded whether the field contains NaN values.
+    pub contains_nan: Option<bool>,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+#[derive(Debug)]
+pub(super) struct ManifestEntry {
+    pub content: i32,
+    pub file_path: String,
+    pub file_format: String,
+    pub partition_spec_id: i32,
+    /// Partition values, in the order of the fields of the partition spec.
+    pub partition: Vec<AnyValue<'static>>,
+    pub sequence_number: i64,
+    pub record_count: u64,
+    /// The data file a position delete file applies to, if it only applies to a single file.
+    pub referenced_data_file: Option<String>,
+    pub null_value_counts: PlHashMap<u32, u64>,
+    pub lower_bounds: PlHashMap<u32, Vec<u8>>,
+    pub upper_bounds: PlHashMap<u32, Vec<u8>>,
+}
+
+fn read_avro(path: &Path) -> PolarsResult<DataFrame> {
+    let file = File::open(path).map_err(
+        |e| polars_err!(ComputeError: "failed to open Iceberg manifest {}: {}", path.display(), e),
+    )?;
+    AvroReader::new(file).finish()
+}
+
+pub(super) fn read_manifest_list(path: &Path) -> PolarsResult<Vec<ManifestFile>> {
+    let df = read_avro(path)?;
+    let fields = Fields::from_frame(&df);
+
+    let paths = fields.str("manifest_path")?;
+    let content = fields.i64("content")?;
+    let partition_spec_id = fields.i64("partition_spec_id")?;
+    let sequence_number = fields.i64("sequence_number")?;
+    let added_rows_count = fields.i64("added_rows_count")?;
+    let existing_rows_count = fields.i64("existing_rows_count")?;
+    let partitions = fields.column("partitions").ok();
+
+    (0..df.height())
+        .map(|i| {
+            Ok(ManifestFile {
+                path: paths
+                    .get(i)
+                    .ok_or_else(|| missing("manifest_path"))?
+                    .to_string(),
+                content: get_or(&content, i, CONTENT_DATA as i64) as i32,
+                partition_spec_id: get_or(&partition_spec_id, i, 0) as i32,
+                sequence_number: get_or(&sequence_number, i, 0),
+                row_count: added_rows_count
+                    .as_ref()
+                    .and_then(|x| x.get(i))
+                    .zip(existing_rows_count.as_ref().and_then(|x| x.get(i)))
+                    .map(|(added, existing)| (added + existing) as u64),
+                partitions: read_field_summaries(partitions.as_ref(), i)?,
+            })
+        })
+        .collect()
+}
+
+/// Reads row `i` of the `partitions` field of a manifest list, which is stored as a list of
+/// structs.
+fn read_field_summaries(s: Option<&Series>, i: usize) -> PolarsResult<Vec<FieldSummary>> {
+    let Some(summaries) = s
+        .map(|s| s.list())
+        .transpose()?
+        .and_then(|x| x.get_as_series(i))
+    else {
+        return Ok(vec![]);
+    };
+
+    let fields = Fields::from_struct(&summaries)?;
+    let contains_null = fields.column("contains_null")?;
+    let contains_null = contains_null.bool()?;
+    let contains_nan = fields.column("contains_nan").ok();
+    let contains_nan = contains_nan.as_ref().map(|s| s.bool()).transpose()?;
+    let lower_bound = fields.column("lower_bound").ok();
+    let upper_bound = fields.column("upper_bound").ok();
+
+    let bound = |s: Option<&Series>, j| s.map_or(Ok(None), |s| binary_value(s, j));
+
+    (0..summaries.len())
+        .map(|j| {
+            Ok(FieldSummary {
+                // Be conservative if it is not recorded.
+                contains_null: contains_null.get(j).unwrap_or(true),
+                contains_nan: contains_nan.and_then(|x| x.get(j)),
+                lower_bound: bound(lower_bound.as_ref(), j)?,
+                upper_bound: bound(upper_bound.as_ref(), j)?,
+            })
+        })
+        .collect()
+}
+
+/// Reads the entries of a manifest, skipping files that were deleted.
+pub(super) fn read_manifest(
+    path: &Path,
+    manifest: &ManifestFile,
+) -> PolarsResult<Vec<ManifestEntry>> {
+    let df = read_avro(path)?;
+    let fields = Fields::from_frame(&df);
+
+    let status = fields.i64("status")?;
+    let sequence_number = fields.i64("sequence_number")?;
+    let data_file = Fields::from_struct(&fields.column("data_file")?)?;
+
+    let content = data_file.i64("content")?;
+    let file_path = data_file.str("file_path")?;
+    let file_format = data_file.str("file_format")?;
+    let record_count = data_file.i64("record_count")?;
+    let referenced_data_file = data_file.str("referenced_data_file")?;
+    let partition = match data_file.column("partition").ok() {
+        Some(s) => Fields::from_struct(&s)?.0,
+        None => vec![],
+    };
+    let null_value_counts = data_file.column("null_value_counts").ok();
+    let lower_bounds = data_file.column("lower_bounds").ok();
+    let upper_bounds = data_file.column("upper_bounds").ok();
+
+    let mut out = Vec::with_capacity(df.height());
+
+    for i in 0..df.height() {
+        if get_or(&status, i, 0) == STATUS_DELETED as i64 {
+            continue;
+        }
+
+        out.push(ManifestEntry {
+            content: get_or(&content, i, manifest.content as i64) as i32,
+            file_path: file_path
+                .get(i)
+                .ok_or_else(|| missing("file_path"))?
+                .to_string(),
+            file_format: file_format.get(i).unwrap_or("PARQUET").to_uppercase(),
+            partition_spec_id: manifest.partition_spec_id,
+            partition: partition
+                .iter()
+                .map(|s| Ok(s.get(i)?.into_static()))
+                .collect::<PolarsResult<_>>()?,
+            // Entries that were added in the snapshot of the manifest inherit its sequence number.
+            sequence_number: get_or(&sequence_number, i, manifest.sequence_number),
+            record_count: get_or(&record_count, i, 0) as u64,
+            referenced_data_file: referenced_data_file.get(i).map(|x| x.to_string()),
+            null_value_counts: read_map(null_value_counts.as_ref(), i, |s, j| {
+                Ok(s.cast(&DataType::Int64)?.i64()?.get(j).map(|x| x as u64))
+            })?,
+            lower_bounds: read_map(lower_bounds.as_ref(), i, binary_value)?,
+            upper_bounds: read_map(upper_bounds.as_ref(), i, binary_value)?,
+        });
+    }
+
+    Ok(out)
+}
+
+fn missing(name: &str) -> PolarsError {
+    polars_err!(ComputeError: "Iceberg manifest is missing required field '{}'", name)
+}
+
+fn get_or(ca: &Option<Int64Chunked>, i: usize, default: i64) -> i64 {
+    ca.as_ref().and_then(|x| x.get(i)).unwrap_or(default)
+}
+
+fn binary_value(s: &Series, i: usize) -> PolarsResult<Option<Vec<u8>>> {
+    Ok(s.binary()?.get(i).map(|x| x.to_vec()))
+}
+
+/// Reads row `i` of a map from field IDs, which is stored as a list of key-value structs.
+fn read_map<T>(
+    s: Option<&Series>,
+    i: usize,
+    value: impl Fn(&Series, usize) -> PolarsResult<Option<T>>,
+) -> PolarsResult<PlHashMap<u32, T>> {
+    let mut out = PlHashMap::new();
+    let Some(s) = s else {
+        return Ok(out);
+    };
+    let Some(entries) = s.list()?.get_as_series(i) else {
+        return Ok(out);
+    };
+
+    let entries = entries.struct_()?;
+    let keys = entries.field_by_name("key")?.cast(&DataType::UInt32)?;
+    let values = entries.field_by_name("value")?;
+
+    for (j, key) in keys.u32()?.iter().enumerate() {
+        if let Some(key) = key
+            && let Some(value) = value(&values, j)?
+        {
+            out.insert(key, value);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Named fields of a record, either the columns of a frame or the fields of a struct.
+struct Fields(Vec<Series>);
+
+impl Fields {
+    fn from_frame(df: &DataFrame) -> Self {
+        Self(
+            df.columns()
+                .iter()
+                .map(|c| c.as_materialized_series().clone())
+                .collect(),
+        )
+    }
+
+    fn from_struct(s: &Series) -> PolarsResult<Self> {
+        Ok(Self(s.struct_()?.fields_as_series()))
+    }
+
+    fn column(&self, name: &str) -> PolarsResult<Series> {
+        self.0
+            .iter()
+            .find(|s| s.name() == name)
+            .cloned()
+            .ok_or_else(|| missing(name))
+    }
+
+    /// Returns the field cast to `Int64`, or `None` if the field does not exist. Optional fields
+    /// are missing from manifests written with older format versions.
+    fn i64(&self, name: &str) -> PolarsResult<Option<Int64Chunked>> {
+        self.column(name)
+            .ok()
+            .map(|s| Ok(s.cast(&DataType::Int64)?.i64()?.clone()))
+            .transpose()
+    }
+
+    fn str(&self, name: &str) -> PolarsResult<StringChunked> {
+        match self.column(name) {
+            Ok(s) => Ok(s.str()?.clone()),
+            Err(_) => Ok(StringChunked::full_null(
+                PlSmallStr::from_str(name),
+                self.len(),
+            )),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.0.first().map_or(0, |s| s.len())
+    }
+}
@@ -0,0 +1,207 @@
+//! Table metadata of Iceberg tables.
+//!
+//! Reference: <https://iceberg.apache.org/spec/#table-metadata-fields>
+
+use std::path::{Path, PathBuf};
+
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use serde::Deserialize;
+
+use super::schema::NestedField;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct TableMetadata {
+    pub format_version: i32,
+    /// Only used by format version 1 tables that have a single schema.
+    pub schema: Option<SchemaJson>,
+    #[serde(default)]
+    pub schemas: Vec<SchemaJson>,
+    pub current_schema_id: Option<i32>,
+    /// Only used by format version 1 tables that have a single partition spec.
+    pub partition_spec: Option<Vec<PartitionField>>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub snapshot_log: Vec<SnapshotLogEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct SchemaJson {
+    #[serde(default)]
+    pub schema_id: i32,
+    pub fields: Vec<NestedField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct PartitionField {
+    pub source_id: u32,
+    pub name: String,
+    pub transform: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct Snapshot {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+    pub manifest_list: Option<String>,
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct SnapshotLogEntry {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+}
+
+impl TableMetadata {
+    /// Reads the metadata of the table at `path`, which is either a metadata file or the root
+    /// directory of the table.
+    pub fn try_from_path(path: &Path) -> PolarsResult<Self> {
+        let metadata_path = if path.is_dir() {
+            find_latest_metadata_file(&path.join("metadata"))?
+        } else {
+            path.to_path_buf()
+        };
+
+        let bytes = std::fs::read(&metadata_path).map_err(|e| {
+            polars_err!(
+                ComputeError:
+                "failed to read Iceberg metadata file {}: {}", metadata_path.display(), e
+            )
+        })?;
+        serde_json::from_slice(&bytes).map_err(|e| {
+            polars_err!(
+                ComputeError:
+                "failed to parse Iceberg metadata file {}: {}", metadata_path.display(), e
+            )
+        })
+    }
+
+    pub fn snapshot(&self, snapshot_id: i64) -> PolarsResult<&Snapshot> {
+        self.snapshots
+            .iter()
+            .find(|x| x.snapshot_id == snapshot_id)
+            .ok_or_else(
+                || polars_err!(ComputeError: "Iceberg snapshot ID not found: {}", snapshot_id),
+            )
+    }
+
+    /// Returns the ID of the snapshot that was current at `timestamp_ms`.
+    pub fn snapshot_id_at(&self, timestamp_ms: i64) -> PolarsResult<i64> {
+        let snapshot_id = if self.snapshot_log.is_empty() {
+            self.snapshots
+                .iter()
+                .filter(|x| x.timestamp_ms <= timestamp_ms)
+                .max_by_key(|x| x.timestamp_ms)
+                .map(|x| x.snapshot_id)
+        } else {
+            self.snapshot_log
+                .iter()
+                .take_while(|x| x.timestamp_ms <= timestamp_ms)
+                .last()
+                .map(|x| x.snapshot_id)
+        };
+
+        snapshot_id.ok_or_else(|| {
+            polars_err!(
+                ComputeError:
+                "timestamp {} is before the earliest snapshot of the Iceberg table", timestamp_ms
+            )
+        })
+    }
+
+    pub fn schema(&self, schema_id: Option<i32>) -> PolarsResult<&SchemaJson> {
+        let schema_id = schema_id.or(self.current_schema_id);
+
+        match (&self.schema, schema_id) {
+            (Some(schema), _) if self.schemas.is_empty() => Ok(schema),
+            (_, Some(schema_id)) => self
+                .schemas
+                .iter()
+                .find(|x| x.schema_id == schema_id)
+                .ok_or_else(
+                    || polars_err!(ComputeError: "Iceberg schema ID not found: {}", schema_id),
+                ),
+            _ => polars_bail!(ComputeError: "Iceberg metadata does not contain a schema"),
+        }
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> PolarsResult<PartitionSpec> {
+        if self.partition_specs.is_empty()
+            && let Some(fields) = &self.partition_spec
+        {
+            return Ok(PartitionSpec {
+                spec_id,
+                fields: fields.clone(),
+            });
+        }
+
+        self.partition_specs
+            .iter()
+            .find(|x| x.spec_id == spec_id)
+            .cloned()
+            .ok_or_else(
+                || polars_err!(ComputeError: "Iceberg partition spec ID not found: {}", spec_id),
+            )
+    }
+}
+
+/// Finds the latest metadata file in the metadata directory of a table, which is either named
+/// `v{version}.metadata.json` or `{version}-{uuid}.metadata.json`.
+fn find_latest_metadata_file(metadata_dir: &Path) -> PolarsResult<PathBuf> {
+    // Tables written by the Hadoop catalog record their current version in a hint file.
+    if let Ok(hint) = std::fs::read_to_string(metadata_dir.join("version-hint.text")) {
+        let path = metadata_dir.join(format!("v{}.metadata.json", hint.trim()));
+        if path.exists() {
+            return Ok(path);
+        }
+    }
+
+    let entries = std::fs::read_dir(metadata_dir).map_err(|e| {
+        polars_err!(
+            ComputeError:
+            "failed to list the Iceberg metadata directory {}: {}", metadata_dir.display(), e
+        )
+    })?;
+
+    let mut latest: Option<(u64, PathBuf)> = None;
+
+    for entry in entries {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|x| x.to_str()) else {
+            continue;
+        };
+        let Some(version) = name.strip_suffix(".metadata.json").and_then(|x| {
+            let x = x.strip_prefix('v').unwrap_or(x);
+            x.split('-').next()?.parse::<u64>().ok()
+        }) else {
+            continue;
+        };
+
+        if latest.as_ref().is_none_or(|(v, _)| version > *v) {
+            latest = Some((version, path));
+        }
+    }
+
+    latest.map(|(_, path)| path).ok_or_else(|| {
+        polars_err!(
+            ComputeError:
+            "no Iceberg table found: {} contains no metadata files", metadata_dir.display()
+        )
+    })
+}
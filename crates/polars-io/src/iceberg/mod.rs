@@ -0,0 +1,13 @@
+//! Native reading of Apache Iceberg tables.
+//!
+//! The table metadata, manifest lists and manifests of a snapshot are read to resolve its data
+//! files, which can then be scanned as parquet files.
+//!
+//! Reference: <https://iceberg.apache.org/spec/>
+
+mod manifest;
+mod metadata;
+mod schema;
+mod snapshot;
+
+pub use snapshot::{IcebergDataFile, IcebergSnapshot, IcebergTimeTravel};
@@ -0,0 +1,189 @@
+//! Conversion of Iceberg schemas to Polars schemas.
+//!
+//! Reference: <https://iceberg.apache.org/spec/#schemas-and-data-types>
+
+use polars_core::prelude::*;
+use polars_core::schema::iceberg::{
+    IcebergColumn, IcebergColumnType, IcebergSchema, LIST_ELEMENT_DEFAULT_ID,
+};
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(super) enum IcebergType {
+    Primitive(String),
+    Nested(NestedType),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub(super) enum NestedType {
+    Struct {
+        fields: Vec<NestedField>,
+    },
+    List {
+        #[serde(rename = "element-id")]
+        element_id: u32,
+        element: Box<IcebergType>,
+    },
+    Map {
+        #[serde(rename = "key-id")]
+        key_id: u32,
+        key: Box<IcebergType>,
+        #[serde(rename = "value-id")]
+        value_id: u32,
+        value: Box<IcebergType>,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct NestedField {
+    pub id: u32,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: IcebergType,
+}
+
+/// Converts the fields of an Iceberg schema to the Polars schema of the table, and to the
+/// [`IcebergSchema`] used to map the columns of the data files by their field IDs.
+pub(super) fn to_schemas(fields: &[NestedField]) -> PolarsResult<(Schema, IcebergSchema)> {
+    let columns = fields
+        .iter()
+        .map(|field| to_iceberg_column(field.id, &field.name, &field.type_))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let schema = columns
+        .iter()
+        .map(|col| Field::new(col.name.clone(), col.type_.to_polars_dtype()))
+        .collect();
+    let iceberg_schema = columns
+        .into_iter()
+        .map(|col| (col.physical_id, col))
+        .collect();
+
+    Ok((schema, iceberg_schema))
+}
+
+fn to_iceberg_column(id: u32, name: &str, type_: &IcebergType) -> PolarsResult<IcebergColumn> {
+    let type_ = match type_ {
+        IcebergType::Primitive(name) => IcebergColumnType::Primitive {
+            dtype: primitive_to_dtype(name)?,
+        },
+        IcebergType::Nested(NestedType::Struct { fields }) => IcebergColumnType::Struct(
+            fields
+                .iter()
+                .map(|field| {
+                    let col = to_iceberg_column(field.id, &field.name, &field.type_)?;
+                    Ok((col.physical_id, col))
+                })
+                .collect::<PolarsResult<_>>()?,
+        ),
+        IcebergType::Nested(NestedType::List {
+            element_id,
+            element,
+        }) => IcebergColumnType::List(Box::new(to_iceberg_column(
+            *element_id,
+            "element",
+            element,
+        )?)),
+        // Maps are read as a list of key-value structs.
+        IcebergType::Nested(NestedType::Map {
+            key_id,
+            key,
+            value_id,
+            value,
+        }) => {
+            let key = to_iceberg_column(*key_id, "key", key)?;
+            let value = to_iceberg_column(*value_id, "value", value)?;
+
+            IcebergColumnType::List(Box::new(IcebergColumn {
+                name: PlSmallStr::from_static("entries"),
+                physical_id: LIST_ELEMENT_DEFAULT_ID,
+                type_: IcebergColumnType::Struct(IcebergSchema::from_iter([
+                    (key.physical_id, key),
+                    (value.physical_id, value),
+                ])),
+            }))
+        },
+    };
+
+    Ok(IcebergColumn {
+        name: PlSmallStr::from_str(name),
+        physical_id: id,
+        type_,
+    })
+}
+
+pub(super) fn primitive_to_dtype(name: &str) -> PolarsResult<DataType> {
+    use DataType::*;
+
+    let dtype = match name {
+        "boolean" => Boolean,
+        "int" => Int32,
+        "long" => Int64,
+        "float" => Float32,
+        "double" => Float64,
+        "date" => Date,
+        "time" => Time,
+        "timestamp" => Datetime(TimeUnit::Microseconds, None),
+        "timestamptz" => Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+        "timestamp_ns" => Datetime(TimeUnit::Nanoseconds, None),
+        "timestamptz_ns" => Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)),
+        "string" => String,
+        "uuid" | "binary" => Binary,
+        v if v.starts_with("fixed[") => Binary,
+        v if v.starts_with("decimal(") => {
+            let (precision, scale) = v
+                .strip_prefix("decimal(")
+                .and_then(|x| x.strip_suffix(')'))
+                .and_then(|x| x.split_once(','))
+                .and_then(|(p, s)| Some((p.trim().parse().ok()?, s.trim().parse().ok()?)))
+                .ok_or_else(|| polars_err!(ComputeError: "invalid Iceberg decimal type: {}", v))?;
+
+            #[cfg(feature = "dtype-decimal")]
+            {
+                Decimal(precision, scale)
+            }
+            #[cfg(not(feature = "dtype-decimal"))]
+            {
+                let _: (usize, usize) = (precision, scale);
+                polars_bail!(ComputeError: "reading Iceberg decimal columns requires the 'dtype-decimal' feature")
+            }
+        },
+        v => polars_bail!(nyi = "Iceberg type '{}'", v),
+    };
+
+    Ok(dtype)
+}
+
+/// Decodes a lower or upper bound of a column, which is stored in the single-value serialization
+/// of the type of the column. Returns `None` for types that don't have usable bounds.
+///
+/// Reference: <https://iceberg.apache.org/spec/#binary-single-value-serialization>
+pub(super) fn decode_bound(bytes: &[u8], dtype: &DataType) -> Option<AnyValue<'static>> {
+    fn le<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+        bytes.try_into().ok()
+    }
+
+    // Bounds of columns that were promoted from `int` are still stored as 4 bytes.
+    let i64_value = || match bytes.len() {
+        4 => le::<4>(bytes).map(|x| i32::from_le_bytes(x) as i64),
+        _ => le::<8>(bytes).map(i64::from_le_bytes),
+    };
+
+    Some(match dtype {
+        DataType::Boolean => AnyValue::Boolean(*bytes.first()? != 0),
+        DataType::Int32 => AnyValue::Int32(i32::from_le_bytes(le(bytes)?)),
+        DataType::Int64 => AnyValue::Int64(i64_value()?),
+        DataType::Date => AnyValue::Date(i32::from_le_bytes(le(bytes)?)),
+        DataType::Time => AnyValue::Time(i64::from_le_bytes(le(bytes)?) * 1000),
+        DataType::Datetime(tu, tz) => {
+            AnyValue::DatetimeOwned(i64_value()?, *tu, tz.clone().map(Arc::new))
+        },
+        DataType::String => AnyValue::StringOwned(std::str::from_utf8(bytes).ok()?.into()),
+        DataType::Binary => AnyValue::BinaryOwned(bytes.to_vec()),
+        // Floats are skipped as their bounds ignore NaNs.
+        _ => return None,
+    })
+}
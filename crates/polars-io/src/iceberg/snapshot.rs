@@ -0,0 +1,770 @@
+//! Resolution of the data files of an Iceberg table snapshot.
+//!
+//! Reference: <https://iceberg.apache.org/spec/#scan-planning>
+
+use std::path::{Path, PathBuf};
+
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_core::schema::iceberg::{IcebergColumnType, IcebergSchema, IcebergSchemaRef};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_path::PlRefPath;
+
+use super::manifest::{
+    CONTENT_DATA, CONTENT_POSITION_DELETES, ManifestEntry, ManifestFile,
+    POSITION_DELETE_FILE_PATH_ID, read_manifest, read_manifest_list,
+};
+use super::metadata::{PartitionSpec, TableMetadata};
+use super::schema::{decode_bound, to_schemas};
+use crate::predicates::{ColumnStatistics, SkipBatchPredicate};
+
+/// The snapshot of an Iceberg table to read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IcebergTimeTravel {
+    /// Read the current snapshot of the table.
+    #[default]
+    Latest,
+    SnapshotId(i64),
+    /// Read the snapshot that was current at this timestamp, in milliseconds since the Unix
+    /// epoch.
+    Timestamp(i64),
+}
+
+/// A data file of an Iceberg table snapshot.
+#[derive(Debug, Clone)]
+pub struct IcebergDataFile {
+    pub path: PlRefPath,
+    pub record_count: u64,
+    /// Paths of the position delete files that apply to this file.
+    pub position_delete_files: Vec<String>,
+    partition_spec_id: i32,
+    partition: Vec<AnyValue<'static>>,
+    null_value_counts: PlHashMap<u32, u64>,
+    lower_bounds: PlHashMap<u32, Vec<u8>>,
+    upper_bounds: PlHashMap<u32, Vec<u8>>,
+}
+
+/// The data files of an Iceberg table at a specific snapshot.
+#[derive(Debug, Clone)]
+pub struct IcebergSnapshot {
+    /// `None` if the table does not have any snapshots.
+    pub snapshot_id: Option<i64>,
+    pub schema: SchemaRef,
+    /// Maps the field IDs of the columns in the data files to the columns of the table.
+    pub iceberg_schema: IcebergSchemaRef,
+    pub files: Vec<IcebergDataFile>,
+    partition_specs: PlHashMap<i32, PartitionSpec>,
+    num_deleted_rows: u64,
+}
+
+impl IcebergSnapshot {
+    /// Resolves the data files of the Iceberg table at `table_path`, which is either the path to
+    /// a metadata file or to the root directory of the table.
+    ///
+    /// Only tables on the local filesystem are supported.
+    pub fn try_new(table_path: &PlRefPath, time_travel: IcebergTimeTravel) -> PolarsResult<Self> {
+        Self::try_new_with_predicate(table_path, time_travel, |_| Ok(None))
+    }
+
+    /// Same as [`IcebergSnapshot::try_new`], but does not read the data manifests that the
+    /// partition summaries in the manifest list show to not contain any file that can match a
+    /// predicate. The predicate is created from the schema of the table by `predicate`.
+    ///
+    /// Files that do not match the predicate can still be returned, so it must still be applied
+    /// to the scan of the files.
+    pub fn try_new_with_predicate(
+        table_path: &PlRefPath,
+        time_travel: IcebergTimeTravel,
+        predicate: impl FnOnce(&SchemaRef) -> PolarsResult<Option<Arc<dyn SkipBatchPredicate>>>,
+    ) -> PolarsResult<Self> {
+        let metadata = read_metadata(table_path)?;
+        let (snapshot_id, schema_id) = resolve_snapshot(&metadata, time_travel)?;
+        let snapshot = snapshot_id.map(|x| metadata.snapshot(x)).transpose()?;
+        let (schema, iceberg_schema) = to_schemas(&metadata.schema(schema_id)?.fields)?;
+        let schema = Arc::new(schema);
+        let predicate = match snapshot {
+            Some(_) => predicate(&schema)?,
+            None => None,
+        };
+
+        let mut data_files = vec![];
+        let mut delete_files = vec![];
+        let mut partition_specs = PlHashMap::new();
+
+        if let Some(snapshot) = snapshot {
+            let Some(manifest_list) = &snapshot.manifest_list else {
+                polars_bail!(nyi = "Iceberg snapshots without a manifest list")
+            };
+
+            for manifest in read_manifest_list(&local_path(manifest_list)?)? {
+                if !partition_specs.contains_key(&manifest.partition_spec_id) {
+                    let spec = metadata.partition_spec(manifest.partition_spec_id)?;
+                    partition_specs.insert(manifest.partition_spec_id, spec);
+                }
+
+                // Delete manifests are always read, their files are matched against the data
+                // files that are kept.
+                if manifest.content == CONTENT_DATA
+                    && let Some(predicate) = &predicate
+                    && can_skip_manifest(
+                        &manifest,
+                        &partition_specs[&manifest.partition_spec_id],
+                        &iceberg_schema,
+                        predicate.as_ref(),
+                    )?
+                {
+                    if config::verbose() {
+                        eprintln!(
+                            "[IcebergSnapshot]: skipping manifest {} based on partition summaries",
+                            manifest.path
+                        );
+                    }
+                    continue;
+                }
+
+                for entry in read_manifest(&local_path(&manifest.path)?, &manifest)? {
+                    match entry.content {
+                        CONTENT_DATA => data_files.push(entry),
+                        CONTENT_POSITION_DELETES => delete_files.push(entry),
+                        _ => polars_bail!(nyi = "Iceberg equality delete files"),
+                    }
+                }
+            }
+        }
+
+        for entry in data_files.iter().chain(&delete_files) {
+            polars_ensure!(
+                entry.file_format == "PARQUET",
+                nyi = "Iceberg files of format {}",
+                entry.file_format
+            );
+        }
+
+        let num_deleted_rows = delete_files.iter().map(|x| x.record_count).sum();
+        let files = data_files
+            .into_iter()
+            .map(|entry| {
+                let position_delete_files = delete_files
+                    .iter()
+                    .filter(|delete| applies_to(delete, &entry, &partition_specs))
+                    .map(|delete| normalize_path(&delete.file_path).as_str().to_string())
+                    .collect();
+
+                IcebergDataFile {
+                    path: normalize_path(&entry.file_path),
+                    record_count: entry.record_count,
+                    position_delete_files,
+                    partition_spec_id: entry.partition_spec_id,
+                    partition: entry.partition,
+                    null_value_counts: entry.null_value_counts,
+                    lower_bounds: entry.lower_bounds,
+                    upper_bounds: entry.upper_bounds,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            snapshot_id,
+            schema,
+            iceberg_schema: Arc::new(iceberg_schema),
+            files,
+            partition_specs,
+            num_deleted_rows,
+        })
+    }
+
+    /// Resolves the schema of the Iceberg table at `table_path` at the snapshot selected by
+    /// `time_travel`. This only reads the metadata file of the table.
+    pub fn try_schema(
+        table_path: &PlRefPath,
+        time_travel: IcebergTimeTravel,
+    ) -> PolarsResult<SchemaRef> {
+        let metadata = read_metadata(table_path)?;
+        let (_, schema_id) = resolve_snapshot(&metadata, time_travel)?;
+        let (schema, _) = to_schemas(&metadata.schema(schema_id)?.fields)?;
+        Ok(Arc::new(schema))
+    }
+
+    /// Returns the `(physical, deleted)` row counts. This is only known if there are no
+    /// deletes, as position delete files may contain duplicate rows.
+    pub fn row_count(&self) -> Option<(u64, u64)> {
+        (self.num_deleted_rows == 0).then(|| (self.files.iter().map(|x| x.record_count).sum(), 0))
+    }
+
+    /// Returns the values of identity-partitioned columns for each file, keyed by the field ID of
+    /// the column. These are used for columns that are missing from the data files.
+    pub fn identity_partition_values(&self) -> PlIndexMap<u32, Result<Column, String>> {
+        let mut out = PlIndexMap::new();
+
+        for (field_id, col) in self.iceberg_schema.iter() {
+            let IcebergColumnType::Primitive { dtype } = &col.type_ else {
+                continue;
+            };
+            let values = self
+                .files
+                .iter()
+                .map(|file| self.identity_partition_value(file, *field_id))
+                .collect::<Vec<_>>();
+
+            if values.iter().all(|x| x.is_none()) {
+                continue;
+            }
+
+            let values = values
+                .into_iter()
+                .map(|x| x.unwrap_or(AnyValue::Null))
+                .collect::<Vec<_>>();
+            let column = Series::from_any_values_and_dtype(col.name.clone(), &values, dtype, false)
+                .map(Column::from)
+                .map_err(|e| format!("failed to load partition values: {e}"));
+
+            out.insert(*field_id, column);
+        }
+
+        out
+    }
+
+    fn identity_partition_value(
+        &self,
+        file: &IcebergDataFile,
+        field_id: u32,
+    ) -> Option<AnyValue<'static>> {
+        let spec = self.partition_specs.get(&file.partition_spec_id)?;
+        let idx = spec
+            .fields
+            .iter()
+            .position(|x| x.source_id == field_id && x.transform == "identity")?;
+
+        file.partition.get(idx).cloned()
+    }
+
+    /// Returns the statistics of the data files, with a `len` column and `{name}_nc`,
+    /// `{name}_min` and `{name}_max` columns for each column of the table. The bounds of
+    /// identity-partitioned columns are the partition values.
+    pub fn table_statistics(&self) -> PolarsResult<DataFrame> {
+        let height = self.files.len();
+        let mut columns = Vec::with_capacity(1 + 3 * self.iceberg_schema.len());
+
+        columns.push(Column::new(
+            PlSmallStr::from_static("len"),
+            self.files
+                .iter()
+                .map(|x| x.record_count as IdxSize)
+                .collect::<Vec<_>>(),
+        ));
+
+        for (field_id, col) in self.iceberg_schema.iter() {
+            let name = &col.name;
+            let dtype = col.type_.to_polars_dtype();
+
+            columns.push(Column::new(
+                format_pl_smallstr!("{name}_nc"),
+                self.files
+                    .iter()
+                    .map(|x| x.null_value_counts.get(field_id).map(|x| *x as IdxSize))
+                    .collect::<Vec<_>>(),
+            ));
+
+            if col.type_.is_nested() {
+                for suffix in ["min", "max"] {
+                    columns.push(Column::full_null(
+                        format_pl_smallstr!("{name}_{suffix}"),
+                        height,
+                        &dtype,
+                    ));
+                }
+                continue;
+            }
+
+            for (suffix, bounds) in [("min", true), ("max", false)] {
+                let values = self
+                    .files
+                    .iter()
+                    .map(|file| {
+                        self.identity_partition_value(file, *field_id)
+                            .filter(|x| !x.is_null())
+                            .or_else(|| {
+                                let bounds = match bounds {
+                                    true => &file.lower_bounds,
+                                    false => &file.upper_bounds,
+                                };
+                                decode_bound(bounds.get(field_id)?, &dtype)
+                            })
+                            .unwrap_or(AnyValue::Null)
+                    })
+                    .collect::<Vec<_>>();
+
+                columns.push(
+                    Series::from_any_values_and_dtype(
+                        format_pl_smallstr!("{name}_{suffix}"),
+                        &values,
+                        &dtype,
+                        false,
+                    )?
+                    .into_column(),
+                );
+            }
+        }
+
+        DataFrame::new(height, columns)
+    }
+
+    /// Returns the paths of the position delete files for each data file that has any.
+    pub fn position_deletes(&self) -> PlIndexMap<usize, Arc<[String]>> {
+        self.files
+            .iter()
+            .enumerate()
+            .filter(|(_, file)| !file.position_delete_files.is_empty())
+            .map(|(i, file)| (i, Arc::from(file.position_delete_files.as_slice())))
+            .collect()
+    }
+}
+
+/// Whether none of the files of a manifest can match a predicate, based on the partition summaries
+/// of its identity-partitioned columns.
+fn read_metadata(table_path: &PlRefPath) -> PolarsResult<TableMetadata> {
+    let metadata = TableMetadata::try_from_path(&local_path(table_path.as_str())?)?;
+    polars_ensure!(
+        metadata.format_version <= 2,
+        nyi = "Iceberg table format version {}",
+        metadata.format_version
+    );
+    Ok(metadata)
+}
+
+/// Returns the ID of the snapshot selected by `time_travel`, and the ID of the schema to read it
+/// with.
+fn resolve_snapshot(
+    metadata: &TableMetadata,
+    time_travel: IcebergTimeTravel,
+) -> PolarsResult<(Option<i64>, Option<i32>)> {
+    let snapshot_id = match time_travel {
+        IcebergTimeTravel::Latest => metadata.current_snapshot_id.filter(|x| *x >= 0),
+        IcebergTimeTravel::SnapshotId(snapshot_id) => Some(snapshot_id),
+        IcebergTimeTravel::Timestamp(timestamp_ms) => Some(metadata.snapshot_id_at(timestamp_ms)?),
+    };
+
+    // The current schema is used for the current snapshot, as it may have been updated
+    // without creating a new snapshot.
+    let schema_id = match (time_travel, snapshot_id) {
+        (IcebergTimeTravel::Latest, _) | (_, None) => None,
+        (_, Some(snapshot_id)) => metadata.snapshot(snapshot_id)?.schema_id,
+    };
+
+    Ok((snapshot_id, schema_id))
+}
+
+fn can_skip_manifest(
+    manifest: &ManifestFile,
+    spec: &PartitionSpec,
+    iceberg_schema: &IcebergSchema,
+    predicate: &dyn SkipBatchPredicate,
+) -> PolarsResult<bool> {
+    let mut statistics = PlIndexMap::new();
+
+    for (field, summary) in spec.fields.iter().zip(&manifest.partitions) {
+        if field.transform != "identity" {
+            continue;
+        }
+        let Some(col) = iceberg_schema.get(&field.source_id) else {
+            continue;
+        };
+        let IcebergColumnType::Primitive { dtype } = &col.type_ else {
+            continue;
+        };
+        // The bounds do not include NaN values.
+        if dtype.is_float() && summary.contains_nan != Some(false) {
+            continue;
+        }
+
+        let bound = |bytes: &Option<Vec<u8>>| {
+            bytes
+                .as_deref()
+                .and_then(|x| decode_bound(x, dtype))
+                .unwrap_or(AnyValue::Null)
+        };
+
+        statistics.insert(
+            col.name.clone(),
+            ColumnStatistics {
+                dtype: dtype.clone(),
+                min: bound(&summary.lower_bound),
+                max: bound(&summary.upper_bound),
+                null_count: (!summary.contains_null).then_some(0),
+            },
+        );
+    }
+
+    if statistics.is_empty() {
+        return Ok(false);
+    }
+
+    let live_columns = predicate.schema().iter_names().cloned().collect();
+    // The number of rows is only used to compare with null counts, for which a larger count is
+    // conservative.
+    let batch_size = manifest
+        .row_count
+        .map_or(IdxSize::MAX, |x| x.min(IdxSize::MAX as u64) as IdxSize);
+
+    predicate.can_skip_batch(batch_size, &live_columns, statistics)
+}
+
+/// Whether a position delete file applies to a data file.
+fn applies_to(
+    delete: &ManifestEntry,
+    data: &ManifestEntry,
+    partition_specs: &PlHashMap<i32, PartitionSpec>,
+) -> bool {
+    if delete.sequence_number < data.sequence_number {
+        return false;
+    }
+
+    // Delete files that only reference a single data file record it, or have equal bounds for
+    // the `file_path` column.
+    let referenced_data_file = delete.referenced_data_file.as_deref().or_else(|| {
+        let lower = delete.lower_bounds.get(&POSITION_DELETE_FILE_PATH_ID)?;
+        let upper = delete.upper_bounds.get(&POSITION_DELETE_FILE_PATH_ID)?;
+        (lower == upper).then(|| std::str::from_utf8(lower).ok())?
+    });
+    if let Some(path) = referenced_data_file {
+        return path == data.file_path;
+    }
+
+    let is_unpartitioned =
+        |entry: &ManifestEntry| partition_specs[&entry.partition_spec_id].fields.is_empty();
+
+    (is_unpartitioned(delete) && is_unpartitioned(data))
+        || (delete.partition_spec_id == data.partition_spec_id
+            && delete.partition == data.partition)
+}
+
+/// Converts a file URI of the table metadata to a local path.
+fn local_path(uri: &str) -> PolarsResult<PathBuf> {
+    let path = PlRefPath::new(uri);
+    polars_ensure!(
+        path.scheme().is_none_or(|x| x.is_file()),
+        nyi = "native scan of Iceberg tables in cloud storage"
+    );
+    Ok(Path::new(path.strip_scheme()).to_path_buf())
+}
+
+fn normalize_path(uri: &str) -> PlRefPath {
+    let path = PlRefPath::new(uri);
+    match path.scheme() {
+        Some(scheme) if scheme.is_file() => PlRefPath::new(path.strip_scheme()),
+        _ => path,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::path::Path;
+
+    use arrow::bitmap::Bitmap;
+    use polars_core::prelude::*;
+    use polars_utils::pl_path::PlRefPath;
+
+    use super::{IcebergSnapshot, IcebergTimeTravel};
+    use crate::SerWriter;
+    use crate::avro::AvroWriter;
+    use crate::predicates::SkipBatchPredicate;
+
+    struct Entry {
+        content: i32,
+        path: String,
+        partition: i64,
+        sequence_number: Option<i64>,
+        record_count: i64,
+        /// Bounds of the column with field ID 1.
+        bounds: Option<(i64, i64)>,
+    }
+
+    fn id_map(name: &str, rows: Vec<Vec<(i32, Series)>>, dtype: &DataType) -> Series {
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                let keys = Series::new("key".into(), row.iter().map(|x| x.0).collect::<Vec<_>>());
+                let values = row.iter().map(|x| x.1.clone()).reduce(|mut a, b| {
+                    a.append(&b).unwrap();
+                    a
+                });
+                let values = values.unwrap_or_else(|| Series::new_empty("value".into(), dtype));
+                StructChunked::from_series("".into(), keys.len(), [keys, values].iter())
+                    .unwrap()
+                    .into_series()
+            })
+            .collect::<Vec<_>>();
+        Series::new(name.into(), rows)
+    }
+
+    fn write_manifest(path: &Path, entries: &[Entry]) {
+        let n = entries.len();
+        let partition = StructChunked::from_series(
+            "partition".into(),
+            n,
+            [Series::new(
+                "p".into(),
+                entries.iter().map(|x| x.partition).collect::<Vec<_>>(),
+            )]
+            .iter(),
+        )
+        .unwrap()
+        .into_series();
+
+        let bound = |f: fn((i64, i64)) -> i64| {
+            entries
+                .iter()
+                .map(|x| {
+                    x.bounds
+                        .map(|b| {
+                            let bytes = f(b).to_le_bytes();
+                            vec![(1, Series::new("value".into(), [bytes.as_slice()]))]
+                        })
+                        .unwrap_or_default()
+                })
+                .collect()
+        };
+        let null_value_counts = entries
+            .iter()
+            .map(|x| {
+                x.bounds
+                    .map(|_| vec![(1, Series::new("value".into(), [0i64]))])
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        let data_file = StructChunked::from_series(
+            "data_file".into(),
+            n,
+            [
+                Series::new(
+                    "content".into(),
+                    entries.iter().map(|x| x.content).collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "file_path".into(),
+                    entries.iter().map(|x| x.path.as_str()).collect::<Vec<_>>(),
+                ),
+                Series::new("file_format".into(), vec!["PARQUET"; n]),
+                partition,
+                Series::new(
+                    "record_count".into(),
+                    entries.iter().map(|x| x.record_count).collect::<Vec<_>>(),
+                ),
+                id_map("null_value_counts", null_value_counts, &DataType::Int64),
+                id_map("lower_bounds", bound(|b| b.0), &DataType::Binary),
+                id_map("upper_bounds", bound(|b| b.1), &DataType::Binary),
+            ]
+            .iter(),
+        )
+        .unwrap()
+        .into_series();
+
+        let mut df = DataFrame::new_infer_height(vec![
+            Column::new("status".into(), vec![1i32; n]),
+            Column::new(
+                "sequence_number".into(),
+                entries
+                    .iter()
+                    .map(|x| x.sequence_number)
+                    .collect::<Vec<_>>(),
+            ),
+            data_file.into_column(),
+        ])
+        .unwrap();
+        AvroWriter::new(File::create(path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+    }
+
+    /// Writes a manifest list of `(path, content, sequence_number, partition)`, where
+    /// `partition` is the summary of the values of the `p` partition field.
+    fn write_manifest_list(path: &Path, manifests: &[(&Path, i32, i64, i64)]) {
+        let partitions = manifests
+            .iter()
+            .map(|x| {
+                let bound = x.3.to_le_bytes();
+                let fields = [
+                    Series::new("contains_null".into(), [false]),
+                    Series::new("lower_bound".into(), [bound.as_slice()]),
+                    Series::new("upper_bound".into(), [bound.as_slice()]),
+                ];
+                StructChunked::from_series("".into(), 1, fields.iter())
+                    .unwrap()
+                    .into_series()
+            })
+            .collect::<Vec<_>>();
+
+        let mut df = df!(
+            "manifest_path" => manifests.iter().map(|x| x.0.to_str().unwrap()).collect::<Vec<_>>(),
+            "partition_spec_id" => vec![0i32; manifests.len()],
+            "content" => manifests.iter().map(|x| x.1).collect::<Vec<_>>(),
+            "sequence_number" => manifests.iter().map(|x| x.2).collect::<Vec<_>>(),
+            "partitions" => partitions,
+        )
+        .unwrap();
+        AvroWriter::new(File::create(path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+    }
+
+    fn write_metadata(path: &Path, snapshots: &[(i64, i64, &Path)]) {
+        let snapshots = snapshots
+            .iter()
+            .map(|(id, ts, manifest_list)| {
+                format!(
+                    r#"{{"snapshot-id":{id},"timestamp-ms":{ts},"manifest-list":"{}","schema-id":0}}"#,
+                    manifest_list.to_str().unwrap()
+                )
+            })
+            .collect::<Vec<_>>();
+        let current_snapshot_id = snapshots.len() as i64;
+        let metadata = format!(
+            r#"{{"format-version":2,"table-uuid":"1","location":"/","last-updated-ms":0,
+            "current-schema-id":0,"schemas":[{{"type":"struct","schema-id":0,"fields":[
+                {{"id":1,"name":"x","required":false,"type":"long"}},
+                {{"id":2,"name":"p","required":false,"type":"long"}}]}}],
+            "default-spec-id":0,"partition-specs":[{{"spec-id":0,"fields":[
+                {{"source-id":2,"field-id":1000,"name":"p","transform":"identity"}}]}}],
+            "current-snapshot-id":{current_snapshot_id},"snapshots":[{}]}}"#,
+            snapshots.join(",")
+        );
+        std::fs::write(path, metadata).unwrap();
+    }
+
+    /// Skips batches where all values of `p` are below a value.
+    struct SkipPBelow(i64, SchemaRef);
+
+    impl SkipBatchPredicate for SkipPBelow {
+        fn schema(&self) -> &SchemaRef {
+            &self.1
+        }
+
+        fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+            let p_max = df.column("p_max")?.i64()?;
+            Ok(p_max
+                .iter()
+                .map(|x| x.is_some_and(|x| x < self.0))
+                .collect())
+        }
+    }
+
+    fn file_names(snapshot: &IcebergSnapshot) -> Vec<&str> {
+        snapshot
+            .files
+            .iter()
+            .map(|x| x.path.as_str().rsplit_once('/').unwrap().1)
+            .collect()
+    }
+
+    #[test]
+    fn test_iceberg_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let metadata_dir = root.join("metadata");
+        std::fs::create_dir_all(&metadata_dir).unwrap();
+        let data_path = |name: &str| format!("file:{}", root.join("data").join(name).display());
+
+        let m1 = metadata_dir.join("m1.avro");
+        let m2 = metadata_dir.join("m2.avro");
+        let m3 = metadata_dir.join("m3.avro");
+        write_manifest(
+            &m1,
+            &[Entry {
+                content: 0,
+                path: data_path("a.parquet"),
+                partition: 1,
+                sequence_number: None,
+                record_count: 3,
+                bounds: Some((0, 2)),
+            }],
+        );
+        write_manifest(
+            &m2,
+            &[Entry {
+                content: 0,
+                path: data_path("b.parquet"),
+                partition: 2,
+                sequence_number: Some(2),
+                record_count: 2,
+                bounds: Some((10, 11)),
+            }],
+        );
+        write_manifest(
+            &m3,
+            &[Entry {
+                content: 1,
+                path: data_path("delete.parquet"),
+                partition: 1,
+                sequence_number: Some(2),
+                record_count: 1,
+                bounds: None,
+            }],
+        );
+
+        let snap_1 = metadata_dir.join("snap-1.avro");
+        let snap_2 = metadata_dir.join("snap-2.avro");
+        write_manifest_list(&snap_1, &[(&m1, 0, 1, 1)]);
+        write_manifest_list(&snap_2, &[(&m1, 0, 1, 1), (&m2, 0, 2, 2), (&m3, 1, 2, 1)]);
+        write_metadata(
+            &metadata_dir.join("v1.metadata.json"),
+            &[(1, 1000, &snap_1)],
+        );
+        write_metadata(
+            &metadata_dir.join("v2.metadata.json"),
+            &[(1, 1000, &snap_1), (2, 2000, &snap_2)],
+        );
+
+        let table_path = PlRefPath::new(root.to_str().unwrap());
+        let latest = IcebergSnapshot::try_new(&table_path, IcebergTimeTravel::Latest).unwrap();
+        assert_eq!(latest.snapshot_id, Some(2));
+        assert_eq!(
+            latest.schema.as_ref(),
+            &Schema::from_iter([
+                Field::new("x".into(), DataType::Int64),
+                Field::new("p".into(), DataType::Int64),
+            ])
+        );
+        assert_eq!(file_names(&latest), ["a.parquet", "b.parquet"]);
+        assert_eq!(latest.position_deletes().len(), 1);
+        assert!(latest.position_deletes()[&0][0].ends_with("/data/delete.parquet"));
+        assert_eq!(latest.row_count(), None);
+
+        let partition_values = latest.identity_partition_values();
+        let p = partition_values[&2].as_ref().unwrap();
+        assert_eq!(p.i64().unwrap().to_vec(), [Some(1), Some(2)]);
+
+        let stats = latest.table_statistics().unwrap();
+        let column = |name: &str| stats.column(name).unwrap().i64().unwrap().to_vec();
+        assert_eq!(column("x_min"), [Some(0), Some(10)]);
+        assert_eq!(column("x_max"), [Some(2), Some(11)]);
+        assert_eq!(column("p_min"), [Some(1), Some(2)]);
+        assert_eq!(column("p_max"), [Some(1), Some(2)]);
+
+        for time_travel in [
+            IcebergTimeTravel::SnapshotId(1),
+            IcebergTimeTravel::Timestamp(1999),
+        ] {
+            let snapshot = IcebergSnapshot::try_new(&table_path, time_travel).unwrap();
+            assert_eq!(snapshot.snapshot_id, Some(1));
+            assert_eq!(file_names(&snapshot), ["a.parquet"]);
+            assert!(snapshot.position_deletes().is_empty());
+            assert_eq!(snapshot.row_count(), Some((3, 0)));
+        }
+
+        // The manifest of `a.parquet` only contains files with `p = 1`.
+        let pruned = IcebergSnapshot::try_new_with_predicate(
+            &table_path,
+            IcebergTimeTravel::Latest,
+            |schema| Ok(Some(Arc::new(SkipPBelow(2, schema.clone())))),
+        )
+        .unwrap();
+        assert_eq!(file_names(&pruned), ["b.parquet"]);
+        assert!(pruned.position_deletes().is_empty());
+
+        assert!(IcebergSnapshot::try_new(&table_path, IcebergTimeTravel::Timestamp(999)).is_err());
+        assert!(IcebergSnapshot::try_new(&table_path, IcebergTimeTravel::SnapshotId(3)).is_err());
+    }
+}
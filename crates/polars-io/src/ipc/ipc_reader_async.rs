@@ -18,6 +18,7 @@ use crate::cloud::{
     CloudLocation, CloudOptions, PolarsObjectStore, build_object_store, object_path_from_str,
 };
 use crate::file_cache::{FileCacheEntry, init_entries_from_uri_list};
+use crate::metrics::{IOMetrics, OptIOMetrics};
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::{IpcReader, materialize_projection};
 use crate::shared::SerReader;
@@ -27,6 +28,7 @@ pub struct IpcReaderAsync {
     store: PolarsObjectStore,
     cache_entry: Arc<FileCacheEntry>,
     path: Path,
+    io_metrics: OptIOMetrics,
 }
 
 #[derive(Default, Clone)]
@@ -70,11 +72,13 @@ impl IpcReaderAsync {
     pub async fn from_uri(
         uri: PlRefPath,
         cloud_options: Option<&CloudOptions>,
+        io_metrics: Option<Arc<IOMetrics>>,
     ) -> PolarsResult<IpcReaderAsync> {
         let cache_entry =
             init_entries_from_uri_list([uri.clone()].into_iter(), cloud_options).await?[0].clone();
-        let (CloudLocation { prefix, .. }, store) =
+        let (CloudLocation { prefix, .. }, mut store) =
             build_object_store(uri, cloud_options, false).await?;
+        store.set_io_metrics(io_metrics.clone());
 
         let path = object_path_from_str(&prefix)?;
 
@@ -82,6 +86,7 @@ impl IpcReaderAsync {
             store,
             cache_entry,
             path,
+            io_metrics: OptIOMetrics(io_metrics),
         })
     }
 
@@ -144,7 +149,8 @@ impl IpcReaderAsync {
     ) -> PolarsResult<DataFrame> {
         // TODO: Only download what is needed rather than the entire file by
         // making use of the projection, row limit, predicate and such.
-        let file = ASYNC.block_in_place(|| self.cache_entry.try_open_check_latest())?;
+        let file =
+            ASYNC.block_in_place(|| self.cache_entry.try_open_check_latest(&self.io_metrics))?;
         let bytes = MMapSemaphore::new_from_file(&file).unwrap();
 
         let projection = match options.projection.as_deref() {
@@ -193,7 +199,8 @@ impl IpcReaderAsync {
     pub async fn count_rows(&self, _metadata: Option<&FileMetadata>) -> PolarsResult<i64> {
         // TODO: Only download what is needed rather than the entire file by
         // making use of the projection, row limit, predicate and such.
-        let file = ASYNC.block_in_place(|| self.cache_entry.try_open_check_latest())?;
+        let file =
+            ASYNC.block_in_place(|| self.cache_entry.try_open_check_latest(&self.io_metrics))?;
         let bytes = MMapSemaphore::new_from_file(&file).unwrap();
         get_row_count(&mut std::io::Cursor::new(bytes.as_ref()))
     }
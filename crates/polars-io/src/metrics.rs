@@ -9,6 +9,14 @@ pub struct IOMetrics {
     pub bytes_requested: RelaxedCell<u64>,
     pub bytes_received: RelaxedCell<u64>,
     pub bytes_sent: RelaxedCell<u64>,
+    /// Number of row groups skipped after probing their bloom filters.
+    pub row_groups_skipped_bloom_filter: RelaxedCell<u64>,
+    /// Number of files opened from the file cache without downloading them.
+    pub file_cache_hits: RelaxedCell<u64>,
+    /// Number of files that were downloaded into the file cache.
+    pub file_cache_misses: RelaxedCell<u64>,
+    /// Number of bytes evicted from the file cache to make room for downloaded files.
+    pub file_cache_bytes_evicted: RelaxedCell<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -35,6 +43,26 @@ impl OptIOMetrics {
         self.0.as_ref().map(|x| x.bytes_sent.fetch_add(bytes_sent));
     }
 
+    pub fn add_row_groups_skipped_bloom_filter(&self, num_row_groups: u64) {
+        self.0
+            .as_ref()
+            .map(|x| x.row_groups_skipped_bloom_filter.fetch_add(num_row_groups));
+    }
+
+    pub fn add_file_cache_hit(&self) {
+        self.0.as_ref().map(|x| x.file_cache_hits.fetch_add(1));
+    }
+
+    pub fn add_file_cache_miss(&self) {
+        self.0.as_ref().map(|x| x.file_cache_misses.fetch_add(1));
+    }
+
+    pub fn add_file_cache_bytes_evicted(&self, num_bytes: u64) {
+        self.0
+            .as_ref()
+            .map(|x| x.file_cache_bytes_evicted.fetch_add(num_bytes));
+    }
+
     pub async fn record_io_read<F, O>(&self, num_bytes: u64, fut: F) -> O
     where
         F: Future<Output = O>,
@@ -5,8 +5,11 @@ use object_store::path::Path as ObjectPath;
 use polars_buffer::Buffer;
 use polars_core::prelude::*;
 use polars_parquet::parquet::error::ParquetError;
-use polars_parquet::parquet::read::{deserialize_metadata, deserialize_num_rows};
-use polars_parquet::parquet::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, PARQUET_MAGIC};
+use polars_parquet::parquet::read::{deserialize_metadata_with_decryption, deserialize_num_rows};
+use polars_parquet::parquet::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC,
+};
+use polars_parquet::read::FileDecryptionProperties;
 use polars_parquet::write::FileMetadata;
 use polars_utils::pl_path::PlRefPath;
 
@@ -22,6 +25,7 @@ pub struct ParquetObjectStore {
     length: Option<usize>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
+    decryption: Option<FileDecryptionProperties>,
 }
 
 impl ParquetObjectStore {
@@ -39,9 +43,16 @@ impl ParquetObjectStore {
             length: None,
             metadata,
             schema: None,
+            decryption: None,
         })
     }
 
+    /// Set the keys to decrypt a file written with Parquet modular encryption.
+    pub fn with_decryption(mut self, decryption: Option<FileDecryptionProperties>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     /// Initialize the length property of the object, unless it has already been fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
@@ -64,7 +75,7 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, length, self.decryption.as_ref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -144,7 +155,7 @@ async fn fetch_footer_bytes(
         let footer_byte_size = read_i32le(reader).unwrap();
         let magic = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != PARQUET_MAGIC {
+        if magic != PARQUET_MAGIC && magic != PARQUET_ENCRYPTED_MAGIC {
             return Err(out_of_spec("incorrect magic in parquet footer").into());
         }
         footer_byte_size
@@ -172,14 +183,16 @@ async fn fetch_footer_bytes(
     }
 }
 
-/// Asynchronously reads the files' metadata.
+/// Asynchronously reads the files' metadata, decrypting it with `decryption` if the file is
+/// encrypted.
 pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<FileMetadata> {
     let footer = fetch_footer_bytes(store, path, file_byte_length).await?;
-    Ok(deserialize_metadata(footer)?)
+    Ok(deserialize_metadata_with_decryption(footer, decryption)?)
 }
 
 /// Fetch only `FileMetaData.num_rows` from a remote parquet footer.
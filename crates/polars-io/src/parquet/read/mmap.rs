@@ -5,6 +5,7 @@ use arrow::bitmap::Bitmap;
 use arrow::datatypes::Field;
 use polars_buffer::Buffer;
 use polars_error::PolarsResult;
+use polars_parquet::parquet::read::PageMetaData;
 use polars_parquet::read::{
     BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, column_iter_to_arrays,
 };
@@ -73,3 +74,31 @@ pub fn to_deserializer(
 
     column_iter_to_arrays(columns, types, field, filter)
 }
+
+/// Like [`to_deserializer`], but for columns of which only a subset of the pages is available.
+///
+/// Every buffer holds the (optional) dictionary page followed by the data pages to decode, and
+/// the [`PageMetaData`] holds the number of values in those pages.
+pub fn to_deserializer_with_page_meta(
+    columns: Vec<(PageMetaData, Buffer<u8>)>,
+    field: Field,
+    filter: Option<Filter>,
+) -> PolarsResult<(Vec<Box<dyn Array>>, Bitmap)> {
+    let types = columns
+        .iter()
+        .map(|(page_meta, _)| page_meta.descriptor.primitive_type.clone())
+        .collect::<Vec<_>>();
+
+    let columns = columns
+        .into_iter()
+        .map(|(page_meta, chunk)| {
+            prefetch_l2(&chunk);
+
+            let pages =
+                PageReader::new_with_page_meta(Cursor::new(chunk), page_meta, vec![], usize::MAX);
+            BasicDecompressor::new(pages, vec![])
+        })
+        .collect();
+
+    column_iter_to_arrays(columns, types.iter().collect(), field, filter)
+}
@@ -20,6 +20,7 @@ mod mmap;
 mod options;
 mod read_impl;
 mod reader;
+mod sorting;
 mod utils;
 
 const ROW_COUNT_OVERFLOW_ERR: PolarsError = PolarsError::ComputeError(ErrString::new_static(
@@ -34,13 +35,16 @@ pub use async_impl::ParquetObjectStore;
 pub use options::{ParallelStrategy, ParquetOptions};
 use polars_error::{ErrString, PolarsError};
 pub use polars_parquet::arrow::read::infer_schema;
-pub use polars_parquet::read::FileMetadata;
+pub use polars_parquet::read::{
+    FileDecryptionProperties, FileMetadata, KeyRetriever, KeyRetrieverRef, SecretKey,
+};
 pub use read_impl::{create_sorting_map, try_set_sorted_flag};
 pub use reader::ParquetReader;
+pub use sorting::{file_sorting_columns, has_parquet_sort_order};
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
-    pub use super::mmap::to_deserializer;
+    pub use super::mmap::{to_deserializer, to_deserializer_with_page_meta};
     pub use super::read_impl::{PrefilterMaskSetting, calc_prefilter_cost};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
@@ -1,4 +1,5 @@
 use polars_core::schema::SchemaRef;
+use polars_parquet::read::FileDecryptionProperties;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +11,9 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// The keys to decrypt files written with Parquet modular encryption.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub decryption: Option<FileDecryptionProperties>,
 }
 
 impl Default for ParquetOptions {
@@ -19,6 +23,7 @@ impl Default for ParquetOptions {
             parallel: ParallelStrategy::default(),
             low_memory: false,
             use_statistics: true,
+            decryption: None,
         }
     }
 }
@@ -28,6 +28,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, PlRefStr)>,
+    decryption: Option<read::FileDecryptionProperties>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -62,6 +63,12 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Set the keys to decrypt a file written with Parquet modular encryption.
+    pub fn with_decryption(mut self, decryption: Option<read::FileDecryptionProperties>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     /// Add a row index column.
     pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
         self.row_index = row_index;
@@ -170,7 +177,10 @@ impl<R: MmapBytesReader> ParquetReader<R> {
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            self.metadata = Some(Arc::new(read::read_metadata_with_decryption(
+                &mut self.reader,
+                self.decryption.as_ref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -192,6 +202,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            decryption: None,
         }
     }
 
@@ -0,0 +1,178 @@
+use std::cmp::Ordering;
+
+use polars_core::prelude::*;
+use polars_parquet::parquet::metadata::SortingColumn;
+use polars_parquet::read::statistics::deserialize_all;
+use polars_parquet::read::{FileMetadata, RowGroupMetadata};
+
+use crate::parquet::write::ParquetSortingColumn;
+
+/// Whether the order of `dtype` in polars matches the order of its values in Parquet, such that
+/// the Parquet `sorting_columns` can be translated to sortedness flags and vice versa.
+pub fn has_parquet_sort_order(dtype: &DataType) -> bool {
+    use DataType as D;
+    match dtype {
+        D::Boolean | D::String | D::Binary => true,
+        D::Date | D::Time | D::Datetime(_, _) | D::Duration(_) => true,
+        dt => dt.is_integer(),
+    }
+}
+
+/// Returns the columns by which all rows of the file are sorted.
+///
+/// The `sorting_columns` recorded in the metadata only describe the order within a row group.
+/// A column is only returned if every row group records it in the same position and the
+/// statistics prove that the row groups themselves are in order. A column is returned only if
+/// all columns before it are returned as well.
+pub fn file_sorting_columns(
+    metadata: &FileMetadata,
+    schema: &ArrowSchema,
+) -> PolarsResult<Vec<ParquetSortingColumn>> {
+    let Some((first, rest)) = metadata.row_groups.split_first() else {
+        return Ok(vec![]);
+    };
+
+    let Some(sorting_columns) = first.sorting_columns() else {
+        return Ok(vec![]);
+    };
+
+    let num_common = rest.iter().fold(sorting_columns.len(), |n, rg| {
+        let other = rg.sorting_columns().unwrap_or_default();
+        sorting_columns
+            .iter()
+            .zip(other)
+            .take(n)
+            .take_while(|(l, r)| l == r)
+            .count()
+    });
+
+    let mut out = Vec::with_capacity(num_common);
+
+    for sorting_column in &sorting_columns[..num_common] {
+        let Some(field) = sorting_column_field(first, sorting_column, schema) else {
+            break;
+        };
+
+        if !has_parquet_sort_order(&DataType::from_arrow_field(field)) {
+            break;
+        }
+
+        let nulls_last = !sorting_column.nulls_first;
+
+        if !rest.is_empty()
+            && !row_groups_in_order(
+                metadata,
+                field,
+                sorting_column.column_idx as usize,
+                sorting_column.descending,
+                nulls_last,
+            )?
+        {
+            break;
+        }
+
+        out.push(ParquetSortingColumn {
+            name: field.name.clone(),
+            descending: sorting_column.descending,
+            nulls_last,
+        });
+    }
+
+    Ok(out)
+}
+
+/// The top-level field of `schema` that is stored in the leaf column of `sorting_column`.
+fn sorting_column_field<'a>(
+    rg: &RowGroupMetadata,
+    sorting_column: &SortingColumn,
+    schema: &'a ArrowSchema,
+) -> Option<&'a ArrowField> {
+    let column_idx = usize::try_from(sorting_column.column_idx).ok()?;
+    let column = rg.parquet_columns().get(column_idx)?;
+
+    let [name] = column.descriptor().path_in_schema.as_slice() else {
+        return None;
+    };
+
+    schema.get(name.as_str())
+}
+
+/// Checks with the row group statistics that the rows of consecutive row groups are ordered.
+fn row_groups_in_order(
+    metadata: &FileMetadata,
+    field: &ArrowField,
+    column_idx: usize,
+    descending: bool,
+    nulls_last: bool,
+) -> PolarsResult<bool> {
+    let Some(statistics) = deserialize_all(
+        field,
+        &metadata.row_groups,
+        column_idx,
+        &metadata.footer_buf,
+    )?
+    else {
+        return Ok(false);
+    };
+
+    let min = Series::try_from((field, statistics.min_value))?;
+    let max = Series::try_from((field, statistics.max_value))?;
+    let null_count = &statistics.null_count;
+
+    // The last non-null value of the previous row group that has non-null values.
+    let mut prev: Option<AnyValue> = None;
+    // Whether a null was seen, after which only nulls may follow.
+    let mut seen_trailing_nulls = false;
+    let mut seen_values = false;
+
+    for (i, rg) in metadata.row_groups.iter().enumerate() {
+        let Some(null_count) = null_count.get(i) else {
+            return Ok(false);
+        };
+        let all_null = null_count as usize == rg.num_rows();
+        let has_nulls = null_count > 0;
+
+        if nulls_last {
+            // No value may follow a null.
+            if seen_trailing_nulls && !all_null {
+                return Ok(false);
+            }
+            seen_trailing_nulls |= has_nulls;
+        } else if has_nulls && seen_values {
+            // No null may follow a value.
+            return Ok(false);
+        }
+
+        if all_null {
+            continue;
+        }
+        seen_values = true;
+
+        let (first, last) = if descending {
+            (max.get(i)?, min.get(i)?)
+        } else {
+            (min.get(i)?, max.get(i)?)
+        };
+
+        if first.is_null() || last.is_null() {
+            return Ok(false);
+        }
+
+        if let Some(prev_last) = &prev {
+            let expected = if descending {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+
+            match prev_last.partial_cmp(&first) {
+                Some(ord) if ord == expected || ord == Ordering::Equal => {},
+                _ => return Ok(false),
+            }
+        }
+
+        prev = Some(last.into_static());
+    }
+
+    Ok(true)
+}
@@ -7,13 +7,16 @@ use polars_core::prelude::*;
 use polars_core::runtime::RAYON;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
-    CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding, FallibleStreamingIterator,
-    FileWriter, Page, ParquetType, RowGroupIterColumns, SchemaDescriptor, WriteOptions,
-    array_to_columns, schema_to_metadata_key,
+    BloomFilterOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
+    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
+    SchemaDescriptor, SortingColumn, WriteOptions, array_to_bloom_filters, array_to_columns,
+    schema_to_metadata_key, to_parquet_leaves,
 };
 use rayon::prelude::*;
 
+use super::writer::leaf_column_index;
 use super::{KeyValueMetadata, ParquetMetadataContext};
+use crate::parquet::read::has_parquet_sort_order;
 
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
@@ -24,6 +27,9 @@ pub struct BatchedWriter<W: Write> {
     // @TODO: Remove when old streaming engine is removed
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Buffer<Vec<Encoding>>,
+    pub(super) bloom_filters: Buffer<Option<BloomFilterOptions>>,
+    /// If `None`, the sorting columns of every batch are derived from its sortedness flags.
+    pub(super) sorting_columns: Option<Vec<SortingColumn>>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
     pub(super) key_value_metadata: Option<KeyValueMetadata>,
@@ -41,6 +47,8 @@ impl<W: Write> BatchedWriter<W> {
             writer,
             parquet_schema: SchemaDescriptor::new(PlSmallStr::EMPTY, vec![]),
             encodings,
+            bloom_filters: Buffer::default(),
+            sorting_columns: None,
             options,
             parallel,
             key_value_metadata,
@@ -72,17 +80,23 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let sorting_columns = match &self.sorting_columns {
+            Some(sorting_columns) => sorting_columns.clone(),
+            None => sorting_columns_from_flags(df, &self.parquet_schema),
+        };
         let row_group_iter = prepare_rg_iter(
             df,
             &self.parquet_schema,
             &self.encodings,
+            &self.bloom_filters,
             self.options,
             self.parallel,
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
-        for (num_rows, group) in row_group_iter {
-            writer.write(num_rows as u64, group?)?;
+        writer.set_sorting_columns((!sorting_columns.is_empty()).then_some(sorting_columns))?;
+        for (num_rows, group, bloom_filters) in row_group_iter {
+            writer.write_with_bloom_filters(num_rows as u64, group?, bloom_filters)?;
         }
         Ok(())
     }
@@ -93,10 +107,13 @@ impl<W: Write> BatchedWriter<W> {
     }
 
     /// Note: `num_rows` can be passed as `u64::MAX` to infer `num_rows` from the encoded data.
+    ///
+    /// `bloom_filters` is either empty or contains the bloom filter bitset of every leaf column.
     pub fn write_row_group(
         &mut self,
         num_rows: u64,
         rg: &[Vec<CompressedPage>],
+        bloom_filters: Vec<Option<Vec<u8>>>,
     ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
@@ -104,7 +121,7 @@ impl<W: Write> BatchedWriter<W> {
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(num_rows, rg)?;
+        writer.write_with_bloom_filters(num_rows, rg, bloom_filters)?;
         Ok(())
     }
 
@@ -149,31 +166,89 @@ impl<W: Write> BatchedWriter<W> {
     }
 }
 
+/// The sorting columns of the row groups of `df`, derived from the sortedness flags of its
+/// columns. Every row group is a slice of `df`, so it is sorted if the full column is.
+fn sorting_columns_from_flags(
+    df: &DataFrame,
+    parquet_schema: &SchemaDescriptor,
+) -> Vec<SortingColumn> {
+    df.columns()
+        .iter()
+        .filter_map(|c| {
+            let descending = match c.is_sorted_flag() {
+                IsSorted::Ascending => false,
+                IsSorted::Descending => true,
+                IsSorted::Not => return None,
+            };
+
+            if !has_parquet_sort_order(c.dtype()) {
+                return None;
+            }
+
+            let column_idx = leaf_column_index(parquet_schema, c.name())?;
+            let last_is_null = c.len() > 0 && c.get(c.len() - 1).is_ok_and(|v| v.is_null());
+
+            Some(SortingColumn {
+                column_idx: column_idx as i32,
+                descending,
+                nulls_first: !last_is_null,
+            })
+        })
+        .collect()
+}
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
+    bloom_filters: &'a [Option<BloomFilterOptions>],
     options: WriteOptions,
     parallel: bool,
 ) -> impl Iterator<
     Item = (
         usize,
         PolarsResult<RowGroupIterColumns<'static, PolarsError>>,
+        Vec<Option<Vec<u8>>>,
     ),
 > + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         num_rows => {
+            let bloom_filters =
+                create_bloom_filters(&batch, parquet_schema.fields(), bloom_filters);
             let row_group =
                 create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
 
-            Some((num_rows, row_group))
+            Some((num_rows, row_group, bloom_filters))
         },
     })
 }
 
+/// Builds the bloom filter bitsets of every leaf column of `batch`. Returns an empty `Vec` if
+/// no column has bloom filters enabled.
+fn create_bloom_filters(
+    batch: &RecordBatch,
+    fields: &[ParquetType],
+    bloom_filters: &[Option<BloomFilterOptions>],
+) -> Vec<Option<Vec<u8>>> {
+    if bloom_filters.iter().all(Option::is_none) {
+        return vec![];
+    }
+
+    batch
+        .columns()
+        .iter()
+        .zip(fields)
+        .zip(bloom_filters)
+        .flat_map(|((array, type_), options)| match options {
+            Some(options) => array_to_bloom_filters(array.as_ref(), options),
+            None => vec![None; to_parquet_leaves(type_.clone()).len()],
+        })
+        .collect()
+}
+
 fn pages_iter_to_compressor(
     encoded_columns: Vec<DynIter<'static, PolarsResult<Page>>>,
     options: WriteOptions,
@@ -7,6 +7,9 @@ mod writer;
 
 pub use batched_writer::BatchedWriter;
 pub use key_value_metadata::{KeyValueMetadata, ParquetMetadataContext};
-pub use options::{ParquetCompression, ParquetWriteOptions};
-pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
-pub use writer::{ParquetWriter, get_encodings};
+pub use options::{ParquetCompression, ParquetSortingColumn, ParquetWriteOptions};
+pub use polars_parquet::write::{
+    BloomFilterOptions, ColumnKey, EncryptionAlgorithm, FileEncryptionProperties,
+    RowGroupIterColumns, SecretKey, StatisticsOptions,
+};
+pub use writer::{ParquetWriter, get_bloom_filter_options, get_encodings, get_sorting_columns};
@@ -1,14 +1,16 @@
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::CompatLevel;
 use polars_parquet::write::{
-    BrotliLevel, CompressionOptions, GzipLevel, StatisticsOptions, ZstdLevel,
+    BloomFilterOptions, BrotliLevel, CompressionOptions, FileEncryptionProperties, GzipLevel,
+    StatisticsOptions, ZstdLevel,
 };
+use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::KeyValueMetadata;
 
-#[derive(Default, Clone, Debug, PartialEq, Hash, Eq)]
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct ParquetWriteOptions {
@@ -25,6 +27,43 @@ pub struct ParquetWriteOptions {
     pub arrow_schema: Option<ArrowSchemaRef>,
     #[cfg_attr(feature = "serde", serde(default))]
     pub compat_level: Option<CompatLevel>,
+    /// Columns to write split-block bloom filters for.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bloom_filters: Vec<(PlSmallStr, BloomFilterOptions)>,
+    /// Write the page index (page-level min/max statistics and page locations), which allows
+    /// readers to skip individual pages.
+    #[cfg_attr(feature = "serde", serde(default = "default_write_page_index"))]
+    pub write_page_index: bool,
+    /// The columns the data is sorted by, recorded as the `sorting_columns` of every row group.
+    /// If `None`, these are derived from the sortedness of the written columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sorting_columns: Option<Vec<ParquetSortingColumn>>,
+    /// Encrypt the file with Parquet modular encryption.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub encryption: Option<FileEncryptionProperties>,
+}
+
+#[cfg(feature = "serde")]
+fn default_write_page_index() -> bool {
+    true
+}
+
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        Self {
+            compression: ParquetCompression::default(),
+            statistics: StatisticsOptions::default(),
+            row_group_size: None,
+            data_page_size: None,
+            key_value_metadata: None,
+            arrow_schema: None,
+            compat_level: None,
+            bloom_filters: Vec::new(),
+            write_page_index: true,
+            sorting_columns: None,
+            encryption: None,
+        }
+    }
 }
 
 impl ParquetWriteOptions {
@@ -33,6 +72,16 @@ impl ParquetWriteOptions {
     }
 }
 
+/// A column that the rows of a Parquet row group are sorted by.
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetSortingColumn {
+    pub name: PlSmallStr,
+    pub descending: bool,
+    pub nulls_last: bool,
+}
+
 /// The compression strategy to use for writing Parquet files.
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
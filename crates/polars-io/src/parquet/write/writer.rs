@@ -5,13 +5,14 @@ use polars_buffer::Buffer;
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 use polars_parquet::write::{
-    CompressionOptions, Encoding, FileWriter, StatisticsOptions, Version, WriteOptions,
-    get_dtype_encoding, to_parquet_schema,
+    BloomFilterOptions, CompressionOptions, Encoding, FileEncryptionProperties, FileWriter,
+    SchemaDescriptor, SortingColumn, StatisticsOptions, Version, WriteOptions, get_dtype_encoding,
+    to_parquet_schema,
 };
 
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
-use super::{KeyValueMetadata, ParquetWriteOptions};
+use super::{KeyValueMetadata, ParquetSortingColumn, ParquetWriteOptions};
 use crate::shared::schema_to_arrow_checked;
 
 impl ParquetWriteOptions {
@@ -25,6 +26,10 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_bloom_filters(self.bloom_filters.clone())
+            .with_page_index(self.write_page_index)
+            .with_sorting_columns(self.sorting_columns.clone())
+            .with_encryption(self.encryption.clone())
     }
 }
 
@@ -46,6 +51,14 @@ pub struct ParquetWriter<W> {
     key_value_metadata: Option<KeyValueMetadata>,
     /// Context info for the Parquet file being written.
     context_info: Option<PlHashMap<String, String>>,
+    /// Columns to write split-block bloom filters for.
+    bloom_filters: Vec<(PlSmallStr, BloomFilterOptions)>,
+    /// Write the page index
+    write_page_index: bool,
+    /// The columns the data is sorted by. If `None`, derived from the sortedness flags.
+    sorting_columns: Option<Vec<ParquetSortingColumn>>,
+    /// Parquet modular encryption of the file.
+    encryption: Option<FileEncryptionProperties>,
 }
 
 impl<W> ParquetWriter<W>
@@ -66,6 +79,10 @@ where
             parallel: true,
             key_value_metadata: None,
             context_info: None,
+            bloom_filters: Vec::new(),
+            write_page_index: true,
+            sorting_columns: None,
+            encryption: None,
         }
     }
 
@@ -115,17 +132,62 @@ where
         self
     }
 
+    /// Write split-block bloom filters for the given columns. Bloom filters allow readers to
+    /// skip row groups on equality predicates.
+    pub fn with_bloom_filters(
+        mut self,
+        bloom_filters: Vec<(PlSmallStr, BloomFilterOptions)>,
+    ) -> Self {
+        self.bloom_filters = bloom_filters;
+        self
+    }
+
+    /// Write the page index, which allows readers to skip pages based on their statistics.
+    /// Defaults to `true`.
+    pub fn with_page_index(mut self, write_page_index: bool) -> Self {
+        self.write_page_index = write_page_index;
+        self
+    }
+
+    /// Set the columns the data is sorted by, which are written as the `sorting_columns` of
+    /// every row group. If `None`, the sorting columns are derived from the sortedness flags of
+    /// the written columns.
+    pub fn with_sorting_columns(
+        mut self,
+        sorting_columns: Option<Vec<ParquetSortingColumn>>,
+    ) -> Self {
+        self.sorting_columns = sorting_columns;
+        self
+    }
+
+    /// Encrypt the file with Parquet modular encryption. The bloom filters and page indexes of
+    /// encrypted columns are not written.
+    pub fn with_encryption(mut self, encryption: Option<FileEncryptionProperties>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
         let parquet_schema = to_parquet_schema(&schema)?;
         let encodings = get_encodings(&schema);
+        let bloom_filters = get_bloom_filter_options(&schema, &self.bloom_filters)?;
+        let sorting_columns = self
+            .sorting_columns
+            .as_deref()
+            .map(|sorting_columns| get_sorting_columns(&parquet_schema, sorting_columns))
+            .transpose()?;
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
+        let mut writer = FileWriter::try_new(self.writer, schema, options)?;
+        writer.set_encryption(self.encryption)?;
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
+            bloom_filters,
+            sorting_columns,
             options,
             parallel: self.parallel,
             key_value_metadata: self.key_value_metadata,
@@ -138,6 +200,7 @@ where
             compression: self.compression,
             version: Version::V1,
             data_page_size: self.data_page_size,
+            write_page_index: self.write_page_index,
         }
     }
 
@@ -157,3 +220,55 @@ pub fn get_encodings(schema: &ArrowSchema) -> Buffer<Vec<Encoding>> {
         .map(|f| get_dtype_encoding(&f.dtype))
         .collect()
 }
+
+/// Resolves the bloom filter options of every top-level column of `schema`.
+pub fn get_bloom_filter_options(
+    schema: &ArrowSchema,
+    bloom_filters: &[(PlSmallStr, BloomFilterOptions)],
+) -> PolarsResult<Buffer<Option<BloomFilterOptions>>> {
+    let mut out = vec![None; schema.len()];
+
+    for (name, options) in bloom_filters {
+        options.validate()?;
+        let Some(idx) = schema.index_of(name) else {
+            polars_bail!(ColumnNotFound: "cannot write bloom filter for column '{}': not found in schema", name);
+        };
+        out[idx] = Some(*options);
+    }
+
+    Ok(out.into())
+}
+
+/// Resolves the parquet leaf column of every sorting column.
+pub fn get_sorting_columns(
+    parquet_schema: &SchemaDescriptor,
+    sorting_columns: &[ParquetSortingColumn],
+) -> PolarsResult<Vec<SortingColumn>> {
+    sorting_columns
+        .iter()
+        .map(|sorting_column| {
+            let name = &sorting_column.name;
+            let Some(column_idx) = leaf_column_index(parquet_schema, name) else {
+                polars_bail!(
+                    ColumnNotFound:
+                    "cannot write sorting column '{}': not found in schema or not a primitive column",
+                    name
+                );
+            };
+
+            Ok(SortingColumn {
+                column_idx: column_idx as i32,
+                descending: sorting_column.descending,
+                nulls_first: !sorting_column.nulls_last,
+            })
+        })
+        .collect()
+}
+
+/// The index of the parquet leaf column of the top-level primitive column `name`.
+pub(super) fn leaf_column_index(parquet_schema: &SchemaDescriptor, name: &str) -> Option<usize> {
+    parquet_schema
+        .columns()
+        .iter()
+        .position(|c| matches!(c.path_in_schema.as_slice(), [path] if path == name))
+}
@@ -0,0 +1,186 @@
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::prelude::*;
+
+/// The maximum number of keys accepted by [`LazyFrame::group_by_cube`].
+const MAX_CUBE_KEYS: usize = 12;
+
+#[derive(Clone)]
+enum GroupingSets {
+    Sets(Vec<Vec<usize>>),
+    Rollup,
+    Cube,
+}
+
+/// Utility struct for aggregating over several grouping sets at once.
+///
+/// Created by [`LazyFrame::group_by_grouping_sets`], [`LazyFrame::group_by_rollup`] and
+/// [`LazyFrame::group_by_cube`].
+#[derive(Clone)]
+#[must_use]
+pub struct LazyGroupingSets {
+    input: LazyFrame,
+    keys: Vec<Expr>,
+    sets: GroupingSets,
+    grouping_id: Option<PlSmallStr>,
+}
+
+impl LazyFrame {
+    /// Group by each of the given sets of keys and concatenate the results (SQL
+    /// `GROUPING SETS`).
+    ///
+    /// Every set holds indices into `by`. The result has a column for each key in `by`;
+    /// keys that are not part of a set are null in the rows produced for that set.
+    pub fn group_by_grouping_sets<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+        sets: Vec<Vec<usize>>,
+    ) -> LazyGroupingSets {
+        LazyGroupingSets::new(self, by, GroupingSets::Sets(sets))
+    }
+
+    /// Group by every prefix of the keys, from all keys down to none (SQL `ROLLUP`).
+    ///
+    /// Grouping by `[a, b, c]` aggregates over the sets `(a, b, c)`, `(a, b)`, `(a)` and `()`.
+    pub fn group_by_rollup<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+    ) -> LazyGroupingSets {
+        LazyGroupingSets::new(self, by, GroupingSets::Rollup)
+    }
+
+    /// Group by every subset of the keys (SQL `CUBE`).
+    ///
+    /// Grouping by `[a, b]` aggregates over the sets `(a, b)`, `(a)`, `(b)` and `()`.
+    pub fn group_by_cube<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> LazyGroupingSets {
+        LazyGroupingSets::new(self, by, GroupingSets::Cube)
+    }
+}
+
+impl LazyGroupingSets {
+    fn new<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        input: LazyFrame,
+        by: E,
+        sets: GroupingSets,
+    ) -> Self {
+        Self {
+            input,
+            keys: by.as_ref().iter().map(|e| e.clone().into()).collect(),
+            sets,
+            grouping_id: None,
+        }
+    }
+
+    /// Add a `UInt64` column `name` identifying the grouping set of each row.
+    ///
+    /// Bit `n - 1 - i` of the id is set if the `i`-th of `n` keys is *not* part of the
+    /// grouping set (as for SQL `GROUPING_ID`). The column is also available to the
+    /// aggregations, holding the id of the grouping set being aggregated.
+    pub fn with_grouping_id(mut self, name: impl Into<PlSmallStr>) -> Self {
+        self.grouping_id = Some(name.into());
+        self
+    }
+
+    /// Group by each grouping set and apply the aggregations.
+    ///
+    /// The input is cached so that it is only computed once for all grouping sets. The
+    /// output columns are the keys, the grouping id (if requested) and the aggregations.
+    pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> PolarsResult<LazyFrame> {
+        let n_keys = self.keys.len();
+        let sets = match self.sets {
+            GroupingSets::Sets(sets) => {
+                for idx in sets.iter().flatten() {
+                    polars_ensure!(
+                        *idx < n_keys,
+                        OutOfBounds: "grouping set index {} is out of bounds for {} keys", idx, n_keys
+                    );
+                }
+                sets
+            },
+            GroupingSets::Rollup => (0..=n_keys).rev().map(|n| (0..n).collect()).collect(),
+            GroupingSets::Cube => {
+                polars_ensure!(
+                    n_keys <= MAX_CUBE_KEYS,
+                    InvalidOperation: "cube supports at most {} keys (got {})", MAX_CUBE_KEYS, n_keys
+                );
+                (0..1usize << n_keys)
+                    .rev()
+                    .map(|mask| {
+                        (0..n_keys)
+                            .filter(|i| mask & (1 << (n_keys - 1 - i)) != 0)
+                            .collect()
+                    })
+                    .collect()
+            },
+        };
+        polars_ensure!(!sets.is_empty(), InvalidOperation: "at least one grouping set is required");
+        if self.grouping_id.is_some() {
+            polars_ensure!(
+                n_keys < u64::BITS as usize,
+                InvalidOperation: "a grouping id supports at most {} keys (got {})", u64::BITS - 1, n_keys
+            );
+        }
+
+        let key_names = self
+            .keys
+            .iter()
+            .map(|k| k.clone().meta().output_name())
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut fixed_names = PlHashSet::with_capacity(n_keys + 1);
+        for name in key_names.iter().chain(&self.grouping_id) {
+            polars_ensure!(
+                fixed_names.insert(name.clone()),
+                Duplicate: "grouping keys contain duplicate output name '{}'", name
+            );
+        }
+        let mut output_order: Vec<Expr> = key_names.iter().cloned().map(col).collect();
+        output_order.extend(self.grouping_id.iter().cloned().map(col));
+        output_order.push(all().exclude_cols(Vec::from_iter(fixed_names)).as_expr());
+
+        let input = self.input.cache();
+        let aggs = aggs.as_ref();
+        let branches = sets
+            .iter()
+            .map(|set| {
+                let grouping_id: u64 = (0..n_keys)
+                    .filter(|i| !set.contains(i))
+                    .map(|i| 1 << (n_keys - 1 - i))
+                    .sum();
+                let mut lf = input.clone();
+                if let Some(name) = &self.grouping_id {
+                    lf = lf.with_column(lit(grouping_id).alias(name.clone()));
+                }
+                let set_keys: Vec<Expr> = set.iter().map(|&i| self.keys[i].clone()).collect();
+                lf = if set_keys.is_empty() {
+                    lf.select(aggs)
+                } else {
+                    lf.group_by(set_keys).agg(aggs)
+                };
+
+                // Keys that are not part of the set are null.
+                let mut fill: Vec<Expr> = (0..n_keys)
+                    .filter(|i| !set.contains(i))
+                    .map(|i| lit(NULL).alias(key_names[i].clone()))
+                    .collect();
+                fill.extend(
+                    self.grouping_id
+                        .iter()
+                        .map(|name| lit(grouping_id).alias(name.clone())),
+                );
+                if !fill.is_empty() {
+                    lf = lf.with_columns(fill);
+                }
+                lf.select(&output_order)
+            })
+            .collect::<Vec<_>>();
+
+        concat(
+            branches,
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )
+    }
+}
@@ -1,6 +1,12 @@
 pub(crate) use polars_expr::prelude::*;
+#[cfg(feature = "avro")]
+pub use polars_io::avro::{AvroCodec, AvroWriterOptions};
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
+#[cfg(feature = "delta")]
+pub use polars_io::delta::DeltaTimeTravel;
+#[cfg(feature = "iceberg")]
+pub use polars_io::iceberg::IcebergTimeTravel;
 #[cfg(feature = "ipc")]
 pub use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
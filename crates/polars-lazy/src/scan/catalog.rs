@@ -1,6 +1,10 @@
 use polars_core::error::{PolarsResult, feature_gated, polars_bail};
+#[cfg(feature = "delta")]
+use polars_io::catalog::unity::client::CatalogClient;
 use polars_io::catalog::unity::models::{DataSourceFormat, TableInfo};
 use polars_io::catalog::unity::schema::table_info_to_schemas;
+#[cfg(feature = "delta")]
+use polars_io::catalog::unity::write::WriteTableOptions;
 use polars_io::cloud::CloudOptions;
 use polars_utils::pl_path::PlRefPath;
 
@@ -54,4 +58,72 @@ impl LazyFrame {
             ),
         }
     }
+
+    /// Creates a sink that writes the query to a catalog table, see
+    /// [`CatalogClient::prepare_write_table`].
+    ///
+    /// The table is resolved, and created if needed, when the sink is created. The query is
+    /// streamed to a new data file in the storage location of the table with the cloud writer,
+    /// and the file is committed to the Delta table once it is written. If nothing is to be
+    /// written, the returned frame is empty.
+    #[cfg(feature = "delta")]
+    pub fn sink_unity(
+        mut self,
+        client: &CatalogClient,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        options: &WriteTableOptions,
+    ) -> PolarsResult<Self> {
+        use std::sync::Arc;
+
+        use polars_core::prelude::DataFrame;
+        use polars_core::runtime::ASYNC;
+        use polars_plan::dsl::sink::{SinkedPathsCallback, SinkedPathsCallbackArgs};
+        use polars_plan::dsl::{
+            FileWriteFormat, SinkDestination, SinkTarget, SpecialEq, UnifiedSinkArgs,
+        };
+        use polars_plan::prelude::PlanCallback;
+
+        use crate::frame::IntoLazy;
+
+        let schema = self.collect_schema()?;
+        let Some(write) = ASYNC.block_in_place_on(client.prepare_write_table(
+            catalog_name,
+            namespace,
+            table_name,
+            &schema,
+            options,
+        ))?
+        else {
+            return Ok(DataFrame::empty().lazy());
+        };
+
+        let path = write.new_data_file_path();
+        let cloud_options = write.cloud_options().cloned().map(Arc::new);
+        let commit: Arc<dyn Fn(SinkedPathsCallbackArgs) -> PolarsResult<()> + Send + Sync> =
+            Arc::new(move |args| {
+                let paths = args
+                    .path_info_list
+                    .into_iter()
+                    .map(|x| x.path)
+                    .collect::<Vec<_>>();
+                ASYNC.block_in_place_on(write.commit(&paths)).map(|_| ())
+            });
+
+        self.sink(
+            SinkDestination::File {
+                target: SinkTarget::Path(path),
+            },
+            FileWriteFormat::Parquet(Arc::new(options.delta.parquet_options.clone())),
+            UnifiedSinkArgs {
+                mkdir: true,
+                cloud_options,
+                sinked_paths_callback: Some(SinkedPathsCallback::Callback(PlanCallback::Rust(
+                    SpecialEq::new(commit),
+                ))),
+                ..Default::default()
+            },
+        )
+    }
 }
@@ -0,0 +1,103 @@
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+use polars_io::HiveOptions;
+use polars_io::delta::{DeltaSnapshot, DeltaTimeTravel};
+use polars_io::prelude::ParquetOptions;
+use polars_plan::dsl::default_values::{DefaultFieldValues, DeltaPartitionValues};
+use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_utils::pl_path::PlRefPath;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsDelta {
+    /// The version of the table to read.
+    pub time_travel: DeltaTimeTravel,
+    pub rechunk: bool,
+    pub cache: bool,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsDelta {
+    fn default() -> Self {
+        Self {
+            time_travel: DeltaTimeTravel::Latest,
+            rechunk: false,
+            cache: true,
+            include_file_paths: None,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a Delta Lake table.
+    ///
+    /// The transaction log is replayed to resolve the data files of the requested table version.
+    /// The partition values recorded in the log are attached to each file as hive partitions and
+    /// deletion vectors are applied while scanning.
+    /// Only tables on the local filesystem are supported.
+    pub fn scan_delta(path: PlRefPath, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let snapshot = DeltaSnapshot::try_new(&path, args.time_travel)?;
+        let partition_values = snapshot.partition_values()?;
+
+        let sources = ScanSources::Paths(Buffer::from_iter(
+            snapshot.files.iter().map(|file| file.path.clone()),
+        ));
+        let deletion_vectors = snapshot
+            .files
+            .iter()
+            .enumerate()
+            .filter_map(|(i, file)| Some((i, file.deletion_vector.clone()?)))
+            .collect::<PlIndexMap<_, _>>();
+
+        let parquet_options = ParquetOptions {
+            schema: Some(snapshot.file_schema()),
+            parallel: Default::default(),
+            low_memory: false,
+            use_statistics: true,
+            decryption: None,
+        };
+
+        let unified_scan_args = UnifiedScanArgs {
+            schema: None,
+            cloud_options: None,
+            hive_options: HiveOptions::new_disabled(),
+            rechunk: args.rechunk,
+            cache: args.cache,
+            glob: false,
+            hidden_file_prefix: None,
+            projection: None,
+            column_mapping: None,
+            default_values: partition_values
+                .map(|df| DefaultFieldValues::Delta(Arc::new(DeltaPartitionValues(df)))),
+            row_index: None,
+            pre_slice: None,
+            // Files may have been written with older versions of the table schema.
+            cast_columns_policy: CastColumnsPolicy {
+                integer_upcast: true,
+                float_upcast: true,
+                float_downcast: true,
+                datetime_nanoseconds_downcast: true,
+                datetime_convert_timezone: true,
+                categorical_to_string: true,
+                missing_struct_fields: MissingColumnsPolicy::Insert,
+                extra_struct_fields: ExtraColumnsPolicy::Ignore,
+                ..CastColumnsPolicy::ERROR_ON_MISMATCH
+            },
+            missing_columns_policy: MissingColumnsPolicy::Insert,
+            extra_columns_policy: ExtraColumnsPolicy::Ignore,
+            include_file_paths: args.include_file_paths,
+            deletion_files: DeletionFilesList::filter_empty(Some(
+                DeletionFilesList::DeltaDeletionVectors(Arc::new(deletion_vectors)),
+            )),
+            table_statistics: None,
+            row_count: snapshot.row_count(),
+        };
+
+        Ok(
+            DslBuilder::scan_parquet(sources, parquet_options, unified_scan_args)?
+                .build()
+                .into(),
+        )
+    }
+}
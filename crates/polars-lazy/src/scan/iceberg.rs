@@ -0,0 +1,184 @@
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+use polars_expr::ExpressionConversionState;
+use polars_io::HiveOptions;
+use polars_io::cloud::CloudOptions;
+use polars_io::iceberg::{IcebergSnapshot, IcebergTimeTravel};
+use polars_io::predicates::SkipBatchPredicate;
+use polars_io::prelude::ParquetOptions;
+use polars_mem_engine::scan_predicate::create_scan_predicate;
+use polars_plan::dsl::default_values::{
+    DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
+};
+use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::dsl::{ColumnMapping, DatasetProvider, TableStatistics};
+use polars_plan::plans::expr_ir::ExprIR;
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_path::PlRefPath;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsIceberg {
+    /// The snapshot of the table to read.
+    pub time_travel: IcebergTimeTravel,
+    pub cloud_options: Option<CloudOptions>,
+    pub rechunk: bool,
+    pub cache: bool,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsIceberg {
+    fn default() -> Self {
+        Self {
+            time_travel: IcebergTimeTravel::Latest,
+            cloud_options: None,
+            rechunk: false,
+            cache: true,
+            include_file_paths: None,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from an Apache Iceberg table.
+    ///
+    /// `table_path` is either the path to a metadata file or to the root directory of the table.
+    /// Columns are mapped to the data files by their field IDs. The files of the table are
+    /// resolved when the query is optimized: manifests whose partition summaries show that none
+    /// of their files can match the predicate pushed down to the scan are not read, and files are
+    /// skipped based on their partition values and column bounds.
+    pub fn scan_iceberg(table_path: PlRefPath, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let provider = IcebergDataset {
+            table_path,
+            time_travel: args.time_travel,
+            cloud_options: args.cloud_options.clone(),
+            rechunk: args.rechunk,
+            cache: args.cache,
+        };
+
+        let unified_scan_args = UnifiedScanArgs {
+            cloud_options: args.cloud_options,
+            rechunk: args.rechunk,
+            cache: args.cache,
+            include_file_paths: args.include_file_paths,
+            ..Default::default()
+        };
+
+        Ok(
+            DslBuilder::scan_dataset(Arc::new(provider), unified_scan_args)
+                .build()
+                .into(),
+        )
+    }
+}
+
+/// Resolves the data files of an Iceberg table snapshot to a Parquet scan.
+struct IcebergDataset {
+    table_path: PlRefPath,
+    time_travel: IcebergTimeTravel,
+    cloud_options: Option<CloudOptions>,
+    rechunk: bool,
+    cache: bool,
+}
+
+impl DatasetProvider for IcebergDataset {
+    fn name(&self) -> PlSmallStr {
+        format_pl_smallstr!("iceberg[{}]", self.table_path.as_str())
+    }
+
+    fn schema(&self) -> PolarsResult<SchemaRef> {
+        IcebergSnapshot::try_schema(&self.table_path, self.time_travel)
+    }
+
+    fn to_dataset_scan(
+        &self,
+        predicate: Option<&ExprIR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<DslPlan> {
+        let snapshot = IcebergSnapshot::try_new_with_predicate(
+            &self.table_path,
+            self.time_travel,
+            |schema| match predicate {
+                Some(predicate) => skip_batch_predicate(predicate, expr_arena, schema),
+                None => Ok(None),
+            },
+        )?;
+
+        let sources = ScanSources::Paths(Buffer::from_iter(
+            snapshot.files.iter().map(|file| file.path.clone()),
+        ));
+
+        let parquet_options = ParquetOptions {
+            schema: Some(snapshot.schema.clone()),
+            parallel: Default::default(),
+            low_memory: false,
+            use_statistics: true,
+            decryption: None,
+        };
+
+        let identity_partition_values = snapshot.identity_partition_values();
+
+        let unified_scan_args = UnifiedScanArgs {
+            schema: None,
+            cloud_options: self.cloud_options.clone(),
+            hive_options: HiveOptions::new_disabled(),
+            rechunk: self.rechunk,
+            cache: self.cache,
+            glob: false,
+            hidden_file_prefix: None,
+            projection: None,
+            column_mapping: Some(ColumnMapping::Iceberg(snapshot.iceberg_schema.clone())),
+            default_values: (!identity_partition_values.is_empty()).then(|| {
+                DefaultFieldValues::Iceberg(Arc::new(IcebergIdentityTransformedPartitionFields(
+                    identity_partition_values,
+                )))
+            }),
+            row_index: None,
+            pre_slice: None,
+            // Files may have been written with older versions of the table schema.
+            cast_columns_policy: CastColumnsPolicy {
+                integer_upcast: true,
+                float_upcast: true,
+                float_downcast: true,
+                datetime_nanoseconds_downcast: true,
+                datetime_convert_timezone: true,
+                categorical_to_string: true,
+                missing_struct_fields: MissingColumnsPolicy::Insert,
+                extra_struct_fields: ExtraColumnsPolicy::Ignore,
+                ..CastColumnsPolicy::ERROR_ON_MISMATCH
+            },
+            missing_columns_policy: MissingColumnsPolicy::Insert,
+            extra_columns_policy: ExtraColumnsPolicy::Ignore,
+            include_file_paths: None,
+            deletion_files: DeletionFilesList::filter_empty(Some(
+                DeletionFilesList::IcebergPositionDelete(Arc::new(snapshot.position_deletes())),
+            )),
+            table_statistics: Some(TableStatistics(Arc::new(snapshot.table_statistics()?))),
+            row_count: snapshot.row_count(),
+        };
+
+        Ok(DslBuilder::scan_parquet(sources, parquet_options, unified_scan_args)?.build())
+    }
+}
+
+/// Converts `predicate` to a predicate on the statistics of the columns of `schema`.
+fn skip_batch_predicate(
+    predicate: &ExprIR,
+    expr_arena: &mut Arena<AExpr>,
+    schema: &SchemaRef,
+) -> PolarsResult<Option<Arc<dyn SkipBatchPredicate>>> {
+    let scan_predicate = create_scan_predicate(
+        predicate,
+        expr_arena,
+        schema,
+        None,
+        &mut ExpressionConversionState::new(true),
+        true,  // create_skip_batch_predicate
+        false, // create_column_predicates
+    )?;
+
+    Ok(scan_predicate
+        .to_io(None, schema.clone())
+        .skip_batch_predicate)
+}
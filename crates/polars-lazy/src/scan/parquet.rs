@@ -2,7 +2,7 @@ use polars_buffer::Buffer;
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
-use polars_io::prelude::ParquetOptions;
+use polars_io::prelude::{FileDecryptionProperties, ParquetOptions};
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::pl_path::PlRefPath;
 use polars_utils::slice_enum::Slice;
@@ -25,6 +25,8 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// The keys to decrypt files written with Parquet modular encryption.
+    pub decryption: Option<FileDecryptionProperties>,
 }
 
 impl Default for ScanArgsParquet {
@@ -43,6 +45,7 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            decryption: None,
         }
     }
 }
@@ -70,6 +73,7 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            decryption: self.args.decryption,
         };
 
         let unified_scan_args = UnifiedScanArgs {
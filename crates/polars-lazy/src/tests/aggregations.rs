@@ -598,3 +598,51 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+fn test_group_by_grouping_sets() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "x", "y"],
+        "b" => [1, 2, 1],
+        "v" => [10i64, 20, 30],
+    ]?;
+    let sort_opts = SortMultipleOptions::default().with_nulls_last(true);
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_rollup([col("a"), col("b")])
+        .with_grouping_id("gid")
+        .agg([col("v").sum()])?
+        .sort(["gid", "a", "b"], sort_opts.clone())
+        .collect()?;
+    let expected = df![
+        "a" => [Some("x"), Some("x"), Some("y"), Some("x"), Some("y"), None],
+        "b" => [Some(1), Some(2), Some(1), None, None, None],
+        "gid" => [0u64, 0, 0, 1, 1, 3],
+        "v" => [10i64, 20, 30, 30, 30, 60],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_cube([col("a"), col("b")])
+        .agg([col("v").sum()])?
+        .collect()?;
+    assert_eq!(out.height(), 8);
+
+    let out = df
+        .lazy()
+        .group_by_grouping_sets([col("a"), col("b")], vec![vec![1], vec![]])
+        .agg([col("v").sum()])?
+        .sort(["b"], sort_opts)
+        .collect()?;
+    assert_eq!(out.column("a")?.null_count(), 3);
+    let expected = df![
+        "b" => [Some(1), Some(2), None],
+        "v" => [40i64, 20, 60],
+    ]?;
+    assert!(out.drop("a")?.equals_missing(&expected));
+    Ok(())
+}
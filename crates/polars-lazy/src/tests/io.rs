@@ -810,3 +810,349 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "delta")]
+fn test_scan_delta() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("_delta_log"))?;
+
+    // Partition values are taken from the log rather than the file paths.
+    let mut df = df!("a" => [0i64, 1, 2, 3, 4])?;
+    ParquetWriter::new(std::fs::File::create(root.join("0.parquet"))?).finish(&mut df)?;
+    let mut df = df!("a" => [5i64])?;
+    ParquetWriter::new(std::fs::File::create(root.join("1.parquet"))?).finish(&mut df)?;
+
+    // A `RoaringBitmapArray` deleting rows 1 and 3.
+    let mut bitmap = 1681511377u32.to_le_bytes().to_vec();
+    bitmap.extend_from_slice(&1u64.to_le_bytes());
+    bitmap.extend_from_slice(&0u32.to_le_bytes());
+    for x in [12346u32, 1] {
+        bitmap.extend_from_slice(&x.to_le_bytes());
+    }
+    for x in [0u16, 1] {
+        bitmap.extend_from_slice(&x.to_le_bytes());
+    }
+    bitmap.extend_from_slice(&16u32.to_le_bytes());
+    for x in [1u16, 3] {
+        bitmap.extend_from_slice(&x.to_le_bytes());
+    }
+    let mut dv_file = vec![1u8];
+    dv_file.extend_from_slice(&(bitmap.len() as u32).to_be_bytes());
+    dv_file.extend_from_slice(&bitmap);
+    std::fs::write(root.join("dv.bin"), dv_file)?;
+
+    let add = |dv: &str| {
+        format!(
+            r#"{{"add":{{"path":"0.parquet","partitionValues":{{"p":"x","q":"7"}},"size":1,"modificationTime":0,"dataChange":true,"stats":"{{\"numRecords\":5}}"{dv}}}}}"#
+        )
+    };
+    let commits = [
+        vec![
+            r#"{"protocol":{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["deletionVectors"],"writerFeatures":["deletionVectors"]}}"#.to_string(),
+            r#"{"metaData":{"id":"1","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"p\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}},{\"name\":\"q\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":["p","q"],"configuration":{}}}"#.to_string(),
+            add(""),
+        ],
+        vec![
+            r#"{"remove":{"path":"0.parquet","dataChange":false}}"#.to_string(),
+            add(&format!(
+                r#","deletionVector":{{"storageType":"p","pathOrInlineDv":"dv.bin","offset":1,"sizeInBytes":{},"cardinality":2}}"#,
+                bitmap.len()
+            )),
+        ],
+        vec![
+            r#"{"add":{"path":"1.parquet","partitionValues":{"p":null,"q":"8"},"size":1,"modificationTime":0,"dataChange":true,"stats":"{\"numRecords\":1}"}}"#.to_string(),
+        ],
+    ];
+    for (version, actions) in commits.iter().enumerate() {
+        let path = root.join(format!("_delta_log/{version:020}.json"));
+        std::fs::write(path, actions.join("\n"))?;
+    }
+
+    let scan = |time_travel| {
+        let args = ScanArgsDelta {
+            time_travel,
+            ..Default::default()
+        };
+        LazyFrame::scan_delta(PlRefPath::new(root.to_str().unwrap()), args)
+    };
+
+    let out = scan(DeltaTimeTravel::Latest)?.collect()?;
+    let expected = df!(
+        "a" => [0i64, 2, 4, 5],
+        "p" => [Some("x"), Some("x"), Some("x"), None],
+        "q" => [7i64, 7, 7, 8],
+    )?;
+    assert!(out.equals_missing(&expected));
+
+    let out = scan(DeltaTimeTravel::Latest)?
+        .filter(col("q").eq(lit(8i64)))
+        .collect()?;
+    assert!(out.equals_missing(&expected.slice(3, 1)));
+
+    let out = scan(DeltaTimeTravel::Version(0))?
+        .select([col("a")])
+        .collect()?;
+    assert!(out.equals(&df!("a" => [0i64, 1, 2, 3, 4])?));
+
+    let out = scan(DeltaTimeTravel::Latest)?.select([len()]).collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(4));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "catalog", feature = "delta", feature = "streaming"))]
+fn test_sink_unity() -> PolarsResult<()> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    use polars_io::catalog::unity::client::CatalogClientBuilder;
+    use polars_io::catalog::unity::write::WriteTableOptions;
+    use polars_io::delta::{DeltaWriteMode, DeltaWriteOptions};
+
+    let dir = tempfile::tempdir()?;
+    let storage_location = dir.path().join("t").to_str().unwrap().to_string();
+
+    // Serves the catalog table `main.default.t` for every request.
+    let table_info = serde_json::json!({
+        "name": "t",
+        "table_id": "1",
+        "table_type": "EXTERNAL",
+        "data_source_format": "DELTA",
+        "storage_location": storage_location,
+        "columns": null,
+        "created_at": null,
+        "created_by": null,
+        "updated_at": null,
+        "updated_by": null,
+    })
+    .to_string();
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let url = format!("http://{}", listener.local_addr()?);
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            for line in BufReader::new(&stream).lines() {
+                if line.unwrap().is_empty() {
+                    break;
+                }
+            }
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{table_info}",
+                table_info.len()
+            )
+            .unwrap();
+        }
+    });
+
+    let client = CatalogClientBuilder::new()
+        .with_workspace_url(url)
+        .build()?;
+    let sink = |df: DataFrame, mode| {
+        let options = WriteTableOptions {
+            delta: DeltaWriteOptions {
+                mode,
+                ..Default::default()
+            },
+            cloud_options: Some(Default::default()),
+            create_table: None,
+        };
+        df.lazy()
+            .sink_unity(&client, "main", "default", "t", &options)?
+            .collect_with_engine(Engine::Streaming)
+    };
+
+    sink(df!("a" => [1i64, 2])?, DeltaWriteMode::Error)?;
+    sink(df!("a" => [3i64])?, DeltaWriteMode::Append)?;
+    sink(df!("a" => [4i64])?, DeltaWriteMode::Ignore)?;
+    assert!(sink(df!("a" => [4i64])?, DeltaWriteMode::Error).is_err());
+
+    let out = LazyFrame::scan_delta(PlRefPath::new(&storage_location), Default::default())?
+        .sort(["a"], Default::default())
+        .collect()?;
+    assert!(out.equals(&df!("a" => [1i64, 2, 3])?));
+
+    Ok(())
+}
+
+/// Writes a parquet file with the field ID of each column set in its metadata, as written by
+/// Iceberg writers.
+#[cfg(feature = "iceberg")]
+fn write_parquet_with_field_ids(
+    path: &std::path::Path,
+    columns: &[(&str, u32, Series)],
+) -> PolarsResult<()> {
+    use arrow::datatypes::{ArrowSchema, Metadata};
+    use arrow::record_batch::RecordBatchT;
+    use polars_buffer::Buffer;
+    use polars_parquet::write::{
+        CompressionOptions, Encoding, FileWriter, RowGroupIterator, StatisticsOptions, Version,
+        WriteOptions,
+    };
+
+    let schema = ArrowSchema::from_iter(columns.iter().map(|(name, field_id, s)| {
+        s.dtype()
+            .to_arrow_field((*name).into(), CompatLevel::newest())
+            .with_metadata(Metadata::from_iter([(
+                "PARQUET:field_id".into(),
+                field_id.to_string().into(),
+            )]))
+    }));
+    let num_rows = columns[0].2.len();
+    let arrays = columns
+        .iter()
+        .map(|(_, _, s)| s.rechunk().to_arrow(0, CompatLevel::newest()))
+        .collect();
+
+    let options = WriteOptions {
+        statistics: StatisticsOptions::full(),
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_page_size: None,
+        write_page_index: false,
+    };
+    let batch = RecordBatchT::try_new(num_rows, Arc::new(schema.clone()), arrays);
+    let row_groups = RowGroupIterator::try_new(
+        std::iter::once(batch),
+        &schema,
+        options,
+        Buffer::from_iter(columns.iter().map(|_| vec![Encoding::Plain])),
+    )?;
+
+    let mut writer = FileWriter::try_new(std::fs::File::create(path)?, schema, options)?;
+    for row_group in row_groups {
+        writer.write(num_rows as u64, row_group?)?;
+    }
+    writer.end(None)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "iceberg")]
+fn test_scan_iceberg() -> PolarsResult<()> {
+    use polars_io::SerWriter;
+    use polars_io::avro::AvroWriter;
+
+    let root = std::env::temp_dir().join(format!("polars-scan-iceberg-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("data"))?;
+    std::fs::create_dir_all(root.join("metadata"))?;
+    let path = |name: &str| root.join(name).to_str().unwrap().to_string();
+
+    // The data files were written before `x` was renamed to `a`, and do not contain the
+    // partition column `p`.
+    write_parquet_with_field_ids(
+        &root.join("data/1.parquet"),
+        &[("x", 1, Series::new("x".into(), [0i64, 1, 2]))],
+    )?;
+    write_parquet_with_field_ids(
+        &root.join("data/2.parquet"),
+        &[("x", 1, Series::new("x".into(), [10i64, 11]))],
+    )?;
+    // Deletes the row at position 1 of the first file.
+    ParquetWriter::new(std::fs::File::create(root.join("data/delete.parquet"))?)
+        .finish(&mut df!("file_path" => [path("data/1.parquet").as_str()], "pos" => [1i64])?)?;
+
+    // Writes a manifest of a single file of partition `p`.
+    let write_manifest = |name: &str, content: i32, file: &str, p: i64, record_count: i64| {
+        let data_file = StructChunked::from_series(
+            "data_file".into(),
+            1,
+            [
+                Series::new("content".into(), [content]),
+                Series::new("file_path".into(), [path(file).as_str()]),
+                Series::new("file_format".into(), ["PARQUET"]),
+                StructChunked::from_series(
+                    "partition".into(),
+                    1,
+                    [Series::new("p".into(), [p])].iter(),
+                )?
+                .into_series(),
+                Series::new("record_count".into(), [record_count]),
+            ]
+            .iter(),
+        )?;
+        let mut df = DataFrame::new_infer_height(vec![
+            Column::new("status".into(), [1i32]),
+            Column::new("sequence_number".into(), [1i64]),
+            data_file.into_column(),
+        ])?;
+        AvroWriter::new(std::fs::File::create(root.join(name))?).finish(&mut df)
+    };
+    write_manifest("metadata/m1.avro", 0, "data/1.parquet", 1, 3)?;
+    write_manifest("metadata/m2.avro", 0, "data/2.parquet", 2, 2)?;
+    write_manifest("metadata/m3.avro", 1, "data/delete.parquet", 1, 1)?;
+
+    let manifests = [
+        ("metadata/m1.avro", 0, 1),
+        ("metadata/m2.avro", 0, 2),
+        ("metadata/m3.avro", 1, 1),
+    ];
+    let partitions = manifests
+        .iter()
+        .map(|(_, _, p)| {
+            let bound = i64::to_le_bytes(*p);
+            let fields = [
+                Series::new("contains_null".into(), [false]),
+                Series::new("lower_bound".into(), [bound.as_slice()]),
+                Series::new("upper_bound".into(), [bound.as_slice()]),
+            ];
+            Ok(StructChunked::from_series("".into(), 1, fields.iter())?.into_series())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut manifest_list = df!(
+        "manifest_path" => manifests.iter().map(|x| path(x.0)).collect::<Vec<_>>(),
+        "partition_spec_id" => vec![0i32; 3],
+        "content" => manifests.iter().map(|x| x.1).collect::<Vec<_>>(),
+        "sequence_number" => vec![1i64; 3],
+        "partitions" => partitions,
+    )?;
+    AvroWriter::new(std::fs::File::create(root.join("metadata/snap-1.avro"))?)
+        .finish(&mut manifest_list)?;
+
+    let metadata = format!(
+        r#"{{"format-version":2,"table-uuid":"1","location":"{}","last-updated-ms":0,
+        "current-schema-id":0,"schemas":[{{"type":"struct","schema-id":0,"fields":[
+            {{"id":1,"name":"a","required":false,"type":"long"}},
+            {{"id":2,"name":"p","required":false,"type":"long"}}]}}],
+        "default-spec-id":0,"partition-specs":[{{"spec-id":0,"fields":[
+            {{"source-id":2,"field-id":1000,"name":"p","transform":"identity"}}]}}],
+        "current-snapshot-id":1,"snapshots":[{{"snapshot-id":1,"timestamp-ms":0,
+            "manifest-list":"{}","schema-id":0}}]}}"#,
+        path(""),
+        path("metadata/snap-1.avro"),
+    );
+    std::fs::write(root.join("metadata/v1.metadata.json"), metadata)?;
+
+    let scan =
+        || LazyFrame::scan_iceberg(PlRefPath::new(root.to_str().unwrap()), Default::default());
+
+    let out = scan()?.collect()?;
+    let expected = df!("a" => [0i64, 2, 10, 11], "p" => [1i64, 1, 2, 2])?;
+    assert!(out.equals(&expected));
+
+    let out = scan()?.filter(col("a").gt(lit(5i64))).collect()?;
+    assert!(out.equals(&df!("a" => [10i64, 11], "p" => [2i64, 2])?));
+
+    // The manifest of the first file is not read when the pushed-down predicate excludes its
+    // partition.
+    std::fs::remove_file(root.join("metadata/m1.avro"))?;
+    assert!(scan()?.collect().is_err());
+    let out = scan()?.filter(col("p").eq(lit(2i64))).collect()?;
+    assert!(out.equals(&df!("a" => [10i64, 11], "p" => [2i64, 2])?));
+    // A predicate that is applied after the row index cannot be used to skip manifests.
+    assert!(
+        scan()?
+            .with_row_index("i", None)
+            .filter(col("p").eq(lit(2i64)))
+            .collect()
+            .is_err()
+    );
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
@@ -10,7 +10,7 @@ use polars_error::polars_warn;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
 use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::default_values::{
-    DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
+    DefaultFieldValues, DeltaPartitionValues, IcebergIdentityTransformedPartitionFields,
 };
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{
@@ -523,6 +523,23 @@ where
         // No-op - Delta takes scan paths at the execution stage.
         #[cfg(feature = "python")]
         DeletionFilesList::Delta(provider) => Some(DeletionFilesList::Delta(provider)),
+        #[cfg(feature = "delta")]
+        DeletionFilesList::DeltaDeletionVectors(dvs) => {
+            let mut out = None;
+
+            for (out_idx, source_idx) in selected_path_indices.clone().enumerate() {
+                if let Some(v) = dvs.get(&source_idx) {
+                    out.get_or_insert_with(|| {
+                        PlIndexMap::with_capacity(
+                            selected_path_indices.size_hint().0.saturating_sub(out_idx),
+                        )
+                    })
+                    .insert(out_idx, v.clone());
+                }
+            }
+
+            out.map(|x| DeletionFilesList::DeltaDeletionVectors(Arc::new(x)))
+        },
     });
 
     *table_statistics = table_statistics.as_ref().map(|x| {
@@ -588,5 +605,14 @@ where
 
             DefaultFieldValues::Iceberg(Arc::new(IcebergIdentityTransformedPartitionFields(out)))
         },
+        DefaultFieldValues::Delta(v) => {
+            let df_height = IdxSize::try_from(v.0.height()).unwrap();
+
+            assert!(selected_path_indices_idxsize.iter().all(|x| *x < df_height));
+
+            // Safety: Asserted all < df.height() above.
+            let df = unsafe { v.0.take_slice_unchecked(&selected_path_indices_idxsize) };
+            DefaultFieldValues::Delta(Arc::new(DeltaPartitionValues(df)))
+        },
     });
 }
@@ -9,13 +9,15 @@ use arrow::datatypes::{ArrowDataType, Field, IntegerType, IntervalUnit, TimeUnit
 use arrow::types::{days_ms, i256};
 use ethnum::I256;
 use num_traits::{AsPrimitive, FromBytes};
+use polars_parquet_format::Statistics as ThriftStatistics;
 use polars_utils::IdxSize;
 use polars_utils::float16::pf16;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::{ParquetTimeUnit, RowGroupMetadata};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+use crate::parquet::read::indexes::ColumnIndex;
+use crate::parquet::schema::types::{PhysicalType as ParquetPhysicalType, PrimitiveType};
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
@@ -295,264 +297,340 @@ pub fn deserialize_all(
     footer_buf: &[u8],
 ) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     assert!(!row_groups.is_empty());
+
+    if !has_leaf_statistics(field.dtype()) {
+        return Ok(None);
+    }
+
+    let primitive_type = &row_groups[0].parquet_columns()[field_idx]
+        .descriptor()
+        .descriptor
+        .primitive_type;
+
+    let statistics = row_groups
+        .iter()
+        .map(|rg| {
+            rg.parquet_columns()[field_idx]
+                .statistics(footer_buf)
+                .transpose()
+        })
+        .collect::<ParquetResult<Vec<_>>>()?;
+    let num_rows = row_groups
+        .iter()
+        .map(|rg| rg.num_rows())
+        .collect::<Vec<_>>();
+
+    deserialize_batches(field, primitive_type, statistics, &num_rows).map(Some)
+}
+
+/// Deserializes the page statistics in the [`ColumnIndex`] of a column chunk associated with
+/// `field`. `page_num_rows` is the number of rows of every page.
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_column_index(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    column_index: &ColumnIndex,
+    page_num_rows: &[usize],
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    if !has_leaf_statistics(field.dtype()) {
+        return Ok(None);
+    }
+
+    let num_pages = column_index.null_pages.len();
+    if column_index.min_values.len() != num_pages
+        || column_index.max_values.len() != num_pages
+        || page_num_rows.len() != num_pages
+        || column_index
+            .null_counts
+            .as_ref()
+            .is_some_and(|x| x.len() != num_pages)
+    {
+        return Err(ParquetError::oos(
+            "The number of pages in the page index is inconsistent",
+        ));
+    }
+
+    let statistics = (0..num_pages)
+        .map(|i| {
+            let is_null_page = column_index.null_pages[i];
+            let statistics = ThriftStatistics {
+                max: None,
+                min: None,
+                null_count: column_index.null_counts.as_ref().map(|x| x[i]),
+                distinct_count: None,
+                max_value: (!is_null_page).then(|| column_index.max_values[i].clone()),
+                min_value: (!is_null_page).then(|| column_index.min_values[i].clone()),
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            };
+            ParquetStatistics::deserialize(&statistics, primitive_type.clone()).map(Some)
+        })
+        .collect::<ParquetResult<Vec<_>>>()?;
+
+    deserialize_batches(field, primitive_type, statistics, page_num_rows).map(Some)
+}
+
+/// Whether statistics of columns of type `dtype` can be deserialized into arrays.
+fn has_leaf_statistics(dtype: &ArrowDataType) -> bool {
     use ArrowDataType as D;
-    match field.dtype() {
-        // @TODO: These are all a bit more complex, skip for now.
-        D::List(..) | D::LargeList(..) => Ok(None),
-        D::Dictionary(..) => Ok(None),
-        D::FixedSizeList(..) => Ok(None),
-        D::Struct(..) => Ok(None),
+    // @TODO: These are all a bit more complex, skip for now.
+    !matches!(
+        dtype,
+        D::List(..) | D::LargeList(..) | D::Dictionary(..) | D::FixedSizeList(..) | D::Struct(..)
+    )
+}
 
-        _ => {
-            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-            let mut distinct_count =
-                MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-
-            let primitive_type = &row_groups[0].parquet_columns()[field_idx]
-                .descriptor()
-                .descriptor
-                .primitive_type;
-
-            let logical_type = &primitive_type.logical_type;
-            let physical_type = &primitive_type.physical_type;
-
-            macro_rules! rmap {
-                ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
-                    let mut min_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-                    let mut max_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-
-                    for rg in row_groups {
-                        let column = &rg.parquet_columns()[field_idx];
-                        let s = column.statistics(footer_buf).transpose()?;
-
-                        let (v_min, v_max, v_null_count, v_distinct_count) = match s {
-                            None => (None, None, None, None),
-                            Some(s) => {
-                                let s = s.$expect();
-
-                                let min = s.min_value;
-                                let max = s.max_value;
-
-                                let min = ($map)(min)?;
-                                let max = ($map)(max)?;
-
-                                (
-                                min,
-                                max,
-                                s.null_count.map(|v| v as IdxSize),
-                                s.distinct_count.map(|v| v as IdxSize),
-                                )
-                            }
-                        };
-
-                        min_arr.push(v_min);
-                        max_arr.push(v_max);
-                        null_count.push(v_null_count);
-                        distinct_count.push(v_distinct_count);
+/// Deserializes the statistics of a leaf column for a number of batches (e.g. row groups or
+/// pages) into arrays with one value per batch.
+fn deserialize_batches(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    statistics: Vec<Option<ParquetStatistics>>,
+    num_rows: &[usize],
+) -> ParquetResult<ArrowColumnStatisticsArrays> {
+    debug_assert_eq!(statistics.len(), num_rows.len());
+    let num_batches = statistics.len();
+
+    let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(num_batches);
+    let mut distinct_count = MutablePrimitiveArray::<IdxSize>::with_capacity(num_batches);
+
+    let logical_type = &primitive_type.logical_type;
+    let physical_type = &primitive_type.physical_type;
+
+    macro_rules! rmap {
+        ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
+            let mut min_arr = <$arr>::with_capacity(num_batches$(, $arg)?);
+            let mut max_arr = <$arr>::with_capacity(num_batches$(, $arg)?);
+
+            for s in statistics {
+                let (v_min, v_max, v_null_count, v_distinct_count) = match s {
+                    None => (None, None, None, None),
+                    Some(s) => {
+                        let s = s.$expect();
+
+                        let min = s.min_value;
+                        let max = s.max_value;
+
+                        let min = ($map)(min)?;
+                        let max = ($map)(max)?;
+
+                        (
+                        min,
+                        max,
+                        s.null_count.map(|v| v as IdxSize),
+                        s.distinct_count.map(|v| v as IdxSize),
+                        )
                     }
+                };
 
-                    (min_arr.freeze().to_boxed(), max_arr.freeze().to_boxed())
-                }};
-                ($expect:ident, $arr:ty, @prim $from:ty $(as $to:ty)? $(, $map:expr)?) => {{
-                    rmap!(
-                        $expect,
-                        |x: Option<$from>| {
-                            $(
-                            let x = x.map(|x| AsPrimitive::<$to>::as_(x));
-                            )?
-                            $(
-                            let x = x.map($map);
-                            )?
-                            ParquetResult::Ok(x)
-                        },
-                        $arr
-                    )
-                }};
-                (@binary $(, $map:expr)?) => {{
-                    rmap!(
-                        expect_binary,
-                        |x: Option<Vec<u8>>| {
-                            $(
-                            let x = x.map($map);
-                            )?
-                            ParquetResult::Ok(x)
-                        },
-                        MutableBinaryViewArray<[u8]>
-                    )
-                }};
-                (@string) => {{
-                    rmap!(
-                        expect_binary,
-                        |x: Option<Vec<u8>>| {
-                            let x = x.map(String::from_utf8).transpose().map_err(|_| {
-                                ParquetError::oos("Invalid UTF8 in Statistics")
-                            })?;
-                            ParquetResult::Ok(x)
-                        },
-                        MutableBinaryViewArray<str>
-                    )
-                }};
+                min_arr.push(v_min);
+                max_arr.push(v_max);
+                null_count.push(v_null_count);
+                distinct_count.push(v_distinct_count);
             }
 
-            use ArrowDataType as D;
-            use ParquetPhysicalType as PPT;
-            let (min_value, max_value) = match (field.dtype(), physical_type) {
-                (D::Null, _) => {
-                    for rg in row_groups {
-                        null_count.push(Some(rg.num_rows() as IdxSize));
-                        distinct_count.push(Some(0));
-                    }
-                    (
-                        NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                        NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                    )
+            (min_arr.freeze().to_boxed(), max_arr.freeze().to_boxed())
+        }};
+        ($expect:ident, $arr:ty, @prim $from:ty $(as $to:ty)? $(, $map:expr)?) => {{
+            rmap!(
+                $expect,
+                |x: Option<$from>| {
+                    $(
+                    let x = x.map(|x| AsPrimitive::<$to>::as_(x));
+                    )?
+                    $(
+                    let x = x.map($map);
+                    )?
+                    ParquetResult::Ok(x)
                 },
-
-                (D::Boolean, _) => rmap!(
-                    expect_boolean,
-                    |x: Option<bool>| ParquetResult::Ok(x),
-                    MutableBooleanArray
-                ),
-
-                (D::Int8, _) => rmap!(expect_int32, MutablePrimitiveArray::<i8>, @prim i32 as i8),
-                (D::Int16, _) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i16>, @prim i32 as i16)
+                $arr
+            )
+        }};
+        (@binary $(, $map:expr)?) => {{
+            rmap!(
+                expect_binary,
+                |x: Option<Vec<u8>>| {
+                    $(
+                    let x = x.map($map);
+                    )?
+                    ParquetResult::Ok(x)
                 },
-                (D::Int32 | D::Date32 | D::Time32(_), _) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i32>, @prim i32 as i32)
+                MutableBinaryViewArray<[u8]>
+            )
+        }};
+        (@string) => {{
+            rmap!(
+                expect_binary,
+                |x: Option<Vec<u8>>| {
+                    let x = x.map(String::from_utf8).transpose().map_err(|_| {
+                        ParquetError::oos("Invalid UTF8 in Statistics")
+                    })?;
+                    ParquetResult::Ok(x)
                 },
+                MutableBinaryViewArray<str>
+            )
+        }};
+    }
 
-                // some implementations of parquet write arrow's date64 into i32.
-                (D::Date64, PPT::Int32) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i64>, @prim i32 as i64, |x| x * 86400000)
-                },
+    use ArrowDataType as D;
+    use ParquetPhysicalType as PPT;
+    let (min_value, max_value) = match (field.dtype(), physical_type) {
+        (D::Null, _) => {
+            for num_rows in num_rows {
+                null_count.push(Some(*num_rows as IdxSize));
+                distinct_count.push(Some(0));
+            }
+            (
+                NullArray::new(ArrowDataType::Null, num_batches).to_boxed(),
+                NullArray::new(ArrowDataType::Null, num_batches).to_boxed(),
+            )
+        },
 
-                (D::Int64 | D::Time64(_) | D::Duration(_), _) | (D::Date64, PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<i64>, @prim i64 as i64)
-                },
+        (D::Boolean, _) => rmap!(
+            expect_boolean,
+            |x: Option<bool>| ParquetResult::Ok(x),
+            MutableBooleanArray
+        ),
 
-                (D::Interval(IntervalUnit::YearMonth), _) => rmap!(
-                    expect_binary,
-                    MutablePrimitiveArray::<i32>,
-                    @prim Vec<u8>,
-                    |x| convert_year_month(&x)
-                ),
-                (D::Interval(IntervalUnit::DayTime), _) => rmap!(
-                    expect_binary,
-                    MutablePrimitiveArray::<days_ms>,
-                    @prim Vec<u8>,
-                    |x| convert_days_ms(&x)
-                ),
-
-                (D::UInt8, _) => rmap!(expect_int32, MutablePrimitiveArray::<u8>, @prim i32 as u8),
-                (D::UInt16, _) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<u16>, @prim i32 as u16)
-                },
-                (D::UInt32, PPT::Int32) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<u32>, @prim i32 as u32)
-                },
+        (D::Int8, _) => rmap!(expect_int32, MutablePrimitiveArray::<i8>, @prim i32 as i8),
+        (D::Int16, _) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i16>, @prim i32 as i16)
+        },
+        (D::Int32 | D::Date32 | D::Time32(_), _) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i32>, @prim i32 as i32)
+        },
 
-                // some implementations of parquet write arrow's u32 into i64.
-                (D::UInt32, PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<u32>, @prim i64 as u32)
-                },
-                (D::UInt64, _) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<u64>, @prim i64 as u64)
-                },
+        // some implementations of parquet write arrow's date64 into i32.
+        (D::Date64, PPT::Int32) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i64>, @prim i32 as i64, |x| x * 86400000)
+        },
 
-                (D::Timestamp(time_unit, _), PPT::Int96) => {
-                    rmap!(expect_int96, MutablePrimitiveArray::<i64>, @prim [u32; 3], |x| {
-                        timestamp(logical_type.as_ref(), *time_unit, int96_to_i64_ns(x).unwrap_or(i64::MAX))
-                    })
-                },
-                (D::Timestamp(time_unit, _), PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<i64>, @prim i64, |x| {
-                        timestamp(logical_type.as_ref(), *time_unit, x)
-                    })
-                },
+        (D::Int64 | D::Time64(_) | D::Duration(_), _) | (D::Date64, PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<i64>, @prim i64 as i64)
+        },
 
-                (D::Float16, _) => {
-                    rmap!(expect_fixedlen, MutablePrimitiveArray::<pf16>, @prim Vec<u8>, |v| {
-                        let le_bytes: [u8; 2] = [v[0], v[1]];
-                        pf16::from_le_bytes(&le_bytes)
-                    })
-                },
-                (D::Float32, _) => rmap!(expect_float, MutablePrimitiveArray::<f32>, @prim f32),
-                (D::Float64, _) => rmap!(expect_double, MutablePrimitiveArray::<f64>, @prim f64),
+        (D::Interval(IntervalUnit::YearMonth), _) => rmap!(
+            expect_binary,
+            MutablePrimitiveArray::<i32>,
+            @prim Vec<u8>,
+            |x| convert_year_month(&x)
+        ),
+        (D::Interval(IntervalUnit::DayTime), _) => rmap!(
+            expect_binary,
+            MutablePrimitiveArray::<days_ms>,
+            @prim Vec<u8>,
+            |x| convert_days_ms(&x)
+        ),
+
+        (D::UInt8, _) => rmap!(expect_int32, MutablePrimitiveArray::<u8>, @prim i32 as u8),
+        (D::UInt16, _) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<u16>, @prim i32 as u16)
+        },
+        (D::UInt32, PPT::Int32) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<u32>, @prim i32 as u32)
+        },
 
-                (D::Decimal(_, _), PPT::Int32) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i128>, @prim i32 as i128)
-                },
-                (D::Decimal(_, _), PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<i128>, @prim i64 as i128)
-                },
-                (D::Decimal(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
-                    return Err(ParquetError::not_supported(format!(
-                        "Can't decode Decimal128 type from Fixed Size Byte Array of len {n:?}",
-                    )));
-                },
-                (D::Decimal(_, _), PPT::FixedLenByteArray(n)) => rmap!(
-                    expect_fixedlen,
-                    MutablePrimitiveArray::<i128>,
-                    @prim Vec<u8>,
-                    |x| convert_i128(&x, *n)
-                ),
-                (D::Decimal256(_, _), PPT::Int32) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i256>, @prim i32, |x: i32| i256(I256::new(x.into())))
-                },
-                (D::Decimal256(_, _), PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<i256>, @prim i64, |x: i64| i256(I256::new(x.into())))
-                },
-                (D::Decimal256(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
-                    return Err(ParquetError::not_supported(format!(
-                        "Can't decode Decimal256 type from Fixed Size Byte Array of len {n:?}",
-                    )));
-                },
-                (D::Decimal256(_, _), PPT::FixedLenByteArray(_)) => rmap!(
-                    expect_fixedlen,
-                    MutablePrimitiveArray::<i256>,
-                    @prim Vec<u8>,
-                    |x| convert_i256(&x)
-                ),
-                (D::Binary, _) => rmap!(@binary),
-                (D::LargeBinary, _) => rmap!(@binary),
-                (D::Utf8, _) => rmap!(@string),
-                (D::LargeUtf8, _) => rmap!(@string),
-
-                (D::BinaryView, _) => rmap!(@binary),
-                (D::Utf8View, _) => rmap!(@string),
-
-                (D::FixedSizeBinary(width), _) => {
-                    struct FixedSizeBinaryArray2;
-
-                    impl FixedSizeBinaryArray2 {
-                        fn with_capacity(
-                            row_groups_len: usize,
-                            row_width: usize,
-                        ) -> MutableFixedSizeBinaryArray {
-                            MutableFixedSizeBinaryArray::with_capacity(row_width, row_groups_len)
-                        }
-                    }
+        // some implementations of parquet write arrow's u32 into i64.
+        (D::UInt32, PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<u32>, @prim i64 as u32)
+        },
+        (D::UInt64, _) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<u64>, @prim i64 as u64)
+        },
 
-                    rmap!(
-                        expect_fixedlen,
-                        |x: Option<Vec<u8>>| ParquetResult::Ok(x),
-                        FixedSizeBinaryArray2,
-                        *width
-                    )
-                },
+        (D::Timestamp(time_unit, _), PPT::Int96) => {
+            rmap!(expect_int96, MutablePrimitiveArray::<i64>, @prim [u32; 3], |x| {
+                timestamp(logical_type.as_ref(), *time_unit, int96_to_i64_ns(x).unwrap_or(i64::MAX))
+            })
+        },
+        (D::Timestamp(time_unit, _), PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<i64>, @prim i64, |x| {
+                timestamp(logical_type.as_ref(), *time_unit, x)
+            })
+        },
 
-                other => todo!("{:?}", other),
-            };
+        (D::Float16, _) => {
+            rmap!(expect_fixedlen, MutablePrimitiveArray::<pf16>, @prim Vec<u8>, |v| {
+                let le_bytes: [u8; 2] = [v[0], v[1]];
+                pf16::from_le_bytes(&le_bytes)
+            })
+        },
+        (D::Float32, _) => rmap!(expect_float, MutablePrimitiveArray::<f32>, @prim f32),
+        (D::Float64, _) => rmap!(expect_double, MutablePrimitiveArray::<f64>, @prim f64),
 
-            Ok(Some(ArrowColumnStatisticsArrays {
-                null_count: null_count.freeze(),
-                distinct_count: distinct_count.freeze(),
-                min_value,
-                max_value,
-            }))
+        (D::Decimal(_, _), PPT::Int32) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i128>, @prim i32 as i128)
         },
-    }
+        (D::Decimal(_, _), PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<i128>, @prim i64 as i128)
+        },
+        (D::Decimal(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
+            return Err(ParquetError::not_supported(format!(
+                "Can't decode Decimal128 type from Fixed Size Byte Array of len {n:?}",
+            )));
+        },
+        (D::Decimal(_, _), PPT::FixedLenByteArray(n)) => rmap!(
+            expect_fixedlen,
+            MutablePrimitiveArray::<i128>,
+            @prim Vec<u8>,
+            |x| convert_i128(&x, *n)
+        ),
+        (D::Decimal256(_, _), PPT::Int32) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i256>, @prim i32, |x: i32| i256(I256::new(x.into())))
+        },
+        (D::Decimal256(_, _), PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<i256>, @prim i64, |x: i64| i256(I256::new(x.into())))
+        },
+        (D::Decimal256(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
+            return Err(ParquetError::not_supported(format!(
+                "Can't decode Decimal256 type from Fixed Size Byte Array of len {n:?}",
+            )));
+        },
+        (D::Decimal256(_, _), PPT::FixedLenByteArray(_)) => rmap!(
+            expect_fixedlen,
+            MutablePrimitiveArray::<i256>,
+            @prim Vec<u8>,
+            |x| convert_i256(&x)
+        ),
+        (D::Binary, _) => rmap!(@binary),
+        (D::LargeBinary, _) => rmap!(@binary),
+        (D::Utf8, _) => rmap!(@string),
+        (D::LargeUtf8, _) => rmap!(@string),
+
+        (D::BinaryView, _) => rmap!(@binary),
+        (D::Utf8View, _) => rmap!(@string),
+
+        (D::FixedSizeBinary(width), _) => {
+            struct FixedSizeBinaryArray2;
+
+            impl FixedSizeBinaryArray2 {
+                fn with_capacity(
+                    row_groups_len: usize,
+                    row_width: usize,
+                ) -> MutableFixedSizeBinaryArray {
+                    MutableFixedSizeBinaryArray::with_capacity(row_width, row_groups_len)
+                }
+            }
+
+            rmap!(
+                expect_fixedlen,
+                |x: Option<Vec<u8>>| ParquetResult::Ok(x),
+                FixedSizeBinaryArray2,
+                *width
+            )
+        },
+
+        other => todo!("{:?}", other),
+    };
+
+    Ok(ArrowColumnStatisticsArrays {
+        null_count: null_count.freeze(),
+        distinct_count: distinct_count.freeze(),
+        min_value,
+        max_value,
+    })
 }
 
 /// Deserializes the statistics in the column chunks from a single `row_group`
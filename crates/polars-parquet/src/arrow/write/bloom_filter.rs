@@ -0,0 +1,175 @@
+use arrow::array::*;
+use arrow::datatypes::ArrowDataType;
+use arrow::match_integer_type;
+
+use super::{BloomFilterOptions, decimal_length_from_precision, to_leaves};
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+use crate::parquet::schema::types::PhysicalType;
+
+/// Builds the split-block bloom filter bitsets of the leaf columns of `array`.
+///
+/// Returns one entry per leaf column, in the same order as
+/// [`array_to_columns`](super::array_to_columns). Leaves whose parquet physical type cannot be
+/// hashed (e.g. booleans) have no bloom filter.
+///
+/// Values are hashed in their parquet representation, so the casts below MUST match the casts
+/// done when encoding the pages.
+pub fn array_to_bloom_filters(
+    array: &dyn Array,
+    options: &BloomFilterOptions,
+) -> Vec<Option<Vec<u8>>> {
+    let mut leaves = vec![];
+    to_leaves(array, &mut leaves);
+
+    leaves
+        .iter()
+        .map(|leaf| leaf_to_bloom_filter(leaf.as_ref(), options))
+        .collect()
+}
+
+fn leaf_to_bloom_filter(array: &dyn Array, options: &BloomFilterOptions) -> Option<Vec<u8>> {
+    if let ArrowDataType::Dictionary(key_type, _, _) = array.dtype().to_storage() {
+        // The dictionary is written as-is, so every value is hashed.
+        return match_integer_type!(key_type, |$T| {
+            let array: &DictionaryArray<$T> = array.as_any().downcast_ref().unwrap();
+            leaf_to_bloom_filter(array.values().as_ref(), options)
+        });
+    }
+
+    physical_type(array.dtype())?;
+
+    let ndv = options
+        .ndv
+        .unwrap_or((array.len() - array.null_count()) as u64);
+    let mut bitset = vec![0; optimal_num_bytes(ndv, options.fpp)];
+
+    for_each_hash(array, &mut |hash| insert(&mut bitset, hash));
+    Some(bitset)
+}
+
+/// Hashes the non-null values of `array` as they would be inserted in the bloom filter of a
+/// column chunk with the given `physical_type`.
+///
+/// Returns `None` if `array` would not be written with that physical type, in which case the
+/// hashes cannot be compared against the bloom filter.
+///
+/// Also returns `None` for floating point columns. Floats are hashed by their bit pattern, so
+/// values that compare equal (`-0.0` and `0.0`, or NaNs with different payloads) have different
+/// hashes, and a miss in the bloom filter does not prove that no value compares equal.
+pub fn array_to_hashes(array: &dyn Array, physical_type: PhysicalType) -> Option<Vec<u64>> {
+    if matches!(physical_type, PhysicalType::Float | PhysicalType::Double)
+        || self::physical_type(array.dtype())? != physical_type
+    {
+        return None;
+    }
+
+    let mut hashes = Vec::with_capacity(array.len() - array.null_count());
+    for_each_hash(array, &mut |hash| hashes.push(hash));
+    Some(hashes)
+}
+
+/// The parquet physical type `dtype` is written as, if values of that type can be hashed.
+fn physical_type(dtype: &ArrowDataType) -> Option<PhysicalType> {
+    use ArrowDataType as D;
+    Some(match dtype.to_storage() {
+        D::UInt8
+        | D::UInt16
+        | D::UInt32
+        | D::Int8
+        | D::Int16
+        | D::Int32
+        | D::Date32
+        | D::Time32(_) => PhysicalType::Int32,
+        D::UInt64 | D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            PhysicalType::Int64
+        },
+        D::Float32 => PhysicalType::Float,
+        D::Float64 => PhysicalType::Double,
+        D::LargeUtf8 | D::LargeBinary | D::Utf8View | D::BinaryView => PhysicalType::ByteArray,
+        D::FixedSizeBinary(size) => PhysicalType::FixedLenByteArray(*size),
+        D::Decimal(precision, _) => {
+            if *precision <= 9 {
+                PhysicalType::Int32
+            } else if *precision <= 18 {
+                PhysicalType::Int64
+            } else {
+                PhysicalType::FixedLenByteArray(decimal_length_from_precision(*precision))
+            }
+        },
+        _ => return None,
+    })
+}
+
+fn hash_primitive<T, P>(array: &dyn Array, f: &mut impl FnMut(u64))
+where
+    T: arrow::types::NativeType + num_traits::AsPrimitive<P>,
+    P: crate::parquet::types::NativeType,
+{
+    let array: &PrimitiveArray<T> = array.as_any().downcast_ref().unwrap();
+    for value in array.non_null_values_iter() {
+        f(hash_native::<P>(value.as_()));
+    }
+}
+
+fn for_each_hash(array: &dyn Array, f: &mut impl FnMut(u64)) {
+    use ArrowDataType as D;
+    match array.dtype().to_storage() {
+        D::UInt8 => hash_primitive::<u8, i32>(array, f),
+        D::UInt16 => hash_primitive::<u16, i32>(array, f),
+        D::UInt32 => hash_primitive::<u32, i32>(array, f),
+        D::UInt64 => hash_primitive::<u64, i64>(array, f),
+        D::Int8 => hash_primitive::<i8, i32>(array, f),
+        D::Int16 => hash_primitive::<i16, i32>(array, f),
+        D::Int32 | D::Date32 | D::Time32(_) => hash_primitive::<i32, i32>(array, f),
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            hash_primitive::<i64, i64>(array, f)
+        },
+        D::Float32 => hash_primitive::<f32, f32>(array, f),
+        D::Float64 => hash_primitive::<f64, f64>(array, f),
+        D::LargeUtf8 => {
+            let array: &Utf8Array<i64> = array.as_any().downcast_ref().unwrap();
+            for value in array.non_null_values_iter() {
+                f(hash_byte(value));
+            }
+        },
+        D::LargeBinary => {
+            let array: &BinaryArray<i64> = array.as_any().downcast_ref().unwrap();
+            for value in array.non_null_values_iter() {
+                f(hash_byte(value));
+            }
+        },
+        D::Utf8View => {
+            let array: &Utf8ViewArray = array.as_any().downcast_ref().unwrap();
+            for value in array.non_null_values_iter() {
+                f(hash_byte(value));
+            }
+        },
+        D::BinaryView => {
+            let array: &BinaryViewArray = array.as_any().downcast_ref().unwrap();
+            for value in array.non_null_values_iter() {
+                f(hash_byte(value));
+            }
+        },
+        D::FixedSizeBinary(_) => {
+            let array: &FixedSizeBinaryArray = array.as_any().downcast_ref().unwrap();
+            for value in array.iter().flatten() {
+                f(hash_byte(value));
+            }
+        },
+        D::Decimal(precision, _) => {
+            let precision = *precision;
+            if precision <= 9 {
+                hash_primitive::<i128, i32>(array, f)
+            } else if precision <= 18 {
+                hash_primitive::<i128, i64>(array, f)
+            } else {
+                let size = decimal_length_from_precision(precision);
+                let array: &PrimitiveArray<i128> = array.as_any().downcast_ref().unwrap();
+                for value in array.non_null_values_iter() {
+                    f(hash_byte(&value.to_be_bytes()[16 - size..]));
+                }
+            }
+        },
+        _ => unreachable!(),
+    }
+}
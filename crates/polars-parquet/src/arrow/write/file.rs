@@ -5,7 +5,8 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{ThriftFileMetadata, WriteOptions, to_parquet_schema};
-use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
+use crate::parquet::encryption::FileEncryptionProperties;
+use crate::parquet::metadata::{KeyValue, SchemaDescriptor, SortingColumn};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
 /// An interface to write a parquet to a [`Write`]
@@ -52,6 +53,7 @@ impl<W: Write> FileWriter<W> {
                 FileWriteOptions {
                     version: options.version,
                     write_statistics: options.has_statistics(),
+                    write_page_index: options.write_page_index,
                 },
                 created_by,
             ),
@@ -73,6 +75,22 @@ impl<W: Write> FileWriter<W> {
         ))
     }
 
+    /// Sets the columns that the row groups written after this call are sorted by.
+    pub fn set_sorting_columns(
+        &mut self,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> PolarsResult<()> {
+        Ok(self.writer.set_sorting_columns(sorting_columns)?)
+    }
+
+    /// Encrypts the file with `encryption`. Must be called before any row group is written.
+    pub fn set_encryption(
+        &mut self,
+        encryption: Option<FileEncryptionProperties>,
+    ) -> PolarsResult<()> {
+        Ok(self.writer.set_encryption(encryption)?)
+    }
+
     /// Writes a row group to the file.
     pub fn write(
         &mut self,
@@ -82,6 +100,19 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(num_rows, row_group)?)
     }
 
+    /// Writes a row group to the file, together with the bloom filter bitsets of its leaf
+    /// columns.
+    pub fn write_with_bloom_filters(
+        &mut self,
+        num_rows: u64,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_bloom_filters(num_rows, row_group, bloom_filters)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -38,8 +40,12 @@ pub use utils::write_def_levels;
 
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
+pub use crate::parquet::encryption::{
+    ColumnKey, EncryptionAlgorithm, FileEncryptionProperties, KeyRetriever, KeyRetrieverRef,
+    SecretKey,
+};
 pub use crate::parquet::metadata::{
-    Descriptor, FileMetadata, KeyValue, SchemaDescriptor, ThriftFileMetadata,
+    Descriptor, FileMetadata, KeyValue, SchemaDescriptor, SortingColumn, ThriftFileMetadata,
 };
 pub use crate::parquet::page::{CompressedDataPage, CompressedPage, Page};
 use crate::parquet::schema::Repetition;
@@ -80,6 +86,47 @@ impl Default for StatisticsOptions {
     }
 }
 
+/// The options to build a split-block bloom filter for a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct BloomFilterOptions {
+    /// Target false positive probability, in `(0, 1)`.
+    pub fpp: f64,
+    /// Expected number of distinct values per row group. If `None`, the number of non-null
+    /// values of the column chunk is used as an upper bound.
+    pub ndv: Option<u64>,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            fpp: 0.05,
+            ndv: None,
+        }
+    }
+}
+
+impl Eq for BloomFilterOptions {}
+
+impl std::hash::Hash for BloomFilterOptions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.fpp.to_bits().hash(state);
+        self.ndv.hash(state);
+    }
+}
+
+impl BloomFilterOptions {
+    pub fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.fpp > 0.0 && self.fpp < 1.0,
+            InvalidOperation: "bloom filter false positive probability must be in (0, 1), got {}",
+            self.fpp
+        );
+        Ok(())
+    }
+}
+
 /// Options to encode an array
 #[derive(Clone, Copy)]
 pub enum EncodeNullability {
@@ -98,13 +145,17 @@ pub struct WriteOptions {
     pub compression: CompressionOptions,
     /// The size to flush a page, defaults to 1024 * 1024 if None
     pub data_page_size: Option<usize>,
+    /// Whether to write the page index
+    pub write_page_index: bool,
 }
 
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::{array_to_bloom_filters, array_to_hashes};
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 pub use row_group::{RowGroupIterator, row_group_iter};
 pub use schema::{schema_to_metadata_key, to_parquet_type};
 
@@ -1,11 +1,11 @@
-//! API to read and use bloom filters
+//! API to read, build and use bloom filters
 mod hash;
 mod read;
 mod split_block;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
-pub use split_block::{insert, is_in_set};
+pub use read::{read, read_from_bytes};
+pub use split_block::{insert, is_in_set, optimal_num_bytes};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +68,23 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn sizing() {
+        assert_eq!(optimal_num_bytes(0, 0.01), 32);
+        assert_eq!(optimal_num_bytes(10, 0.01), 32);
+        // ~9.6 bits per value for 1% fpp
+        assert_eq!(optimal_num_bytes(1_000_000, 0.01), 2 * 1024 * 1024);
+        assert_eq!(optimal_num_bytes(u64::MAX, 0.01), 128 * 1024 * 1024);
+
+        let num_bytes = optimal_num_bytes(1000, 0.01);
+        let mut bitset = vec![0; num_bytes];
+        for a in 0..1000i64 {
+            insert(&mut bitset, hash_native(a));
+        }
+        let false_positives = (1000..11000i64)
+            .filter(|a| is_in_set(&bitset, hash_native(*a)))
+            .count();
+        assert!(false_positives < 200);
+    }
 }
@@ -15,7 +15,7 @@ use crate::parquet::metadata::ColumnChunkMetadata;
 /// Errors if the column contains no metadata or the filter can't be read or deserialized.
 pub fn read<R: Read + Seek>(
     column_metadata: &ColumnChunkMetadata,
-    mut reader: &mut R,
+    reader: &mut R,
     bitset: &mut Vec<u8>,
 ) -> ParquetResult<()> {
     let offset = if let Some(offset) = column_metadata.bloom_filter_offset() {
@@ -26,6 +26,19 @@ pub fn read<R: Read + Seek>(
     };
     reader.seek(SeekFrom::Start(offset))?;
 
+    read_bitset(reader, bitset)
+}
+
+/// Reads the bloom filter serialized in `bytes` (a header followed by the bitset) into `bitset`,
+/// e.g. from the range `[bloom_filter_offset, bloom_filter_offset + bloom_filter_length)` of a file.
+/// Results in an empty `bitset` if the algorithm is not supported.
+/// # Error
+/// Errors if the filter can't be deserialized.
+pub fn read_from_bytes(bytes: &[u8], bitset: &mut Vec<u8>) -> ParquetResult<()> {
+    read_bitset(bytes, bitset)
+}
+
+fn read_bitset<R: Read>(mut reader: R, bitset: &mut Vec<u8>) -> ParquetResult<()> {
     // deserialize header
     let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
     let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
@@ -43,7 +56,7 @@ pub fn read<R: Read + Seek>(
 
     bitset.clear();
     bitset.try_reserve(length)?;
-    reader.by_ref().take(length as u64).read_to_end(bitset)?;
+    reader.take(length as u64).read_to_end(bitset)?;
 
     Ok(())
 }
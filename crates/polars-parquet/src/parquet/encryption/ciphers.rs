@@ -0,0 +1,273 @@
+//! The AES-GCM and AES-CTR module formats.
+//!
+//! An AES-GCM module is laid out as `length ‖ nonce ‖ ciphertext ‖ tag`, an AES-CTR module as
+//! `length ‖ nonce ‖ ciphertext`, where `length` is the little-endian `u32` length of the rest of
+//! the module.
+
+use super::modules::ModuleType;
+use super::properties::EncryptionAlgorithm;
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+pub(crate) const SIZE_LEN: usize = 4;
+pub(crate) const NONCE_LEN: usize = 12;
+pub(crate) const TAG_LEN: usize = 16;
+/// The length of the signature of a plaintext footer: a nonce followed by an AES-GCM tag.
+pub(crate) const SIGNATURE_LEN: usize = NONCE_LEN + TAG_LEN;
+
+fn uses_ctr(algorithm: EncryptionAlgorithm, module_type: ModuleType) -> bool {
+    algorithm == EncryptionAlgorithm::AesGcmCtrV1 && module_type.is_page_data()
+}
+
+/// Encrypts `plaintext` into a module with a random nonce.
+pub(crate) fn encrypt_module(
+    algorithm: EncryptionAlgorithm,
+    module_type: ModuleType,
+    key: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> ParquetResult<Vec<u8>> {
+    let mut nonce = [0u8; NONCE_LEN];
+    aes_impl::fill_random(&mut nonce)?;
+
+    let body = if uses_ctr(algorithm, module_type) {
+        let mut ciphertext = plaintext.to_vec();
+        aes_impl::ctr_apply(key, &nonce, &mut ciphertext)?;
+        ciphertext
+    } else {
+        aes_impl::gcm_encrypt(key, &nonce, aad, plaintext)?
+    };
+
+    let length = u32::try_from(NONCE_LEN + body.len())
+        .map_err(|_| ParquetError::oos("an encrypted module can hold at most 4 GiB"))?;
+
+    let mut module = Vec::with_capacity(SIZE_LEN + NONCE_LEN + body.len());
+    module.extend_from_slice(&length.to_le_bytes());
+    module.extend_from_slice(&nonce);
+    module.extend_from_slice(&body);
+    Ok(module)
+}
+
+/// The total length of the module at the start of `buf`, including its length prefix.
+pub(crate) fn module_len(buf: &[u8]) -> ParquetResult<usize> {
+    let length = buf
+        .get(..SIZE_LEN)
+        .ok_or_else(|| ParquetError::oos("an encrypted module must start with its length"))?;
+    Ok(SIZE_LEN + u32::from_le_bytes(length.try_into().unwrap()) as usize)
+}
+
+/// Decrypts the module `module`, which must include its length prefix.
+pub(crate) fn decrypt_module(
+    algorithm: EncryptionAlgorithm,
+    module_type: ModuleType,
+    key: &[u8],
+    aad: &[u8],
+    module: &[u8],
+) -> ParquetResult<Vec<u8>> {
+    let ctr = uses_ctr(algorithm, module_type);
+    let min_len = SIZE_LEN + NONCE_LEN + if ctr { 0 } else { TAG_LEN };
+
+    if module.len() < min_len || module_len(module)? != module.len() {
+        return Err(ParquetError::oos(format!(
+            "the encrypted {module_type:?} module has an invalid length"
+        )));
+    }
+
+    let (nonce, body) = module[SIZE_LEN..].split_at(NONCE_LEN);
+    let nonce: &[u8; NONCE_LEN] = nonce.try_into().unwrap();
+
+    if ctr {
+        let mut plaintext = body.to_vec();
+        aes_impl::ctr_apply(key, nonce, &mut plaintext)?;
+        Ok(plaintext)
+    } else {
+        aes_impl::gcm_decrypt(key, nonce, aad, body).map_err(|e| match e {
+            ParquetError::OutOfSpec(_) => ParquetError::oos(format!(
+                "failed to decrypt the {module_type:?} module, the key is wrong or the file is corrupted"
+            )),
+            e => e,
+        })
+    }
+}
+
+/// Signs a plaintext footer, returning the nonce followed by the AES-GCM tag of the footer.
+pub(crate) fn sign_footer(
+    key: &[u8],
+    aad: &[u8],
+    footer: &[u8],
+) -> ParquetResult<[u8; SIGNATURE_LEN]> {
+    let mut signature = [0u8; SIGNATURE_LEN];
+    aes_impl::fill_random(&mut signature[..NONCE_LEN])?;
+
+    let nonce: &[u8; NONCE_LEN] = signature[..NONCE_LEN].try_into().unwrap();
+    let ciphertext = aes_impl::gcm_encrypt(key, nonce, aad, footer)?;
+    signature[NONCE_LEN..].copy_from_slice(&ciphertext[ciphertext.len() - TAG_LEN..]);
+    Ok(signature)
+}
+
+/// Verifies the signature of a plaintext footer written by [`sign_footer`].
+pub(crate) fn verify_footer(
+    key: &[u8],
+    aad: &[u8],
+    footer: &[u8],
+    signature: &[u8],
+) -> ParquetResult<()> {
+    if signature.len() != SIGNATURE_LEN {
+        return Err(ParquetError::oos(
+            "the plaintext footer signature is missing",
+        ));
+    }
+
+    let nonce: &[u8; NONCE_LEN] = signature[..NONCE_LEN].try_into().unwrap();
+
+    // The tag is verified by AES-GCM, which compares tags in constant time, by decrypting the
+    // encrypted footer together with the tag of the signature.
+    let mut ciphertext = aes_impl::gcm_encrypt(key, nonce, aad, footer)?;
+    let tag_start = ciphertext.len() - TAG_LEN;
+    ciphertext[tag_start..].copy_from_slice(&signature[NONCE_LEN..]);
+
+    aes_impl::gcm_decrypt(key, nonce, aad, &ciphertext).map_err(|e| match e {
+        ParquetError::OutOfSpec(_) => ParquetError::oos(
+            "the plaintext footer signature does not match, the footer key is wrong or the file was tampered with",
+        ),
+        e => e,
+    })?;
+    Ok(())
+}
+
+/// Fills `buf` with random bytes from the operating system.
+pub(crate) fn fill_random(buf: &mut [u8]) -> ParquetResult<()> {
+    aes_impl::fill_random(buf)
+}
+
+#[cfg(feature = "encryption")]
+mod aes_impl {
+    use aes_gcm::aead::consts::U12;
+    use aes_gcm::aead::{Aead, KeyInit, Payload};
+    use aes_gcm::{AesGcm, Nonce};
+    use ctr::cipher::{KeyIvInit, StreamCipher};
+
+    use super::NONCE_LEN;
+    use crate::parquet::error::{ParquetError, ParquetResult};
+
+    /// Evaluates `$body` with `$aes` bound to the AES variant of the length of `$key`.
+    macro_rules! with_aes {
+        ($key:expr, $aes:ident => $body:expr) => {
+            match $key.len() {
+                16 => {
+                    type $aes = aes::Aes128;
+                    $body
+                },
+                24 => {
+                    type $aes = aes::Aes192;
+                    $body
+                },
+                32 => {
+                    type $aes = aes::Aes256;
+                    $body
+                },
+                n => Err(ParquetError::InvalidParameter(format!(
+                    "AES keys must be 16, 24 or 32 bytes long, got {n} bytes"
+                ))),
+            }
+        };
+    }
+
+    fn invalid_key() -> ParquetError {
+        ParquetError::InvalidParameter("invalid AES key".to_string())
+    }
+
+    pub(super) fn gcm_encrypt(
+        key: &[u8],
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        with_aes!(key, Aes => {
+            let cipher = AesGcm::<Aes, U12>::new_from_slice(key).map_err(|_| invalid_key())?;
+            cipher
+                .encrypt(Nonce::<U12>::from_slice(nonce), Payload { msg: plaintext, aad })
+                .map_err(|_| ParquetError::oos("AES-GCM encryption failed"))
+        })
+    }
+
+    pub(super) fn gcm_decrypt(
+        key: &[u8],
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        ciphertext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        with_aes!(key, Aes => {
+            let cipher = AesGcm::<Aes, U12>::new_from_slice(key).map_err(|_| invalid_key())?;
+            cipher
+                .decrypt(Nonce::<U12>::from_slice(nonce), Payload { msg: ciphertext, aad })
+                .map_err(|_| ParquetError::oos("AES-GCM decryption failed"))
+        })
+    }
+
+    /// Applies the AES-CTR keystream, with the nonce followed by a 32-bit big-endian counter
+    /// starting at 1 as the initial counter block.
+    pub(super) fn ctr_apply(
+        key: &[u8],
+        nonce: &[u8; NONCE_LEN],
+        data: &mut [u8],
+    ) -> ParquetResult<()> {
+        let mut iv = [0u8; 16];
+        iv[..NONCE_LEN].copy_from_slice(nonce);
+        iv[15] = 1;
+
+        with_aes!(key, Aes => {
+            let mut cipher =
+                ctr::Ctr32BE::<Aes>::new_from_slices(key, &iv).map_err(|_| invalid_key())?;
+            cipher.apply_keystream(data);
+            Ok(())
+        })
+    }
+
+    pub(super) fn fill_random(buf: &mut [u8]) -> ParquetResult<()> {
+        getrandom::fill(buf)
+            .map_err(|e| ParquetError::oos(format!("failed to generate random bytes: {e}")))
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+mod aes_impl {
+    use super::NONCE_LEN;
+    use crate::parquet::error::{Feature, ParquetError, ParquetResult};
+
+    fn not_active<T>() -> ParquetResult<T> {
+        Err(ParquetError::FeatureNotActive(
+            Feature::Encryption,
+            "read or write encrypted parquet files".to_string(),
+        ))
+    }
+
+    pub(super) fn gcm_encrypt(
+        _key: &[u8],
+        _nonce: &[u8; NONCE_LEN],
+        _aad: &[u8],
+        _plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        not_active()
+    }
+
+    pub(super) fn gcm_decrypt(
+        _key: &[u8],
+        _nonce: &[u8; NONCE_LEN],
+        _aad: &[u8],
+        _ciphertext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        not_active()
+    }
+
+    pub(super) fn ctr_apply(
+        _key: &[u8],
+        _nonce: &[u8; NONCE_LEN],
+        _data: &mut [u8],
+    ) -> ParquetResult<()> {
+        not_active()
+    }
+
+    pub(super) fn fill_random(_buf: &mut [u8]) -> ParquetResult<()> {
+        not_active()
+    }
+}
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{
+    ColumnCryptoMetaData, EncryptionAlgorithm as ThriftEncryptionAlgorithm, FileCryptoMetaData,
+};
+
+use super::ciphers::{decrypt_module, verify_footer};
+use super::modules::{ModuleType, footer_aad, module_aad};
+use super::properties::{EncryptionAlgorithm, FileDecryptionProperties};
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::{FOOTER_SIZE, PARQUET_ENCRYPTED_MAGIC};
+
+/// Whether `footer`, which ends with the footer length and magic, is an encrypted footer.
+pub(crate) fn is_encrypted_footer(footer: &[u8]) -> bool {
+    footer.ends_with(&PARQUET_ENCRYPTED_MAGIC)
+}
+
+/// Decrypts the modules of the footer and column metadata of a file.
+pub(crate) struct FileDecryptor {
+    algorithm: EncryptionAlgorithm,
+    file_aad: Arc<[u8]>,
+    footer_key: Option<Vec<u8>>,
+    properties: FileDecryptionProperties,
+}
+
+impl FileDecryptor {
+    pub(crate) fn try_new(
+        algorithm: &ThriftEncryptionAlgorithm,
+        footer_key_metadata: Option<&[u8]>,
+        properties: &FileDecryptionProperties,
+    ) -> ParquetResult<Self> {
+        let (algorithm, aad_prefix, aad_file_unique, supply_aad_prefix) = match algorithm {
+            ThriftEncryptionAlgorithm::AESGCMV1(a) => (
+                EncryptionAlgorithm::AesGcmV1,
+                &a.aad_prefix,
+                &a.aad_file_unique,
+                a.supply_aad_prefix,
+            ),
+            ThriftEncryptionAlgorithm::AESGCMCTRV1(a) => (
+                EncryptionAlgorithm::AesGcmCtrV1,
+                &a.aad_prefix,
+                &a.aad_file_unique,
+                a.supply_aad_prefix,
+            ),
+        };
+
+        let aad_prefix = match (aad_prefix, &properties.aad_prefix) {
+            (Some(stored), Some(given)) if stored != given => {
+                return Err(ParquetError::InvalidParameter(
+                    "the given AAD prefix does not match the AAD prefix stored in the file"
+                        .to_string(),
+                ));
+            },
+            (Some(prefix), _) | (None, Some(prefix)) => prefix.as_slice(),
+            (None, None) if supply_aad_prefix == Some(true) => {
+                return Err(ParquetError::InvalidParameter(
+                    "the file was encrypted with an AAD prefix that must be supplied to decrypt it"
+                        .to_string(),
+                ));
+            },
+            (None, None) => &[],
+        };
+
+        let file_aad = [aad_prefix, aad_file_unique.as_deref().unwrap_or_default()].concat();
+        let footer_key = properties.resolve_footer_key(footer_key_metadata)?;
+
+        Ok(Self {
+            algorithm,
+            file_aad: file_aad.into(),
+            footer_key,
+            properties: properties.clone(),
+        })
+    }
+
+    fn footer_key(&self) -> ParquetResult<&[u8]> {
+        self.footer_key.as_deref().ok_or_else(|| {
+            ParquetError::InvalidParameter(
+                "the footer key of the encrypted parquet file is not available".to_string(),
+            )
+        })
+    }
+
+    /// Decrypts an encrypted footer: a `FileCryptoMetaData` followed by the encrypted
+    /// `FileMetaData` module, the footer length and the magic. Returns the `FileMetaData` bytes.
+    pub(crate) fn decrypt_footer(
+        footer: &[u8],
+        properties: &FileDecryptionProperties,
+    ) -> ParquetResult<(Self, Vec<u8>)> {
+        let body = &footer[..footer.len().saturating_sub(FOOTER_SIZE as usize)];
+
+        let mut reader = body;
+        let mut prot = TCompactInputProtocol::new(&mut reader, body.len());
+        let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+        let module = reader;
+
+        let decryptor = Self::try_new(
+            &crypto_metadata.encryption_algorithm,
+            crypto_metadata.key_metadata.as_deref(),
+            properties,
+        )?;
+        let plaintext = decrypt_module(
+            decryptor.algorithm,
+            ModuleType::Footer,
+            decryptor.footer_key()?,
+            &footer_aad(&decryptor.file_aad),
+            module,
+        )?;
+
+        Ok((decryptor, plaintext))
+    }
+
+    /// Verifies the signature of a plaintext footer, if the footer key is available.
+    pub(crate) fn verify_plaintext_footer(
+        &self,
+        footer: &[u8],
+        signature: &[u8],
+    ) -> ParquetResult<()> {
+        let Some(key) = &self.footer_key else {
+            return Ok(());
+        };
+        verify_footer(key, &footer_aad(&self.file_aad), footer, signature)
+    }
+
+    /// Returns the decryptor of the column chunk at `column_ordinal` in the row group at
+    /// `row_group_ordinal`, or `None` if its key is not available.
+    pub(crate) fn column_decryptor(
+        &self,
+        crypto_metadata: &ColumnCryptoMetaData,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> Option<ColumnDecryptor> {
+        let key = match crypto_metadata {
+            ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_) => self.footer_key.clone(),
+            // A column whose key cannot be retrieved can still be listed, but not read.
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(column_key) => self
+                .properties
+                .resolve_column_key(
+                    &column_key.path_in_schema.join("."),
+                    column_key.key_metadata.as_deref(),
+                )
+                .ok()
+                .flatten(),
+        }?;
+
+        Some(ColumnDecryptor {
+            algorithm: self.algorithm,
+            key,
+            file_aad: self.file_aad.clone(),
+            row_group_ordinal,
+            column_ordinal,
+            has_dictionary_page: false,
+        })
+    }
+}
+
+/// The encryption state of a column chunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnCrypto {
+    Decryptable(Arc<ColumnDecryptor>),
+    /// The column chunk is encrypted with a key that is not available.
+    MissingKey,
+}
+
+impl ColumnCrypto {
+    pub(crate) fn decryptor(&self, column: &str) -> ParquetResult<&ColumnDecryptor> {
+        match self {
+            Self::Decryptable(decryptor) => Ok(decryptor.as_ref()),
+            Self::MissingKey => Err(ParquetError::InvalidParameter(format!(
+                "the key of the encrypted column \"{column}\" is not available"
+            ))),
+        }
+    }
+}
+
+/// Decrypts the modules of a column chunk.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ColumnDecryptor {
+    algorithm: EncryptionAlgorithm,
+    key: Vec<u8>,
+    file_aad: Arc<[u8]>,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    pub(crate) has_dictionary_page: bool,
+}
+
+impl std::fmt::Debug for ColumnDecryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDecryptor")
+            .field("algorithm", &self.algorithm)
+            .field("row_group_ordinal", &self.row_group_ordinal)
+            .field("column_ordinal", &self.column_ordinal)
+            .field("has_dictionary_page", &self.has_dictionary_page)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ColumnDecryptor {
+    /// Decrypts `module`, which includes its length prefix. `page_ordinal` must be given for
+    /// data pages and their headers.
+    pub(crate) fn decrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        module: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group_ordinal,
+            self.column_ordinal,
+            page_ordinal,
+        )?;
+        decrypt_module(self.algorithm, module_type, &self.key, &aad, module)
+    }
+}
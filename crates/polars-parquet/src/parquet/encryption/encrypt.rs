@@ -0,0 +1,221 @@
+use polars_parquet_format::{
+    AesGcmCtrV1, AesGcmV1, ColumnCryptoMetaData, EncryptionAlgorithm as ThriftEncryptionAlgorithm,
+    EncryptionWithColumnKey, EncryptionWithFooterKey, FileCryptoMetaData,
+};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::ciphers::{SIGNATURE_LEN, encrypt_module, fill_random, sign_footer};
+use super::modules::{ModuleType, footer_aad, module_aad};
+use super::properties::{EncryptionAlgorithm, FileEncryptionProperties, SecretKey};
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::metadata::SchemaDescriptor;
+
+/// The length of the random file identifier that is part of the AAD of every module.
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+fn column_path(path_in_schema: &[PlSmallStr]) -> String {
+    path_in_schema.join(".")
+}
+
+/// Encrypts the modules of a file that is being written.
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    aad_file_unique: Vec<u8>,
+    file_aad: Vec<u8>,
+}
+
+impl FileEncryptor {
+    pub(crate) fn try_new(
+        properties: FileEncryptionProperties,
+        schema: &SchemaDescriptor,
+    ) -> ParquetResult<Self> {
+        properties.validate()?;
+
+        if let Some(path) = properties.column_keys.keys().find(|path| {
+            !schema
+                .columns()
+                .iter()
+                .any(|c| column_path(&c.path_in_schema) == **path)
+        }) {
+            return Err(ParquetError::InvalidParameter(format!(
+                "encryption key given for column \"{path}\", which is not in the schema"
+            )));
+        }
+
+        let mut aad_file_unique = vec![0u8; AAD_FILE_UNIQUE_LEN];
+        fill_random(&mut aad_file_unique)?;
+
+        let file_aad = [
+            properties.aad_prefix.as_deref().unwrap_or_default(),
+            &aad_file_unique,
+        ]
+        .concat();
+
+        Ok(Self {
+            properties,
+            aad_file_unique,
+            file_aad,
+        })
+    }
+
+    pub(crate) fn is_footer_encrypted(&self) -> bool {
+        !self.properties.plaintext_footer
+    }
+
+    pub(crate) fn is_column_encrypted(&self, path_in_schema: &[PlSmallStr]) -> bool {
+        self.properties.column_keys.is_empty()
+            || self
+                .properties
+                .column_keys
+                .contains_key(&column_path(path_in_schema))
+    }
+
+    /// Returns the encryptor of the column chunk at `column_ordinal` in the row group at
+    /// `row_group_ordinal`, or `None` if the column is not encrypted.
+    pub(crate) fn column_encryptor(
+        &self,
+        path_in_schema: &[PlSmallStr],
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> Option<ColumnEncryptor> {
+        if !self.is_column_encrypted(path_in_schema) {
+            return None;
+        }
+
+        let key = match self
+            .properties
+            .column_keys
+            .get(&column_path(path_in_schema))
+        {
+            Some(column_key) => column_key.key.clone(),
+            None => self.properties.footer_key.clone(),
+        };
+
+        Some(ColumnEncryptor {
+            algorithm: self.properties.algorithm,
+            key,
+            file_aad: self.file_aad.clone(),
+            row_group_ordinal,
+            column_ordinal,
+        })
+    }
+
+    /// The crypto metadata of an encrypted column, stored in its `ColumnChunk`.
+    pub(crate) fn column_crypto_metadata(
+        &self,
+        path_in_schema: &[PlSmallStr],
+    ) -> ColumnCryptoMetaData {
+        match self
+            .properties
+            .column_keys
+            .get(&column_path(path_in_schema))
+        {
+            Some(column_key) => {
+                ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                    path_in_schema: path_in_schema.iter().map(|p| p.to_string()).collect(),
+                    key_metadata: column_key.key_metadata.clone(),
+                })
+            },
+            None => ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey {}),
+        }
+    }
+
+    /// Whether the metadata of the encrypted column is readable with the footer key, such that
+    /// it can be stored unencrypted in an encrypted footer.
+    pub(crate) fn uses_footer_key(&self, path_in_schema: &[PlSmallStr]) -> bool {
+        !self
+            .properties
+            .column_keys
+            .contains_key(&column_path(path_in_schema))
+    }
+
+    pub(crate) fn algorithm(&self) -> ThriftEncryptionAlgorithm {
+        let aad_prefix = self
+            .properties
+            .aad_prefix
+            .as_ref()
+            .filter(|_| self.properties.store_aad_prefix)
+            .cloned();
+        let supply_aad_prefix = self
+            .properties
+            .aad_prefix
+            .as_ref()
+            .map(|_| !self.properties.store_aad_prefix);
+        let aad_file_unique = Some(self.aad_file_unique.clone());
+
+        match self.properties.algorithm {
+            EncryptionAlgorithm::AesGcmV1 => ThriftEncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+            EncryptionAlgorithm::AesGcmCtrV1 => {
+                ThriftEncryptionAlgorithm::AESGCMCTRV1(AesGcmCtrV1 {
+                    aad_prefix,
+                    aad_file_unique,
+                    supply_aad_prefix,
+                })
+            },
+        }
+    }
+
+    pub(crate) fn footer_key_metadata(&self) -> Option<Vec<u8>> {
+        self.properties.footer_key_metadata.clone()
+    }
+
+    pub(crate) fn file_crypto_metadata(&self) -> FileCryptoMetaData {
+        FileCryptoMetaData {
+            encryption_algorithm: self.algorithm(),
+            key_metadata: self.footer_key_metadata(),
+        }
+    }
+
+    /// Encrypts the serialized `FileMetaData` into the footer module.
+    pub(crate) fn encrypt_footer(&self, footer: &[u8]) -> ParquetResult<Vec<u8>> {
+        encrypt_module(
+            self.properties.algorithm,
+            ModuleType::Footer,
+            &self.properties.footer_key,
+            &footer_aad(&self.file_aad),
+            footer,
+        )
+    }
+
+    /// Signs the serialized `FileMetaData` of a plaintext footer.
+    pub(crate) fn sign_footer(&self, footer: &[u8]) -> ParquetResult<[u8; SIGNATURE_LEN]> {
+        sign_footer(
+            &self.properties.footer_key,
+            &footer_aad(&self.file_aad),
+            footer,
+        )
+    }
+}
+
+/// Encrypts the modules of a column chunk.
+pub(crate) struct ColumnEncryptor {
+    algorithm: EncryptionAlgorithm,
+    key: SecretKey,
+    file_aad: Vec<u8>,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+}
+
+impl ColumnEncryptor {
+    /// Encrypts `plaintext` into a module. `page_ordinal` must be given for data pages and their
+    /// headers.
+    pub(crate) fn encrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let aad = module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group_ordinal,
+            self.column_ordinal,
+            page_ordinal,
+        )?;
+        encrypt_module(self.algorithm, module_type, &self.key, &aad, plaintext)
+    }
+}
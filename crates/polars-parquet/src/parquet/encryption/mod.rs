@@ -0,0 +1,24 @@
+//! Parquet modular encryption.
+//!
+//! Implements the AES-GCM and AES-GCM-CTR algorithms of
+//! <https://github.com/apache/parquet-format/blob/master/Encryption.md>, with encrypted or signed
+//! plaintext footers and per-column keys. The bloom filters and page indexes of encrypted columns
+//! are neither written nor read.
+//!
+//! The ciphers require the `encryption` feature. Without it, reading or writing an encrypted
+//! file fails with [`crate::parquet::error::ParquetError::FeatureNotActive`].
+
+mod ciphers;
+mod decrypt;
+mod encrypt;
+pub(crate) mod modules;
+mod properties;
+
+pub(crate) use ciphers::{SIGNATURE_LEN, module_len};
+pub use decrypt::{ColumnCrypto, ColumnDecryptor};
+pub(crate) use decrypt::{FileDecryptor, is_encrypted_footer};
+pub(crate) use encrypt::{ColumnEncryptor, FileEncryptor};
+pub use properties::{
+    ColumnKey, EncryptionAlgorithm, FileDecryptionProperties, FileEncryptionProperties,
+    KeyRetriever, KeyRetrieverRef, SecretKey,
+};
@@ -0,0 +1,72 @@
+//! Additional authenticated data (AAD) of the encrypted modules of a file.
+//!
+//! See <https://github.com/apache/parquet-format/blob/master/Encryption.md#442-aad-suffix>.
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+/// The kind of an encrypted module, stored as the first byte of its AAD suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+}
+
+impl ModuleType {
+    /// Whether the module holds page data, which AES-GCM-CTR encrypts with AES-CTR.
+    pub(crate) fn is_page_data(self) -> bool {
+        matches!(self, Self::DataPage | Self::DictionaryPage)
+    }
+}
+
+/// The AAD of the footer: the file AAD followed by the module type.
+pub(crate) fn footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 1);
+    aad.extend_from_slice(file_aad);
+    aad.push(ModuleType::Footer as u8);
+    aad
+}
+
+/// The AAD of a module of the column chunk at `column_ordinal` in the row group at
+/// `row_group_ordinal`. The ordinal of the data page is only part of the AAD of data pages and
+/// their headers.
+pub(crate) fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    page_ordinal: Option<usize>,
+) -> ParquetResult<Vec<u8>> {
+    debug_assert_ne!(module_type, ModuleType::Footer);
+    debug_assert_eq!(
+        page_ordinal.is_some(),
+        matches!(
+            module_type,
+            ModuleType::DataPage | ModuleType::DataPageHeader
+        )
+    );
+
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    aad.extend_from_slice(&ordinal_bytes("row group", row_group_ordinal)?);
+    aad.extend_from_slice(&ordinal_bytes("column", column_ordinal)?);
+    if let Some(page_ordinal) = page_ordinal {
+        aad.extend_from_slice(&ordinal_bytes("page", page_ordinal)?);
+    }
+    Ok(aad)
+}
+
+fn ordinal_bytes(name: &str, ordinal: usize) -> ParquetResult<[u8; 2]> {
+    let ordinal = i16::try_from(ordinal).map_err(|_| {
+        ParquetError::not_supported(format!(
+            "encrypted files can have at most {} {name}s, got {}",
+            i16::MAX as usize + 1,
+            ordinal + 1
+        ))
+    })?;
+    Ok(ordinal.to_le_bytes())
+}
@@ -0,0 +1,343 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+/// The cipher used to encrypt the modules of a Parquet file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum EncryptionAlgorithm {
+    /// All modules are encrypted with AES-GCM.
+    #[default]
+    AesGcmV1,
+    /// Page data is encrypted with AES-CTR, all other modules with AES-GCM.
+    AesGcmCtrV1,
+}
+
+/// Resolves the `key_metadata` stored in an encrypted Parquet file to the key it refers to, e.g.
+/// by unwrapping it with a key management service.
+pub trait KeyRetriever: Send + Sync + Debug {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>>;
+}
+
+/// A shared [`KeyRetriever`].
+///
+/// Compares by pointer. This cannot be serialized.
+#[derive(Debug, Clone)]
+pub struct KeyRetrieverRef(pub Arc<dyn KeyRetriever>);
+
+impl KeyRetrieverRef {
+    pub fn new(key_retriever: impl KeyRetriever + 'static) -> Self {
+        Self(Arc::new(key_retriever))
+    }
+}
+
+impl PartialEq for KeyRetrieverRef {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for KeyRetrieverRef {}
+
+impl Hash for KeyRetrieverRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.0) as *const () as usize)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for KeyRetrieverRef {
+    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom(
+            "cannot serialize a parquet key retriever",
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for KeyRetrieverRef {
+    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+        Err(serde::de::Error::custom(
+            "cannot deserialize a parquet key retriever",
+        ))
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for KeyRetrieverRef {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "KeyRetrieverRef".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "KeyRetrieverRef"))
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        Vec::<u8>::json_schema(generator)
+    }
+}
+
+/// The bytes of an AES key.
+///
+/// Keys must not end up in serialized or hashed query plans, so this compares and hashes by
+/// pointer and cannot be serialized, like [`KeyRetrieverRef`].
+#[derive(Clone)]
+pub struct SecretKey(Arc<[u8]>);
+
+impl SecretKey {
+    pub fn new(key: Vec<u8>) -> Self {
+        Self(key.into())
+    }
+}
+
+impl From<Vec<u8>> for SecretKey {
+    fn from(key: Vec<u8>) -> Self {
+        Self::new(key)
+    }
+}
+
+impl std::ops::Deref for SecretKey {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SecretKey {}
+
+impl Hash for SecretKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.0) as *const () as usize)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for SecretKey {
+    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom(
+            "cannot serialize a parquet encryption key",
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for SecretKey {
+    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+        Err(serde::de::Error::custom(
+            "cannot deserialize a parquet encryption key",
+        ))
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for SecretKey {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "SecretKey".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "SecretKey"))
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        Vec::<u8>::json_schema(generator)
+    }
+}
+
+/// An AES key together with the metadata that is stored in the file to retrieve it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ColumnKey {
+    pub key: SecretKey,
+    pub key_metadata: Option<Vec<u8>>,
+}
+
+/// How to encrypt a Parquet file, following the Parquet modular encryption specification.
+///
+/// Keys must be 16, 24 or 32 bytes long, selecting AES-128, AES-192 or AES-256.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FileEncryptionProperties {
+    pub algorithm: EncryptionAlgorithm,
+    /// The key that encrypts the footer, and the columns without a key of their own.
+    pub footer_key: SecretKey,
+    pub footer_key_metadata: Option<Vec<u8>>,
+    /// The keys of the encrypted columns, by dot-separated column path. If empty, every column
+    /// is encrypted with the footer key. Otherwise, only these columns are encrypted.
+    pub column_keys: BTreeMap<String, ColumnKey>,
+    /// Leave the footer readable by readers without the footer key. It is signed instead.
+    pub plaintext_footer: bool,
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Store the AAD prefix in the file. If `false`, readers must supply it.
+    pub store_aad_prefix: bool,
+}
+
+impl FileEncryptionProperties {
+    /// Encrypt the footer and every column with `footer_key` using AES-GCM.
+    pub fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            algorithm: EncryptionAlgorithm::default(),
+            footer_key: footer_key.into(),
+            footer_key_metadata: None,
+            column_keys: BTreeMap::new(),
+            plaintext_footer: false,
+            aad_prefix: None,
+            store_aad_prefix: true,
+        }
+    }
+
+    pub fn with_algorithm(mut self, algorithm: EncryptionAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    pub fn with_footer_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.footer_key_metadata = Some(key_metadata);
+        self
+    }
+
+    /// Encrypt the column at the dot-separated `path` with its own key.
+    pub fn with_column_key(
+        mut self,
+        path: impl Into<String>,
+        key: Vec<u8>,
+        key_metadata: Option<Vec<u8>>,
+    ) -> Self {
+        self.column_keys.insert(
+            path.into(),
+            ColumnKey {
+                key: key.into(),
+                key_metadata,
+            },
+        );
+        self
+    }
+
+    pub fn with_plaintext_footer(mut self, plaintext_footer: bool) -> Self {
+        self.plaintext_footer = plaintext_footer;
+        self
+    }
+
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>, store_aad_prefix: bool) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self.store_aad_prefix = store_aad_prefix;
+        self
+    }
+
+    pub fn validate(&self) -> ParquetResult<()> {
+        validate_key("footer", &self.footer_key)?;
+        for (path, column_key) in self.column_keys.iter() {
+            validate_key(&format!("column \"{path}\""), &column_key.key)?;
+        }
+        Ok(())
+    }
+}
+
+/// How to decrypt a Parquet file.
+///
+/// Keys are taken from this struct first and otherwise retrieved from the `key_metadata` in the
+/// file with the [`KeyRetriever`]. Columns whose key cannot be found can still be listed in the
+/// metadata, but not read.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FileDecryptionProperties {
+    pub footer_key: Option<SecretKey>,
+    /// The keys of encrypted columns, by dot-separated column path.
+    pub column_keys: BTreeMap<String, SecretKey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub key_retriever: Option<KeyRetrieverRef>,
+    /// The AAD prefix for files that were written without storing it.
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+impl FileDecryptionProperties {
+    pub fn with_footer_key(mut self, key: Vec<u8>) -> Self {
+        self.footer_key = Some(key.into());
+        self
+    }
+
+    pub fn with_column_key(mut self, path: impl Into<String>, key: Vec<u8>) -> Self {
+        self.column_keys.insert(path.into(), key.into());
+        self
+    }
+
+    pub fn with_key_retriever(mut self, key_retriever: impl KeyRetriever + 'static) -> Self {
+        self.key_retriever = Some(KeyRetrieverRef::new(key_retriever));
+        self
+    }
+
+    pub fn with_aad_prefix(mut self, aad_prefix: Vec<u8>) -> Self {
+        self.aad_prefix = Some(aad_prefix);
+        self
+    }
+
+    /// The footer key, if it is given or can be retrieved.
+    pub(crate) fn resolve_footer_key(
+        &self,
+        key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<Option<Vec<u8>>> {
+        self.resolve_key(self.footer_key.as_ref(), key_metadata)
+    }
+
+    /// The key of the column at `path`, if it is given or can be retrieved.
+    pub(crate) fn resolve_column_key(
+        &self,
+        path: &str,
+        key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<Option<Vec<u8>>> {
+        self.resolve_key(self.column_keys.get(path), key_metadata)
+    }
+
+    fn resolve_key(
+        &self,
+        key: Option<&SecretKey>,
+        key_metadata: Option<&[u8]>,
+    ) -> ParquetResult<Option<Vec<u8>>> {
+        let key = match (key, &self.key_retriever, key_metadata) {
+            (Some(key), _, _) => key.to_vec(),
+            (None, Some(retriever), Some(key_metadata)) => {
+                retriever.0.retrieve_key(key_metadata)?
+            },
+            _ => return Ok(None),
+        };
+        validate_key("decryption", &key)?;
+        Ok(Some(key))
+    }
+}
+
+fn validate_key(name: &str, key: &[u8]) -> ParquetResult<()> {
+    if !matches!(key.len(), 16 | 24 | 32) {
+        return Err(ParquetError::InvalidParameter(format!(
+            "the {name} key must be 16, 24 or 32 bytes long, got {} bytes",
+            key.len()
+        )));
+    }
+    Ok(())
+}
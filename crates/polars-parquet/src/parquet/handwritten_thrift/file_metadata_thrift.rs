@@ -11,11 +11,21 @@
 //!
 //!   See <https://github.com/apache/parquet-format/blob/96edf77704b60b6f3ca2232c218c64eff6c874d3/src/main/thrift/parquet.thrift> for spec
 
+use std::sync::Arc;
+
 use polars_buffer::Buffer;
-use polars_parquet_format::{ColumnOrder, KeyValue, SchemaElement, SortingColumn};
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{
+    ColumnCryptoMetaData, ColumnOrder, EncryptionAlgorithm, KeyValue, SchemaElement, SortingColumn,
+};
 
 use super::parquet_thrift::{FieldType, ThriftCompactInputProtocol, ThriftSliceInputProtocol};
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::modules::ModuleType;
+use crate::parquet::encryption::{
+    ColumnCrypto, ColumnDecryptor, FileDecryptionProperties, FileDecryptor, SIGNATURE_LEN,
+    is_encrypted_footer,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{
     ByteRange, CompactColumnChunk, CompactColumnMetaData, CompactFileMetaData, CompactRowGroup,
@@ -70,19 +80,123 @@ macro_rules! read_struct_fields {
 
 /// Decode a Parquet `FileMetaData` footer into [`CompactFileMetaData`].
 ///
-/// `footer` holds the bytes `&buf` views. `ByteRange` offsets into stats
-/// min/max are recorded relative to `footer.as_ptr()`, and the [`Buffer`]
-/// is cloned (refcount-bump) into the output so they stay resolvable.
+/// `footer` holds the bytes `&buf` views, followed by the footer length and
+/// magic. `ByteRange` offsets into stats min/max are recorded relative to
+/// `footer.as_ptr()`, and the [`Buffer`] is cloned (refcount-bump) into the
+/// output so they stay resolvable.
+///
+/// Encrypted footers are decrypted first, and the column metadata of
+/// encrypted columns whose key is available is decrypted and appended to the
+/// footer buffer, see [`DecryptionState`].
 ///
 /// Crate-internal: external callers go through
 /// [`crate::parquet::read::deserialize_metadata`] which combines this with
 /// [`crate::parquet::metadata::FileMetadata::from_compact`] to produce the
 /// public [`crate::parquet::metadata::FileMetadata`].
-pub(crate) fn decode_file_metadata(footer: Buffer<u8>) -> ParquetResult<CompactFileMetaData> {
+pub(crate) fn decode_file_metadata(
+    footer: Buffer<u8>,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<CompactFileMetaData> {
+    if is_encrypted_footer(&footer) {
+        let properties = decryption.ok_or_else(|| {
+            ParquetError::InvalidParameter(
+                "the parquet file has an encrypted footer, decryption properties are required to read it"
+                    .to_string(),
+            )
+        })?;
+        let (decryptor, plaintext) = FileDecryptor::decrypt_footer(&footer, properties)?;
+        return decode_decrypted_file_metadata(
+            Buffer::from_vec(plaintext),
+            Some(&decryptor),
+            false,
+        );
+    }
+
+    let decryptor = match decryption {
+        Some(properties) => plaintext_footer_decryptor(&footer, properties)?,
+        None => None,
+    };
+    decode_decrypted_file_metadata(footer, decryptor.as_ref(), true)
+}
+
+/// State of decoding the metadata of encrypted column chunks.
+///
+/// The decrypted `ColumnMetaData` of encrypted chunks lives outside of the
+/// footer. Its bytes are collected in `decrypted` and appended to the footer
+/// buffer after decoding, so the `ByteRange`s of its statistics are shifted
+/// by the footer length plus the bytes decrypted before it.
+struct DecryptionState<'a> {
+    /// `None` if the footer is plaintext and no decryption properties were
+    /// given; encrypted chunks are then marked [`ColumnCrypto::MissingKey`].
+    decryptor: Option<&'a FileDecryptor>,
+    footer_len: usize,
+    decrypted: Vec<u8>,
+}
+
+fn decode_decrypted_file_metadata(
+    footer: Buffer<u8>,
+    decryptor: Option<&FileDecryptor>,
+    plaintext_footer: bool,
+) -> ParquetResult<CompactFileMetaData> {
     let buf: &[u8] = footer.as_ref();
     let origin_ptr = buf.as_ptr();
     let mut prot = ThriftSliceInputProtocol::new(buf);
-    read_file_metadata(&mut prot, origin_ptr, &footer)
+    let mut state = DecryptionState {
+        decryptor,
+        footer_len: buf.len(),
+        decrypted: Vec::new(),
+    };
+    let mut compact = read_file_metadata(&mut prot, origin_ptr, &footer, &mut state)?;
+
+    // The signature of a plaintext footer directly follows the `FileMetaData`.
+    if plaintext_footer && let Some(decryptor) = decryptor {
+        let len = buf.len() - prot.as_slice().len();
+        let signature = prot.as_slice().get(..SIGNATURE_LEN).unwrap_or_default();
+        decryptor.verify_plaintext_footer(&buf[..len], signature)?;
+    }
+
+    if !state.decrypted.is_empty() {
+        compact.footer_buf = Buffer::from_vec([buf, state.decrypted.as_slice()].concat());
+    }
+    Ok(compact)
+}
+
+/// Scan the top-level fields of a plaintext footer for `encryption_algorithm`
+/// (8) and `footer_signing_key_metadata` (9). These follow the row groups,
+/// whose encrypted column metadata needs them, so they are read up front.
+fn plaintext_footer_decryptor(
+    footer: &[u8],
+    properties: &FileDecryptionProperties,
+) -> ParquetResult<Option<FileDecryptor>> {
+    let mut prot = ThriftSliceInputProtocol::new(footer);
+    let mut algorithm: Option<EncryptionAlgorithm> = None;
+    let mut key_metadata: Option<&[u8]> = None;
+
+    read_struct_fields!(prot, |f| {
+        8 => algorithm = Some(read_format_struct(&mut prot, |p| {
+            EncryptionAlgorithm::read_from_in_protocol(p)
+        })?),
+        9 => key_metadata = Some(prot.read_bytes()?),
+    });
+
+    algorithm
+        .map(|algorithm| FileDecryptor::try_new(&algorithm, key_metadata, properties))
+        .transpose()
+}
+
+/// Decode the struct at the cursor with the format-crate decoder. Used for
+/// the rare encryption structs, which keep their format-crate types.
+fn read_format_struct<T>(
+    prot: &mut ThriftSliceInputProtocol<'_>,
+    read: impl FnOnce(
+        &mut TCompactInputProtocol<&mut &[u8]>,
+    ) -> polars_parquet_format::thrift::Result<T>,
+) -> ParquetResult<T> {
+    let start = prot.as_slice();
+    prot.skip(FieldType::Struct)?;
+    let mut bytes = &start[..start.len() - prot.as_slice().len()];
+    let len = bytes.len();
+    Ok(read(&mut TCompactInputProtocol::new(&mut bytes, len))?)
 }
 
 /// Decode just `FileMetaData.num_rows` (field 3) for the `RowCounts`
@@ -105,6 +219,7 @@ fn read_file_metadata(
     prot: &mut ThriftSliceInputProtocol<'_>,
     origin_ptr: *const u8,
     footer: &Buffer<u8>,
+    state: &mut DecryptionState<'_>,
 ) -> ParquetResult<CompactFileMetaData> {
     let mut version: Option<i32> = None;
     let mut schema: Option<Vec<SchemaElement>> = None;
@@ -114,12 +229,17 @@ fn read_file_metadata(
     let mut created_by: Option<String> = None;
     let mut column_orders: Option<Vec<ColumnOrder>> = None;
 
-    // 8/9 (encryption): polars has no encryption support; skip via fallthrough.
+    // 8/9 (encryption): read up front by `plaintext_footer_decryptor`; skip
+    // via fallthrough.
+    let mut row_group_ordinal = 0;
     read_struct_fields!(prot, |f| {
         1 => version = Some(prot.read_i32()?),
         2 => schema = Some(read_list(prot, read_schema_element)?),
         3 => num_rows = Some(prot.read_i64()?),
-        4 => row_groups = Some(read_list(prot, |p| read_row_group(p, origin_ptr))?),
+        4 => row_groups = Some(read_list(prot, |p| {
+            row_group_ordinal += 1;
+            read_row_group(p, origin_ptr, state, row_group_ordinal - 1)
+        })?),
         5 => key_value_metadata = Some(read_list(prot, read_key_value)?),
         6 => created_by = Some(prot.read_string()?.to_owned()),
         7 => column_orders = Some(read_list(prot, read_column_order)?),
@@ -190,6 +310,8 @@ fn read_schema_element(prot: &mut ThriftSliceInputProtocol<'_>) -> ParquetResult
 fn read_row_group(
     prot: &mut ThriftSliceInputProtocol<'_>,
     origin_ptr: *const u8,
+    state: &mut DecryptionState<'_>,
+    row_group_ordinal: usize,
 ) -> ParquetResult<CompactRowGroup> {
     let mut columns: Option<Vec<CompactColumnChunk>> = None;
     let mut total_byte_size: Option<i64> = None;
@@ -198,8 +320,12 @@ fn read_row_group(
 
     // Inlined skips at ids 5/6/7 (file_offset, total_compressed_size, ordinal):
     // no in-tree consumer; bypass the generic recursive `skip_till_depth`.
+    let mut column_ordinal = 0;
     read_struct_fields!(prot, |f| {
-        1 => columns = Some(read_list(prot, |p| read_column_chunk(p, origin_ptr))?),
+        1 => columns = Some(read_list(prot, |p| {
+            column_ordinal += 1;
+            read_column_chunk(p, origin_ptr, state, row_group_ordinal, column_ordinal - 1)
+        })?),
         2 => total_byte_size = Some(prot.read_i64()?),
         3 => num_rows = Some(prot.read_i64()?),
         4 => sorting_columns = Some(read_list(prot, read_sorting_column)?),
@@ -218,20 +344,24 @@ fn read_row_group(
 
 /// Decode a `ColumnChunk` into a [`CompactColumnChunk`].
 ///
-/// Skip-decode: `file_path`, `file_offset`, encryption fields.
+/// Skip-decode: `file_path`, `file_offset`.
 fn read_column_chunk(
     prot: &mut ThriftSliceInputProtocol<'_>,
     origin_ptr: *const u8,
+    state: &mut DecryptionState<'_>,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
 ) -> ParquetResult<CompactColumnChunk> {
     let mut meta_data: Option<CompactColumnMetaData> = None;
     let mut offset_index_offset: Option<i64> = None;
     let mut offset_index_length: Option<i32> = None;
     let mut column_index_offset: Option<i64> = None;
     let mut column_index_length: Option<i32> = None;
+    let mut crypto_metadata: Option<ColumnCryptoMetaData> = None;
+    let mut encrypted_column_metadata: Option<&[u8]> = None;
 
     // Inlined skips at ids 1/2 (file_path, file_offset): no in-tree consumer,
     // hot path runs once per column chunk × 200k chunks on wide fixtures.
-    // 8/9 (encryption_algorithm, encrypted_column_metadata): rare; fall through.
     read_struct_fields!(prot, |f| {
         1 => prot.skip_binary()?,
         2 => prot.skip_vlq()?,
@@ -240,10 +370,55 @@ fn read_column_chunk(
         5 => offset_index_length = Some(prot.read_i32()?),
         6 => column_index_offset = Some(prot.read_i64()?),
         7 => column_index_length = Some(prot.read_i32()?),
+        8 => crypto_metadata = Some(read_format_struct(prot, |p| {
+            ColumnCryptoMetaData::read_from_in_protocol(p)
+        })?),
+        9 => encrypted_column_metadata = Some(prot.read_bytes()?),
     });
 
+    let crypto = match crypto_metadata {
+        None => None,
+        Some(crypto_metadata) => {
+            let decryptor = state.decryptor.and_then(|d| {
+                d.column_decryptor(&crypto_metadata, row_group_ordinal, column_ordinal)
+            });
+
+            if let (Some(decryptor), Some(module)) = (&decryptor, encrypted_column_metadata) {
+                meta_data = Some(decrypt_column_meta_data(state, decryptor, module)?);
+            }
+
+            Some(match decryptor {
+                Some(mut decryptor) => {
+                    let meta_data = meta_data.as_ref().require("ColumnChunk.meta_data")?;
+                    decryptor.has_dictionary_page = meta_data.dictionary_page_offset.is_some();
+                    ColumnCrypto::Decryptable(Arc::new(decryptor))
+                },
+                None => ColumnCrypto::MissingKey,
+            })
+        },
+    };
+
+    // The metadata of an encrypted chunk without a key can be missing from an
+    // encrypted footer. Its pages cannot be read, so a placeholder suffices.
+    let meta_data = match (meta_data, &crypto) {
+        (None, Some(ColumnCrypto::MissingKey)) => CompactColumnMetaData {
+            codec: Compression::Uncompressed,
+            num_values: 0,
+            total_uncompressed_size: 0,
+            total_compressed_size: 0,
+            data_page_offset: 0,
+            index_page_offset: None,
+            dictionary_page_offset: None,
+            statistics: None,
+            bloom_filter_offset: None,
+            bloom_filter_length: None,
+        },
+        (meta_data, _) => meta_data.require("ColumnChunk.meta_data")?,
+    };
+
     Ok(CompactColumnChunk {
-        meta_data: meta_data.require("ColumnChunk.meta_data")?,
+        meta_data,
+        crypto,
         offset_index_offset,
         offset_index_length,
         column_index_offset,
@@ -251,6 +426,28 @@ fn read_column_chunk(
     })
 }
 
+/// Decrypt and decode the `encrypted_column_metadata` of a column chunk.
+fn decrypt_column_meta_data(
+    state: &mut DecryptionState<'_>,
+    decryptor: &ColumnDecryptor,
+    module: &[u8],
+) -> ParquetResult<CompactColumnMetaData> {
+    let plaintext = decryptor.decrypt(ModuleType::ColumnMetaData, None, module)?;
+    let mut meta_data = read_column_meta_data(
+        &mut ThriftSliceInputProtocol::new(&plaintext),
+        plaintext.as_ptr(),
+    )?;
+
+    let shift = (state.footer_len + state.decrypted.len()) as u32;
+    if let Some(statistics) = &mut meta_data.statistics {
+        statistics.max_value = statistics.max_value.map(|r| r.shifted(shift));
+        statistics.min_value = statistics.min_value.map(|r| r.shifted(shift));
+    }
+    state.decrypted.extend_from_slice(&plaintext);
+
+    Ok(meta_data)
+}
+
 /// Decode a `ColumnMetaData` into a [`CompactColumnMetaData`].
 ///
 /// Skip-decode: `type_`, `encodings`, `path_in_schema`, `key_value_metadata`,
@@ -3,6 +3,7 @@ use polars_parquet_format::Statistics as ParquetStatistics;
 use super::column_descriptor::{ColumnDescriptor, ColumnDescriptorRef};
 use super::compact::{CompactColumnChunk, CompactColumnMetaData, CompactStatistics};
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnCrypto;
 use crate::parquet::error::ParquetResult;
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -27,8 +28,9 @@ pub struct ColumnChunkMetadata {
 
 // Represents common operations for a column chunk.
 impl ColumnChunkMetadata {
-    /// The compact column metadata for this chunk. Always present;
-    /// encrypted columns are rejected at footer-decode time.
+    /// The compact column metadata for this chunk. Always present; for
+    /// encrypted chunks without a key it is the stripped plaintext metadata
+    /// or a placeholder.
     ///
     /// Crate-internal: callers outside `polars-parquet` should use the
     /// typed accessors below (`compression()`, `num_values()`, etc.)
@@ -147,6 +149,16 @@ impl ColumnChunkMetadata {
         self.column_chunk.column_index_length
     }
 
+    /// Whether this column chunk is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.column_chunk.crypto.is_some()
+    }
+
+    /// The encryption state of this column chunk, if it is encrypted.
+    pub fn crypto(&self) -> Option<&ColumnCrypto> {
+        self.column_chunk.crypto.as_ref()
+    }
+
     /// Returns the offset and length in bytes of the column chunk within the file.
     pub fn byte_range(&self) -> core::ops::Range<u64> {
         column_metadata_byte_range_compact(self.compact_metadata())
@@ -154,7 +166,8 @@ impl ColumnChunkMetadata {
 
     /// Build from a [`CompactColumnChunk`] + descriptor handle.
     /// Infallible: the decoder rejects malformed chunks (missing
-    /// `meta_data`) up front, so by here the invariant is type-enforced.
+    /// `meta_data` without encryption) up front, so by here the invariant
+    /// is type-enforced.
     pub(crate) fn from_compact(
         column_descr: ColumnDescriptorRef,
         column_chunk: CompactColumnChunk,
@@ -20,6 +20,7 @@ use polars_buffer::Buffer;
 use polars_parquet_format::{ColumnOrder, KeyValue, SchemaElement, SortingColumn};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnCrypto;
 
 /// `(offset, len)` into a shared [`Buffer<u8>`] holding the footer bytes.
 /// Used by [`CompactStatistics`] to reference `min_value` / `max_value`
@@ -43,6 +44,15 @@ impl ByteRange {
     pub(crate) fn resolve<'a>(&self, buf: &'a [u8]) -> &'a [u8] {
         &buf[self.offset as usize..(self.offset as usize + self.len as usize)]
     }
+
+    /// This range moved `by` bytes towards the end of the buffer.
+    #[inline]
+    pub(crate) fn shifted(self, by: u32) -> Self {
+        Self {
+            offset: self.offset + by,
+            len: self.len,
+        }
+    }
 }
 
 /// Compact replacement for `polars_parquet_format::Statistics`.
@@ -84,17 +94,18 @@ pub(crate) struct CompactColumnMetaData {
 
 /// Compact replacement for `polars_parquet_format::ColumnChunk`.
 ///
-/// Drops `file_path`, `file_offset`, encryption fields. None have read-path
-/// consumers in this build (the write path constructs format-crate types).
+/// Drops `file_path`, `file_offset`. Neither has read-path consumers in this
+/// build (the write path constructs format-crate types).
 ///
-/// `meta_data` is non-`Option` because polars has no encryption support: a
-/// chunk without unencrypted metadata is unrepresentable here. The decoder
-/// rejects such chunks at footer-decode time with `"ColumnChunk.meta_data
-/// missing"`, so by the time a `CompactColumnChunk` exists the field is
-/// guaranteed present.
+/// `meta_data` is non-`Option`: the decoder decrypts the
+/// `encrypted_column_metadata` of encrypted chunks whose key is available.
+/// Encrypted chunks without a key keep the stripped plaintext metadata of a
+/// plaintext footer, or a placeholder if there is none, and are marked
+/// [`ColumnCrypto::MissingKey`] so reading their pages fails.
 #[derive(Debug, Clone)]
 pub(crate) struct CompactColumnChunk {
     pub meta_data: CompactColumnMetaData,
+    pub crypto: Option<ColumnCrypto>,
     pub offset_index_offset: Option<i64>,
     pub offset_index_length: Option<i32>,
     pub column_index_offset: Option<i64>,
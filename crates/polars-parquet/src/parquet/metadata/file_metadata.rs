@@ -7,7 +7,7 @@ use super::column_order::ColumnOrder;
 use super::compact::{CompactColumnChunk, CompactFileMetaData, CompactRowGroup};
 use super::schema_descriptor::SchemaDescriptor;
 use crate::parquet::error::ParquetResult;
-use crate::parquet::metadata::get_sort_order;
+use crate::parquet::metadata::{SortingColumn, get_sort_order};
 use crate::parquet::schema::types::ParquetType;
 pub use crate::parquet::thrift_format::KeyValue;
 
@@ -133,24 +133,45 @@ impl FileMetadata {
             .row_groups
             .iter()
             .map(|rg| {
+                // Leaf index in the source schema → leaf index in the pruned schema.
+                let mut leaf_map = vec![None; rg.parquet_columns().len()];
+                let mut num_kept = 0;
                 let kept_chunks: Vec<CompactColumnChunk> = rg
                     .parquet_columns()
                     .iter()
-                    .filter_map(|c| {
+                    .enumerate()
+                    .filter_map(|(i, c)| {
                         let keep_stats = *keep.get(c.descriptor().path_in_schema[0].as_str())?;
                         let mut chunk = c.compact_column_chunk().clone();
                         if !keep_stats {
                             chunk.meta_data.statistics = None;
                         }
+                        leaf_map[i] = Some(num_kept);
+                        num_kept += 1;
                         Some(chunk)
                     })
                     .collect();
 
+                // Sorting columns refer to leaf indices. Keep the prefix that is not pruned, as
+                // the later sorting columns are meaningless without the earlier ones.
+                let sorting_columns = rg.sorting_columns().map(|sc| {
+                    sc.iter()
+                        .map_while(|c| {
+                            let column_idx =
+                                (*leaf_map.get(usize::try_from(c.column_idx).ok()?)?)?;
+                            Some(SortingColumn {
+                                column_idx,
+                                ..c.clone()
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                });
+
                 let compact_rg = CompactRowGroup {
                     columns: kept_chunks,
                     total_byte_size: rg.total_byte_size() as i64,
                     num_rows: rg.num_rows() as i64,
-                    sorting_columns: rg.sorting_columns().map(|sc| sc.to_vec()),
+                    sorting_columns: sorting_columns.filter(|sc| !sc.is_empty()),
                 };
 
                 let md = RowGroupMetadata::from_compact(&pruned_schema, compact_rg)?;
@@ -90,6 +90,18 @@ struct StatWire {
 
 impl Serialize for FileMetadata {
     fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        // The decryption keys of encrypted chunks must not end up on the wire.
+        if self
+            .row_groups
+            .iter()
+            .flat_map(|rg| rg.parquet_columns())
+            .any(|c| c.is_encrypted())
+        {
+            return Err(serde::ser::Error::custom(
+                "cannot serialize the metadata of an encrypted parquet file",
+            ));
+        }
+
         let footer = &self.footer_buf;
 
         let row_groups: Vec<RowGroupWire> = self
@@ -259,6 +271,7 @@ fn chunk_from_wire(c: ChunkWire, footer: &mut Vec<u8>) -> CompactColumnChunk {
 
     CompactColumnChunk {
         meta_data,
+        crypto: None,
         offset_index_offset: None,
         offset_index_length: None,
         column_index_offset: None,
@@ -0,0 +1,24 @@
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::ParquetResult;
+
+/// Deserializes a [`ColumnIndex`], e.g. from the range
+/// `[column_index_offset, column_index_offset + column_index_length)` of a file.
+pub fn deserialize_column_index(mut bytes: &[u8]) -> ParquetResult<ColumnIndex> {
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_allocation(bytes.len()));
+    Ok(ColumnIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes an [`OffsetIndex`], e.g. from the range
+/// `[offset_index_offset, offset_index_offset + offset_index_length)` of a file.
+pub fn deserialize_offset_index(mut bytes: &[u8]) -> ParquetResult<OffsetIndex> {
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_allocation(bytes.len()));
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Every list element takes at least one byte in the compact protocol, but is accounted for
+/// with up to 8 bytes by the protocol's allocation limit.
+fn max_allocation(num_bytes: usize) -> usize {
+    num_bytes.saturating_mul(16)
+}
@@ -4,7 +4,10 @@ use std::io::{Read, Seek, SeekFrom};
 use polars_buffer::Buffer;
 
 use super::super::metadata::FileMetadata;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use super::super::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC,
+};
+use crate::parquet::encryption::{FileDecryptionProperties, is_encrypted_footer};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::handwritten_thrift::{decode_file_metadata, decode_num_rows};
 
@@ -43,20 +46,50 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     deserialize_metadata(footer)
 }
 
+/// As [`read_metadata`], decrypting the metadata of an encrypted file with `decryption`.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let file_size = stream_len(reader)?;
+    let footer = fetch_footer_buf(reader, file_size)?;
+    deserialize_metadata_with_decryption(footer, decryption)
+}
+
 /// Parse loaded metadata bytes via the hand-written Thrift compact decoder.
 ///
 /// `footer` must be a [`Buffer<u8>`] because [`FileMetadata`] holds the buffer
 /// for the lifetime of the metadata; column-chunk statistics store
 /// `ByteRange`s into it instead of allocating per-stat byte vecs.
 pub fn deserialize_metadata(footer: Buffer<u8>) -> ParquetResult<FileMetadata> {
-    let compact = decode_file_metadata(footer)?;
+    deserialize_metadata_with_decryption(footer, None)
+}
+
+/// As [`deserialize_metadata`], decrypting the metadata of an encrypted file with `decryption`.
+///
+/// `footer` must end with the footer length and magic. Without `decryption`, files with an
+/// encrypted footer fail to decode, and the encrypted columns of files with a plaintext footer
+/// cannot be read.
+pub fn deserialize_metadata_with_decryption(
+    footer: Buffer<u8>,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let compact = decode_file_metadata(footer, decryption)?;
     FileMetadata::from_compact(compact)
 }
 
 /// Decode only `FileMetaData.num_rows` (thrift field 3) from `footer`.
 /// Used by Polars multi-file scans in `RowCounts` resolve mode. See
 /// [`crate::parquet::handwritten_thrift::decode_num_rows`].
+///
+/// Encrypted footers must be decoded with [`deserialize_metadata_with_decryption`].
 pub fn deserialize_num_rows(footer: Buffer<u8>) -> ParquetResult<i64> {
+    if is_encrypted_footer(&footer) {
+        return Err(ParquetError::InvalidParameter(
+            "the parquet file has an encrypted footer, decryption properties are required to read it"
+                .to_string(),
+        ));
+    }
     decode_num_rows(footer)
 }
 
@@ -72,7 +105,7 @@ pub(crate) fn read_num_rows_with_size<R: Read + Seek>(
     file_size: u64,
 ) -> ParquetResult<i64> {
     let footer = fetch_footer_buf(reader, file_size)?;
-    decode_num_rows(footer)
+    deserialize_num_rows(footer)
 }
 
 /// Fetch the trailing footer bytes from a [`Read`] + [`Seek`]. Returns a
@@ -97,8 +130,9 @@ fn fetch_footer_buf<R: Read + Seek>(reader: &mut R, file_size: u64) -> ParquetRe
         .read_to_end(&mut buffer)?;
 
     // Check this is indeed a parquet file.
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(ParquetError::oos("The file must end with PAR1"));
+    let magic = &buffer[default_end_len - 4..];
+    if magic != PARQUET_MAGIC && magic != PARQUET_ENCRYPTED_MAGIC {
+        return Err(ParquetError::oos("The file must end with PAR1 or PARE"));
     }
 
     let metadata_len = metadata_len(&buffer) as u64;
@@ -1,5 +1,6 @@
 mod column;
 mod compression;
+pub mod indexes;
 pub mod levels;
 mod metadata;
 mod page;
@@ -11,8 +12,8 @@ use std::io::{Cursor, Seek, SeekFrom};
 pub use column::*;
 pub use compression::{BasicDecompressor, decompress};
 pub use metadata::{
-    deserialize_metadata, deserialize_num_rows, read_metadata, read_metadata_with_size,
-    read_num_rows,
+    deserialize_metadata, deserialize_metadata_with_decryption, deserialize_num_rows,
+    read_metadata, read_metadata_with_decryption, read_metadata_with_size, read_num_rows,
 };
 pub use page::{PageIterator, PageMetaData, PageReader};
 #[cfg(feature = "async")]
@@ -7,6 +7,8 @@ use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use super::PageIterator;
 use crate::parquet::CowBuffer;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::modules::ModuleType;
+use crate::parquet::encryption::{ColumnCrypto, ColumnDecryptor, module_len};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
 use crate::parquet::page::{
@@ -26,6 +28,8 @@ pub struct PageMetaData {
     pub compression: Compression,
     /// The descriptor of this parquet column
     pub descriptor: Descriptor,
+    /// The encryption state of this column chunk, if it is encrypted
+    pub crypto: Option<ColumnCrypto>,
 }
 
 impl PageMetaData {
@@ -41,6 +45,7 @@ impl PageMetaData {
             num_values,
             compression,
             descriptor,
+            crypto: None,
         }
     }
 }
@@ -52,6 +57,7 @@ impl From<&ColumnChunkMetadata> for PageMetaData {
             num_values: column.num_values(),
             compression: column.compression(),
             descriptor: column.descriptor().descriptor.clone(),
+            crypto: column.crypto().cloned(),
         }
     }
 }
@@ -81,6 +87,15 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // The encryption state of the column chunk, if it is encrypted.
+    crypto: Option<ColumnCrypto>,
+
+    // Whether the dictionary page of an encrypted column chunk is yet to be read.
+    dictionary_pending: bool,
+
+    // The ordinal of the next data page, part of the AAD of encrypted pages.
+    page_ordinal: usize,
 }
 
 impl PageReader {
@@ -106,6 +121,10 @@ impl PageReader {
         scratch: Vec<u8>,
         max_page_size: usize,
     ) -> Self {
+        let dictionary_pending = matches!(
+            &reader_meta.crypto,
+            Some(ColumnCrypto::Decryptable(decryptor)) if decryptor.has_dictionary_page
+        );
         Self {
             reader,
             total_num_values: reader_meta.num_values,
@@ -114,6 +133,9 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            crypto: reader_meta.crypto,
+            dictionary_pending,
+            page_ordinal: 0,
         }
     }
 
@@ -134,10 +156,16 @@ impl PageReader {
             return Ok(None);
         }
 
+        // The header of an encrypted page can only be decrypted knowing its type, so the metadata
+        // tells whether there is a dictionary page.
+        if self.crypto.is_some() && !self.dictionary_pending {
+            return Ok(None);
+        }
+
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let page_header = self.read_page_header()?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
@@ -145,6 +173,66 @@ impl PageReader {
             return Ok(None);
         }
 
+        let buffer = self.read_page_data(&page_header)?;
+
+        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
+            if let CompressedPage::Dict(d) = p {
+                Some(d)
+            } else {
+                unreachable!()
+            }
+        })
+    }
+}
+
+impl PageReader {
+    fn decryptor(&self) -> ParquetResult<Option<&ColumnDecryptor>> {
+        self.crypto
+            .as_ref()
+            .map(|crypto| crypto.decryptor(&self.descriptor.primitive_type.field_info.name))
+            .transpose()
+    }
+
+    /// The module type and page ordinal of the next page of an encrypted column chunk.
+    fn next_module(&self) -> (ModuleType, ModuleType, Option<usize>) {
+        if self.dictionary_pending {
+            (
+                ModuleType::DictionaryPageHeader,
+                ModuleType::DictionaryPage,
+                None,
+            )
+        } else {
+            (
+                ModuleType::DataPageHeader,
+                ModuleType::DataPage,
+                Some(self.page_ordinal),
+            )
+        }
+    }
+
+    /// Reads the header of the next page, decrypting it if the column chunk is encrypted.
+    fn read_page_header(&mut self) -> ParquetResult<ParquetPageHeader> {
+        let Some(decryptor) = self.decryptor()? else {
+            return read_page_header(&mut self.reader, self.max_page_size);
+        };
+
+        let (header_module, _, page_ordinal) = self.next_module();
+        let buf = self.reader.get_ref();
+        let pos = (self.reader.position() as usize).min(buf.len());
+        let len = module_len(&buf[pos..])?;
+        let module = buf
+            .get(pos..pos + len)
+            .ok_or_else(|| ParquetError::oos("The encrypted page header is truncated"))?;
+        let header = decryptor.decrypt(header_module, page_ordinal, module)?;
+        self.reader.set_position((pos + len) as u64);
+
+        let mut prot = TCompactInputProtocol::new(header.as_slice(), self.max_page_size);
+        Ok(ParquetPageHeader::read_from_in_protocol(&mut prot)?)
+    }
+
+    /// Reads the data of the page of `page_header`, decrypting it if the column chunk is
+    /// encrypted.
+    fn read_page_data(&mut self, page_header: &ParquetPageHeader) -> ParquetResult<Buffer<u8>> {
         let read_size: usize = page_header.compressed_page_size.try_into()?;
 
         if read_size > self.max_page_size {
@@ -164,13 +252,18 @@ impl PageReader {
             ));
         }
 
-        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
-            if let CompressedPage::Dict(d) = p {
-                Some(d)
-            } else {
-                unreachable!()
-            }
-        })
+        let Some(decryptor) = self.decryptor()? else {
+            return Ok(buffer);
+        };
+
+        let (_, data_module, page_ordinal) = self.next_module();
+        let data = decryptor.decrypt(data_module, page_ordinal, &buffer)?;
+        if self.dictionary_pending {
+            self.dictionary_pending = false;
+        } else {
+            self.page_ordinal += 1;
+        }
+        Ok(Buffer::from_vec(data))
     }
 }
 
@@ -214,28 +307,11 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.read_page_header()?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
 
-    let read_size: usize = page_header.compressed_page_size.try_into()?;
-
-    if read_size > reader.max_page_size {
-        return Err(ParquetError::WouldOverAllocate);
-    }
-
-    // Read read_size into new buffer and advance reader.
-    let orig_buf = reader.reader.get_ref();
-    let pos = reader.reader.position() as usize;
-    let new_pos = (pos + read_size).min(orig_buf.len());
-    let buffer = orig_buf.clone().sliced(pos..new_pos);
-    reader.reader.set_position(new_pos as u64);
-
-    if buffer.len() != read_size {
-        return Err(ParquetError::oos(
-            "The page header reported the wrong page size",
-        ));
-    }
+    let buffer = reader.read_page_data(&page_header)?;
 
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
@@ -16,15 +16,18 @@ use super::statistics::reduce;
 use crate::parquet::FallibleStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::ColumnEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
 
+/// Writes the pages of a column chunk, encrypting them with `encryptor` if given.
 pub fn write_column_chunk<W, E>(
     writer: &mut W,
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    encryptor: Option<&ColumnEncryptor>,
 ) -> ParquetResult<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -36,8 +39,12 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    let mut page_ordinal = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let spec = write_page(writer, offset, compressed_page, encryptor, page_ordinal)?;
+        if matches!(compressed_page, CompressedPage::Data(_)) {
+            page_ordinal += 1;
+        }
         offset += spec.bytes_written;
         specs.push(spec);
     }
@@ -45,13 +52,16 @@ where
 
     let column_chunk = build_column_chunk(&specs, descriptor)?;
 
-    // write metadata
-    let mut protocol = TCompactOutputProtocol::new(writer);
-    bytes_written += column_chunk
-        .meta_data
-        .as_ref()
-        .unwrap()
-        .write_to_out_protocol(&mut protocol)? as u64;
+    // write metadata. The metadata of encrypted columns is only stored in the footer, where it is
+    // encrypted as a whole or on its own.
+    if encryptor.is_none() {
+        let mut protocol = TCompactOutputProtocol::new(writer);
+        bytes_written += column_chunk
+            .meta_data
+            .as_ref()
+            .unwrap()
+            .write_to_out_protocol(&mut protocol)? as u64;
+    }
 
     Ok((column_chunk, specs, bytes_written))
 }
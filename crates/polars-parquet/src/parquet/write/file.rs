@@ -1,23 +1,63 @@
 use std::io::Write;
 
-use polars_parquet_format::RowGroup;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{ColumnMetaData, RowGroup};
 
+use super::bloom_filter::write_bloom_filter;
 use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+use crate::parquet::encryption::modules::ModuleType;
+use crate::parquet::encryption::{FileEncryptionProperties, FileEncryptor};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
-use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
+use crate::parquet::metadata::{SchemaDescriptor, SortingColumn, ThriftFileMetadata};
 use crate::parquet::write::State;
-use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
+use crate::parquet::{FOOTER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC};
 
 pub(super) fn start_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
     writer.write_all(&PARQUET_MAGIC)?;
     Ok(PARQUET_MAGIC.len() as u64)
 }
 
+/// Writes the footer of an encrypted file: either the `FileCryptoMetaData` followed by the
+/// encrypted `FileMetaData`, or the plaintext `FileMetaData` followed by its signature.
+fn end_encrypted_file<W: Write>(
+    writer: &mut W,
+    metadata: &ThriftFileMetadata,
+    encryptor: &FileEncryptor,
+) -> ParquetResult<u64> {
+    let mut metadata_bytes = vec![];
+    metadata.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut metadata_bytes))?;
+
+    let (footer, magic) = if encryptor.is_footer_encrypted() {
+        let mut footer = vec![];
+        encryptor
+            .file_crypto_metadata()
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut footer))?;
+        footer.extend_from_slice(&encryptor.encrypt_footer(&metadata_bytes)?);
+        (footer, PARQUET_ENCRYPTED_MAGIC)
+    } else {
+        let signature = encryptor.sign_footer(&metadata_bytes)?;
+        metadata_bytes.extend_from_slice(&signature);
+        (metadata_bytes, PARQUET_MAGIC)
+    };
+
+    let footer_len: i32 = footer.len().try_into().map_err(|_| {
+        ParquetError::oos(format!(
+            "The footer can contain at most i32::MAX bytes. This one contains {}",
+            footer.len()
+        ))
+    })?;
+
+    writer.write_all(&footer)?;
+    writer.write_all(&footer_len.to_le_bytes())?;
+    writer.write_all(&magic)?;
+    writer.flush()?;
+    Ok(footer.len() as u64 + FOOTER_SIZE)
+}
+
 pub(super) fn end_file<W: Write>(
     mut writer: &mut W,
     metadata: &ThriftFileMetadata,
@@ -66,6 +106,12 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// The bloom filter bitsets of every column chunk, written when the file ends.
+    bloom_filters: Vec<Vec<Option<Vec<u8>>>>,
+    /// The sorting columns of the row groups that are written next.
+    sorting_columns: Option<Vec<SortingColumn>>,
+    /// Encrypts the pages and footer of the file, if it is encrypted.
+    encryptor: Option<FileEncryptor>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
@@ -125,6 +171,9 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            bloom_filters: vec![],
+            sorting_columns: None,
+            encryptor: None,
             state: State::Initialised,
             metadata: None,
         }
@@ -138,7 +187,12 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> ParquetResult<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = if self.is_footer_encrypted() {
+                self.writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+                PARQUET_ENCRYPTED_MAGIC.len() as u64
+            } else {
+                start_file(&mut self.writer)?
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -148,6 +202,121 @@ impl<W: Write> FileWriter<W> {
         }
     }
 
+    /// Sets the columns that the row groups written after this call are sorted by.
+    ///
+    /// # Errors
+    /// Returns an error if a sorting column does not refer to a leaf column of the schema.
+    pub fn set_sorting_columns(
+        &mut self,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> ParquetResult<()> {
+        let num_columns = self.schema.columns().len();
+
+        if let Some(sorting_column) = sorting_columns.iter().flatten().find(|sorting_column| {
+            !usize::try_from(sorting_column.column_idx).is_ok_and(|idx| idx < num_columns)
+        }) {
+            return Err(ParquetError::InvalidParameter(format!(
+                "sorting column index {} is out of bounds for {} columns",
+                sorting_column.column_idx, num_columns
+            )));
+        }
+
+        self.sorting_columns = sorting_columns;
+        Ok(())
+    }
+
+    /// Encrypts the file with `encryption`, or writes it unencrypted if `None`.
+    ///
+    /// # Errors
+    /// Returns an error if data has been written to the file or if the encryption properties
+    /// are invalid for the schema of the file.
+    pub fn set_encryption(
+        &mut self,
+        encryption: Option<FileEncryptionProperties>,
+    ) -> ParquetResult<()> {
+        if self.offset != 0 {
+            return Err(ParquetError::InvalidParameter(
+                "The encryption must be set before data is written".to_string(),
+            ));
+        }
+
+        self.encryptor = encryption
+            .map(|properties| FileEncryptor::try_new(properties, &self.schema))
+            .transpose()?;
+        Ok(())
+    }
+
+    fn is_footer_encrypted(&self) -> bool {
+        self.encryptor
+            .as_ref()
+            .is_some_and(|encryptor| encryptor.is_footer_encrypted())
+    }
+
+    /// Whether each leaf column of the schema is encrypted.
+    fn encrypted_columns(&self) -> Vec<bool> {
+        self.schema
+            .columns()
+            .iter()
+            .map(|column| {
+                self.encryptor
+                    .as_ref()
+                    .is_some_and(|encryptor| encryptor.is_column_encrypted(&column.path_in_schema))
+            })
+            .collect()
+    }
+
+    /// Sets the crypto metadata of the encrypted column chunks and moves their metadata into
+    /// `encrypted_column_metadata` where it cannot be stored in the footer as is.
+    fn encrypt_column_metadata(&mut self) -> ParquetResult<()> {
+        let Some(encryptor) = &self.encryptor else {
+            return Ok(());
+        };
+
+        for (row_group_ordinal, group) in self.row_groups.iter_mut().enumerate() {
+            for (column_ordinal, (column, descriptor)) in group
+                .columns
+                .iter_mut()
+                .zip(self.schema.columns())
+                .enumerate()
+            {
+                let path = &descriptor.path_in_schema;
+                let Some(column_encryptor) =
+                    encryptor.column_encryptor(path, row_group_ordinal, column_ordinal)
+                else {
+                    continue;
+                };
+
+                column.crypto_metadata = Some(encryptor.column_crypto_metadata(path));
+                if encryptor.is_footer_encrypted() && encryptor.uses_footer_key(path) {
+                    continue;
+                }
+
+                let meta_data = column.meta_data.take().unwrap();
+                let mut meta_data_bytes = vec![];
+                meta_data.write_to_out_protocol(&mut TCompactOutputProtocol::new(
+                    &mut meta_data_bytes,
+                ))?;
+                column.encrypted_column_metadata = Some(column_encryptor.encrypt(
+                    ModuleType::ColumnMetaData,
+                    None,
+                    &meta_data_bytes,
+                )?);
+
+                // SPEC: a plaintext footer keeps the column metadata for legacy readers, without
+                // the statistics that could leak the encrypted data.
+                if !encryptor.is_footer_encrypted() {
+                    column.meta_data = Some(ColumnMetaData {
+                        statistics: None,
+                        encoding_stats: None,
+                        size_statistics: None,
+                        ..meta_data
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Writes a row group to the file.
     ///
     /// This call is IO-bounded
@@ -160,21 +329,52 @@ impl<W: Write> FileWriter<W> {
         ParquetError: From<E>,
         E: std::error::Error,
     {
+        self.write_with_bloom_filters(num_rows, row_group, vec![])
+    }
+
+    /// Writes a row group to the file, together with the split-block bloom filter bitsets of
+    /// its column chunks.
+    ///
+    /// `bloom_filters` is either empty or contains an entry per leaf column. The bitsets are
+    /// written after the last row group, when [`Self::end`] is called.
+    ///
+    /// This call is IO-bounded
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        num_rows: u64,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        if !bloom_filters.is_empty() && bloom_filters.len() != self.schema.columns().len() {
+            return Err(ParquetError::InvalidParameter(format!(
+                "expected {} bloom filters, got {}",
+                self.schema.columns().len(),
+                bloom_filters.len()
+            )));
+        }
+
         if self.offset == 0 {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             num_rows,
             self.offset,
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
+        group.sorting_columns = self.sorting_columns.clone();
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        self.bloom_filters.push(bloom_filters);
         Ok(())
     }
 
@@ -193,44 +393,90 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
-        if self.options.write_statistics {
+        // the bloom filters and page indexes of encrypted columns are not written
+        let encrypted_columns = self.encrypted_columns();
+
+        // write bloom filters
+        let bloom_filters = std::mem::take(&mut self.bloom_filters);
+        self.row_groups
+            .iter_mut()
+            .zip(bloom_filters)
+            .try_for_each(|(group, bloom_filters)| {
+                group
+                    .columns
+                    .iter_mut()
+                    .zip(bloom_filters)
+                    .zip(&encrypted_columns)
+                    .try_for_each(|((column, bitset), is_encrypted)| {
+                        let Some(bitset) = bitset.filter(|_| !is_encrypted) else {
+                            return ParquetResult::Ok(());
+                        };
+                        let offset = self.offset;
+                        self.offset += write_bloom_filter(&mut self.writer, &bitset)?;
+                        let metadata = column.meta_data.as_mut().unwrap();
+                        metadata.bloom_filter_offset = Some(offset as i64);
+                        metadata.bloom_filter_length = Some((self.offset - offset).try_into()?);
+                        ParquetResult::Ok(())
+                    })?;
+                ParquetResult::Ok(())
+            })?;
+
+        if self.options.write_page_index && self.options.write_statistics {
             // write column indexes (require page statistics)
             self.row_groups
                 .iter_mut()
                 .zip(self.page_specs.iter())
                 .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
+                    group
+                        .columns
+                        .iter_mut()
+                        .zip(pages.iter())
+                        .zip(&encrypted_columns)
+                        .filter(|(_, is_encrypted)| !**is_encrypted)
+                        .try_for_each(|((column, pages), _)| {
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
                             self.offset += write_column_index(&mut self.writer, pages)?;
                             let length = self.offset - offset;
                             column.column_index_length = Some(length as i32);
                             ParquetResult::Ok(())
-                        },
-                    )?;
+                        })?;
                     ParquetResult::Ok(())
                 })?;
         };
 
-        // write offset index
-        self.row_groups
-            .iter_mut()
-            .zip(self.page_specs.iter())
-            .try_for_each(|(group, pages)| {
-                group
-                    .columns
-                    .iter_mut()
-                    .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
-                        let offset = self.offset;
-                        column.offset_index_offset = Some(offset as i64);
-                        self.offset += write_offset_index(&mut self.writer, pages)?;
-                        column.offset_index_length = Some((self.offset - offset) as i32);
-                        ParquetResult::Ok(())
-                    })?;
-                ParquetResult::Ok(())
-            })?;
+        if self.options.write_page_index {
+            // write offset index
+            self.row_groups
+                .iter_mut()
+                .zip(self.page_specs.iter())
+                .try_for_each(|(group, pages)| {
+                    group
+                        .columns
+                        .iter_mut()
+                        .zip(pages.iter())
+                        .zip(&encrypted_columns)
+                        .filter(|(_, is_encrypted)| !**is_encrypted)
+                        .try_for_each(|((column, pages), _)| {
+                            let offset = self.offset;
+                            column.offset_index_offset = Some(offset as i64);
+                            self.offset += write_offset_index(&mut self.writer, pages)?;
+                            column.offset_index_length = Some((self.offset - offset) as i32);
+                            ParquetResult::Ok(())
+                        })?;
+                    ParquetResult::Ok(())
+                })?;
+        }
+
+        self.encrypt_column_metadata()?;
+
+        // a plaintext footer stores the encryption algorithm itself
+        let (encryption_algorithm, footer_signing_key_metadata) = match &self.encryptor {
+            Some(encryptor) if !encryptor.is_footer_encrypted() => {
+                (Some(encryptor.algorithm()), encryptor.footer_key_metadata())
+            },
+            _ => (None, None),
+        };
 
         let metadata = ThriftFileMetadata::new(
             self.options.version.into(),
@@ -240,11 +486,14 @@ impl<W: Write> FileWriter<W> {
             key_value_metadata,
             self.created_by.clone(),
             Some(create_column_orders(&self.schema)),
-            None,
-            None,
+            encryption_algorithm,
+            footer_signing_key_metadata,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => end_encrypted_file(&mut self.writer, &metadata, encryptor)?,
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
@@ -1,3 +1,4 @@
+mod bloom_filter;
 mod column_chunk;
 mod compression;
 mod file;
@@ -30,6 +31,9 @@ pub type RowGroupIter<'a, E> = DynIter<'a, RowGroupIterColumns<'a, E>>;
 pub struct WriteOptions {
     /// Whether to write statistics, including indexes
     pub write_statistics: bool,
+    /// Whether to write the page index (the `ColumnIndex` and `OffsetIndex` of every column
+    /// chunk). The `ColumnIndex` is only written if statistics are written.
+    pub write_page_index: bool,
     /// Which Parquet version to use
     pub version: Version,
 }
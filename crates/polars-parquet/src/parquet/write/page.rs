@@ -8,6 +8,8 @@ use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
 use polars_parquet_format::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnEncryptor;
+use crate::parquet::encryption::modules::ModuleType;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -52,34 +54,66 @@ pub struct PageWriteSpec {
     pub statistics: Option<Statistics>,
 }
 
+/// Writes `compressed_page` into `writer`. If `encryptor` is given, the page header and data are
+/// written as encrypted modules, with `page_ordinal` the ordinal of a data page in its column
+/// chunk.
 pub fn write_page<W: Write>(
     writer: &mut W,
     offset: u64,
     compressed_page: &CompressedPage,
+    encryptor: Option<&ColumnEncryptor>,
+    page_ordinal: usize,
 ) -> ParquetResult<PageWriteSpec> {
     let num_values = compressed_page.num_values();
     let num_rows = compressed_page
         .num_rows()
         .expect("We should have num_rows when we are writing");
 
-    let header = match &compressed_page {
+    let mut header = match &compressed_page {
         CompressedPage::Data(compressed_page) => assemble_data_page_header(compressed_page),
         CompressedPage::Dict(compressed_page) => assemble_dict_page_header(compressed_page),
     }?;
 
-    let header_size = write_page_header(writer, &header)?;
-    let mut bytes_written = header_size;
+    let buffer: &[u8] = match &compressed_page {
+        CompressedPage::Data(compressed_page) => &compressed_page.buffer,
+        CompressedPage::Dict(compressed_page) => &compressed_page.buffer,
+    };
 
-    bytes_written += match &compressed_page {
-        CompressedPage::Data(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
+    let (header_size, data_size) = match encryptor {
+        None => {
+            let header_size = write_page_header(writer, &header)?;
+            writer.write_all(buffer)?;
+            (header_size, buffer.len() as u64)
         },
-        CompressedPage::Dict(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
+        Some(encryptor) => {
+            let (header_module, data_module, page_ordinal) = match &compressed_page {
+                CompressedPage::Data(_) => (
+                    ModuleType::DataPageHeader,
+                    ModuleType::DataPage,
+                    Some(page_ordinal),
+                ),
+                CompressedPage::Dict(_) => (
+                    ModuleType::DictionaryPageHeader,
+                    ModuleType::DictionaryPage,
+                    None,
+                ),
+            };
+
+            // SPEC: the compressed page size of an encrypted page is the length of its
+            // encrypted module.
+            let data = encryptor.encrypt(data_module, page_ordinal, buffer)?;
+            (_, header.compressed_page_size) = maybe_bytes(0, data.len())?;
+
+            let mut header_bytes = vec![];
+            write_page_header(&mut header_bytes, &header)?;
+            let header_bytes = encryptor.encrypt(header_module, page_ordinal, &header_bytes)?;
+
+            writer.write_all(&header_bytes)?;
+            writer.write_all(&data)?;
+            (header_bytes.len() as u64, data.len() as u64)
         },
     };
+    let bytes_written = header_size + data_size;
 
     let statistics = match &compressed_page {
         CompressedPage::Data(compressed_page) => compressed_page.statistics().transpose()?,
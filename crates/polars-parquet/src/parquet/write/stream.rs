@@ -146,7 +146,7 @@ impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
-        if self.options.write_statistics {
+        if self.options.write_page_index && self.options.write_statistics {
             // write column indexes (require page statistics)
             for (group, pages) in self.row_groups.iter_mut().zip(self.page_specs.iter()) {
                 for (column, pages) in group.columns.iter_mut().zip(pages.iter()) {
@@ -159,13 +159,15 @@ impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
             }
         };
 
-        // write offset index
-        for (group, pages) in self.row_groups.iter_mut().zip(self.page_specs.iter()) {
-            for (column, pages) in group.columns.iter_mut().zip(pages.iter()) {
-                let offset = self.offset;
-                column.offset_index_offset = Some(offset as i64);
-                self.offset += write_offset_index_async(&mut self.writer, pages).await?;
-                column.offset_index_length = Some((self.offset - offset) as i32);
+        if self.options.write_page_index {
+            // write offset index
+            for (group, pages) in self.row_groups.iter_mut().zip(self.page_specs.iter()) {
+                for (column, pages) in group.columns.iter_mut().zip(pages.iter()) {
+                    let offset = self.offset;
+                    column.offset_index_offset = Some(offset as i64);
+                    self.offset += write_offset_index_async(&mut self.writer, pages).await?;
+                    column.offset_index_length = Some((self.offset - offset) as i32);
+                }
             }
         }
 
@@ -98,6 +98,20 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "avro")]
+    pub fn scan_avro(
+        sources: ScanSources,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Avro),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     pub fn expand_paths(
         sources: ScanSources,
         unified_scan_args: UnifiedScanArgs,
@@ -130,6 +144,19 @@ impl DslBuilder {
         .into())
     }
 
+    pub fn scan_dataset(
+        provider: Arc<dyn DatasetProvider>,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> DslBuilder {
+        DslPlan::Scan {
+            sources: ScanSources::default(),
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Dataset { provider }),
+            cached_ir: Default::default(),
+        }
+        .into()
+    }
+
     #[cfg(feature = "python")]
     pub fn scan_python_dataset(
         dataset_object: polars_utils::python_function::PythonObject,
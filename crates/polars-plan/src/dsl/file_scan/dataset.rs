@@ -0,0 +1,35 @@
+use std::fmt::{Debug, Formatter};
+
+use polars_core::error::PolarsResult;
+use polars_core::schema::SchemaRef;
+use polars_utils::arena::Arena;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::dsl::DslPlan;
+use crate::plans::{AExpr, ExprIR};
+
+/// A dataset whose files are only resolved after the query is optimized, so that they can be
+/// pruned with the predicate that is pushed down to its scan.
+pub trait DatasetProvider: Send + Sync {
+    fn name(&self) -> PlSmallStr;
+
+    /// The schema of the dataset. This should not need to resolve the files of the dataset.
+    fn schema(&self) -> PolarsResult<SchemaRef>;
+
+    /// Resolves the dataset to a `DslPlan::Scan` of its files.
+    ///
+    /// Files that cannot match `predicate` may be left out, but the predicate is still applied
+    /// to the scan of the remaining files. The predicate only refers to columns of the schema
+    /// of the dataset.
+    fn to_dataset_scan(
+        &self,
+        predicate: Option<&ExprIR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<DslPlan>;
+}
+
+impl Debug for dyn DatasetProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "dataset[{}]", self.name())
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use polars_core::prelude::{Column, PlIndexMap};
+use polars_core::prelude::{Column, DataFrame, PlIndexMap};
 
 /// Default field values when they are missing from the data file.
 #[derive(Debug, Clone, Eq, Hash, PartialEq, strum_macros::IntoStaticStr)]
@@ -12,6 +12,19 @@ pub enum DefaultFieldValues {
     ///
     /// Note: This is not the Iceberg V3 `initial-default`.
     Iceberg(Arc<IcebergIdentityTransformedPartitionFields>),
+    /// Partition values of each file as recorded in the Delta transaction log, which are
+    /// authoritative over the values in the file paths. These become the hive partitions of the
+    /// scan.
+    Delta(Arc<DeltaPartitionValues>),
+}
+
+impl DefaultFieldValues {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Iceberg(v) => v.is_empty(),
+            Self::Delta(v) => v.0.width() == 0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -42,3 +55,20 @@ impl std::ops::DerefMut for IcebergIdentityTransformedPartitionFields {
         &mut self.0
     }
 }
+
+/// Partition values of a Delta table, with a row for each file and a column for each partition
+/// column.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct DeltaPartitionValues(pub DataFrame);
+
+impl Eq for DeltaPartitionValues {}
+
+impl std::hash::Hash for DeltaPartitionValues {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for name in self.0.get_column_names() {
+            name.hash(state);
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
 use polars_core::prelude::PlIndexMap;
+#[cfg(feature = "delta")]
+use polars_io::delta::DeletionVector;
 
 #[cfg(feature = "python")]
 pub use super::python_delta_dv_provider::{
@@ -28,6 +30,9 @@ pub enum DeletionFilesList {
     /// Delta deletion vector
     #[cfg(feature = "python")]
     Delta(DeltaDeletionVectorProvider),
+    /// Delta deletion vectors resolved from the transaction log
+    #[cfg(feature = "delta")]
+    DeltaDeletionVectors(Arc<PlIndexMap<usize, DeletionVector>>),
 }
 
 impl DeletionFilesList {
@@ -41,6 +46,10 @@ impl DeletionFilesList {
             },
             #[cfg(feature = "python")]
             Some(Delta(provider)) => Some(Delta(provider)),
+            #[cfg(feature = "delta")]
+            Some(DeltaDeletionVectors(dvs)) => {
+                (!dvs.is_empty()).then_some(DeltaDeletionVectors(dvs))
+            },
             None => None,
         }
     }
@@ -53,6 +62,8 @@ impl DeletionFilesList {
             IcebergPositionDelete(paths) => Some(paths.len()),
             #[cfg(feature = "python")]
             Delta(_) => None,
+            #[cfg(feature = "delta")]
+            DeltaDeletionVectors(dvs) => Some(dvs.len()),
         }
     }
 }
@@ -73,6 +84,8 @@ impl std::hash::Hash for DeletionFilesList {
             },
             #[cfg(feature = "python")]
             Delta(provider) => provider.hash(state),
+            #[cfg(feature = "delta")]
+            DeltaDeletionVectors(dvs) => (Arc::as_ptr(dvs) as *const () as usize).hash(state),
         }
     }
 }
@@ -90,6 +103,11 @@ impl std::fmt::Display for DeletionFilesList {
             Delta(_) => {
                 write!(f, "delta-deletion-vector-python-callback")?;
             },
+            #[cfg(feature = "delta")]
+            DeltaDeletionVectors(dvs) => {
+                let s = if dvs.len() == 1 { "" } else { "s" };
+                write!(f, "delta-deletion-vector: {} source{s}", dvs.len())?;
+            },
         }
 
         Ok(())
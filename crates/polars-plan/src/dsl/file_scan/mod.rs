@@ -21,6 +21,8 @@ use strum_macros::IntoStaticStr;
 
 use super::*;
 use crate::dsl::default_values::DefaultFieldValues;
+mod dataset;
+pub use dataset::DatasetProvider;
 pub mod default_values;
 pub mod deletion;
 #[cfg(feature = "python")]
@@ -78,10 +80,18 @@ pub enum FileScanDsl {
         name: PlSmallStr,
     },
 
+    #[cfg(feature = "avro")]
+    Avro,
+
     ExpandedPaths {
         name: PlSmallStr,
     },
 
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    Dataset {
+        provider: Arc<dyn DatasetProvider>,
+    },
+
     #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
     Anonymous {
         options: Arc<AnonymousScanOptions>,
@@ -142,10 +152,19 @@ pub enum FileScanIR {
         name: PlSmallStr,
     },
 
+    #[cfg(feature = "avro")]
+    Avro,
+
     ExpandedPaths {
         name: PlSmallStr,
     },
 
+    /// Replaced by the scan that the provider resolves to in `expand_datasets`.
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    Dataset {
+        provider: Arc<dyn DatasetProvider>,
+    },
+
     #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
     Anonymous {
         options: Arc<AnonymousScanOptions>,
@@ -235,7 +254,10 @@ impl FileScanIR {
             },
             #[cfg(feature = "scan_lines")]
             Self::Lines { name: _ } => {},
+            #[cfg(feature = "avro")]
+            Self::Avro => {},
             Self::ExpandedPaths { name: _ } => {},
+            Self::Dataset { provider: _ } => {},
             Self::Anonymous {
                 options: _,
                 function: _,
@@ -502,10 +524,17 @@ mod _file_scan_eq_hash {
             name: &'a PlSmallStr,
         },
 
+        #[cfg(feature = "avro")]
+        Avro,
+
         ExpandedPaths {
             name: &'a PlSmallStr,
         },
 
+        Dataset {
+            provider: usize,
+        },
+
         Anonymous {
             options: &'a crate::dsl::AnonymousScanOptions,
             function: usize,
@@ -554,8 +583,15 @@ mod _file_scan_eq_hash {
                 #[cfg(feature = "scan_lines")]
                 FileScanIR::Lines { name } => FileScanEqHashWrap::Lines { name },
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro => FileScanEqHashWrap::Avro,
+
                 FileScanIR::ExpandedPaths { name } => FileScanEqHashWrap::ExpandedPaths { name },
 
+                FileScanIR::Dataset { provider } => FileScanEqHashWrap::Dataset {
+                    provider: arc_as_ptr(provider),
+                },
+
                 FileScanIR::Anonymous { options, function } => FileScanEqHashWrap::Anonymous {
                     options,
                     function: arc_as_ptr(function),
@@ -227,6 +227,18 @@ pub enum FunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "approx_unique")]
+    HllSketch,
+    #[cfg(feature = "approx_unique")]
+    HllMerge,
+    #[cfg(feature = "approx_unique")]
+    HllEstimate,
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile {
+        quantile: f64,
+    },
+    #[cfg(feature = "approx_quantile")]
+    QuantileSketch,
     Coalesce,
     #[cfg(feature = "diff")]
     Diff(NullBehavior),
@@ -568,7 +580,11 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "unique_counts")]
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => {},
+            ApproxNUnique | HllSketch | HllMerge | HllEstimate => {},
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { quantile } => quantile.to_bits().hash(state),
+            #[cfg(feature = "approx_quantile")]
+            QuantileSketch => {},
             Coalesce => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
@@ -802,6 +818,16 @@ impl Display for FunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "approx_unique")]
+            HllSketch => "hll_sketch",
+            #[cfg(feature = "approx_unique")]
+            HllMerge => "hll_merge",
+            #[cfg(feature = "approx_unique")]
+            HllEstimate => "hll_estimate",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => "approx_quantile",
+            #[cfg(feature = "approx_quantile")]
+            QuantileSketch => "quantile_sketch",
             Coalesce => "coalesce",
             #[cfg(feature = "diff")]
             Diff(_) => "diff",
@@ -981,6 +981,52 @@ impl Expr {
         self.map_unary(FunctionExpr::ApproxNUnique)
     }
 
+    /// Aggregate into a serialized HyperLogLog sketch of the unique values, ignoring
+    /// nulls.
+    ///
+    /// Sketches can be combined with [`Expr::hll_merge`] and counted with
+    /// [`Expr::hll_estimate`]; they can only be combined if created from the same dtype.
+    #[cfg(feature = "approx_unique")]
+    pub fn hll_sketch(self) -> Self {
+        self.map_unary(FunctionExpr::HllSketch)
+    }
+
+    /// Merge a binary column of HyperLogLog sketches into a single sketch.
+    #[cfg(feature = "approx_unique")]
+    pub fn hll_merge(self) -> Self {
+        self.map_unary(FunctionExpr::HllMerge)
+    }
+
+    /// Estimate the number of unique values of each HyperLogLog sketch.
+    #[cfg(feature = "approx_unique")]
+    pub fn hll_estimate(self) -> Self {
+        self.map_unary(FunctionExpr::HllEstimate)
+    }
+
+    /// Get the approximate quantile using a mergeable sketch.
+    ///
+    /// The input is either numeric, or a binary column of sketches created with
+    /// [`Expr::quantile_sketch`], which are merged.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: f64) -> Self {
+        self.map_unary(FunctionExpr::ApproxQuantile { quantile })
+    }
+
+    /// Get the approximate median using a mergeable sketch.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_median(self) -> Self {
+        self.approx_quantile(0.5)
+    }
+
+    /// Aggregate into a serialized quantile sketch.
+    ///
+    /// The input is either numeric, or a binary column of sketches which are merged, so
+    /// that sketches can be rolled up into coarser groups.
+    #[cfg(feature = "approx_quantile")]
+    pub fn quantile_sketch(self) -> Self {
+        self.map_unary(FunctionExpr::QuantileSketch)
+    }
+
     /// Bitwise "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -9,6 +9,8 @@ pub mod sink;
 pub use polars_config::Engine;
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -326,6 +328,8 @@ pub enum FileWriteFormat {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     NDJson(NDJsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
 }
 
 impl FileWriteFormat {
@@ -339,6 +343,8 @@ impl FileWriteFormat {
             Self::Csv(_) => "csv",
             #[cfg(feature = "json")]
             Self::NDJson(_) => "jsonl",
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -8,7 +8,7 @@ use polars_error::polars_err;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "cloud")]
 use polars_io::file_cache::FileCacheEntry;
-use polars_io::metrics::IOMetrics;
+use polars_io::metrics::{IOMetrics, OptIOMetrics};
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder};
 use polars_io::{
     decode_file_uri_paths, expand_paths, expand_paths_hive, expanded_from_single_directory,
@@ -399,7 +399,7 @@ impl ScanSourceRef<'_> {
 
     /// Turn the scan source into a memory slice
     pub fn to_memslice(&self) -> PolarsResult<Buffer<u8>> {
-        self.to_buffer_possibly_async(false, None, 0)
+        self.to_buffer_possibly_async(false, None, 0, None)
     }
 
     #[allow(clippy::wrong_self_convention)]
@@ -431,13 +431,23 @@ impl ScanSourceRef<'_> {
     }
 
     #[cfg(feature = "cloud")]
-    pub fn to_buffer_async_assume_latest(&self, run_async: bool) -> PolarsResult<Buffer<u8>> {
-        self.to_buffer_async(|entry| entry.try_open_assume_latest(), run_async)
+    pub fn to_buffer_async_assume_latest(
+        &self,
+        run_async: bool,
+        io_metrics: Option<Arc<IOMetrics>>,
+    ) -> PolarsResult<Buffer<u8>> {
+        let io_metrics = OptIOMetrics(io_metrics);
+        self.to_buffer_async(|entry| entry.try_open_assume_latest(&io_metrics), run_async)
     }
 
     #[cfg(feature = "cloud")]
-    pub fn to_buffer_async_check_latest(&self, run_async: bool) -> PolarsResult<Buffer<u8>> {
-        self.to_buffer_async(|entry| entry.try_open_check_latest(), run_async)
+    pub fn to_buffer_async_check_latest(
+        &self,
+        run_async: bool,
+        io_metrics: Option<Arc<IOMetrics>>,
+    ) -> PolarsResult<Buffer<u8>> {
+        let io_metrics = OptIOMetrics(io_metrics);
+        self.to_buffer_async(|entry| entry.try_open_check_latest(&io_metrics), run_async)
     }
 
     #[cfg(not(feature = "cloud"))]
@@ -456,12 +466,20 @@ impl ScanSourceRef<'_> {
     }
 
     #[cfg(not(feature = "cloud"))]
-    pub fn to_buffer_async_assume_latest(&self, run_async: bool) -> PolarsResult<Buffer<u8>> {
+    pub fn to_buffer_async_assume_latest(
+        &self,
+        run_async: bool,
+        _io_metrics: Option<Arc<IOMetrics>>,
+    ) -> PolarsResult<Buffer<u8>> {
         self.to_buffer_async(run_async)
     }
 
     #[cfg(not(feature = "cloud"))]
-    pub fn to_buffer_async_check_latest(&self, run_async: bool) -> PolarsResult<Buffer<u8>> {
+    pub fn to_buffer_async_check_latest(
+        &self,
+        run_async: bool,
+        _io_metrics: Option<Arc<IOMetrics>>,
+    ) -> PolarsResult<Buffer<u8>> {
         self.to_buffer_async(run_async)
     }
 
@@ -473,12 +491,14 @@ impl ScanSourceRef<'_> {
         >,
         #[cfg(not(feature = "cloud"))] cache_entries: Option<&()>,
         index: usize,
+        io_metrics: Option<Arc<IOMetrics>>,
     ) -> PolarsResult<Buffer<u8>> {
         match self {
             Self::Path(path) => {
                 let file = if run_async {
                     feature_gated!("cloud", {
-                        cache_entries.unwrap()[index].try_open_check_latest()?
+                        cache_entries.unwrap()[index]
+                            .try_open_check_latest(&OptIOMetrics(io_metrics))?
                     })
                 } else {
                     polars_utils::open_file(path.as_std_path())?
@@ -180,7 +180,9 @@ fn is_inherently_nondeterministic_fn(f: &IRFunctionExpr) -> bool {
         #[cfg(feature = "unique_counts")]
         F::UniqueCounts => false,
         #[cfg(feature = "approx_unique")]
-        F::ApproxNUnique => false,
+        F::ApproxNUnique | F::HllSketch | F::HllMerge | F::HllEstimate => false,
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantile { .. } | F::QuantileSketch => false,
         F::Coalesce => false,
         #[cfg(feature = "diff")]
         F::Diff(_) => false,
@@ -237,6 +237,18 @@ pub enum IRFunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "approx_unique")]
+    HllSketch,
+    #[cfg(feature = "approx_unique")]
+    HllMerge,
+    #[cfg(feature = "approx_unique")]
+    HllEstimate,
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile {
+        quantile: f64,
+    },
+    #[cfg(feature = "approx_quantile")]
+    QuantileSketch,
     Coalesce,
     #[cfg(feature = "diff")]
     Diff(NullBehavior),
@@ -589,7 +601,11 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "unique_counts")]
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => {},
+            ApproxNUnique | HllSketch | HllMerge | HllEstimate => {},
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { quantile } => quantile.to_bits().hash(state),
+            #[cfg(feature = "approx_quantile")]
+            QuantileSketch => {},
             Coalesce => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
@@ -823,6 +839,16 @@ impl Display for IRFunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "approx_unique")]
+            HllSketch => "hll_sketch",
+            #[cfg(feature = "approx_unique")]
+            HllMerge => "hll_merge",
+            #[cfg(feature = "approx_unique")]
+            HllEstimate => "hll_estimate",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => "approx_quantile",
+            #[cfg(feature = "approx_quantile")]
+            QuantileSketch => "quantile_sketch",
             Coalesce => "coalesce",
             #[cfg(feature = "diff")]
             Diff(_) => "diff",
@@ -1142,7 +1168,13 @@ impl IRFunctionExpr {
             #[cfg(feature = "unique_counts")]
             F::UniqueCounts => FunctionOptions::groupwise(),
             #[cfg(feature = "approx_unique")]
-            F::ApproxNUnique => {
+            F::ApproxNUnique | F::HllSketch | F::HllMerge => {
+                FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
+            },
+            #[cfg(feature = "approx_unique")]
+            F::HllEstimate => FunctionOptions::elementwise(),
+            #[cfg(feature = "approx_quantile")]
+            F::ApproxQuantile { .. } | F::QuantileSketch => {
                 FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
             },
             F::Coalesce => FunctionOptions::elementwise()
@@ -215,6 +215,14 @@ impl IRFunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_unique")]
+            HllSketch | HllMerge => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "approx_unique")]
+            HllEstimate => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "approx_quantile")]
+            QuantileSketch => mapper.with_dtype(DataType::Binary),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
@@ -875,6 +875,16 @@ pub(super) fn convert_functions(
         F::UniqueCounts => I::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => I::ApproxNUnique,
+        #[cfg(feature = "approx_unique")]
+        F::HllSketch => I::HllSketch,
+        #[cfg(feature = "approx_unique")]
+        F::HllMerge => I::HllMerge,
+        #[cfg(feature = "approx_unique")]
+        F::HllEstimate => I::HllEstimate,
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantile { quantile } => I::ApproxQuantile { quantile },
+        #[cfg(feature = "approx_quantile")]
+        F::QuantileSketch => I::QuantileSketch,
         F::Coalesce => I::Coalesce,
         #[cfg(feature = "diff")]
         F::Diff(n) => {
@@ -13,6 +13,7 @@ use polars_io::utils::compression::{ByteSourceReader, CompressedReader, Supporte
 use polars_io::utils::stream_buf_reader::ReaderSource;
 
 use super::*;
+use crate::dsl::default_values::DefaultFieldValues;
 
 pub(super) async fn dsl_to_ir(
     sources: ScanSources,
@@ -75,7 +76,18 @@ pub(super) async fn dsl_to_ir(
             },
             #[cfg(feature = "scan_lines")]
             FileScanDsl::Lines { .. } => sources.expand_paths(unified_scan_args).await?,
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro => {
+                sources
+                    .expand_paths_with_hive_update(unified_scan_args)
+                    .await?
+            },
             FileScanDsl::ExpandedPaths { .. } => sources.expand_paths(unified_scan_args).await?,
+            FileScanDsl::Dataset { .. } => {
+                // The files are resolved in `expand_datasets`. As for python datasets, a dummy
+                // path is given so that the scan is not short-circuited as empty.
+                ScanSources::Paths(Buffer::from_iter([PlRefPath::new("PL_DSET")]))
+            },
             FileScanDsl::Anonymous { .. } => sources.clone(),
         };
 
@@ -99,7 +111,13 @@ pub(super) async fn dsl_to_ir(
             unified_scan_args.hive_options.enabled = Some(false);
         }
 
-        let hive_parts = if unified_scan_args.hive_options.enabled.unwrap()
+        let hive_parts = if let Some(DefaultFieldValues::Delta(partition_values)) =
+            unified_scan_args
+                .default_values
+                .take_if(|x| matches!(x, DefaultFieldValues::Delta(_)))
+        {
+            Some(hive::HivePartitionsDf::from(partition_values.0.clone()))
+        } else if unified_scan_args.hive_options.enabled.unwrap()
             && let Some(file_schema) = file_info.reader_schema.as_ref()
         {
             let paths = sources
@@ -211,7 +229,7 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -242,6 +260,7 @@ pub(super) async fn parquet_file_info(
     sources: &ScanSources,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<(
     FileInfo,
     Option<FileMetadataRef>,
@@ -263,7 +282,9 @@ pub(super) async fn parquet_file_info(
             let first_path = first_scan_source.as_path().unwrap();
             feature_gated!("cloud", {
                 let mut reader =
-                    ParquetObjectStore::from_uri(first_path.clone(), cloud_options, None).await?;
+                    ParquetObjectStore::from_uri(first_path.clone(), cloud_options, None)
+                        .await?
+                        .with_decryption(decryption.cloned());
                 PolarsResult::Ok((
                     reader.schema().await?,
                     reader.num_rows().await?,
@@ -272,7 +293,8 @@ pub(super) async fn parquet_file_info(
             })
         } else {
             let memslice = first_scan_source.to_memslice()?;
-            let mut reader = ParquetReader::new(Cursor::new(memslice));
+            let mut reader =
+                ParquetReader::new(Cursor::new(memslice)).with_decryption(decryption.cloned());
             PolarsResult::Ok((
                 reader.schema()?,
                 reader.num_rows()?,
@@ -334,7 +356,7 @@ pub(super) async fn parquet_file_info(
                 let rest_fut = async move {
                     let mut futures = (1..n_sources)
                         .map(|i| async move {
-                            read_parquet_num_rows(sources.at(i), cloud_options).await
+                            read_parquet_num_rows(sources.at(i), cloud_options, decryption).await
                         })
                         .collect::<FuturesUnordered<_>>();
 
@@ -377,7 +399,7 @@ pub(super) async fn parquet_file_info(
                     let mut futures = sample
                         .iter()
                         .map(|&i| async move {
-                            read_parquet_num_rows(sources.at(i), cloud_options).await
+                            read_parquet_num_rows(sources.at(i), cloud_options, decryption).await
                         })
                         .collect::<FuturesUnordered<_>>();
                     let mut rows = 0usize;
@@ -415,7 +437,7 @@ pub(super) async fn parquet_file_info(
                 // with file 0; `None` marks a file that failed to decode.
                 let rest_fut = async move {
                     let mut futures = (1..n_sources)
-                        .map(|i| read_parquet_metadata(sources.at(i), cloud_options))
+                        .map(|i| read_parquet_metadata(sources.at(i), cloud_options, decryption))
                         .collect::<FuturesOrdered<_>>();
                     let mut rest: Vec<Option<FileMetadataRef>> = Vec::with_capacity(n_sources - 1);
                     while let Some(file_result) = futures.next().await {
@@ -500,34 +522,43 @@ fn sampled_source_indices(n_sources: usize, limit: usize) -> Vec<usize> {
 async fn read_parquet_metadata(
     source: ScanSourceRef<'_>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<FileMetadataRef> {
     use polars_core::error::feature_gated;
 
     if source.is_cloud_url() {
         let path = source.as_path().unwrap();
         feature_gated!("cloud", {
-            let mut reader =
-                ParquetObjectStore::from_uri(path.clone(), cloud_options, None).await?;
+            let mut reader = ParquetObjectStore::from_uri(path.clone(), cloud_options, None)
+                .await?
+                .with_decryption(decryption.cloned());
             reader.get_metadata().await.cloned()
         })
     } else {
         let memslice = source.to_memslice()?;
         let mut cursor = Cursor::new(memslice);
-        let md = polars_parquet::parquet::read::read_metadata(&mut cursor)?;
+        let md =
+            polars_parquet::parquet::read::read_metadata_with_decryption(&mut cursor, decryption)?;
         Ok(Arc::new(md))
     }
 }
 
 /// Fetch one source's `num_rows` (thrift field 3 only); skips
 /// schema, row_groups, and the rest. Used by [`parquet_file_info`]
-/// in `RowCounts` resolve mode.
+/// in `RowCounts` resolve mode. Encrypted footers are read in full.
 #[cfg(feature = "parquet")]
 async fn read_parquet_num_rows(
     source: ScanSourceRef<'_>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<i64> {
     use polars_core::error::feature_gated;
 
+    if decryption.is_some() {
+        let md = read_parquet_metadata(source, cloud_options, decryption).await?;
+        return Ok(md.num_rows as i64);
+    }
+
     if source.is_cloud_url() {
         let path = source.as_path().unwrap();
         feature_gated!("cloud", {
@@ -569,7 +600,7 @@ pub(super) async fn ipc_file_info(
         ScanSourceRef::Path(path) => {
             if path.has_scheme() {
                 feature_gated!("cloud", {
-                    polars_io::ipc::IpcReaderAsync::from_uri(path.clone(), cloud_options)
+                    polars_io::ipc::IpcReaderAsync::from_uri(path.clone(), cloud_options, None)
                         .await?
                         .metadata()
                         .await?
@@ -600,6 +631,66 @@ pub(super) async fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) async fn avro_file_info(
+    first_scan_source: ScanSourceRef<'_>,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use arrow::io::avro::avro_schema::read::read_metadata;
+    use polars_core::error::{feature_gated, to_compute_err};
+
+    let metadata = match first_scan_source {
+        ScanSourceRef::Path(path) => {
+            if path.has_scheme() {
+                feature_gated!("cloud", {
+                    let first_scan_source = first_scan_source.into_owned()?;
+                    let cloud_options = cloud_options.cloned();
+
+                    ASYNC
+                        .spawn(async move {
+                            let byte_source =
+                                first_scan_source
+                                    .as_scan_source_ref()
+                                    .to_dyn_byte_source(
+                                        &DynByteSourceBuilder::ObjectStore(
+                                            FetchConfig::random_access(),
+                                        ),
+                                        cloud_options.as_ref(),
+                                        None,
+                                    )
+                                    .await?;
+
+                            polars_io::avro::read_metadata_from_byte_source(&byte_source).await
+                        })
+                        .await
+                        .unwrap()?
+                        .0
+                })
+            } else {
+                read_metadata(&mut BufReader::new(polars_utils::open_file(
+                    path.as_std_path(),
+                )?))
+                .map_err(to_compute_err)?
+            }
+        },
+        ScanSourceRef::File(file) => {
+            read_metadata(&mut BufReader::new(file)).map_err(to_compute_err)?
+        },
+        ScanSourceRef::Buffer(buff) => {
+            read_metadata(&mut Cursor::new(buff.as_ref())).map_err(to_compute_err)?
+        },
+    };
+
+    let arrow_schema = arrow::io::avro::read::infer_schema(&metadata.record)?;
+
+    Ok(FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(&arrow_schema), row_index)?,
+        Some(Either::Left(Arc::new(arrow_schema))),
+        (None, usize::MAX),
+    ))
+}
+
 #[cfg(feature = "csv")]
 pub async fn csv_file_info(
     sources: &ScanSources,
@@ -763,7 +854,7 @@ pub async fn csv_file_info(
             (mem_slice_raw, file_size, decompressed_slice_size_hint)
         } else {
             let mem_slice_raw =
-                source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), i)?;
+                source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), i, None)?;
             let file_size = mem_slice_raw.len();
             let compression = SupportedCompression::check(&mem_slice_raw);
             let decompressed_slice_size_hint = Some(match compression {
@@ -1022,8 +1113,12 @@ pub async fn ndjson_file_info(
         // Download the entire object.
         // Warning - this is potentially memory-expensive in the case of a cloud source, and goes
         // against the design goal of a streaming reader. This can be optimized.
-        let mem_slice =
-            first_scan_source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+        let mem_slice = first_scan_source.to_buffer_possibly_async(
+            run_async,
+            cache_entries.as_ref(),
+            0,
+            None,
+        )?;
         let mut reader = BufReader::new(CompressedReader::try_new(mem_slice)?);
 
         Arc::new(polars_io::ndjson::infer_schema(
@@ -1136,6 +1231,7 @@ this scan to succeed with an empty DataFrame.",
                                 sources,
                                 unified_scan_args.row_index.as_ref(),
                                 cloud_options,
+                                options.decryption.as_ref(),
                             )
                             .await?;
 
@@ -1307,6 +1403,32 @@ this scan to succeed with an empty DataFrame.",
                     FileScanIR::Lines { name },
                 )
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro => {
+                let first_scan_source =
+                    require_first_source("failed to retrieve first file schema (avro)", "")?;
+
+                if verbose() {
+                    eprintln!(
+                        "sourcing avro scan file schema from: '{}'",
+                        first_scan_source.to_include_path_name()
+                    )
+                }
+
+                let mut file_info = scans::avro_file_info(
+                    first_scan_source,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .await?;
+
+                if let Some(exact_row_estimation) = exact_row_estimation {
+                    file_info.row_estimation = exact_row_estimation;
+                }
+
+                PolarsResult::Ok((file_info, FileScanIR::Avro))
+            }
+            .map_err(|e| e.context(failed_here!(avro scan)))?,
             FileScanDsl::ExpandedPaths { name } => {
                 let schema = Arc::new(Schema::from_iter([(name.clone(), DataType::String)]));
 
@@ -1319,6 +1441,24 @@ this scan to succeed with an empty DataFrame.",
                     FileScanIR::ExpandedPaths { name },
                 )
             },
+            FileScanDsl::Dataset { provider } => (|| {
+                let mut schema = provider.schema()?;
+                let reader_schema = schema.clone();
+
+                if let Some(row_index) = &unified_scan_args.row_index {
+                    insert_row_index_to_schema(Arc::make_mut(&mut schema), row_index.name.clone())?;
+                }
+
+                PolarsResult::Ok((
+                    FileInfo {
+                        schema,
+                        reader_schema: Some(either::Either::Right(reader_schema)),
+                        row_estimation: exact_row_estimation.unwrap_or(DEFAULT_ROW_ESTIMATION),
+                    },
+                    FileScanIR::Dataset { provider },
+                ))
+            })()
+            .map_err(|e| e.context(failed_here!(dataset scan)))?,
             FileScanDsl::Anonymous {
                 mut file_info,
                 options,
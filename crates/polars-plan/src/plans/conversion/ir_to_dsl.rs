@@ -972,6 +972,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::UniqueCounts => F::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         IF::ApproxNUnique => F::ApproxNUnique,
+        #[cfg(feature = "approx_unique")]
+        IF::HllSketch => F::HllSketch,
+        #[cfg(feature = "approx_unique")]
+        IF::HllMerge => F::HllMerge,
+        #[cfg(feature = "approx_unique")]
+        IF::HllEstimate => F::HllEstimate,
+        #[cfg(feature = "approx_quantile")]
+        IF::ApproxQuantile { quantile } => F::ApproxQuantile { quantile },
+        #[cfg(feature = "approx_quantile")]
+        IF::QuantileSketch => F::QuantileSketch,
         IF::Coalesce => F::Coalesce,
         #[cfg(feature = "diff")]
         IF::Diff(nb) => F::Diff(nb),
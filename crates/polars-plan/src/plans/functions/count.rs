@@ -62,6 +62,7 @@ fn count_all_rows_csv(
                 options.skip_rows,
                 options.skip_rows_after_header,
                 options.raise_if_empty,
+                None,
             ),
             _ => {
                 let memslice = source.to_memslice()?;
@@ -12,8 +12,9 @@ use polars_utils::{format_pl_smallstr, unitvec};
 
 #[cfg(feature = "python")]
 use crate::dsl::python_dsl::PythonScanSource;
-use crate::dsl::{DslPlan, FileScanIR, UnifiedScanArgs};
-use crate::plans::{AExpr, IR};
+use crate::dsl::{DslPlan, FileScanDsl, FileScanIR, ScanSources, UnifiedScanArgs};
+use crate::plans::hive::HivePartitionsDf;
+use crate::plans::{AExpr, FileInfo, IR};
 
 pub(super) fn expand_datasets(
     root: Node,
@@ -63,6 +64,71 @@ pub(super) fn expand_datasets(
         };
 
         match scan_type.as_mut() {
+            FileScanIR::Dataset { provider } => {
+                use polars_core::prelude::PlHashSet;
+
+                use crate::utils::aexpr_to_leaf_names_iter;
+
+                let provider = provider.clone();
+
+                if config::verbose() {
+                    eprintln!("expand_datasets(): {}", provider.name())
+                }
+
+                let live_filter_columns: PlHashSet<PlSmallStr> = predicate
+                    .as_ref()
+                    .map(|x| {
+                        aexpr_to_leaf_names_iter(x.node(), expr_arena)
+                            .cloned()
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let row_index_in_live_filter = unified_scan_args
+                    .row_index
+                    .as_ref()
+                    .is_some_and(|ri| live_filter_columns.contains(&ri.name));
+
+                // The predicate is applied after the row index and slice, and can refer to
+                // columns that are not in the dataset, e.g. the file path column.
+                let Some(either::Either::Right(dataset_schema)) = &file_info.reader_schema else {
+                    unreachable!()
+                };
+                let dataset_predicate = predicate.as_ref().filter(|_| {
+                    !unified_scan_args.has_row_index_or_slice()
+                        && live_filter_columns
+                            .iter()
+                            .all(|name| dataset_schema.contains(name))
+                });
+
+                match provider.to_dataset_scan(dataset_predicate, expr_arena)? {
+                    DslPlan::Scan {
+                        sources: resolved_sources,
+                        unified_scan_args: resolved_unified_scan_args,
+                        scan_type: resolved_scan_type,
+                        cached_ir: _,
+                    } => apply_resolved_scan(
+                        &resolved_sources,
+                        &resolved_unified_scan_args,
+                        &resolved_scan_type,
+                        row_index_in_live_filter,
+                        sources,
+                        scan_type,
+                        unified_scan_args,
+                        file_info,
+                        hive_parts,
+                    )?,
+
+                    dsl => {
+                        polars_bail!(
+                            ComputeError:
+                            "unknown DSL when resolving dataset scan: {}",
+                            dsl.display()?
+                        )
+                    },
+                };
+            },
+
             #[cfg(feature = "python")]
             FileScanIR::PythonDataset {
                 dataset_object,
@@ -195,159 +261,17 @@ pub(super) fn expand_datasets(
                         unified_scan_args: resolved_unified_scan_args,
                         scan_type: resolved_scan_type,
                         cached_ir: _,
-                    } => {
-                        use crate::dsl::FileScanDsl;
-
-                        // We only want a few configuration flags from here (e.g. column casting config).
-                        // The rest we either expect to be None (e.g. projection / row_index), or ignore.
-                        let UnifiedScanArgs {
-                            schema: _,
-                            cloud_options,
-                            hive_options,
-                            rechunk,
-                            cache,
-                            glob: _,
-                            hidden_file_prefix: _hidden_file_prefix @ None,
-                            projection: _projection @ None,
-                            column_mapping,
-                            default_values,
-                            row_index: _row_index @ None,
-                            pre_slice: _pre_slice @ None,
-                            cast_columns_policy,
-                            missing_columns_policy,
-                            extra_columns_policy,
-                            include_file_paths: _include_file_paths @ None,
-                            deletion_files,
-                            table_statistics,
-                            row_count,
-                        } = resolved_unified_scan_args.as_ref()
-                        else {
-                            panic!(
-                                "invalid scan args from python dataset resolve: {:?}",
-                                &resolved_unified_scan_args
-                            )
-                        };
-
-                        unified_scan_args.cloud_options = cloud_options.clone();
-                        unified_scan_args.rechunk = *rechunk;
-                        unified_scan_args.cache = *cache;
-                        unified_scan_args.cast_columns_policy = cast_columns_policy.clone();
-                        unified_scan_args.missing_columns_policy = *missing_columns_policy;
-                        unified_scan_args.extra_columns_policy = *extra_columns_policy;
-                        unified_scan_args.column_mapping = column_mapping.clone();
-                        unified_scan_args.default_values = default_values.clone();
-                        unified_scan_args.deletion_files = deletion_files.clone();
-                        unified_scan_args.table_statistics = table_statistics.clone();
-                        unified_scan_args.row_count = *row_count;
-
-                        if row_index_in_live_filter {
-                            use polars_core::prelude::{Column, DataType, IdxCa, IntoColumn};
-                            use polars_core::series::IntoSeries;
-
-                            let row_index_name =
-                                &unified_scan_args.row_index.as_ref().unwrap().name;
-                            let table_statistics =
-                                unified_scan_args.table_statistics.as_mut().unwrap();
-
-                            let statistics_df = Arc::make_mut(&mut table_statistics.0);
-                            assert!(
-                                !statistics_df
-                                    .schema()
-                                    .contains(&format_pl_smallstr!("{}_nc", row_index_name))
-                            );
-
-                            unsafe { statistics_df.columns_mut() }.extend([
-                                IdxCa::from_vec(
-                                    format_pl_smallstr!("{}_nc", row_index_name),
-                                    vec![0],
-                                )
-                                .into_series()
-                                .into_column()
-                                .new_from_index(0, sources.len()),
-                                Column::full_null(
-                                    format_pl_smallstr!("{}_min", row_index_name),
-                                    sources.len(),
-                                    &DataType::IDX_DTYPE,
-                                ),
-                                Column::full_null(
-                                    format_pl_smallstr!("{}_max", row_index_name),
-                                    sources.len(),
-                                    &DataType::IDX_DTYPE,
-                                ),
-                            ]);
-                        }
-
-                        *sources = resolved_sources.clone();
-
-                        **scan_type = match *resolved_scan_type.clone() {
-                            #[cfg(feature = "csv")]
-                            FileScanDsl::Csv { options } => FileScanIR::Csv { options },
-
-                            #[cfg(feature = "ipc")]
-                            FileScanDsl::Ipc { options } => FileScanIR::Ipc {
-                                options,
-                                metadata: None,
-                            },
-
-                            #[cfg(feature = "parquet")]
-                            FileScanDsl::Parquet { options } => FileScanIR::Parquet {
-                                options,
-                                // Metadata is resolved later in `parquet_file_info`.
-                                first_metadata: None,
-                                metadata_per_source: None,
-                            },
-
-                            #[cfg(feature = "json")]
-                            FileScanDsl::NDJson { options } => FileScanIR::NDJson { options },
-
-                            #[cfg(feature = "python")]
-                            FileScanDsl::PythonDataset { dataset_object } => {
-                                FileScanIR::PythonDataset {
-                                    dataset_object,
-                                    cached_ir: Default::default(),
-                                }
-                            },
-
-                            #[cfg(feature = "scan_lines")]
-                            FileScanDsl::Lines { name } => FileScanIR::Lines { name },
-
-                            FileScanDsl::ExpandedPaths { name } => {
-                                FileScanIR::ExpandedPaths { name }
-                            },
-
-                            FileScanDsl::Anonymous {
-                                options,
-                                function,
-                                file_info: _,
-                            } => FileScanIR::Anonymous { options, function },
-                        };
-
-                        if hive_options.enabled == Some(true)
-                            && let Some(paths) = sources.as_paths()
-                        {
-                            use arrow::Either;
-
-                            use crate::plans::hive::hive_partitions_from_paths;
-
-                            let owned;
-
-                            *hive_parts = hive_partitions_from_paths(
-                                paths,
-                                hive_options.hive_start_idx,
-                                hive_options.schema.clone(),
-                                match file_info.reader_schema.as_ref().unwrap() {
-                                    Either::Left(v) => {
-                                        use polars_core::schema::{Schema, SchemaExt as _};
-
-                                        owned = Some(Schema::from_arrow_schema(v.as_ref()));
-                                        owned.as_ref().unwrap()
-                                    },
-                                    Either::Right(v) => v.as_ref(),
-                                },
-                                hive_options.try_parse_dates,
-                            )?;
-                        }
-                    },
+                    } => apply_resolved_scan(
+                        resolved_sources,
+                        resolved_unified_scan_args,
+                        resolved_scan_type,
+                        row_index_in_live_filter,
+                        sources,
+                        scan_type,
+                        unified_scan_args,
+                        file_info,
+                        hive_parts,
+                    )?,
 
                     DslPlan::PythonScan { options } => {
                         *python_scan = Some(ExpandedPythonScan {
@@ -381,6 +305,168 @@ pub(super) fn expand_datasets(
     Ok(())
 }
 
+/// Replaces the scan of a dataset with the `DslPlan::Scan` that the dataset resolved to.
+#[allow(clippy::too_many_arguments)]
+fn apply_resolved_scan(
+    resolved_sources: &ScanSources,
+    resolved_unified_scan_args: &UnifiedScanArgs,
+    resolved_scan_type: &FileScanDsl,
+    row_index_in_live_filter: bool,
+    sources: &mut ScanSources,
+    scan_type: &mut FileScanIR,
+    unified_scan_args: &mut UnifiedScanArgs,
+    file_info: &FileInfo,
+    hive_parts: &mut Option<HivePartitionsDf>,
+) -> PolarsResult<()> {
+    // We only want a few configuration flags from here (e.g. column casting config).
+    // The rest we either expect to be None (e.g. projection / row_index), or ignore.
+    let UnifiedScanArgs {
+        schema: _,
+        cloud_options,
+        hive_options,
+        rechunk,
+        cache,
+        glob: _,
+        hidden_file_prefix: _hidden_file_prefix @ None,
+        projection: _projection @ None,
+        column_mapping,
+        default_values,
+        row_index: _row_index @ None,
+        pre_slice: _pre_slice @ None,
+        cast_columns_policy,
+        missing_columns_policy,
+        extra_columns_policy,
+        include_file_paths: _include_file_paths @ None,
+        deletion_files,
+        table_statistics,
+        row_count,
+    } = resolved_unified_scan_args
+    else {
+        panic!(
+            "invalid scan args from dataset resolve: {:?}",
+            &resolved_unified_scan_args
+        )
+    };
+
+    unified_scan_args.cloud_options = cloud_options.clone();
+    unified_scan_args.rechunk = *rechunk;
+    unified_scan_args.cache = *cache;
+    unified_scan_args.cast_columns_policy = cast_columns_policy.clone();
+    unified_scan_args.missing_columns_policy = *missing_columns_policy;
+    unified_scan_args.extra_columns_policy = *extra_columns_policy;
+    unified_scan_args.column_mapping = column_mapping.clone();
+    unified_scan_args.default_values = default_values.clone();
+    unified_scan_args.deletion_files = deletion_files.clone();
+    unified_scan_args.table_statistics = table_statistics.clone();
+    unified_scan_args.row_count = *row_count;
+
+    if row_index_in_live_filter {
+        use polars_core::prelude::{Column, DataType, IdxCa, IntoColumn};
+        use polars_core::series::IntoSeries;
+
+        let row_index_name = &unified_scan_args.row_index.as_ref().unwrap().name;
+        let table_statistics = unified_scan_args.table_statistics.as_mut().unwrap();
+
+        let statistics_df = Arc::make_mut(&mut table_statistics.0);
+        assert!(
+            !statistics_df
+                .schema()
+                .contains(&format_pl_smallstr!("{}_nc", row_index_name))
+        );
+
+        unsafe { statistics_df.columns_mut() }.extend([
+            IdxCa::from_vec(format_pl_smallstr!("{}_nc", row_index_name), vec![0])
+                .into_series()
+                .into_column()
+                .new_from_index(0, resolved_sources.len()),
+            Column::full_null(
+                format_pl_smallstr!("{}_min", row_index_name),
+                resolved_sources.len(),
+                &DataType::IDX_DTYPE,
+            ),
+            Column::full_null(
+                format_pl_smallstr!("{}_max", row_index_name),
+                resolved_sources.len(),
+                &DataType::IDX_DTYPE,
+            ),
+        ]);
+    }
+
+    *sources = resolved_sources.clone();
+
+    *scan_type = match resolved_scan_type.clone() {
+        #[cfg(feature = "csv")]
+        FileScanDsl::Csv { options } => FileScanIR::Csv { options },
+
+        #[cfg(feature = "ipc")]
+        FileScanDsl::Ipc { options } => FileScanIR::Ipc {
+            options,
+            metadata: None,
+        },
+
+        #[cfg(feature = "parquet")]
+        FileScanDsl::Parquet { options } => FileScanIR::Parquet {
+            options,
+            // Metadata is resolved later in `parquet_file_info`.
+            first_metadata: None,
+            metadata_per_source: None,
+        },
+
+        #[cfg(feature = "json")]
+        FileScanDsl::NDJson { options } => FileScanIR::NDJson { options },
+
+        #[cfg(feature = "python")]
+        FileScanDsl::PythonDataset { dataset_object } => FileScanIR::PythonDataset {
+            dataset_object,
+            cached_ir: Default::default(),
+        },
+
+        #[cfg(feature = "scan_lines")]
+        FileScanDsl::Lines { name } => FileScanIR::Lines { name },
+
+        #[cfg(feature = "avro")]
+        FileScanDsl::Avro => FileScanIR::Avro,
+
+        FileScanDsl::ExpandedPaths { name } => FileScanIR::ExpandedPaths { name },
+
+        FileScanDsl::Dataset { provider } => FileScanIR::Dataset { provider },
+
+        FileScanDsl::Anonymous {
+            options,
+            function,
+            file_info: _,
+        } => FileScanIR::Anonymous { options, function },
+    };
+
+    if hive_options.enabled == Some(true)
+        && let Some(paths) = sources.as_paths()
+    {
+        use arrow::Either;
+
+        use crate::plans::hive::hive_partitions_from_paths;
+
+        let owned;
+
+        *hive_parts = hive_partitions_from_paths(
+            paths,
+            hive_options.hive_start_idx,
+            hive_options.schema.clone(),
+            match file_info.reader_schema.as_ref().unwrap() {
+                Either::Left(v) => {
+                    use polars_core::schema::{Schema, SchemaExt as _};
+
+                    owned = Some(Schema::from_arrow_schema(v.as_ref()));
+                    owned.as_ref().unwrap()
+                },
+                Either::Right(v) => v.as_ref(),
+            },
+            hive_options.try_parse_dates,
+        )?;
+    }
+
+    Ok(())
+}
+
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
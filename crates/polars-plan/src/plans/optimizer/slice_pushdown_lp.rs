@@ -370,8 +370,14 @@ impl SlicePushDown {
                     #[cfg(feature = "scan_lines")]
                     FileScanIR::Lines { .. } => true,
 
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro => true,
+
                     FileScanIR::ExpandedPaths { .. } => false,
 
+                    // The slice is kept on the scan that the dataset resolves to.
+                    FileScanIR::Dataset { .. } => true,
+
                     // TODO: This can be `true` after Anonymous scan dispatches to new-streaming.
                     FileScanIR::Anonymous { .. } => state.offset == 0,
                 } =>
@@ -1,9 +1,15 @@
 use std::sync::Arc;
 
+#[cfg(feature = "parquet")]
+use either::Either;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::*;
 use polars_core::schema::Schema;
 use polars_core::series::IsSorted;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::metadata::FileMetadataRef;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::read::file_sorting_columns;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
@@ -11,6 +17,10 @@ use polars_utils::unique_id::UniqueId;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "parquet")]
+use crate::dsl::FileScanIR;
+#[cfg(feature = "parquet")]
+use crate::plans::FileInfo;
 #[cfg(all(feature = "strings", feature = "concat_str"))]
 use crate::plans::IRStringFunction;
 use crate::plans::{
@@ -238,6 +248,28 @@ fn is_sorted_rec(
             input,
             predicate: _,
         } => rec!(*input),
+        #[cfg(feature = "parquet")]
+        IR::Scan {
+            sources,
+            file_info,
+            output_schema,
+            scan_type,
+            unified_scan_args,
+            ..
+        } => match scan_type.as_ref() {
+            FileScanIR::Parquet {
+                first_metadata: Some(metadata),
+                ..
+            } if sources.len() == 1 && unified_scan_args.column_mapping.is_none() => {
+                parquet_scan_sorted(
+                    metadata,
+                    file_info,
+                    output_schema.as_ref().unwrap_or(&file_info.schema),
+                )
+            },
+            _ => None,
+        },
+        #[cfg(not(feature = "parquet"))]
         IR::Scan { .. } => None,
         IR::DataFrameScan { df, .. } => {
             let last_is_null = |c: &Column| Some(c.get(c.len().checked_sub(1)?).ok()?.is_null());
@@ -587,6 +619,35 @@ fn is_sorted_rec(
     sorted
 }
 
+/// The sortedness of a scan of a single Parquet file, derived from the `sorting_columns` in its
+/// metadata.
+#[cfg(feature = "parquet")]
+fn parquet_scan_sorted(
+    metadata: &FileMetadataRef,
+    file_info: &FileInfo,
+    output_schema: &Schema,
+) -> Option<IRSorted> {
+    let Some(Either::Left(reader_schema)) = &file_info.reader_schema else {
+        return None;
+    };
+
+    let sorting_columns = file_sorting_columns(metadata, reader_schema).ok()?;
+
+    let sorted_cols = sorting_columns
+        .into_iter()
+        .map_while(|c| {
+            let dtype = output_schema.get(&c.name)?;
+            let file_dtype = DataType::from_arrow_field(reader_schema.get(&c.name)?);
+            (dtype == &file_dtype).then_some(Sorted {
+                column: c.name,
+                descending: Some(c.descending),
+                nulls_last: Some(c.nulls_last),
+            })
+        })
+        .collect_vec();
+    (!sorted_cols.is_empty()).then(|| IRSorted(sorted_cols.into()))
+}
+
 fn first_expr_ir_sorted(
     exprs: &[ExprIR],
     arena: &Arena<AExpr>,
@@ -262,6 +262,71 @@ impl PyCatalogClient {
             .into())
     }
 
+    #[cfg(feature = "delta")]
+    #[pyo3(signature = (lf, catalog_name, namespace, table_name, mode, cloud_options, credential_provider))]
+    pub fn sink_table(
+        &self,
+        py: Python<'_>,
+        lf: PyLazyFrame,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        mode: &str,
+        cloud_options: OptPyCloudOptions,
+        credential_provider: Option<Py<PyAny>>,
+    ) -> PyResult<PyLazyFrame> {
+        use polars_io::catalog::unity::write::WriteTableOptions;
+        use polars_io::delta::{DeltaWriteMode, DeltaWriteOptions};
+
+        let mode = match mode {
+            "error" => DeltaWriteMode::Error,
+            "append" => DeltaWriteMode::Append,
+            "overwrite" => DeltaWriteMode::Overwrite,
+            "ignore" => DeltaWriteMode::Ignore,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "unsupported delta write mode: {v}"
+                )));
+            },
+        };
+
+        let table_info = py.enter_polars(|| {
+            ASYNC.block_in_place_on(self.client().get_table_info(
+                catalog_name,
+                namespace,
+                table_name,
+            ))
+        })?;
+
+        let cloud_options = cloud_options.extract_opt_cloud_options(
+            table_info
+                .storage_location
+                .as_deref()
+                .and_then(CloudScheme::from_path),
+            credential_provider,
+        )?;
+
+        let options = WriteTableOptions {
+            delta: DeltaWriteOptions {
+                mode,
+                ..Default::default()
+            },
+            cloud_options,
+            create_table: None,
+        };
+
+        py.enter_polars(|| {
+            lf.ldf.into_inner().sink_unity(
+                self.client(),
+                catalog_name,
+                namespace,
+                table_name,
+                &options,
+            )
+        })
+        .map(Into::into)
+    }
+
     #[pyo3(signature = (catalog_name, comment, storage_root))]
     pub fn create_catalog(
         &self,
@@ -463,6 +463,31 @@ impl PyExpr {
         self.inner.clone().approx_n_unique().into()
     }
 
+    #[cfg(feature = "approx_unique")]
+    fn hll_sketch(&self) -> Self {
+        self.inner.clone().hll_sketch().into()
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn hll_merge(&self) -> Self {
+        self.inner.clone().hll_merge().into()
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn hll_estimate(&self) -> Self {
+        self.inner.clone().hll_estimate().into()
+    }
+
+    #[cfg(feature = "approx_quantile")]
+    fn approx_quantile(&self, quantile: f64) -> Self {
+        self.inner.clone().approx_quantile(quantile).into()
+    }
+
+    #[cfg(feature = "approx_quantile")]
+    fn quantile_sketch(&self) -> Self {
+        self.inner.clone().quantile_sketch().into()
+    }
+
     fn is_first_distinct(&self) -> Self {
         self.inner.clone().is_first_distinct().into()
     }
@@ -124,9 +124,7 @@ impl PyScanOptions<'_> {
                 .map(|x| x.into_iter().map(|x| (*x).into()).collect()),
             projection: None,
             column_mapping: column_mapping.map(|x| x.0),
-            default_values: default_values
-                .map(|x| x.0)
-                .filter(|DefaultFieldValues::Iceberg(v)| !v.is_empty()),
+            default_values: default_values.map(|x| x.0).filter(|v| !v.is_empty()),
             row_index,
             pre_slice: pre_slice.map(Slice::from),
             cast_columns_policy: cast_options.0,
@@ -311,6 +311,7 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            decryption: None,
         };
 
         let sources = sources.0;
@@ -703,6 +704,10 @@ impl PyLazyFrame {
             key_value_metadata: metadata.0,
             arrow_schema: arrow_schema.map(|x| Arc::new(x.0)),
             compat_level: None,
+            bloom_filters: Vec::new(),
+            write_page_index: true,
+            sorting_columns: None,
+            encryption: None,
         };
 
         let target = target.extract_file_sink_destination()?;
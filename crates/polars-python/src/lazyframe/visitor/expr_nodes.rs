@@ -1391,6 +1391,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 IRFunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
                 IRFunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
+                IRFunctionExpr::HllSketch => ("hll_sketch",).into_py_any(py),
+                IRFunctionExpr::HllMerge => ("hll_merge",).into_py_any(py),
+                IRFunctionExpr::HllEstimate => ("hll_estimate",).into_py_any(py),
+                IRFunctionExpr::ApproxQuantile { quantile } => {
+                    ("approx_quantile", quantile).into_py_any(py)
+                },
+                IRFunctionExpr::QuantileSketch => ("quantile_sketch",).into_py_any(py),
                 IRFunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
                 IRFunctionExpr::Diff(null_behaviour) => (
                     "diff",
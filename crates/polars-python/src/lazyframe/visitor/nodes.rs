@@ -41,6 +41,8 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "avro")]
+        FileScanIR::Avro => Err(PyNotImplementedError::new_err("avro scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
@@ -55,6 +57,7 @@ fn scan_type_to_pyobject(
         FileScanIR::PythonDataset { .. } => {
             Err(PyNotImplementedError::new_err("python dataset scan"))
         },
+        FileScanIR::Dataset { .. } => Err(PyNotImplementedError::new_err("dataset scan")),
         FileScanIR::Anonymous { .. } => Err(PyNotImplementedError::new_err("anonymous scan")),
     }
 }
@@ -158,6 +161,10 @@ impl PyFileOptions {
                     .into_any()
                     .unbind()
             },
+            #[cfg(feature = "delta")]
+            Some(DeletionFilesList::DeltaDeletionVectors(_)) => {
+                return Err(PyNotImplementedError::new_err("delta deletion vectors"));
+            },
         })
     }
 
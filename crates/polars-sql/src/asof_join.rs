@@ -0,0 +1,281 @@
+//! ASOF joins (`ASOF JOIN`).
+//!
+//! An ASOF join matches each row of the left table with the nearest row of the right
+//! table according to an inequality, optionally within groups of equal keys:
+//!
+//! ```sql
+//! SELECT * FROM trades t ASOF JOIN quotes q ON t.sym = q.sym AND t.ts >= q.ts
+//! ```
+//!
+//! As in DuckDB, left rows without a match are dropped. The inequality can also be
+//! given as a Snowflake-style `MATCH_CONDITION (t.ts >= q.ts)`, and `USING (sym, ts)`
+//! matches on the last column (with `>=`) within groups of the other columns.
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_ops::frame::{AsOfOptions, AsofStrategy, JoinCoalesce};
+use sqlparser::ast::{
+    BinaryOperator as SQLBinaryOperator, Expr as SQLExpr, JoinConstraint, ObjectName,
+    Value as SQLValue, ValueWithSpan,
+};
+use sqlparser::keywords::Keyword;
+use sqlparser::tokenizer::{Token, TokenWithSpan, Whitespace, Word};
+
+use crate::SQLContext;
+use crate::context::{
+    TableInfo, build_join_schema, determine_left_right_join_on, flatten_and_conditions,
+};
+use crate::sql_expr::parse_sql_expr;
+use crate::sql_visitors::expr_refers_to_table;
+
+/// Marks the right-hand rows of an ASOF join, so that unmatched left rows can be dropped.
+const ASOF_MATCH_NAME: &str = "__POLARS_ASOF_MATCH";
+
+/// Rewrite DuckDB-style `ASOF JOIN <relation> ON|USING ...` into the form understood by
+/// the parser, `ASOF JOIN <relation> MATCH_CONDITION (TRUE) ON|USING ...`; the inequality
+/// is then taken from the join constraint.
+///
+/// Only an `ASOF` that directly follows a table factor is rewritten, so tables and aliases
+/// named `asof` (eg: `FROM asof JOIN ...` or `FROM t AS asof JOIN ...`) are left alone.
+pub(crate) fn rewrite_asof_join_tokens(tokens: Vec<TokenWithSpan>) -> Vec<TokenWithSpan> {
+    let keyword = |t: &Token| match t {
+        Token::Word(Word { keyword, .. }) => Some(*keyword),
+        _ => None,
+    };
+    let mut rewritten = Vec::with_capacity(tokens.len());
+    let mut prev_token: Option<Token> = None;
+    // Set on an `ASOF` keyword in join position, until the following token is seen
+    let mut asof_keyword = false;
+    // Parenthesis depth within the relation of an `ASOF JOIN` (if inside one)
+    let mut asof_depth: Option<usize> = None;
+
+    for tok in tokens {
+        if matches!(tok.token, Token::Whitespace(_)) {
+            rewritten.push(tok);
+            continue;
+        }
+        let kw = keyword(&tok.token);
+        if let Some(depth) = asof_depth.as_mut() {
+            match (&tok.token, kw) {
+                (Token::LParen, _) => *depth += 1,
+                (Token::RParen, _) => *depth = depth.saturating_sub(1),
+                (_, Some(Keyword::MATCH_CONDITION)) if *depth == 0 => asof_depth = None,
+                (_, Some(Keyword::ON | Keyword::USING)) if *depth == 0 => {
+                    rewritten.extend(
+                        [
+                            Token::make_keyword("MATCH_CONDITION"),
+                            Token::LParen,
+                            Token::make_keyword("TRUE"),
+                            Token::RParen,
+                            Token::Whitespace(Whitespace::Space),
+                        ]
+                        .map(TokenWithSpan::wrap),
+                    );
+                    asof_depth = None;
+                },
+                _ => {},
+            }
+        } else if asof_keyword && kw == Some(Keyword::JOIN) {
+            asof_depth = Some(0);
+        }
+        asof_keyword =
+            kw == Some(Keyword::ASOF) && prev_token.as_ref().is_some_and(ends_table_factor);
+        prev_token = Some(tok.token.clone());
+        rewritten.push(tok);
+    }
+    rewritten
+}
+
+/// Whether `token` can be the last token of a table factor (a table name, an alias, or
+/// the closing parenthesis of a derived table or table function).
+fn ends_table_factor(token: &Token) -> bool {
+    match token {
+        Token::RParen => true,
+        Token::Word(Word { keyword, .. }) => !matches!(
+            keyword,
+            Keyword::FROM | Keyword::JOIN | Keyword::AS | Keyword::LATERAL | Keyword::ON
+        ),
+        _ => false,
+    }
+}
+
+impl SQLContext {
+    pub(crate) fn process_asof_join(
+        &mut self,
+        tbl_left: &TableInfo,
+        tbl_right: &TableInfo,
+        match_condition: &SQLExpr,
+        constraint: &JoinConstraint,
+    ) -> PolarsResult<LazyFrame> {
+        let mut conditions: Vec<&SQLExpr> = flatten_and_conditions(match_condition)
+            .into_iter()
+            .filter(|cond| {
+                !matches!(
+                    cond,
+                    SQLExpr::Value(ValueWithSpan {
+                        value: SQLValue::Boolean(true),
+                        ..
+                    })
+                )
+            })
+            .collect();
+
+        let mut left_by = vec![];
+        let mut right_by = vec![];
+        let mut inequality = None;
+        match constraint {
+            JoinConstraint::On(expr) => conditions.extend(flatten_and_conditions(expr)),
+            JoinConstraint::Using(idents) => {
+                let names = idents
+                    .iter()
+                    .map(|ObjectName(parts)| match parts.as_slice() {
+                        [part] if part.as_ident().is_some() => {
+                            Ok(PlSmallStr::from_str(&part.as_ident().unwrap().value))
+                        },
+                        _ => polars_bail!(SQLSyntax: "JOIN \"USING\" clause expects simple column names, not qualified names"),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                if let Some((on, by)) = names.split_last() {
+                    if conditions.is_empty() {
+                        inequality = Some((on.clone(), on.clone(), AsofStrategy::Backward, true));
+                    } else {
+                        left_by.push(on.clone());
+                        right_by.push(on.clone());
+                    }
+                    left_by.extend(by.iter().cloned());
+                    right_by.extend(by.iter().cloned());
+                }
+            },
+            JoinConstraint::None => {},
+            JoinConstraint::Natural => {
+                polars_bail!(SQLInterface: "NATURAL ASOF JOIN is not supported")
+            },
+        }
+
+        let join_schema = build_join_schema(tbl_left, tbl_right)?;
+        for cond in conditions {
+            let SQLExpr::BinaryOp {
+                left,
+                op:
+                    op @ (SQLBinaryOperator::Eq
+                    | SQLBinaryOperator::Lt
+                    | SQLBinaryOperator::LtEq
+                    | SQLBinaryOperator::Gt
+                    | SQLBinaryOperator::GtEq),
+                right,
+            } = cond
+            else {
+                polars_bail!(SQLInterface: "unsupported ASOF JOIN condition: {}", cond)
+            };
+            let (left_on, right_on) =
+                determine_left_right_join_on(self, left, right, tbl_left, tbl_right, &join_schema)?;
+            let (left_on, right_on) = (column_name(&left_on[0])?, column_name(&right_on[0])?);
+            let op = if asof_operands_swapped(
+                self,
+                left,
+                &left_on,
+                tbl_left,
+                tbl_right,
+                &join_schema,
+            )? {
+                match op {
+                    SQLBinaryOperator::Lt => SQLBinaryOperator::Gt,
+                    SQLBinaryOperator::LtEq => SQLBinaryOperator::GtEq,
+                    SQLBinaryOperator::Gt => SQLBinaryOperator::Lt,
+                    SQLBinaryOperator::GtEq => SQLBinaryOperator::LtEq,
+                    op => op.clone(),
+                }
+            } else {
+                op.clone()
+            };
+            let (strategy, allow_eq) = match op {
+                SQLBinaryOperator::Eq => {
+                    left_by.push(left_on);
+                    right_by.push(right_on);
+                    continue;
+                },
+                SQLBinaryOperator::GtEq => (AsofStrategy::Backward, true),
+                SQLBinaryOperator::Gt => (AsofStrategy::Backward, false),
+                SQLBinaryOperator::LtEq => (AsofStrategy::Forward, true),
+                SQLBinaryOperator::Lt => (AsofStrategy::Forward, false),
+                _ => unreachable!(),
+            };
+            polars_ensure!(
+                inequality.is_none(),
+                SQLInterface: "ASOF JOIN supports only one inequality condition"
+            );
+            inequality = Some((left_on, right_on, strategy, allow_eq));
+        }
+        let Some((left_on, right_on, strategy, allow_eq)) = inequality else {
+            polars_bail!(SQLSyntax: "ASOF JOIN requires an inequality condition (eg: `a.ts >= b.ts`)")
+        };
+
+        // The asof kernels require (non-null) sorted keys; null keys can never match.
+        let lf = tbl_left
+            .frame
+            .clone()
+            .filter(col(left_on.clone()).is_not_null())
+            .sort([left_on.clone()], Default::default());
+        let rf = tbl_right
+            .frame
+            .clone()
+            .filter(col(right_on.clone()).is_not_null())
+            .sort([right_on.clone()], Default::default())
+            .with_column(lit(true).alias(ASOF_MATCH_NAME));
+
+        let (left_by, right_by) = if left_by.is_empty() {
+            (None, None)
+        } else {
+            (Some(left_by), Some(right_by))
+        };
+        Ok(lf
+            .join_builder()
+            .with(rf)
+            .left_on([col(left_on)])
+            .right_on([col(right_on)])
+            .how(JoinType::AsOf(Box::new(AsOfOptions {
+                strategy,
+                left_by,
+                right_by,
+                allow_eq,
+                ..Default::default()
+            })))
+            .suffix(format!(":{}", tbl_right.name))
+            .coalesce(JoinCoalesce::KeepColumns)
+            .finish()
+            .filter(col(ASOF_MATCH_NAME).is_not_null())
+            .drop(cols([ASOF_MATCH_NAME])))
+    }
+}
+
+/// Check whether the left operand of a join condition refers to the right table.
+fn asof_operands_swapped(
+    ctx: &mut SQLContext,
+    left: &SQLExpr,
+    left_on: &PlSmallStr,
+    tbl_left: &TableInfo,
+    tbl_right: &TableInfo,
+    join_schema: &Schema,
+) -> PolarsResult<bool> {
+    if expr_refers_to_table(left, &tbl_left.name) {
+        return Ok(false);
+    } else if expr_refers_to_table(left, &tbl_right.name) {
+        return Ok(true);
+    }
+    // unqualified: compare with the column resolved for the left table
+    let left = match parse_sql_expr(left, ctx, Some(join_schema))? {
+        Expr::Alias(inner, _) => Arc::unwrap_or_clone(inner),
+        e => e,
+    };
+    Ok(column_name(&left)? != *left_on)
+}
+
+fn column_name(expr: &Expr) -> PolarsResult<PlSmallStr> {
+    match expr {
+        Expr::Column(name) => Ok(name.clone()),
+        _ => {
+            polars_bail!(SQLInterface: "ASOF JOIN conditions must compare columns; found {:?}", expr)
+        },
+    }
+}
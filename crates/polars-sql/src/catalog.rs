@@ -0,0 +1,283 @@
+//! Views and catalog statements (`SHOW VIEWS`, `DESCRIBE`, `SHOW COLUMNS`).
+//!
+//! A view stores its defining query rather than a frame; each statement that refers to
+//! the view executes the query again, so it reflects the tables registered at that time.
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_utils::aliases::PlHashSet;
+use sqlparser::ast::{
+    CreateTableOptions, CreateView, ObjectName, Query, ShowStatementIn, ShowStatementOptions,
+    Statement, Visit,
+};
+
+use crate::SQLContext;
+use crate::sql_visitors::TableIdentifierCollector;
+
+/// A view registered with `CREATE VIEW`.
+#[derive(Clone)]
+pub(crate) struct SQLView {
+    query: Query,
+    /// Output column names given in `CREATE VIEW <name> (<col>, ...)`.
+    columns: Vec<PlSmallStr>,
+}
+
+impl SQLContext {
+    /// Get the names of all views created with `CREATE VIEW`, in sorted order.
+    pub fn get_views(&self) -> Vec<String> {
+        let mut views = Vec::from_iter(self.view_map.read().unwrap().keys().cloned());
+        views.sort_unstable();
+        views
+    }
+
+    // CREATE [OR REPLACE] [TEMPORARY] VIEW [IF NOT EXISTS] <name> [(<col>, ...)] AS <query>
+    pub(crate) fn execute_create_view(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        // Destructure exhaustively so new sqlparser fields surface as compile errors.
+        let Statement::CreateView(CreateView {
+            or_alter,
+            or_replace,
+            materialized,
+            secure,
+            name,
+            name_before_not_exists: _,
+            columns,
+            query,
+            options,
+            cluster_by,
+            comment,
+            with_no_schema_binding,
+            if_not_exists,
+            temporary: _, // views only live as long as the context
+            copy_grants,
+            to,
+            params,
+        }) = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected CREATE VIEW")
+        };
+
+        polars_ensure!(!materialized, SQLInterface: "`CREATE MATERIALIZED VIEW` is not supported; use `CREATE TABLE ... AS` instead");
+        polars_ensure!(!secure, SQLInterface: "`CREATE SECURE VIEW` is not supported");
+        polars_ensure!(!copy_grants, SQLInterface: "`COPY GRANTS` is not supported");
+        polars_ensure!(!with_no_schema_binding, SQLInterface: "`WITH NO SCHEMA BINDING` is not supported");
+        polars_ensure!(to.is_none(), SQLInterface: "`CREATE VIEW ... TO` is not supported");
+        polars_ensure!(params.is_none(), SQLInterface: "view `ALGORITHM`/`DEFINER`/`SQL SECURITY` parameters are not supported");
+        polars_ensure!(matches!(options, CreateTableOptions::None), SQLInterface: "view `WITH`/`OPTIONS` clauses are not supported");
+        polars_ensure!(cluster_by.is_empty(), SQLInterface: "view `CLUSTER BY` clauses are not supported");
+        polars_ensure!(comment.is_none(), SQLInterface: "view `COMMENT` clauses are not supported");
+        polars_ensure!(
+            columns.iter().all(|c| c.data_type.is_none() && c.options.is_none()),
+            SQLInterface: "view column types and options are not supported"
+        );
+
+        let view_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
+        if self.table_map.read().unwrap().contains_key(view_name) {
+            polars_bail!(SQLInterface: "relation '{}' already exists as a table", view_name);
+        }
+        let response = df! { "Response" => [format!("CREATE VIEW {view_name}")] }?.lazy();
+        if self.view_map.read().unwrap().contains_key(view_name) {
+            if *if_not_exists {
+                return Ok(response);
+            }
+            polars_ensure!(
+                *or_replace || *or_alter,
+                SQLInterface: "view '{}' already exists; use `CREATE OR REPLACE VIEW` to replace it", view_name
+            );
+        }
+        self.ensure_no_view_cycle(view_name, query)?;
+
+        let view = SQLView {
+            query: (**query).clone(),
+            columns: columns
+                .iter()
+                .map(|c| c.name.value.as_str().into())
+                .collect(),
+        };
+        // Resolve the view once so that invalid queries are rejected up-front.
+        let mut lf = self.expand_view(&view)?;
+        self.get_frame_schema(&mut lf)?;
+
+        self.view_map
+            .write()
+            .unwrap()
+            .insert(view_name.to_string(), view);
+        Ok(response)
+    }
+
+    // SHOW VIEWS
+    pub(crate) fn execute_show_views(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::ShowViews {
+            terse: _,
+            materialized,
+            show_options,
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected SHOW VIEWS")
+        };
+        polars_ensure!(!materialized, SQLInterface: "`SHOW MATERIALIZED VIEWS` is not supported");
+        let ShowStatementOptions {
+            show_in,
+            starts_with,
+            limit,
+            limit_from,
+            filter_position,
+        } = show_options;
+        polars_ensure!(
+            show_in.is_none()
+                && starts_with.is_none()
+                && limit.is_none()
+                && limit_from.is_none()
+                && filter_position.is_none(),
+            SQLInterface: "`SHOW VIEWS` does not support filter, scope or limit options"
+        );
+
+        let views = Column::new("name".into(), self.get_views());
+        let df = DataFrame::new_infer_height(vec![views])?;
+        Ok(df.lazy())
+    }
+
+    // DESCRIBE <name>
+    pub(crate) fn execute_describe(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::ExplainTable {
+            describe_alias: _,
+            hive_format,
+            has_table_keyword: _,
+            table_name,
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected DESCRIBE")
+        };
+        polars_ensure!(hive_format.is_none(), SQLInterface: "`DESCRIBE EXTENDED/FORMATTED` is not supported");
+        self.describe_relation(table_name)
+    }
+
+    // SHOW COLUMNS {FROM | IN} <name>
+    pub(crate) fn execute_show_columns(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::ShowColumns {
+            extended,
+            full,
+            show_options,
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected SHOW COLUMNS")
+        };
+        polars_ensure!(!extended && !full, SQLInterface: "`SHOW EXTENDED/FULL COLUMNS` is not supported");
+
+        let ShowStatementOptions {
+            show_in,
+            starts_with,
+            limit,
+            limit_from,
+            filter_position,
+        } = show_options;
+        polars_ensure!(
+            starts_with.is_none() && limit.is_none() && limit_from.is_none() && filter_position.is_none(),
+            SQLInterface: "`SHOW COLUMNS` does not support filter or limit options"
+        );
+        let Some(ShowStatementIn {
+            clause: _,
+            parent_type: None,
+            parent_name: Some(name),
+        }) = show_in
+        else {
+            polars_bail!(SQLInterface: "`SHOW COLUMNS` expects `FROM <name>`")
+        };
+        self.describe_relation(name)
+    }
+
+    /// Make the view `name` available to the current statement (unless a table or CTE
+    /// of the same name takes precedence).
+    pub(crate) fn resolve_view(&mut self, name: &str) -> PolarsResult<()> {
+        if self.table_map.read().unwrap().contains_key(name) || self.cte_map.contains_key(name) {
+            return Ok(());
+        }
+        let view = self.view_map.read().unwrap().get(name).cloned();
+        if let Some(view) = view {
+            let lf = self.expand_view(&view)?;
+            self.cte_map.insert(name.to_string(), lf);
+        }
+        Ok(())
+    }
+
+    fn expand_view(&mut self, view: &SQLView) -> PolarsResult<LazyFrame> {
+        // The view query only sees the registered tables and views, not the CTEs
+        // and windows of the statement that refers to it.
+        let mut lf = self.execute_isolated(|ctx| {
+            ctx.cte_map.clear();
+            ctx.named_windows.clear();
+            ctx.execute_query(&view.query)
+        })?;
+        if !view.columns.is_empty() {
+            let schema = self.get_frame_schema(&mut lf)?;
+            polars_ensure!(
+                view.columns.len() == schema.len(),
+                SQLSyntax: "number of view columns ({}) does not match the number of columns in the query ({})",
+                view.columns.len(), schema.len()
+            );
+            let existing_columns: Vec<_> = schema.iter_names().cloned().collect();
+            lf = lf.rename(existing_columns, view.columns.clone(), true);
+        }
+        Ok(lf)
+    }
+
+    fn ensure_no_view_cycle(&self, view_name: &str, query: &Query) -> PolarsResult<()> {
+        let views = self.view_map.read().unwrap();
+        let mut seen = PlHashSet::new();
+        let mut pending = referenced_tables(query);
+        while let Some(name) = pending.pop() {
+            polars_ensure!(
+                name != view_name,
+                SQLInterface: "view '{}' cannot reference itself", view_name
+            );
+            if let Some(view) = views.get(&name).filter(|_| seen.insert(name.clone())) {
+                pending.extend(referenced_tables(&view.query));
+            }
+        }
+        Ok(())
+    }
+
+    fn describe_relation(&mut self, name: &ObjectName) -> PolarsResult<LazyFrame> {
+        let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
+        self.resolve_view(tbl_name)?;
+        let Some(mut lf) = self.get_table_from_current_scope(tbl_name) else {
+            polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name)
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let df = df! {
+            "column_name" => schema.iter_names().map(|name| name.as_str()).collect::<Vec<_>>(),
+            "data_type" => schema.iter_values().map(|dtype| dtype.to_string()).collect::<Vec<_>>(),
+        }?;
+        Ok(df.lazy())
+    }
+}
+
+fn referenced_tables(query: &Query) -> Vec<String> {
+    let mut collector = TableIdentifierCollector::default();
+    let _ = query.visit(&mut collector);
+    collector.tables
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::df;
+    use polars_lazy::prelude::*;
+
+    use crate::SQLContext;
+
+    #[test]
+    fn test_isolated_context_views() {
+        let mut ctx = SQLContext::new();
+        ctx.register("t", df!("a" => [1, 2]).unwrap().lazy());
+        ctx.execute("CREATE VIEW v1 AS SELECT a FROM t").unwrap();
+
+        // Views of the parent are visible in an isolated context, but views created there are
+        // not visible in the parent.
+        let mut isolated = ctx.isolated();
+        isolated
+            .execute("CREATE VIEW v2 AS SELECT * FROM v1")
+            .unwrap();
+        assert_eq!(isolated.get_views(), ["v1", "v2"]);
+        assert_eq!(ctx.get_views(), ["v1"]);
+        assert!(ctx.execute("SELECT * FROM v2").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use std::ops::{ControlFlow, Deref};
 use std::sync::RwLock;
 
 use polars_core::frame::row::Row;
@@ -12,16 +12,18 @@ use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
     BinaryOperator as SQLBinaryOperator, CreateTable, CreateTableLikeKind, CreateTableOptions,
     Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, Fetch, FromTable, FunctionArg,
-    GroupByExpr, HiveDistributionStyle, HiveFormat, Ident, JoinConstraint, JoinOperator,
-    LimitClause, NamedWindowDefinition, NamedWindowExpr, ObjectName, ObjectType, OrderBy,
-    OrderByKind, Query, RenameSelectItem, Select, SelectFlavor, SelectItem,
+    GroupByExpr, GroupByWithModifier, HiveDistributionStyle, HiveFormat, Ident, JoinConstraint,
+    JoinOperator, LimitClause, NamedWindowDefinition, NamedWindowExpr, ObjectName, ObjectType,
+    OrderBy, OrderByKind, Query, RenameSelectItem, Select, SelectFlavor, SelectItem,
     SelectItemQualifiedWildcardKind, SetExpr, SetOperator, SetQuantifier, Statement, TableAlias,
     TableFactor, TableWithJoins, Truncate, UnaryOperator as SQLUnaryOperator, Value as SQLValue,
-    ValueWithSpan, Values, Visit, WildcardAdditionalOptions, WindowSpec,
+    ValueWithSpan, Values, Visit, WildcardAdditionalOptions, WindowSpec, visit_expressions,
 };
 use sqlparser::dialect::GenericDialect;
-use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::parser::{Parser, ParserError, ParserOptions};
+use sqlparser::tokenizer::Tokenizer;
 
+use crate::catalog::SQLView;
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
@@ -156,25 +158,38 @@ pub(crate) enum FilterMode {
 #[derive(Clone)]
 pub struct SQLContext {
     pub(crate) table_map: Arc<RwLock<PlHashMap<String, LazyFrame>>>,
+    pub(crate) view_map: Arc<RwLock<PlHashMap<String, SQLView>>>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
-    cte_map: PlHashMap<String, LazyFrame>,
+    pub(crate) cte_map: PlHashMap<String, LazyFrame>,
     table_aliases: PlHashMap<String, String>,
-    joined_aliases: PlHashMap<String, PlHashMap<String, String>>,
+    pub(crate) joined_aliases: PlHashMap<String, PlHashMap<String, String>>,
     pub(crate) named_windows: PlHashMap<String, WindowSpec>,
+    /// The GROUP BY key expressions of the SELECT being translated (for `GROUPING`).
+    pub(crate) grouping_keys: Option<Vec<SQLExpr>>,
+    pub(crate) recursion_limit: usize,
 }
 
+/// Name of the temporary column holding the grouping set id during a GROUP BY.
+pub(crate) const GROUPING_ID_NAME: &str = "__POLARS_GROUPING_ID";
+
+/// The default maximum number of iterations when evaluating a recursive CTE.
+pub(crate) const DEFAULT_RECURSION_LIMIT: usize = 1000;
+
 impl Default for SQLContext {
     fn default() -> Self {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
             table_map: Default::default(),
+            view_map: Default::default(),
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
             named_windows: Default::default(),
+            grouping_keys: Default::default(),
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -243,18 +258,7 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
-
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
-
+        let ast = parse_statements(query)?;
         polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
         let res = self.execute_statement(ast.first().unwrap())?;
 
@@ -280,6 +284,13 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations used to evaluate a `WITH RECURSIVE` CTE
+    /// (defaults to 1000); exceeding it raises an error.
+    pub fn with_recursion_limit(mut self, limit: usize) -> Self {
+        self.recursion_limit = limit;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -296,6 +307,9 @@ impl SQLContext {
         Self {
             // Deep clone to isolate
             table_map: Arc::new(RwLock::new(self.table_map.read().unwrap().clone())),
+            view_map: Arc::new(RwLock::new(self.view_map.read().unwrap().clone())),
+            function_registry: self.function_registry.clone(),
+            recursion_limit: self.recursion_limit,
             named_windows: self.named_windows.clone(),
             cte_map: self.cte_map.clone(),
 
@@ -308,14 +322,21 @@ impl SQLContext {
         Ok(match ast {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
+            stmt @ Statement::ShowViews { .. } => self.execute_show_views(stmt)?,
+            stmt @ Statement::ShowColumns { .. } => self.execute_show_columns(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
+            stmt @ Statement::CreateView(_) => self.execute_create_view(stmt)?,
             stmt @ Statement::Drop {
-                object_type: ObjectType::Table,
+                object_type: ObjectType::Table | ObjectType::View,
                 ..
             } => self.execute_drop_table(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
+            stmt @ Statement::ExplainTable { .. } => self.execute_describe(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert(_) => self.execute_insert(stmt)?,
+            stmt @ Statement::Update(_) => self.execute_update(stmt)?,
+            stmt @ Statement::Merge(_) => self.execute_merge(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -482,7 +503,11 @@ impl SQLContext {
         column_name.to_string()
     }
 
-    fn process_query(&mut self, expr: &SetExpr, query: &Query) -> PolarsResult<LazyFrame> {
+    pub(crate) fn process_query(
+        &mut self,
+        expr: &SetExpr,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
         match expr {
             SetExpr::Select(select_stmt) => self.execute_select(select_stmt, query),
             SetExpr::Query(nested_query) => {
@@ -512,6 +537,7 @@ impl SQLContext {
 
             SetExpr::Table(tbl) => {
                 if let Some(table_name) = tbl.table_name.as_ref() {
+                    self.resolve_view(table_name)?;
                     self.get_table_from_current_scope(table_name)
                         .ok_or_else(|| {
                             polars_err!(
@@ -745,11 +771,11 @@ impl SQLContext {
         Ok(df.lazy())
     }
 
-    // DROP TABLE <tbl>
+    // DROP {TABLE | VIEW} <name>
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         // Destructure exhaustively so new sqlparser fields surface as compile errors.
         if let Statement::Drop {
-            object_type: _,
+            object_type,
             names,
             if_exists,
 
@@ -769,10 +795,20 @@ impl SQLContext {
 
             for name in names {
                 let tbl = name.to_string();
+                let (dropped, kind) = match object_type {
+                    ObjectType::View => (
+                        self.view_map.write().unwrap().remove(&tbl).is_some(),
+                        "view",
+                    ),
+                    _ => (
+                        self.table_map.write().unwrap().remove(&tbl).is_some(),
+                        "table",
+                    ),
+                };
                 // `DROP TABLE IF EXISTS <tbl>` is a no-op on a missing table;
                 // otherwise dropping a table that doesn't exist is an error.
-                if self.table_map.write().unwrap().remove(&tbl).is_none() && !if_exists {
-                    polars_bail!(SQLInterface: "table '{}' does not exist", tbl);
+                if !dropped && !if_exists {
+                    polars_bail!(SQLInterface: "{} '{}' does not exist", kind, tbl);
                 }
             }
             Ok(DataFrame::empty().lazy())
@@ -883,14 +919,16 @@ impl SQLContext {
         self.cte_map.insert(name.to_owned(), lf);
     }
 
-    fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
+    pub(crate) fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 // Note: isolate CTE execution to prevent context state leakage
                 let cte_name = cte.alias.name.value.clone();
+                if with.recursive && self.is_recursive_cte(cte) {
+                    let lf = self.execute_recursive_cte(cte)?;
+                    self.register_cte(&cte_name, lf);
+                    continue;
+                }
                 let mut lf = self.execute_isolated(|ctx| ctx.execute_query(&cte.query))?;
                 lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
                 self.register_cte(&cte_name, lf);
@@ -948,6 +986,27 @@ impl SQLContext {
                     }
                 }
 
+                // Decorrelate "[LEFT] JOIN LATERAL (subquery)" into a join on its correlation keys
+                if let TableFactor::Derived {
+                    lateral: true,
+                    subquery,
+                    alias,
+                    sample: None,
+                } = &join.relation
+                {
+                    let left_schema = self.get_frame_schema(&mut lf)?;
+                    let (r_name, right_schema);
+                    (lf, r_name, right_schema) = self.process_lateral_join(
+                        lf,
+                        &left_schema,
+                        &join.join_operator,
+                        subquery,
+                        alias,
+                    )?;
+                    self.track_joined_aliases(&mut lf, r_name, &left_schema, &right_schema)?;
+                    continue;
+                }
+
                 let (r_name, mut rf) = self.get_table(&join.relation)?;
                 if r_name.is_empty() {
                     // Require non-empty to avoid duplicate column errors from nested self-joins.
@@ -1014,6 +1073,24 @@ impl SQLContext {
                             },
                         )?
                     },
+                    #[cfg(feature = "asof_join")]
+                    JoinOperator::AsOf {
+                        match_condition,
+                        constraint,
+                    } => self.process_asof_join(
+                        &TableInfo {
+                            frame: lf,
+                            name: (&l_name).into(),
+                            schema: left_schema.clone(),
+                        },
+                        &TableInfo {
+                            frame: rf,
+                            name: (&r_name).into(),
+                            schema: right_schema.clone(),
+                        },
+                        match_condition,
+                        constraint,
+                    )?,
                     JoinOperator::CrossJoin(JoinConstraint::None) => {
                         lf.cross_join(rf, Some(format_pl_smallstr!(":{}", r_name)))
                     },
@@ -1029,31 +1106,37 @@ impl SQLContext {
                     },
                 };
 
-                // track join-aliased columns so we can resolve/check them later
-                let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.insert(
-                    r_name.clone(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{name}:{r_name}");
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.track_joined_aliases(&mut lf, r_name, &left_schema, &right_schema)?;
             }
         };
         Ok(lf)
     }
 
+    /// Track join-aliased columns so we can resolve/check them later.
+    pub(crate) fn track_joined_aliases(
+        &mut self,
+        lf: &mut LazyFrame,
+        r_name: String,
+        left_schema: &Schema,
+        right_schema: &Schema,
+    ) -> PolarsResult<()> {
+        let joined_schema = self.get_frame_schema(lf)?;
+        let aliases = right_schema
+            .iter_names()
+            .filter_map(|name| {
+                // col exists in both tables and is aliased in the joined result
+                let aliased_name = format!("{name}:{r_name}");
+                if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                    Some((name.to_string(), aliased_name))
+                } else {
+                    None
+                }
+            })
+            .collect::<PlHashMap<String, String>>();
+        self.joined_aliases.insert(r_name, aliases);
+        Ok(())
+    }
+
     /// Check that the SELECT statement only contains supported clauses.
     fn validate_select(&self, select_stmt: &Select) -> PolarsResult<()> {
         // Destructure "Select" exhaustively; that way if/when new fields are added in
@@ -1163,7 +1246,7 @@ impl SQLContext {
             ref require_user,
             ref snapshot,
             ref strict,
-            ref temporary,
+            temporary: _, // tables only live as long as the context
             ref transient,
             ref volatile,
             ref without_rowid,
@@ -1220,7 +1303,6 @@ impl SQLContext {
         polars_ensure!(!require_user, SQLInterface: "`REQUIRE USER` is not supported");
         polars_ensure!(!snapshot, SQLInterface: "`CREATE SNAPSHOT TABLE` is not supported");
         polars_ensure!(!strict, SQLInterface: "`STRICT` tables are not supported");
-        polars_ensure!(!temporary, SQLInterface: "`CREATE TEMPORARY TABLE` is not supported");
         polars_ensure!(!transient, SQLInterface: "`CREATE TRANSIENT TABLE` is not supported");
         polars_ensure!(!volatile, SQLInterface: "`CREATE VOLATILE TABLE` is not supported");
         polars_ensure!(!without_rowid, SQLInterface: "`WITHOUT ROWID` is not supported");
@@ -1311,6 +1393,18 @@ impl SQLContext {
 
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
+        // The GROUP BY keys only apply to this SELECT; restore those of any enclosing one.
+        let outer_grouping_keys = self.grouping_keys.take();
+        let out = self.execute_select_impl(select_stmt, query);
+        self.grouping_keys = outer_grouping_keys;
+        out
+    }
+
+    fn execute_select_impl(
+        &mut self,
+        select_stmt: &Select,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
         // Check that the statement doesn't contain unsupported SELECT clauses
         self.validate_select(select_stmt)?;
 
@@ -1405,8 +1499,37 @@ impl SQLContext {
             PlHashSet::new()
         };
 
-        let mut projections =
-            self.column_projections(select_stmt, &schema, &mut select_modifiers)?;
+        // Expand any ROLLUP/CUBE/GROUPING SETS in the GROUP BY clause up-front, so that
+        // `GROUPING(...)` calls in the projections can be resolved against the keys.
+        let (grouping_key_exprs, grouping_sets) = match &select_stmt.group_by {
+            GroupByExpr::Expressions(group_by_exprs, modifiers) => {
+                let (keys, sets) = expand_grouping_sets(group_by_exprs, modifiers)?;
+                (Some(keys), sets)
+            },
+            GroupByExpr::All(modifiers) => {
+                if !modifiers.is_empty() {
+                    polars_bail!(SQLInterface: "GROUP BY ALL does not support CUBE, ROLLUP, TOTALS or GROUPING SETS modifiers")
+                }
+                (None, None)
+            },
+        };
+        self.grouping_keys = grouping_key_exprs.clone().filter(|keys| !keys.is_empty());
+
+        // Join the values of any scalar subqueries in the projections to the frame,
+        // referring to them as (temporary) columns named after the subquery result
+        let rewritten_select;
+        let subquery_columns;
+        (lf, subquery_columns, rewritten_select) =
+            self.rewrite_projection_subqueries(lf, select_stmt, &schema)?;
+        if !subquery_columns.is_empty() {
+            schema = self.get_frame_schema(&mut lf)?;
+        }
+
+        let mut projections = self.column_projections(
+            rewritten_select.as_ref().unwrap_or(select_stmt),
+            &schema,
+            &mut select_modifiers,
+        )?;
 
         // Apply `UNNEST` expressions
         let mut explode_names = Vec::new();
@@ -1463,16 +1586,25 @@ impl SQLContext {
             schema = self.get_frame_schema(&mut lf)?;
         }
 
+        // Make the grouping set id available to `GROUPING(...)` (it is always zero for
+        // a plain GROUP BY; grouping sets overwrite it per set).
+        if grouping_sets.is_some() || uses_grouping_function(select_stmt) {
+            lf = lf.with_column(lit(0u64).alias(GROUPING_ID_NAME));
+            schema = self.get_frame_schema(&mut lf)?;
+        }
+
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
         match &select_stmt.group_by {
             // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
-            GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
+            GroupByExpr::Expressions(group_by_exprs, _) => {
+                // With grouping sets, the keys are the distinct expressions of all sets.
+                let key_exprs = match (&grouping_sets, &grouping_key_exprs) {
+                    (Some(_), Some(keys)) => keys.as_slice(),
+                    _ => group_by_exprs.as_slice(),
+                };
                 // Translate the group expressions, resolving ordinal values and SELECT aliases
-                group_by_keys = group_by_exprs
+                group_by_keys = key_exprs
                     .iter()
                     .map(|e| match e {
                         SQLExpr::Identifier(ident) => {
@@ -1495,10 +1627,7 @@ impl SQLContext {
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
-            GroupByExpr::All(modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
+            GroupByExpr::All(_) => {
                 projections.iter().for_each(|expr| match expr {
                     // immediately match the most common cases (col|agg|len|lit, optionally aliased).
                     Expr::Agg(_) | Expr::Len | Expr::Literal(_) => (),
@@ -1640,7 +1769,13 @@ impl SQLContext {
                 .as_ref()
                 .map(|expr| parse_sql_expr(expr, self, Some(&schema)))
                 .transpose()?;
-            lf = self.process_group_by(lf, &group_by_keys, &projections, having)?;
+            lf = self.process_group_by(
+                lf,
+                &group_by_keys,
+                grouping_sets.as_deref(),
+                &projections,
+                having,
+            )?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Drop any extra columns (eg: added to maintain ORDER BY access to original cols)
@@ -1825,6 +1960,13 @@ impl SQLContext {
             (lf, residual_exprs) =
                 self.rewrite_subquery_conjuncts(lf, expr, filter_mode, &schema)?;
 
+            // Join the values of any scalar subqueries to the frame, so that the
+            // filter can refer to them as (temporary) columns.
+            let mut residual_exprs: Vec<SQLExpr> = residual_exprs.into_iter().cloned().collect();
+            let subquery_columns;
+            (lf, subquery_columns) =
+                self.rewrite_scalar_subqueries(lf, residual_exprs.iter_mut(), &schema)?;
+
             let Some(parsed_residual) = residual_exprs
                 .iter()
                 .map(|e| parse_sql_expr(e, self, Some(&*schema)))
@@ -1842,6 +1984,9 @@ impl SQLContext {
                 FilterMode::KeepTrue => lf.filter(filter_expression),
                 FilterMode::RemoveTrue => lf.remove(filter_expression),
             };
+            if !subquery_columns.is_empty() {
+                lf = lf.drop(cols(subquery_columns.into_iter().map(|(name, _)| name)));
+            }
         }
         Ok(lf)
     }
@@ -1921,6 +2066,30 @@ impl SQLContext {
             }
         }
         for tbl_expr in from.iter().skip(1) {
+            // "FROM tbl, LATERAL (subquery) alias" is an inner lateral join
+            if let (
+                TableFactor::Derived {
+                    lateral: true,
+                    subquery,
+                    alias,
+                    sample: None,
+                },
+                [],
+            ) = (&tbl_expr.relation, tbl_expr.joins.as_slice())
+            {
+                let left_schema = self.get_frame_schema(lf)?;
+                let (joined, r_name, right_schema) = self.process_lateral_join(
+                    lf.clone(),
+                    &left_schema,
+                    &JoinOperator::CrossJoin(JoinConstraint::None),
+                    subquery,
+                    alias,
+                )?;
+                *lf = joined;
+                self.track_joined_aliases(lf, r_name.clone(), &left_schema, &right_schema)?;
+                joined_table_names.push(r_name);
+                continue;
+            }
             let mut rf = self.execute_from_statement(tbl_expr)?;
             let r_name = get_table_name(&tbl_expr.relation).unwrap_or_default();
             polars_ensure!(
@@ -1956,23 +2125,7 @@ impl SQLContext {
             remaining_where = residual;
 
             // Track join-aliased columns for later resolution
-            let joined_schema = self.get_frame_schema(lf)?;
-            self.joined_aliases.insert(
-                r_name.clone(),
-                right_schema
-                    .iter_names()
-                    .filter_map(|name| {
-                        let aliased_name = format!("{name}:{r_name}");
-                        if left_schema.contains(name)
-                            && joined_schema.contains(aliased_name.as_str())
-                        {
-                            Some((name.to_string(), aliased_name))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<PlHashMap<String, String>>(),
-            );
+            self.track_joined_aliases(lf, r_name.clone(), &left_schema, &right_schema)?;
             joined_table_names.push(r_name);
             for join in &tbl_expr.joins {
                 if let Some(name) = get_table_name(&join.relation) {
@@ -2011,7 +2164,7 @@ impl SQLContext {
         Ok(lf)
     }
 
-    fn process_subqueries(
+    pub(crate) fn process_subqueries(
         &mut self,
         lf: LazyFrame,
         exprs: Vec<&mut Expr>,
@@ -2071,6 +2224,9 @@ impl SQLContext {
             if *if_not_exists && self.table_map.read().unwrap().contains_key(tbl_name) {
                 polars_bail!(SQLInterface: "relation '{}' already exists", tbl_name);
             }
+            if self.view_map.read().unwrap().contains_key(tbl_name) {
+                polars_bail!(SQLInterface: "relation '{}' already exists as a view", tbl_name);
+            }
             let lf = match (query, columns.is_empty(), like) {
                 (Some(query), true, None) => {
                     // ----------------------------------------------------
@@ -2135,7 +2291,10 @@ impl SQLContext {
         }
     }
 
-    fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
+    pub(crate) fn get_table(
+        &mut self,
+        relation: &TableFactor,
+    ) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
                 name, alias, args, ..
@@ -2145,6 +2304,7 @@ impl SQLContext {
                     return self.execute_table_function(name, alias, &args.args);
                 }
                 let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
+                self.resolve_view(tbl_name)?;
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
                     match alias {
                         Some(alias) => {
@@ -2164,7 +2324,7 @@ impl SQLContext {
                 alias,
                 sample,
             } => {
-                polars_ensure!(!(*lateral), SQLInterface: "`LATERAL` clause is not supported");
+                polars_ensure!(!(*lateral), SQLInterface: "`LATERAL` subqueries are only supported as the right side of a join");
                 polars_ensure!(sample.is_none(), SQLInterface: "table `SAMPLE` clause is not supported");
 
                 // Execute the subquery in isolation so that outer join state
@@ -2259,6 +2419,31 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            #[cfg(feature = "pivot")]
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => self.execute_pivot(
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            ),
+            #[cfg(feature = "pivot")]
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                null_inclusion,
+                alias,
+            } => self.execute_unpivot(table, value, name, columns, null_inclusion, alias),
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
@@ -2286,7 +2471,7 @@ impl SQLContext {
         Ok((tbl_name, lf))
     }
 
-    fn process_order_by(
+    pub(crate) fn process_order_by(
         &mut self,
         mut lf: LazyFrame,
         order_by: &Option<OrderBy>,
@@ -2373,6 +2558,7 @@ impl SQLContext {
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
+        grouping_sets: Option<&[Vec<usize>]>,
         projections: &[Expr],
         having: Option<Expr>,
     ) -> PolarsResult<LazyFrame> {
@@ -2538,7 +2724,13 @@ impl SQLContext {
         };
 
         // Apply HAVING filter after aggregation
-        let mut aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let mut aggregated = match grouping_sets {
+            None => lf.group_by(group_by_keys).agg(&aggregation_projection),
+            Some(sets) => lf
+                .group_by_grouping_sets(group_by_keys, sets.to_vec())
+                .with_grouping_id(GROUPING_ID_NAME)
+                .agg(&aggregation_projection)?,
+        };
         if let Some(filter_expr) = having_filter {
             aggregated = aggregated.filter(filter_expr);
         }
@@ -2605,86 +2797,17 @@ impl SQLContext {
         Ok(aggregated.select(&output_projection))
     }
 
-    fn process_limit_offset(
+    pub(crate) fn process_limit_offset(
         &self,
         lf: LazyFrame,
         limit_clause: &Option<LimitClause>,
         fetch: &Option<Fetch>,
     ) -> PolarsResult<LazyFrame> {
-        // Extract limit and offset from LimitClause
-        let (limit, offset) = match limit_clause {
-            Some(LimitClause::LimitOffset {
-                limit,
-                offset,
-                limit_by,
-            }) => {
-                if !limit_by.is_empty() {
-                    // TODO: might be able to support as an aggregate `top_k_by` operation?
-                    //  (https://clickhouse.com/docs/sql-reference/statements/select/limit-by)
-                    polars_bail!(SQLSyntax: "`LIMIT <n> BY <exprs>` clause is not supported");
-                }
-                (limit.as_ref(), offset.as_ref().map(|o| &o.value))
-            },
-            Some(LimitClause::OffsetCommaLimit { offset, limit }) => (Some(limit), Some(offset)),
-            None => (None, None),
-        };
-
-        // Handle FETCH clause (alternative to LIMIT, mutually exclusive)
-        let limit = match (fetch, limit) {
-            (Some(fetch), None) => fetch.quantity.as_ref(),
-            (Some(_), Some(_)) => {
-                polars_bail!(SQLSyntax: "cannot use both `LIMIT` and `FETCH` in the same query")
-            },
-            (None, limit) => limit,
-        };
-
-        // Apply limit and/or offset
-        match (offset, limit) {
-            (
-                Some(SQLExpr::Value(ValueWithSpan {
-                    value: SQLValue::Number(offset, _),
-                    ..
-                })),
-                Some(SQLExpr::Value(ValueWithSpan {
-                    value: SQLValue::Number(limit, _),
-                    ..
-                })),
-            ) => Ok(lf.slice(
-                offset
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "OFFSET conversion error: {}", e))?,
-                limit.parse().map_err(
-                    |e| polars_err!(SQLInterface: "LIMIT/FETCH conversion error: {}", e),
-                )?,
-            )),
-            (
-                Some(SQLExpr::Value(ValueWithSpan {
-                    value: SQLValue::Number(offset, _),
-                    ..
-                })),
-                None,
-            ) => Ok(lf.slice(
-                offset
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "OFFSET conversion error: {}", e))?,
-                IdxSize::MAX,
-            )),
-            (
-                None,
-                Some(SQLExpr::Value(ValueWithSpan {
-                    value: SQLValue::Number(limit, _),
-                    ..
-                })),
-            ) => {
-                Ok(lf.limit(limit.parse().map_err(
-                    |e| polars_err!(SQLInterface: "LIMIT/FETCH conversion error: {}", e),
-                )?))
-            },
-            (None, None) => Ok(lf),
-            _ => polars_bail!(
-                SQLSyntax: "non-numeric arguments for LIMIT/OFFSET/FETCH are not supported",
-            ),
-        }
+        Ok(match parse_limit_offset(limit_clause, fetch)? {
+            (Some(offset), limit) => lf.slice(offset, limit.unwrap_or(IdxSize::MAX)),
+            (None, Some(limit)) => lf.limit(limit),
+            (None, None) => lf,
+        })
     }
 
     fn process_qualified_wildcard(
@@ -2777,7 +2900,7 @@ impl SQLContext {
         Ok(exprs)
     }
 
-    fn rename_columns_from_table_alias(
+    pub(crate) fn rename_columns_from_table_alias(
         &mut self,
         mut lf: LazyFrame,
         alias: &TableAlias,
@@ -2888,6 +3011,153 @@ pub(crate) fn get_table_name(factor: &TableFactor) -> Option<String> {
     }
 }
 
+/// Expand the GROUP BY clause into its distinct key expressions and, if it uses
+/// ROLLUP, CUBE or GROUPING SETS, the grouping sets (as indices into those keys).
+fn expand_grouping_sets(
+    group_by_exprs: &[SQLExpr],
+    modifiers: &[GroupByWithModifier],
+) -> PolarsResult<(Vec<SQLExpr>, Option<Vec<Vec<usize>>>)> {
+    fn key_idx(keys: &mut Vec<SQLExpr>, e: &SQLExpr) -> usize {
+        keys.iter().position(|k| k == e).unwrap_or_else(|| {
+            keys.push(e.clone());
+            keys.len() - 1
+        })
+    }
+    fn tuples_idx(keys: &mut Vec<SQLExpr>, tuples: &[Vec<SQLExpr>]) -> Vec<Vec<usize>> {
+        tuples
+            .iter()
+            .map(|t| t.iter().map(|e| key_idx(keys, e)).collect())
+            .collect()
+    }
+    let mut keys: Vec<SQLExpr> = Vec::with_capacity(group_by_exprs.len());
+
+    // Each GROUP BY element contributes a list of sets; the grouping sets of the clause
+    // are the cross product of those lists.
+    let mut has_sets = false;
+    let mut element_sets = Vec::with_capacity(group_by_exprs.len());
+    for e in group_by_exprs {
+        element_sets.push(match e {
+            SQLExpr::Rollup(tuples) => {
+                has_sets = true;
+                rollup_sets(&tuples_idx(&mut keys, tuples))
+            },
+            SQLExpr::Cube(tuples) => {
+                has_sets = true;
+                cube_sets(&tuples_idx(&mut keys, tuples))?
+            },
+            SQLExpr::GroupingSets(tuples) => {
+                has_sets = true;
+                tuples_idx(&mut keys, tuples)
+            },
+            SQLExpr::Tuple(exprs) if exprs.is_empty() => {
+                has_sets = true;
+                vec![vec![]]
+            },
+            _ => vec![vec![key_idx(&mut keys, e)]],
+        });
+    }
+    let mut sets =
+        element_sets
+            .into_iter()
+            .fold(vec![vec![]], |acc: Vec<Vec<usize>>, elem_sets| {
+                acc.iter()
+                    .flat_map(|set| {
+                        elem_sets
+                            .iter()
+                            .map(move |elem_set| merge_sets([set, elem_set]))
+                    })
+                    .collect()
+            });
+
+    // Modifier forms: `GROUP BY a, b WITH ROLLUP`, `... WITH CUBE` and `GROUP BY a, b
+    // GROUPING SETS ((a, b), (a))`; these apply to the clause as a whole.
+    for modifier in modifiers {
+        polars_ensure!(
+            !has_sets,
+            SQLSyntax: "GROUP BY cannot combine `{}` with ROLLUP, CUBE or GROUPING SETS", modifier
+        );
+        has_sets = true;
+        let all_keys: Vec<Vec<usize>> = (0..keys.len()).map(|idx| vec![idx]).collect();
+        sets = match modifier {
+            GroupByWithModifier::Rollup => rollup_sets(&all_keys),
+            GroupByWithModifier::Cube => cube_sets(&all_keys)?,
+            GroupByWithModifier::GroupingSets(SQLExpr::GroupingSets(tuples)) => {
+                tuples_idx(&mut keys, tuples)
+            },
+            _ => {
+                polars_bail!(SQLInterface: "GROUP BY does not support the `{}` modifier", modifier)
+            },
+        };
+    }
+    Ok((keys, has_sets.then_some(sets)))
+}
+
+/// The union of the given grouping sets, in order of first appearance.
+fn merge_sets<'a>(sets: impl IntoIterator<Item = &'a Vec<usize>>) -> Vec<usize> {
+    let mut merged = Vec::new();
+    for idx in sets.into_iter().flatten() {
+        if !merged.contains(idx) {
+            merged.push(*idx);
+        }
+    }
+    merged
+}
+
+/// `ROLLUP(t1, ..., tn)`: the sets (t1, ..., tn), (t1, ..., tn-1), ..., ().
+fn rollup_sets(tuples: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    (0..=tuples.len())
+        .rev()
+        .map(|n| merge_sets(&tuples[..n]))
+        .collect()
+}
+
+/// `CUBE(t1, ..., tn)`: all subsets of (t1, ..., tn).
+fn cube_sets(tuples: &[Vec<usize>]) -> PolarsResult<Vec<Vec<usize>>> {
+    const MAX_CUBE_ELEMENTS: usize = 12;
+    let n = tuples.len();
+    polars_ensure!(
+        n <= MAX_CUBE_ELEMENTS,
+        SQLInterface: "CUBE supports at most {} elements (found {})", MAX_CUBE_ELEMENTS, n
+    );
+    Ok((0..1usize << n)
+        .rev()
+        .map(|mask| {
+            merge_sets(
+                (0..n)
+                    .filter(|i| mask & (1 << (n - 1 - i)) != 0)
+                    .map(|i| &tuples[i]),
+            )
+        })
+        .collect())
+}
+
+/// Check whether the SELECT projections or HAVING clause call `GROUPING(...)`.
+fn uses_grouping_function(select_stmt: &Select) -> bool {
+    let is_grouping = |e: &SQLExpr| match e {
+        SQLExpr::Function(func) => func
+            .name
+            .0
+            .last()
+            .and_then(|part| part.as_ident())
+            .is_some_and(|ident| ident.value.eq_ignore_ascii_case("grouping")),
+        _ => false,
+    };
+    let found = |e: &SQLExpr| {
+        visit_expressions(e, |e| {
+            if is_grouping(e) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_break()
+    };
+    select_stmt.projection.iter().any(|item| match item {
+        SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. } => found(e),
+        _ => false,
+    }) || select_stmt.having.as_ref().is_some_and(found)
+}
+
 /// Check if an expression is a simple column reference (with optional alias) to the given name.
 fn is_simple_col_ref(expr: &Expr, col_name: &PlSmallStr) -> bool {
     match expr {
@@ -2947,7 +3217,7 @@ fn expr_cols_all_in_schema(expr: &Expr, schema: &Schema) -> bool {
 /// table (e.g.: you could be joining `df1` to `df2` to `df3`, but the final join condition where
 /// we join `df2` to `df3` could refer to `df1.a = df3.b`; this takes a little more work to
 /// resolve as our native `join` function operates on only two tables at a time.
-fn determine_left_right_join_on(
+pub(crate) fn determine_left_right_join_on(
     ctx: &mut SQLContext,
     expr_left: &SQLExpr,
     expr_right: &SQLExpr,
@@ -3113,7 +3383,10 @@ fn process_join_on(
 
 /// Build a unified schema from both tables; needed for multi/chained joins where suffixed
 /// intermediary/joined cols aren't in an existing schema.
-fn build_join_schema(tbl_left: &TableInfo, tbl_right: &TableInfo) -> PolarsResult<Schema> {
+pub(crate) fn build_join_schema(
+    tbl_left: &TableInfo,
+    tbl_right: &TableInfo,
+) -> PolarsResult<Schema> {
     let mut join_schema = Schema::with_capacity(tbl_left.schema.len() + tbl_right.schema.len());
     for (name, dtype) in tbl_left.schema.iter() {
         join_schema.insert_at_index(join_schema.len(), name.clone(), dtype.clone())?;
@@ -3222,7 +3495,7 @@ fn process_join_constraint(
 }
 
 /// Flatten a SQL AND-expression tree into individual leaf conditions.
-fn flatten_and_conditions(expr: &SQLExpr) -> Vec<&SQLExpr> {
+pub(crate) fn flatten_and_conditions(expr: &SQLExpr) -> Vec<&SQLExpr> {
     match expr {
         SQLExpr::BinaryOp {
             left,
@@ -3307,6 +3580,85 @@ fn extract_join_predicates(
     )
 }
 
+/// Parse the LIMIT/OFFSET (or FETCH) clauses of a query into an `(offset, limit)` pair.
+pub(crate) fn parse_limit_offset(
+    limit_clause: &Option<LimitClause>,
+    fetch: &Option<Fetch>,
+) -> PolarsResult<(Option<i64>, Option<IdxSize>)> {
+    // Extract limit and offset from LimitClause
+    let (limit, offset) = match limit_clause {
+        Some(LimitClause::LimitOffset {
+            limit,
+            offset,
+            limit_by,
+        }) => {
+            if !limit_by.is_empty() {
+                // TODO: might be able to support as an aggregate `top_k_by` operation?
+                //  (https://clickhouse.com/docs/sql-reference/statements/select/limit-by)
+                polars_bail!(SQLSyntax: "`LIMIT <n> BY <exprs>` clause is not supported");
+            }
+            (limit.as_ref(), offset.as_ref().map(|o| &o.value))
+        },
+        Some(LimitClause::OffsetCommaLimit { offset, limit }) => (Some(limit), Some(offset)),
+        None => (None, None),
+    };
+
+    // Handle FETCH clause (alternative to LIMIT, mutually exclusive)
+    let limit = match (fetch, limit) {
+        (Some(fetch), None) => fetch.quantity.as_ref(),
+        (Some(_), Some(_)) => {
+            polars_bail!(SQLSyntax: "cannot use both `LIMIT` and `FETCH` in the same query")
+        },
+        (None, limit) => limit,
+    };
+
+    fn parse_number(e: Option<&SQLExpr>) -> PolarsResult<Option<&str>> {
+        match e {
+            Some(SQLExpr::Value(ValueWithSpan {
+                value: SQLValue::Number(n, _),
+                ..
+            })) => Ok(Some(n)),
+            None => Ok(None),
+            _ => polars_bail!(
+                SQLSyntax: "non-numeric arguments for LIMIT/OFFSET/FETCH are not supported",
+            ),
+        }
+    }
+    let offset = parse_number(offset)?
+        .map(|offset| {
+            offset
+                .parse()
+                .map_err(|e| polars_err!(SQLInterface: "OFFSET conversion error: {}", e))
+        })
+        .transpose()?;
+    let limit = parse_number(limit)?
+        .map(|limit| {
+            limit
+                .parse()
+                .map_err(|e| polars_err!(SQLInterface: "LIMIT/FETCH conversion error: {}", e))
+        })
+        .transpose()?;
+    Ok((offset, limit))
+}
+
+/// Parse a SQL string into statements.
+fn parse_statements(query: &str) -> PolarsResult<Vec<Statement>> {
+    let tokens = Tokenizer::new(&GenericDialect, query)
+        .tokenize_with_location()
+        .map_err(|e| to_sql_interface_err(ParserError::from(e)))?;
+    #[cfg(feature = "asof_join")]
+    let tokens = crate::asof_join::rewrite_asof_join_tokens(tokens);
+
+    Parser::new(&GenericDialect)
+        .with_options(ParserOptions {
+            trailing_commas: true,
+            ..Default::default()
+        })
+        .with_tokens_with_locations(tokens)
+        .parse_statements()
+        .map_err(to_sql_interface_err)
+}
+
 /// Extract table identifiers referenced in a SQL query; uses a visitor to
 /// collect all table names that appear in FROM clauses, JOINs, TABLE refs
 /// in set operations, and subqueries.
@@ -3315,17 +3667,7 @@ pub fn extract_table_identifiers(
     include_schema: bool,
     unique: bool,
 ) -> PolarsResult<Vec<String>> {
-    let mut parser = Parser::new(&GenericDialect);
-    parser = parser.with_options(ParserOptions {
-        trailing_commas: true,
-        ..Default::default()
-    });
-    let ast = parser
-        .try_with_sql(query)
-        .map_err(to_sql_interface_err)?
-        .parse_statements()
-        .map_err(to_sql_interface_err)?;
-
+    let ast = parse_statements(query)?;
     let mut collector = TableIdentifierCollector {
         include_schema,
         ..Default::default()
@@ -0,0 +1,609 @@
+//! `INSERT`, `UPDATE` and `MERGE` statements. Each statement builds the new frame of
+//! its target table, which replaces the registered table and is returned as the result.
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_utils::aliases::PlHashSet;
+use sqlparser::ast::{
+    Assignment, AssignmentTarget, Expr as SQLExpr, Insert, JoinConstraint, Merge, MergeAction,
+    MergeClause, MergeClauseKind, MergeInsertExpr, MergeInsertKind, MergeUpdateExpr, ObjectName,
+    Statement, TableFactor, TableObject, Update, Values,
+};
+
+use crate::SQLContext;
+use crate::context::TableInfo;
+use crate::sql_expr::parse_sql_expr;
+
+const MERGE_TARGET_ROW: &str = "__POLARS_MERGE_TARGET_ROW";
+const MERGE_SOURCE_ROW: &str = "__POLARS_MERGE_SOURCE_ROW";
+const MERGE_ACTION: &str = "__POLARS_MERGE_ACTION";
+const MERGE_MATCHED: &str = "__POLARS_MERGE_MATCHED";
+
+/// A `WHEN ... THEN <action>` clause of a MERGE statement, parsed against the rows it applies to.
+struct MergeOp {
+    condition: Expr,
+    action: MergeOpAction,
+}
+
+enum MergeOpAction {
+    /// Target columns and their new values.
+    Update(Vec<(PlSmallStr, Expr)>),
+    Delete,
+    /// Target columns and their values; the remaining target columns are NULL.
+    Insert(Vec<(PlSmallStr, Expr)>),
+}
+
+impl SQLContext {
+    // INSERT INTO <tbl> [(<col>, ...)] {SELECT ... | VALUES ...}
+    pub(crate) fn execute_insert(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        // Destructure exhaustively so new sqlparser fields surface as compile errors.
+        let Statement::Insert(Insert {
+            insert_token: _,
+            optimizer_hints: _,
+            or,
+            ignore,
+            into: _,
+            table,
+            table_alias: _,
+            columns,
+            overwrite,
+            source,
+            assignments,
+            partitioned,
+            after_columns,
+            has_table_keyword: _,
+            on,
+            returning,
+            output,
+            replace_into,
+            priority,
+            insert_alias,
+            settings,
+            format_clause,
+            multi_table_insert_type,
+            multi_table_into_clauses,
+            multi_table_when_clauses,
+            multi_table_else_clause,
+        }) = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        };
+
+        let error_message: Option<&'static str> =
+            if or.is_some() || *ignore || *replace_into || on.is_some() || insert_alias.is_some() {
+                Some("INSERT does not support conflict resolution clauses")
+            } else if returning.is_some() || output.is_some() {
+                Some("INSERT does not support the RETURNING clause")
+            } else if !assignments.is_empty() {
+                Some("INSERT does not support the SET clause; use VALUES or a query")
+            } else if partitioned.is_some() || !after_columns.is_empty() {
+                Some("INSERT does not support the PARTITION clause")
+            } else if priority.is_some() || settings.is_some() || format_clause.is_some() {
+                Some("INSERT does not support dialect-specific options")
+            } else if multi_table_insert_type.is_some()
+                || !multi_table_into_clauses.is_empty()
+                || !multi_table_when_clauses.is_empty()
+                || multi_table_else_clause.is_some()
+            {
+                Some("INSERT into multiple tables is not supported")
+            } else {
+                None
+            };
+
+        if let Some(msg) = error_message {
+            polars_bail!(SQLInterface: msg);
+        }
+
+        let TableObject::TableName(name) = table else {
+            polars_bail!(SQLInterface: "INSERT expects a table name; found {}", table)
+        };
+        let tbl_name = object_table_name(name)?;
+        let Some(source) = source else {
+            polars_bail!(SQLInterface: "INSERT expects a query or VALUES clause")
+        };
+
+        let mut target = self.get_registered_table(&tbl_name)?;
+        let target_schema = self.get_frame_schema(&mut target)?;
+        let mut rows = self.execute_query(source)?;
+        let rows_schema = self.get_frame_schema(&mut rows)?;
+
+        let insert_cols = if columns.is_empty() {
+            target_schema.iter_names().cloned().collect()
+        } else {
+            target_column_names(columns, &target_schema, &tbl_name)?
+        };
+        polars_ensure!(
+            insert_cols.len() == rows_schema.len(),
+            SQLInterface: "INSERT has {} target column(s) but the inserted rows have {}",
+            insert_cols.len(), rows_schema.len()
+        );
+
+        // Inserted columns are matched by position; the remaining target columns are NULL.
+        let projection = target_schema
+            .iter()
+            .map(|(name, dtype)| {
+                match insert_cols.iter().position(|c| c == name) {
+                    Some(idx) => col(rows_schema.get_at_index(idx).unwrap().0.clone()),
+                    None => lit(NULL),
+                }
+                .strict_cast(dtype.clone())
+                .alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+        let rows = rows.select(projection);
+
+        let lf = if *overwrite {
+            rows
+        } else {
+            concat(
+                [target, rows],
+                UnionArgs {
+                    parallel: true,
+                    maintain_order: true,
+                    ..Default::default()
+                },
+            )?
+        };
+        self.register(&tbl_name, lf.clone());
+        Ok(lf)
+    }
+
+    // UPDATE <tbl> SET <col> = <expr>, ... [WHERE ...]
+    pub(crate) fn execute_update(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Update(Update {
+            update_token: _,
+            optimizer_hints: _,
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+            output,
+            or,
+            order_by,
+            limit,
+        }) = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected UPDATE")
+        };
+
+        let error_message: Option<&'static str> = if from.is_some() {
+            Some("UPDATE does not support the FROM clause; consider MERGE instead")
+        } else if !table.joins.is_empty() {
+            Some("UPDATE does not support table JOINs; consider MERGE instead")
+        } else if returning.is_some() || output.is_some() {
+            Some("UPDATE does not support the RETURNING clause")
+        } else if or.is_some() {
+            Some("UPDATE does not support conflict resolution clauses")
+        } else if limit.is_some() {
+            Some("UPDATE does not support the LIMIT clause")
+        } else if !order_by.is_empty() {
+            Some("UPDATE does not support the ORDER BY clause")
+        } else {
+            None
+        };
+
+        if let Some(msg) = error_message {
+            polars_bail!(SQLInterface: msg);
+        }
+
+        let tbl_name = relation_table_name(&table.relation, "UPDATE")?;
+        self.get_registered_table(&tbl_name)?;
+        let (_, mut lf) = self.get_table(&table.relation)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let mut updates = parse_assignments(assignments, &schema, &tbl_name)?
+            .into_iter()
+            .map(|(name, value)| Ok((name, parse_sql_expr(value, self, Some(&schema))?)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut predicate = selection
+            .as_ref()
+            .map(|expr| parse_sql_expr(expr, self, Some(&schema)))
+            .transpose()?;
+
+        lf = self.process_subqueries(
+            lf,
+            predicate
+                .iter_mut()
+                .chain(updates.iter_mut().map(|(_, value)| value))
+                .collect(),
+        )?;
+
+        // Rows where the predicate is false or NULL keep their values.
+        let updates = updates
+            .into_iter()
+            .map(|(name, value)| {
+                let value = value.strict_cast(schema.get(&name).unwrap().clone());
+                match &predicate {
+                    Some(predicate) => when(predicate.clone())
+                        .then(value)
+                        .otherwise(col(name.clone())),
+                    None => value,
+                }
+                .alias(name)
+            })
+            .collect::<Vec<_>>();
+
+        let lf = lf
+            .with_columns(updates)
+            .select(schema.iter_names().cloned().map(col).collect::<Vec<_>>());
+        self.register(&tbl_name, lf.clone());
+        Ok(lf)
+    }
+
+    // MERGE INTO <tbl> USING <source> ON <expr>
+    //   WHEN MATCHED [AND <expr>] THEN {UPDATE SET ... | DELETE}
+    //   WHEN NOT MATCHED [BY TARGET] [AND <expr>] THEN INSERT ...
+    //   WHEN NOT MATCHED BY SOURCE [AND <expr>] THEN {UPDATE SET ... | DELETE}
+    pub(crate) fn execute_merge(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Merge(Merge {
+            merge_token: _,
+            optimizer_hints: _,
+            into: _,
+            table,
+            source,
+            on,
+            clauses,
+            output,
+        }) = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected MERGE")
+        };
+        polars_ensure!(output.is_none(), SQLInterface: "MERGE does not support the OUTPUT clause");
+
+        let tbl_name = relation_table_name(table, "MERGE")?;
+        self.get_registered_table(&tbl_name)?;
+        let (t_name, target) = self.get_table(table)?;
+        let (s_name, source) = self.get_table(source)?;
+        polars_ensure!(
+            !s_name.is_empty(),
+            SQLInterface: "MERGE cannot use an unnamed source relation; please provide an alias"
+        );
+
+        let mut target = target.with_row_index(MERGE_TARGET_ROW, None);
+        let mut source = source.with_row_index(MERGE_SOURCE_ROW, None);
+        let target_schema = self.get_frame_schema(&mut target)?;
+        let source_schema = self.get_frame_schema(&mut source)?;
+        let tbl_schema = Schema::from_iter(
+            target_schema
+                .iter()
+                .filter(|(name, _)| name.as_str() != MERGE_TARGET_ROW)
+                .map(|(name, dtype)| (name.clone(), dtype.clone())),
+        );
+
+        let t_info = TableInfo {
+            frame: target.clone(),
+            name: t_name.as_str().into(),
+            schema: target_schema.clone(),
+        };
+        let s_info = TableInfo {
+            frame: source.clone(),
+            name: s_name.as_str().into(),
+            schema: source_schema.clone(),
+        };
+        let matched = self.process_join(
+            &t_info,
+            &s_info,
+            &JoinConstraint::On((**on).clone()),
+            JoinType::Inner,
+        )?;
+
+        // As in standard SQL, a target row must not be matched by several source rows, as
+        // it would be modified several times (and the other matches inserted). This is
+        // checked when the statement is executed, rather than collecting the join here.
+        let mut matched = matched.map(
+            |df| {
+                let target_rows = df.column(MERGE_TARGET_ROW)?;
+                polars_ensure!(
+                    target_rows.n_unique()? == target_rows.len(),
+                    SQLInterface: "MERGE matched a target row with more than one source row"
+                );
+                Ok(df)
+            },
+            AllowedOptimizations::empty(),
+            None,
+            Some("MERGE MATCH CHECK"),
+        );
+
+        // The NOT MATCHED clauses only see the rows of one side, so they are parsed before
+        // the joined column names of the source are registered.
+        let mut by_target = vec![];
+        let mut by_source = vec![];
+        let mut when_matched = vec![];
+        for clause in clauses {
+            match clause.clause_kind {
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                    by_target.push(self.parse_merge_clause(clause, &source_schema, &tbl_schema)?)
+                },
+                MergeClauseKind::NotMatchedBySource => {
+                    by_source.push(self.parse_merge_clause(clause, &target_schema, &tbl_schema)?)
+                },
+                MergeClauseKind::Matched => when_matched.push(clause),
+            }
+        }
+
+        self.track_joined_aliases(&mut matched, s_name.clone(), &target_schema, &source_schema)?;
+        let joined_schema = self.get_frame_schema(&mut matched)?;
+        let when_matched = when_matched
+            .into_iter()
+            .map(|clause| self.parse_merge_clause(clause, &joined_schema, &tbl_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let unmatched_target = unmatched_rows(target, matched.clone(), MERGE_TARGET_ROW);
+        let unmatched_source = unmatched_rows(source, matched.clone(), MERGE_SOURCE_ROW);
+
+        let matched = self.apply_merge_ops(matched, when_matched, &tbl_schema, true)?;
+        let unmatched_target =
+            self.apply_merge_ops(unmatched_target, by_source, &tbl_schema, true)?;
+        let inserted = self.apply_merge_ops(unmatched_source, by_target, &tbl_schema, false)?;
+
+        // Target rows keep their order, inserted rows are appended in source order.
+        let union_args = UnionArgs {
+            parallel: true,
+            maintain_order: true,
+            ..Default::default()
+        };
+        let updated = concat([matched, unmatched_target], union_args.clone())?
+            .sort([MERGE_TARGET_ROW], Default::default())
+            .select(
+                tbl_schema
+                    .iter_names()
+                    .cloned()
+                    .map(col)
+                    .collect::<Vec<_>>(),
+            );
+        let lf = concat([updated, inserted], union_args)?;
+
+        self.register(&tbl_name, lf.clone());
+        Ok(lf)
+    }
+
+    fn get_registered_table(&self, name: &str) -> PolarsResult<LazyFrame> {
+        self.table_map
+            .read()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| polars_err!(SQLInterface: "table '{}' does not exist", name))
+    }
+
+    fn parse_merge_clause(
+        &mut self,
+        clause: &MergeClause,
+        schema: &Schema,
+        tbl_schema: &Schema,
+    ) -> PolarsResult<MergeOp> {
+        let MergeClause {
+            when_token: _,
+            clause_kind,
+            predicate,
+            action,
+        } = clause;
+
+        let condition = match predicate {
+            Some(expr) => parse_sql_expr(expr, self, Some(schema))?,
+            None => lit(true),
+        };
+        let is_insert_clause = matches!(
+            clause_kind,
+            MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget
+        );
+
+        let action = match action {
+            MergeAction::Update(MergeUpdateExpr {
+                update_token: _,
+                assignments,
+                update_predicate,
+                delete_predicate,
+            }) if !is_insert_clause => {
+                polars_ensure!(
+                    update_predicate.is_none() && delete_predicate.is_none(),
+                    SQLInterface: "MERGE does not support WHERE/DELETE WHERE on UPDATE actions; use `WHEN ... AND <condition>`"
+                );
+                let assignments = parse_assignments(assignments, tbl_schema, "MERGE")?
+                    .into_iter()
+                    .map(|(name, value)| Ok((name, parse_sql_expr(value, self, Some(schema))?)))
+                    .collect::<PolarsResult<_>>()?;
+                MergeOpAction::Update(assignments)
+            },
+            MergeAction::Delete { .. } if !is_insert_clause => MergeOpAction::Delete,
+            MergeAction::Insert(MergeInsertExpr {
+                insert_token: _,
+                columns,
+                kind_token: _,
+                kind,
+                insert_predicate,
+            }) if is_insert_clause => {
+                polars_ensure!(
+                    insert_predicate.is_none(),
+                    SQLInterface: "MERGE does not support WHERE on INSERT actions; use `WHEN NOT MATCHED AND <condition>`"
+                );
+                let insert_cols = if columns.is_empty() {
+                    tbl_schema.iter_names().cloned().collect()
+                } else {
+                    target_column_names(columns, tbl_schema, "MERGE")?
+                };
+                let values = match kind {
+                    MergeInsertKind::Values(Values { rows, .. }) => {
+                        polars_ensure!(
+                            rows.len() == 1,
+                            SQLInterface: "MERGE INSERT expects a single row of VALUES; found {}", rows.len()
+                        );
+                        rows[0]
+                            .content
+                            .iter()
+                            .map(|value| parse_sql_expr(value, self, Some(schema)))
+                            .collect::<PolarsResult<Vec<_>>>()?
+                    },
+                    // `INSERT ROW` inserts all source columns.
+                    MergeInsertKind::Row => schema
+                        .iter_names()
+                        .filter(|name| name.as_str() != MERGE_SOURCE_ROW)
+                        .cloned()
+                        .map(col)
+                        .collect(),
+                };
+                polars_ensure!(
+                    insert_cols.len() == values.len(),
+                    SQLInterface: "MERGE INSERT has {} target column(s) but {} value(s)",
+                    insert_cols.len(), values.len()
+                );
+                MergeOpAction::Insert(insert_cols.into_iter().zip(values).collect())
+            },
+            _ => polars_bail!(
+                SQLInterface: "MERGE does not support '{}' for 'WHEN {}'", action, clause_kind
+            ),
+        };
+
+        Ok(MergeOp { condition, action })
+    }
+
+    /// Applies the MERGE clauses of one kind to their rows; each row is affected by the first
+    /// clause whose condition holds. With `keep_unaffected`, rows without such a clause are
+    /// kept unchanged, otherwise they are dropped.
+    fn apply_merge_ops(
+        &mut self,
+        lf: LazyFrame,
+        mut ops: Vec<MergeOp>,
+        tbl_schema: &Schema,
+        keep_unaffected: bool,
+    ) -> PolarsResult<LazyFrame> {
+        let mut lf = self.process_subqueries(
+            lf,
+            ops.iter_mut()
+                .flat_map(|op| {
+                    let values: &mut [(PlSmallStr, Expr)] = match &mut op.action {
+                        MergeOpAction::Update(values) | MergeOpAction::Insert(values) => {
+                            values.as_mut_slice()
+                        },
+                        MergeOpAction::Delete => &mut [],
+                    };
+                    std::iter::once(&mut op.condition).chain(values.iter_mut().map(|(_, v)| v))
+                })
+                .collect(),
+        )?;
+
+        // Index of the first clause whose condition is true (NULL if there is none).
+        let action =
+            ops.iter()
+                .enumerate()
+                .rev()
+                .fold(lit(NULL).cast(IDX_DTYPE), |otherwise, (idx, op)| {
+                    when(op.condition.clone())
+                        .then(lit(idx as IdxSize))
+                        .otherwise(otherwise)
+                });
+        lf = lf.with_column(action.alias(MERGE_ACTION));
+
+        let mut keep = if keep_unaffected {
+            lit(true)
+        } else {
+            col(MERGE_ACTION).is_not_null()
+        };
+        let mut projection = vec![];
+        if keep_unaffected {
+            projection.push(col(MERGE_TARGET_ROW));
+        }
+        for (name, dtype) in tbl_schema.iter() {
+            let mut expr = if keep_unaffected {
+                col(name.clone())
+            } else {
+                lit(NULL)
+            };
+            for (idx, op) in ops.iter().enumerate() {
+                if let MergeOpAction::Update(values) | MergeOpAction::Insert(values) = &op.action {
+                    if let Some((_, value)) = values.iter().find(|(n, _)| n == name) {
+                        expr = when(col(MERGE_ACTION).eq(lit(idx as IdxSize)))
+                            .then(value.clone().strict_cast(dtype.clone()))
+                            .otherwise(expr);
+                    }
+                }
+            }
+            projection.push(expr.strict_cast(dtype.clone()).alias(name.clone()));
+        }
+        for (idx, op) in ops.iter().enumerate() {
+            if let MergeOpAction::Delete = op.action {
+                keep = keep.and(col(MERGE_ACTION).neq_missing(lit(idx as IdxSize)));
+            }
+        }
+
+        Ok(lf.filter(keep).select(projection))
+    }
+}
+
+/// Rows of `lf` whose row index (in column `row_index`) does not occur in `matched`.
+fn unmatched_rows(lf: LazyFrame, matched: LazyFrame, row_index: &str) -> LazyFrame {
+    let matched = matched
+        .select([col(row_index), lit(true).alias(MERGE_MATCHED)])
+        .unique(Some(cols([row_index])), UniqueKeepStrategy::Any);
+    lf.join_builder()
+        .with(matched)
+        .left_on([col(row_index)])
+        .right_on([col(row_index)])
+        .how(JoinType::Left)
+        .finish()
+        .filter(col(MERGE_MATCHED).is_null())
+}
+
+fn object_table_name(name: &ObjectName) -> PolarsResult<String> {
+    match name.0.first().and_then(|p| p.as_ident()) {
+        Some(ident) => Ok(ident.value.clone()),
+        None => polars_bail!(SQLInterface: "invalid table name: {}", name),
+    }
+}
+
+fn relation_table_name(relation: &TableFactor, stmt: &str) -> PolarsResult<String> {
+    match relation {
+        TableFactor::Table {
+            name, args: None, ..
+        } => object_table_name(name),
+        _ => polars_bail!(SQLInterface: "{} expects a table name; found {}", stmt, relation),
+    }
+}
+
+/// Resolve (possibly qualified) column names against the target table.
+fn target_column_names(
+    names: &[ObjectName],
+    schema: &Schema,
+    tbl_name: &str,
+) -> PolarsResult<Vec<PlSmallStr>> {
+    let mut seen = PlHashSet::with_capacity(names.len());
+    names
+        .iter()
+        .map(|name| {
+            let Some((_, col_name, _)) = name
+                .0
+                .last()
+                .and_then(|p| p.as_ident())
+                .and_then(|ident| schema.get_full(ident.value.as_str()))
+            else {
+                polars_bail!(SQLInterface: "column '{}' not found in '{}'", name, tbl_name)
+            };
+            polars_ensure!(
+                seen.insert(col_name.clone()),
+                SQLInterface: "column '{}' is specified more than once", col_name
+            );
+            Ok(col_name.clone())
+        })
+        .collect()
+}
+
+fn parse_assignments<'a>(
+    assignments: &'a [Assignment],
+    schema: &Schema,
+    tbl_name: &str,
+) -> PolarsResult<Vec<(PlSmallStr, &'a SQLExpr)>> {
+    let names = assignments
+        .iter()
+        .map(|Assignment { target, .. }| match target {
+            AssignmentTarget::ColumnName(name) => Ok(name.clone()),
+            AssignmentTarget::Tuple(_) => {
+                polars_bail!(SQLInterface: "tuple assignments are not supported in SET clauses")
+            },
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let names = target_column_names(&names, schema, tbl_name)?;
+    Ok(names
+        .into_iter()
+        .zip(assignments.iter().map(|a| &a.value))
+        .collect())
+}
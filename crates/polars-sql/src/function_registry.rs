@@ -1,8 +1,15 @@
 //! This module defines a FunctionRegistry for supported SQL functions and UDFs.
 
-use polars_error::{PolarsResult, polars_bail};
+use std::sync::Arc;
+
+use polars_core::prelude::{Column, DataType, Field, PlHashMap, Schema};
+use polars_core::scalar::Scalar;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_plan::dsl::{BaseColumnUdf, DataTypeSelector, Expr};
 pub use polars_plan::prelude::FunctionOptions;
 use polars_plan::prelude::udf::UserDefinedFunction;
+use polars_utils::pl_str::PlSmallStr;
+
 /// A registry that holds user defined functions.
 pub trait FunctionRegistry: Send + Sync {
     /// Register a function.
@@ -11,6 +18,23 @@ pub trait FunctionRegistry: Send + Sync {
     fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>>;
     /// Check if a function is registered.
     fn contains(&self, name: &str) -> bool;
+
+    /// Build the expression for a SQL call of the function `name`.
+    ///
+    /// `input_schema` is the schema the arguments are evaluated against, if it is
+    /// known when the SQL is translated.
+    fn call(
+        &self,
+        name: &str,
+        args: Vec<Expr>,
+        input_schema: Option<&Schema>,
+    ) -> PolarsResult<Expr> {
+        let _ = input_schema;
+        let udf = self
+            .get_udf(name)?
+            .ok_or_else(|| polars_err!(SQLInterface: "UDF {} not found", name))?;
+        Ok(udf.call(args))
+    }
 }
 
 /// A default registry that does not support registering or calling functions.
@@ -28,3 +52,240 @@ impl FunctionRegistry for DefaultFunctionRegistry {
         false
     }
 }
+
+/// The arguments accepted by a function registered on a [`SQLFunctionRegistry`].
+#[derive(Clone, Debug, Default)]
+pub struct SQLFunctionSignature {
+    params: Vec<DataTypeSelector>,
+    variadic: Option<DataTypeSelector>,
+}
+
+impl SQLFunctionSignature {
+    /// A signature taking exactly one argument per given parameter type.
+    pub fn new(params: impl IntoIterator<Item = DataTypeSelector>) -> Self {
+        Self {
+            params: params.into_iter().collect(),
+            variadic: None,
+        }
+    }
+
+    /// A signature taking any number of arguments of any type.
+    pub fn any() -> Self {
+        Self::default().with_variadic(DataTypeSelector::Wildcard)
+    }
+
+    /// Accept any number of additional trailing arguments matching `dtype`.
+    pub fn with_variadic(mut self, dtype: DataTypeSelector) -> Self {
+        self.variadic = Some(dtype);
+        self
+    }
+
+    fn check_arity(&self, name: &str, n_args: usize) -> PolarsResult<()> {
+        let n_params = self.params.len();
+        match self.variadic {
+            None => polars_ensure!(
+                n_args == n_params,
+                SQLSyntax: "{} expects {} argument{} (found {})",
+                name, n_params, if n_params == 1 { "" } else { "s" }, n_args
+            ),
+            Some(_) => polars_ensure!(
+                n_args >= n_params,
+                SQLSyntax: "{} expects at least {} argument{} (found {})",
+                name, n_params, if n_params == 1 { "" } else { "s" }, n_args
+            ),
+        }
+        Ok(())
+    }
+
+    fn check_arg(&self, name: &str, idx: usize, dtype: &DataType) -> PolarsResult<()> {
+        let Some(expected) = self.params.get(idx).or(self.variadic.as_ref()) else {
+            return Ok(());
+        };
+        // Literals have an unknown dtype until they are materialized.
+        let dtype = dtype.clone().materialize_unknown(true)?;
+        polars_ensure!(
+            expected.matches(&dtype),
+            SchemaMismatch: "{} argument {} expects {}; found {}",
+            name, idx + 1, expected, dtype
+        );
+        Ok(())
+    }
+
+    fn check_fields(&self, name: &str, fields: &[Field]) -> PolarsResult<()> {
+        self.check_arity(name, fields.len())?;
+        for (idx, field) in fields.iter().enumerate() {
+            self.check_arg(name, idx, field.dtype())?;
+        }
+        Ok(())
+    }
+}
+
+type ScalarFunction = Arc<dyn Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync>;
+
+#[derive(Clone)]
+enum RegisteredFunction {
+    /// A function registered through [`FunctionRegistry::register`].
+    Udf(UserDefinedFunction),
+    Scalar {
+        signature: SQLFunctionSignature,
+        fun: ScalarFunction,
+    },
+    Aggregate {
+        signature: SQLFunctionSignature,
+        udf: UserDefinedFunction,
+    },
+}
+
+/// An in-memory registry of user defined scalar and aggregate functions.
+///
+/// Function names are case-insensitive; built-in SQL functions take precedence over
+/// registered functions of the same name.
+/// ```rust
+/// # use std::sync::Arc;
+/// # use polars_core::prelude::*;
+/// # use polars_lazy::prelude::*;
+/// # use polars_plan::dsl::DataTypeSelector;
+/// # use polars_sql::SQLContext;
+/// # use polars_sql::function_registry::{SQLFunctionRegistry, SQLFunctionSignature};
+/// # fn main() -> PolarsResult<()> {
+/// let mut registry = SQLFunctionRegistry::new();
+/// registry.register_scalar(
+///     "add_one",
+///     SQLFunctionSignature::new([DataTypeSelector::Numeric]),
+///     |mut args| Ok(args.pop().unwrap() + lit(1)),
+/// )?;
+///
+/// let mut ctx = SQLContext::new().with_function_registry(Arc::new(registry));
+/// ctx.register("df", df! { "a" => [1, 2, 3] }?.lazy());
+/// let res = ctx.execute("SELECT add_one(a) AS b FROM df")?.collect()?;
+/// assert!(res.equals(&df! { "b" => [2, 3, 4] }?));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct SQLFunctionRegistry {
+    functions: PlHashMap<PlSmallStr, RegisteredFunction>,
+}
+
+impl SQLFunctionRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a scalar function that builds an expression from its argument expressions.
+    pub fn register_scalar<F>(
+        &mut self,
+        name: &str,
+        signature: SQLFunctionSignature,
+        fun: F,
+    ) -> PolarsResult<()>
+    where
+        F: Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync + 'static,
+    {
+        self.insert(
+            name,
+            RegisteredFunction::Scalar {
+                signature,
+                fun: Arc::new(fun),
+            },
+        )
+    }
+
+    /// Register an aggregate function that reduces its argument columns (of the whole
+    /// frame, or of each group under `GROUP BY`) to a single value of `return_dtype`.
+    pub fn register_aggregate<F>(
+        &mut self,
+        name: &str,
+        signature: SQLFunctionSignature,
+        return_dtype: DataType,
+        fun: F,
+    ) -> PolarsResult<()>
+    where
+        F: Fn(&[Column]) -> PolarsResult<Scalar> + Send + Sync + 'static,
+    {
+        let key = PlSmallStr::from_str(&name.to_lowercase());
+        let (name, udf_name) = (key.clone(), key.clone());
+        let (udf_signature, udf_dtype) = (signature.clone(), return_dtype.clone());
+        let mut udf = UserDefinedFunction::new(
+            key.clone(),
+            BaseColumnUdf::new(
+                move |c: &mut [Column]| {
+                    let out_name = c.first().map_or(udf_name.clone(), |c| c.name().clone());
+                    let scalar = fun(c)?;
+                    Column::new_scalar(out_name, scalar, 1).cast(&return_dtype)
+                },
+                move |_: &Schema, fields: &[Field]| {
+                    udf_signature.check_fields(&name, fields)?;
+                    let out_name = fields.first().map_or(name.clone(), |f| f.name().clone());
+                    Ok(Field::new(out_name, udf_dtype.clone()))
+                },
+            ),
+        );
+        udf.options = FunctionOptions::aggregation();
+        self.insert(&key, RegisteredFunction::Aggregate { signature, udf })
+    }
+
+    fn insert(&mut self, name: &str, fun: RegisteredFunction) -> PolarsResult<()> {
+        let name = PlSmallStr::from_str(&name.to_lowercase());
+        polars_ensure!(
+            !self.functions.contains_key(&name),
+            SQLInterface: "function '{}' is already registered", name
+        );
+        self.functions.insert(name, fun);
+        Ok(())
+    }
+}
+
+impl FunctionRegistry for SQLFunctionRegistry {
+    fn register(&mut self, name: &str, fun: UserDefinedFunction) -> PolarsResult<()> {
+        self.insert(name, RegisteredFunction::Udf(fun))
+    }
+
+    /// Get a registered [`UserDefinedFunction`]; scalar functions registered with
+    /// [`SQLFunctionRegistry::register_scalar`] are not backed by one.
+    fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>> {
+        Ok(match self.functions.get(name.to_lowercase().as_str()) {
+            Some(RegisteredFunction::Udf(udf) | RegisteredFunction::Aggregate { udf, .. }) => {
+                Some(udf.clone())
+            },
+            _ => None,
+        })
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name.to_lowercase().as_str())
+    }
+
+    fn call(
+        &self,
+        name: &str,
+        args: Vec<Expr>,
+        input_schema: Option<&Schema>,
+    ) -> PolarsResult<Expr> {
+        let Some(fun) = self.functions.get(name.to_lowercase().as_str()) else {
+            polars_bail!(SQLInterface: "UDF {} not found", name)
+        };
+        match fun {
+            RegisteredFunction::Udf(udf) => Ok(udf.clone().call(args)),
+            RegisteredFunction::Scalar { signature, fun } => {
+                signature.check_arity(name, args.len())?;
+                // Check the argument types up-front where they can be resolved; the
+                // returned expression is opaque to the registry afterwards.
+                if let Some(schema) = input_schema {
+                    for (idx, arg) in args.iter().enumerate() {
+                        if let Ok(field) = arg.to_field(schema) {
+                            signature.check_arg(name, idx, field.dtype())?;
+                        }
+                    }
+                }
+                fun(args)
+            },
+            RegisteredFunction::Aggregate { signature, udf } => {
+                // Argument types are checked when the plan is resolved.
+                signature.check_arity(name, args.len())?;
+                Ok(udf.clone().call(args))
+            },
+        }
+    }
+}
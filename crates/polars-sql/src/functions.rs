@@ -2,8 +2,8 @@ use std::ops::{Add, Sub};
 
 use polars_core::chunked_array::ops::{FillNullStrategy, SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, ExplodeOptions, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail,
-    polars_err,
+    DataType, ExplodeOptions, IDX_DTYPE, PolarsResult, QuantileMethod, RollingOptionsFixedWindow,
+    Schema, TimeUnit, polars_bail, polars_ensure, polars_err,
 };
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "rank")]
@@ -14,8 +14,9 @@ use polars_plan::dsl::functions::{
     as_struct, coalesce, col, cols, concat_str, element, int_range, len, lit, max_horizontal,
     min_horizontal, when,
 };
-use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
+use polars_plan::plans::{DynLiteralValue, LiteralValue, NULL, typed_lit};
 use polars_plan::prelude::StrptimeOptions;
+use polars_time::prelude::{ClosedWindow, Duration, RollingOptionsDynamicWindow};
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
@@ -27,7 +28,10 @@ use sqlparser::ast::{
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::context::GROUPING_ID_NAME;
+use crate::sql_expr::{
+    adjust_one_indexed_param, interval_to_duration, parse_extract_date_part, parse_sql_expr,
+};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -587,6 +591,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(col1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function.
+    /// Returns a bitmask indicating which of the given GROUP BY expressions are
+    /// aggregated over (not part of the grouping set) in the current row.
+    /// ```sql
+    /// SELECT col1, col2, GROUPING(col1, col2), SUM(col3) FROM df GROUP BY ROLLUP(col1, col2);
+    /// ```
+    Grouping,
     /// SQL 'last' function.
     /// Returns the last element of the grouping.
     /// ```sql
@@ -851,6 +862,7 @@ impl PolarsSQLFunctions {
             "first_value",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
@@ -1032,6 +1044,7 @@ impl PolarsSQLFunctions {
             "covar_pop" => Self::CovarPop,
             "covar_samp" | "covar" => Self::CovarSamp,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -1598,15 +1611,22 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_unary_with_opt_sliding_window(Expr::mean, SlidingWindowAgg::Mean),
             Corr => self.visit_binary(polars_lazy::dsl::pearson_corr),
             Count => self.visit_count(),
             CovarPop => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 0)),
             CovarSamp => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 1)),
             First => self.visit_unary(Expr::first),
+            Grouping => self.visit_grouping(),
             Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
-            Median => self.visit_unary(Expr::median),
+            Max => self.visit_unary_with_opt_cumulative(
+                Expr::max,
+                Expr::cum_max,
+                SlidingWindowAgg::Max,
+            ),
+            Median => {
+                self.visit_unary_with_opt_sliding_window(Expr::median, SlidingWindowAgg::Median)
+            },
             QuantileCont | QuantileDisc => {
                 let (fname, method) = if matches!(function_name, QuantileCont) {
                     ("QUANTILE_CONT", QuantileMethod::Linear)
@@ -1638,11 +1658,23 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "{} expects 2 arguments (found {})", fname, args.len()),
                 }
             },
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
-            StdDev => self.visit_unary(|e| e.std(1)),
+            Min => self.visit_unary_with_opt_cumulative(
+                Expr::min,
+                Expr::cum_min,
+                SlidingWindowAgg::Min,
+            ),
+            StdDev => {
+                self.visit_unary_with_opt_sliding_window(|e| e.std(1), SlidingWindowAgg::StdDev)
+            },
             StringAgg => self.visit_string_agg(),
-            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
-            Variance => self.visit_unary(|e| e.var(1)),
+            Sum => self.visit_unary_with_opt_cumulative(
+                Expr::sum,
+                Expr::cum_sum,
+                SlidingWindowAgg::Sum,
+            ),
+            Variance => {
+                self.visit_unary_with_opt_sliding_window(|e| e.var(1), SlidingWindowAgg::Variance)
+            },
 
             // ----
             // Array functions
@@ -1825,6 +1857,39 @@ impl SQLFunctionVisitor<'_> {
         }.and_then(|e| self.apply_window_spec(e, &self.func.over))
     }
 
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let Some(keys) = &self.ctx.grouping_keys else {
+            polars_bail!(SQLSyntax: "GROUPING can only be used in a query with a GROUP BY clause")
+        };
+        polars_ensure!(!args.is_empty(), SQLSyntax: "GROUPING expects at least one argument");
+        polars_ensure!(
+            args.len() < u64::BITS as usize,
+            SQLSyntax: "GROUPING expects at most {} arguments (found {})", u64::BITS - 1, args.len()
+        );
+
+        // Extract the bit of each argument from the grouping set id (in which the
+        // first GROUP BY key is the most significant bit), and pack them in order.
+        let grouping_id = col(GROUPING_ID_NAME).first();
+        let (n_keys, n_args) = (keys.len(), args.len());
+        let mut grouping = lit(0u64);
+        for (i, arg) in args.iter().enumerate() {
+            let key_idx = match arg {
+                FunctionArgExpr::Expr(e) => keys.iter().position(|k| k == e),
+                _ => None,
+            };
+            let Some(key_idx) = key_idx else {
+                polars_bail!(SQLSyntax: "GROUPING arguments must be GROUP BY expressions; found {}", arg)
+            };
+            let key_bit = grouping_id
+                .clone()
+                .floor_div(lit(1u64 << (n_keys - 1 - key_idx)))
+                % lit(2u64);
+            grouping = grouping + key_bit * lit(1u64 << (n_args - 1 - i));
+        }
+        Ok(grouping.cast(DataType::Int64))
+    }
+
     fn visit_udf(&mut self, func_name: &str) -> PolarsResult<Expr> {
         let args = extract_args(self.func)?
             .into_iter()
@@ -1837,68 +1902,151 @@ impl SQLFunctionVisitor<'_> {
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
-        Ok(self
-            .ctx
+        self.ctx
             .function_registry
-            .get_udf(func_name)?
-            .ok_or_else(|| polars_err!(SQLInterface: "UDF {} not found", func_name))?
-            .call(args))
+            .call(func_name, args, self.active_schema)
     }
 
     /// Validate window frame specifications.
     ///
-    /// Polars only supports ROWS frame semantics, and does
-    /// not currently support customising the window.
-    ///
-    /// **Supported Frame Spec**
-    /// - `ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`
-    ///
-    /// **Unsupported Frame Spec**
-    /// - `RANGE ...` (peer group semantics not implemented)
-    /// - `GROUPS ...` (peer group semantics not implemented)
-    /// - `ROWS` with other bounds (e.g., `<n> PRECEDING`, `FOLLOWING`, etc)
+    /// Functions that are not evaluated over a sliding frame only support the default
+    /// frame, or `ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW` (ROWS semantics are
+    /// used for both). Sliding frames are only supported by the aggregate functions
+    /// that map onto a rolling kernel (see [`SlidingWindowAgg`]).
     fn validate_window_frame(&self, window_frame: &Option<WindowFrame>) -> PolarsResult<()> {
         if let Some(frame) = window_frame {
-            match frame.units {
-                WindowFrameUnits::Range => {
-                    polars_bail!(
-                        SQLInterface:
-                        "RANGE-based window frames are not supported"
-                    );
-                },
-                WindowFrameUnits::Groups => {
-                    polars_bail!(
-                        SQLInterface:
-                        "GROUPS-based window frames are not supported"
-                    );
-                },
-                WindowFrameUnits::Rows => {
-                    if !matches!(
-                        (&frame.start_bound, &frame.end_bound),
-                        (
-                            WindowFrameBound::Preceding(None),         // UNBOUNDED PRECEDING
-                            None | Some(WindowFrameBound::CurrentRow)  // CURRENT ROW
-                        )
-                    ) {
-                        polars_bail!(
-                            SQLInterface:
-                            "only 'ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW' is currently supported; found 'ROWS BETWEEN {} AND {}'",
-                            frame.start_bound,
-                            frame.end_bound.as_ref().map_or("CURRENT ROW", |b| {
-                                match b {
-                                    WindowFrameBound::CurrentRow => "CURRENT ROW",
-                                    WindowFrameBound::Preceding(_) => "N PRECEDING",
-                                    WindowFrameBound::Following(_) => "N FOLLOWING",
-                                }
-                            })
-                        );
-                    }
-                },
+            if parse_sliding_window_frame(frame)?.is_some() {
+                polars_bail!(
+                    SQLInterface:
+                    "window frame '{}' is only supported for COUNT, SUM, AVG, MIN, MAX, MEDIAN, STDDEV and VARIANCE",
+                    fmt_window_frame(frame)
+                );
             }
         }
         Ok(())
     }
 
+    /// Resolve the sliding window frame of the function being visited, if it has one.
+    fn sliding_window_frame(&self) -> PolarsResult<Option<(WindowSpec, SlidingWindowFrame)>> {
+        let Some(window_type) = &self.func.over else {
+            return Ok(None);
+        };
+        let spec = self.resolve_window_spec(window_type)?;
+        let frame = match &spec.window_frame {
+            Some(frame) => parse_sliding_window_frame(frame)?,
+            None => None,
+        };
+        Ok(frame.map(|frame| (spec, frame)))
+    }
+
+    /// Evaluate an aggregate over a sliding window frame with the rolling kernels.
+    ///
+    /// - `ROWS BETWEEN <n> PRECEDING AND <m> FOLLOWING` maps onto a fixed-size rolling
+    ///   window of `n + m + 1` rows, aligned so that it ends `m` rows after the current row.
+    /// - `RANGE BETWEEN <offset> PRECEDING AND CURRENT ROW` maps onto a rolling window
+    ///   over the (single) ORDER BY expression, covering `[value - offset, value]`.
+    ///
+    /// Rows outside the partition do not contribute to the frame, so frames are truncated
+    /// at the partition boundaries (as in SQL).
+    fn apply_sliding_window(
+        &mut self,
+        agg: SlidingWindowAgg,
+        value: Expr,
+        spec: &WindowSpec,
+        frame: SlidingWindowFrame,
+    ) -> PolarsResult<Expr> {
+        polars_ensure!(
+            !spec.order_by.is_empty(),
+            SQLSyntax: "window frame '{}' requires an ORDER BY clause",
+            spec.window_frame.as_ref().map(fmt_window_frame).unwrap_or_default()
+        );
+        let (order_by_exprs, all_desc) = self.parse_order_by_in_window(&spec.order_by)?;
+        let partition_by_exprs = if spec.partition_by.is_empty() {
+            None
+        } else {
+            Some(
+                spec.partition_by
+                    .iter()
+                    .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+                    .collect::<PolarsResult<Vec<_>>>()?,
+            )
+        };
+
+        // COUNT sums the non-null indicators of the values in the frame
+        let value = match agg {
+            SlidingWindowAgg::Count => value.is_not_null().cast(IDX_DTYPE),
+            _ => value,
+        };
+        let rolled = match frame {
+            SlidingWindowFrame::Rows { start, end } => {
+                let options = RollingOptionsFixedWindow {
+                    window_size: (end - start + 1) as usize,
+                    min_periods: 1,
+                    ..Default::default()
+                };
+                // Rolling windows end at the current row; pad (or shift) the values so
+                // that they end at the last row of the frame instead.
+                if end > 0 {
+                    agg.rolling(value.extend_constant(lit(NULL), lit(end)), options)
+                        .slice(lit(end), lit(NULL))
+                } else if end < 0 {
+                    agg.rolling(value, options).shift(lit(-end))
+                } else {
+                    agg.rolling(value, options)
+                }
+            },
+            SlidingWindowFrame::Range(offset) => {
+                polars_ensure!(
+                    order_by_exprs.len() == 1 && !all_desc,
+                    SQLSyntax: "RANGE window frames require a single ascending ORDER BY expression"
+                );
+                let options = RollingOptionsDynamicWindow {
+                    window_size: offset,
+                    min_periods: 1,
+                    closed_window: ClosedWindow::Both,
+                    fn_params: None,
+                };
+                agg.rolling_by(value, order_by_exprs[0].clone(), options)
+            },
+        };
+        let rolled = match agg {
+            SlidingWindowAgg::Count => rolled.fill_null(lit(0)),
+            _ => rolled,
+        };
+        let sort_opts = SortOptions::default().with_order_descending(all_desc);
+        rolled.over_with_options(
+            partition_by_exprs,
+            Some((order_by_exprs, sort_opts)),
+            Default::default(),
+        )
+    }
+
+    /// Parse the single argument of an aggregate evaluated over a sliding window frame.
+    fn parse_sliding_window_arg(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)
+            },
+            _ => self.not_supported_error(),
+        }
+    }
+
+    /// Aggregate functions that can also be evaluated over a sliding window frame.
+    fn visit_unary_with_opt_sliding_window(
+        &mut self,
+        f: impl Fn(Expr) -> Expr,
+        agg: SlidingWindowAgg,
+    ) -> PolarsResult<Expr> {
+        match self.sliding_window_frame()? {
+            Some((spec, frame)) => {
+                let value = self.parse_sliding_window_arg()?;
+                self.apply_sliding_window(agg, value, &spec, frame)
+            },
+            None => self.visit_unary(f),
+        }
+    }
+
     /// Window specs that map to cumulative functions.
     ///
     /// Converts SQL window functions with ORDER BY to compatible cumulative ops:
@@ -2025,7 +2173,12 @@ impl SQLFunctionVisitor<'_> {
         &mut self,
         f: impl Fn(Expr) -> Expr,
         cumulative_fn: impl Fn(Expr, bool) -> Expr,
+        agg: SlidingWindowAgg,
     ) -> PolarsResult<Expr> {
+        if let Some((spec, frame)) = self.sliding_window_frame()? {
+            let value = self.parse_sliding_window_arg()?;
+            return self.apply_sliding_window(agg, value, &spec, frame);
+        }
         match self.func.over.as_ref() {
             Some(window_type) => {
                 let spec = self.resolve_window_spec(window_type)?;
@@ -2237,6 +2390,20 @@ impl SQLFunctionVisitor<'_> {
     fn visit_count(&mut self) -> PolarsResult<Expr> {
         let (args, is_distinct) = extract_args_distinct(self.func)?;
 
+        // Window function with a sliding frame?
+        if !is_distinct {
+            if let Some((spec, frame)) = self.sliding_window_frame()? {
+                let value = match args.as_slice() {
+                    [FunctionArgExpr::Wildcard] | [] => {
+                        int_range(lit(0), len(), 1, DataType::Int64)
+                    },
+                    [FunctionArgExpr::Expr(e)] => parse_sql_expr(e, self.ctx, self.active_schema)?,
+                    _ => return self.not_supported_error(),
+                };
+                return self.apply_sliding_window(SlidingWindowAgg::Count, value, &spec, frame);
+            }
+        }
+
         // Window function with an ORDER BY clause?
         let has_order_by = match &self.func.over {
             Some(WindowType::WindowSpec(spec)) => !spec.order_by.is_empty(),
@@ -2280,6 +2447,7 @@ impl SQLFunctionVisitor<'_> {
                         return self.visit_unary_with_opt_cumulative(
                             |e| e.count(),
                             |e, reverse| e.cum_count(reverse),
+                            SlidingWindowAgg::Count,
                         );
                     },
                     _ => {},
@@ -2429,6 +2597,139 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// A window frame that is evaluated with the rolling kernels.
+enum SlidingWindowFrame {
+    /// `ROWS BETWEEN ...`, with the first and last rows of the frame given as offsets
+    /// relative to the current row (negative offsets are `PRECEDING`).
+    Rows { start: i64, end: i64 },
+    /// `RANGE BETWEEN <offset> PRECEDING AND CURRENT ROW`.
+    Range(Duration),
+}
+
+/// Aggregate functions that can be evaluated over a sliding window frame.
+#[derive(Clone, Copy)]
+enum SlidingWindowAgg {
+    Count,
+    Max,
+    Mean,
+    Median,
+    Min,
+    StdDev,
+    Sum,
+    Variance,
+}
+
+impl SlidingWindowAgg {
+    fn rolling(self, expr: Expr, options: RollingOptionsFixedWindow) -> Expr {
+        match self {
+            Self::Count | Self::Sum => expr.rolling_sum(options),
+            Self::Max => expr.rolling_max(options),
+            Self::Mean => expr.rolling_mean(options),
+            Self::Median => expr.rolling_median(options),
+            Self::Min => expr.rolling_min(options),
+            Self::StdDev => expr.rolling_std(options),
+            Self::Variance => expr.rolling_var(options),
+        }
+    }
+
+    fn rolling_by(self, expr: Expr, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        match self {
+            Self::Count | Self::Sum => expr.rolling_sum_by(by, options),
+            Self::Max => expr.rolling_max_by(by, options),
+            Self::Mean => expr.rolling_mean_by(by, options),
+            Self::Median => expr.rolling_median_by(by, options),
+            Self::Min => expr.rolling_min_by(by, options),
+            Self::StdDev => expr.rolling_std_by(by, options),
+            Self::Variance => expr.rolling_var_by(by, options),
+        }
+    }
+}
+
+/// Parse a window frame that has to be evaluated over a sliding window.
+///
+/// Returns `None` for `ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`, which is
+/// handled by the regular (cumulative) window functions.
+fn parse_sliding_window_frame(frame: &WindowFrame) -> PolarsResult<Option<SlidingWindowFrame>> {
+    let end_bound = frame
+        .end_bound
+        .as_ref()
+        .unwrap_or(&WindowFrameBound::CurrentRow);
+
+    match frame.units {
+        WindowFrameUnits::Rows => {
+            let (start, end) = match (&frame.start_bound, end_bound) {
+                (WindowFrameBound::Preceding(None), WindowFrameBound::CurrentRow) => {
+                    return Ok(None);
+                },
+                (WindowFrameBound::Preceding(None), _) | (_, WindowFrameBound::Following(None)) => {
+                    polars_bail!(
+                        SQLInterface:
+                        "only 'ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW' is supported for unbounded ROWS-based window frames; found '{}'",
+                        fmt_window_frame(frame)
+                    )
+                },
+                (start, end) => (frame_bound_offset(start)?, frame_bound_offset(end)?),
+            };
+            polars_ensure!(
+                start <= end,
+                SQLSyntax: "window frame '{}' cannot start after it ends",
+                fmt_window_frame(frame)
+            );
+            Ok(Some(SlidingWindowFrame::Rows { start, end }))
+        },
+        WindowFrameUnits::Range => match (&frame.start_bound, end_bound) {
+            (WindowFrameBound::Preceding(Some(offset)), WindowFrameBound::CurrentRow) => {
+                let offset = match offset.as_ref() {
+                    SQLExpr::Interval(interval) => interval_to_duration(interval, false)?,
+                    offset => Duration::new(frame_offset(offset)?),
+                };
+                Ok(Some(SlidingWindowFrame::Range(offset)))
+            },
+            _ => polars_bail!(
+                SQLInterface:
+                "only 'RANGE BETWEEN <offset> PRECEDING AND CURRENT ROW' is supported for RANGE-based window frames; found '{}'",
+                fmt_window_frame(frame)
+            ),
+        },
+        WindowFrameUnits::Groups => {
+            polars_bail!(SQLInterface: "GROUPS-based window frames are not supported")
+        },
+    }
+}
+
+/// Offset of a (bounded) ROWS window frame bound, relative to the current row.
+fn frame_bound_offset(bound: &WindowFrameBound) -> PolarsResult<i64> {
+    match bound {
+        WindowFrameBound::CurrentRow => Ok(0),
+        WindowFrameBound::Preceding(Some(offset)) => Ok(-frame_offset(offset)?),
+        WindowFrameBound::Following(Some(offset)) => frame_offset(offset),
+        _ => polars_bail!(SQLSyntax: "expected a bounded window frame offset; found {}", bound),
+    }
+}
+
+fn frame_offset(offset: &SQLExpr) -> PolarsResult<i64> {
+    if let SQLExpr::Value(ValueWithSpan {
+        value: SQLValue::Number(n, _),
+        ..
+    }) = offset
+    {
+        if let Ok(n @ 0..) = n.parse::<i64>() {
+            return Ok(n);
+        }
+    }
+    polars_bail!(SQLSyntax: "window frame offset must be a non-negative integer; found {}", offset)
+}
+
+fn fmt_window_frame(frame: &WindowFrame) -> String {
+    match &frame.end_bound {
+        Some(end_bound) => format!(
+            "{} BETWEEN {} AND {}",
+            frame.units, frame.start_bound, end_bound
+        ),
+        None => format!("{} {}", frame.units, frame.start_bound),
+    }
+}
+
 /// Returns true if the SQL expression is a non-null literal value (e.g. `1`, `'hello'`, `TRUE`).
 fn is_non_null_literal(expr: &SQLExpr) -> bool {
     matches!(
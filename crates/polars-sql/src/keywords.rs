@@ -19,13 +19,17 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::ARRAY,
         keywords::AS,
         keywords::ASC,
+        keywords::ASOF,
         keywords::BOOLEAN,
         keywords::BY,
         keywords::CASE,
+        keywords::COLUMNS,
         keywords::CREATE,
+        keywords::CUBE,
         keywords::DATE,
         keywords::DATETIME,
         keywords::DESC,
+        keywords::DESCRIBE,
         keywords::DISTINCT,
         keywords::DOUBLE,
         keywords::DROP,
@@ -35,15 +39,21 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::FROM,
         keywords::FULL,
         keywords::GROUP,
+        keywords::GROUPING,
         keywords::HAVING,
         keywords::IN,
         keywords::INNER,
+        keywords::INSERT,
         keywords::INT,
         keywords::INTERSECT,
         keywords::INTERVAL,
+        keywords::INTO,
         keywords::JOIN,
         keywords::LEFT,
         keywords::LIMIT,
+        keywords::MATCHED,
+        keywords::MATCH_CONDITION,
+        keywords::MERGE,
         keywords::NOT,
         keywords::NULL,
         keywords::OFFSET,
@@ -51,22 +61,33 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::OR,
         keywords::ORDER,
         keywords::OUTER,
+        keywords::PIVOT,
+        keywords::RECURSIVE,
         keywords::REGEXP,
         keywords::RENAME,
         keywords::REPLACE,
         keywords::RIGHT,
         keywords::RLIKE,
+        keywords::ROLLUP,
         keywords::SELECT,
         keywords::SEMI,
+        keywords::SET,
+        keywords::SETS,
         keywords::SHOW,
         keywords::TABLE,
         keywords::TABLES,
+        keywords::TEMPORARY,
         keywords::THEN,
         keywords::TIME,
         keywords::TRUNCATE,
         keywords::UNION,
+        keywords::UNPIVOT,
+        keywords::UPDATE,
         keywords::USING,
+        keywords::VALUES,
         keywords::VARCHAR,
+        keywords::VIEW,
+        keywords::VIEWS,
         keywords::WHEN,
         keywords::WHERE,
         keywords::WITH,
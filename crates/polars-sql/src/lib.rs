@@ -1,10 +1,17 @@
 //! Polars SQL
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
+#[cfg(feature = "asof_join")]
+mod asof_join;
+mod catalog;
 mod context;
+mod dml;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
+#[cfg(feature = "pivot")]
+mod pivot;
+mod recursive_cte;
 mod sql_expr;
 mod sql_visitors;
 mod subquery;
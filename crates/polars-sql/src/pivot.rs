@@ -0,0 +1,208 @@
+//! `PIVOT` and `UNPIVOT` table operators.
+//!
+//! A PIVOT turns the values of a column into new columns, aggregating the other values
+//! for each of them (grouped by the remaining columns); UNPIVOT turns columns into rows.
+//!
+//! ```sql
+//! SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2', 'Q3', 'Q4'))
+//! SELECT * FROM quarterly UNPIVOT (amount FOR quarter IN (Q1, Q2, Q3, Q4))
+//! ```
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use sqlparser::ast::{
+    BinaryOperator as SQLBinaryOperator, Expr as SQLExpr, ExprWithAlias, Ident, NullInclusion,
+    PivotValueSource, TableAlias, TableFactor, Value as SQLValue, ValueWithSpan,
+};
+
+use crate::SQLContext;
+use crate::sql_expr::parse_sql_expr;
+
+impl SQLContext {
+    /// Execute a `PIVOT` over the given table.
+    pub(crate) fn execute_pivot(
+        &mut self,
+        table: &TableFactor,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[SQLExpr],
+        value_source: &PivotValueSource,
+        default_on_null: &Option<SQLExpr>,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        let (tbl_name, mut lf) = self.get_table(table)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let [pivot_column] = value_column else {
+            polars_bail!(SQLInterface: "PIVOT on more than one column is not supported")
+        };
+        let PivotValueSource::List(values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT requires an explicit list of values; found IN ({})", value_source)
+        };
+        polars_ensure!(!values.is_empty(), SQLSyntax: "PIVOT requires at least one value");
+        let default_on_null = default_on_null
+            .as_ref()
+            .map(|e| parse_sql_expr(e, self, Some(&schema)))
+            .transpose()?;
+
+        // Columns that are neither pivoted nor aggregated form the group keys.
+        let mut used_columns = column_names(parse_sql_expr(pivot_column, self, Some(&schema))?);
+        let mut aggs = vec![];
+        for agg in aggregate_functions {
+            let SQLExpr::Function(func) = &agg.expr else {
+                polars_bail!(SQLSyntax: "PIVOT expects aggregate functions; found {}", agg.expr)
+            };
+            used_columns.extend(column_names(parse_sql_expr(
+                &agg.expr,
+                self,
+                Some(&schema),
+            )?));
+
+            for value in values {
+                // Aggregate the rows of each pivot value with an (implicit) FILTER clause.
+                let pivot_filter = SQLExpr::BinaryOp {
+                    left: Box::new(pivot_column.clone()),
+                    op: SQLBinaryOperator::Eq,
+                    right: Box::new(value.expr.clone()),
+                };
+                let mut func = func.clone();
+                func.filter = Some(Box::new(match func.filter.take() {
+                    Some(filter) => SQLExpr::BinaryOp {
+                        left: filter,
+                        op: SQLBinaryOperator::And,
+                        right: Box::new(pivot_filter),
+                    },
+                    None => pivot_filter,
+                }));
+                let mut expr = parse_sql_expr(&SQLExpr::Function(func), self, Some(&schema))?;
+                if let Some(default) = &default_on_null {
+                    expr = expr.fill_null(default.clone());
+                }
+                let name = match aggregate_functions {
+                    [_] => pivot_value_name(value),
+                    _ => format!(
+                        "{}_{}",
+                        pivot_value_name(value),
+                        agg.alias
+                            .as_ref()
+                            .map_or_else(|| agg.expr.to_string(), |a| a.value.clone())
+                    ),
+                };
+                aggs.push(expr.alias(name));
+            }
+        }
+
+        let keys: Vec<Expr> = schema
+            .iter_names()
+            .filter(|name| !used_columns.contains(name))
+            .map(|name| col(name.clone()))
+            .collect();
+        let lf = if keys.is_empty() {
+            lf.select(aggs)
+        } else {
+            lf.group_by_stable(keys).agg(aggs)
+        };
+        self.finish_table_operator(tbl_name, lf, alias)
+    }
+
+    /// Execute an `UNPIVOT` over the given table.
+    pub(crate) fn execute_unpivot(
+        &mut self,
+        table: &TableFactor,
+        value: &SQLExpr,
+        name: &Ident,
+        columns: &[ExprWithAlias],
+        null_inclusion: &Option<NullInclusion>,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        let (tbl_name, mut lf) = self.get_table(table)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let SQLExpr::Identifier(value_name) = value else {
+            polars_bail!(SQLInterface: "UNPIVOT value must be a column name; found {}", value)
+        };
+        let mut on = Vec::with_capacity(columns.len());
+        let mut renamed = (vec![], vec![]);
+        for column in columns {
+            let column_name = match strip_alias(parse_sql_expr(&column.expr, self, Some(&schema))?)
+            {
+                Expr::Column(name) => name,
+                _ => polars_bail!(SQLSyntax: "UNPIVOT expects column names; found {}", column.expr),
+            };
+            // The unpivoted column name (or alias) becomes the value of the name column.
+            match &column.alias {
+                Some(alias) => {
+                    renamed.0.push(column_name);
+                    renamed.1.push(PlSmallStr::from_str(&alias.value));
+                    on.push(PlSmallStr::from_str(&alias.value));
+                },
+                None => on.push(column_name),
+            }
+        }
+        let index: Vec<PlSmallStr> = schema
+            .iter_names()
+            .filter(|name| !on.contains(name) && !renamed.0.contains(name))
+            .cloned()
+            .collect();
+
+        let mut lf = lf
+            .rename(renamed.0, renamed.1, true)
+            .unpivot(UnpivotArgsDSL {
+                on: Some(cols(on)),
+                index: cols(index),
+                variable_name: Some(PlSmallStr::from_str(&name.value)),
+                value_name: Some(PlSmallStr::from_str(&value_name.value)),
+            });
+        if !matches!(null_inclusion, Some(NullInclusion::IncludeNulls)) {
+            lf = lf.filter(col(value_name.value.as_str()).is_not_null());
+        }
+        self.finish_table_operator(tbl_name, lf, alias)
+    }
+
+    /// Apply the (optional) alias of a PIVOT or UNPIVOT to its result.
+    fn finish_table_operator(
+        &mut self,
+        tbl_name: String,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map
+                    .write()
+                    .unwrap()
+                    .insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((tbl_name, lf)),
+        }
+    }
+}
+
+/// The column name of a PIVOT value: its alias, or the value itself.
+fn pivot_value_name(value: &ExprWithAlias) -> String {
+    match (&value.alias, &value.expr) {
+        (Some(alias), _) => alias.value.clone(),
+        (
+            None,
+            SQLExpr::Value(ValueWithSpan {
+                value: SQLValue::SingleQuotedString(s) | SQLValue::DoubleQuotedString(s),
+                ..
+            }),
+        ) => s.clone(),
+        (None, expr) => expr.to_string(),
+    }
+}
+
+/// Remove the alias that column resolution may add to an expression.
+fn strip_alias(expr: Expr) -> Expr {
+    match expr {
+        Expr::Alias(inner, _) => Arc::unwrap_or_clone(inner),
+        e => e,
+    }
+}
+
+fn column_names(expr: Expr) -> Vec<PlSmallStr> {
+    strip_alias(expr).meta().root_names()
+}
@@ -0,0 +1,160 @@
+//! Recursive CTEs (`WITH RECURSIVE`).
+//!
+//! A recursive CTE has the form `<anchor> UNION [ALL] <recursive term>`, where only the
+//! recursive term refers to the CTE itself. It is evaluated as a fixpoint when the plan
+//! is executed: the recursive term is applied to the rows produced by the previous
+//! iteration (starting with the anchor rows) until it produces no new rows.
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use sqlparser::ast::{Cte, Query, SetExpr, SetOperator, SetQuantifier, Visit};
+
+use crate::SQLContext;
+use crate::sql_visitors::TableIdentifierCollector;
+
+impl SQLContext {
+    /// Check whether the CTE refers to itself.
+    pub(crate) fn is_recursive_cte(&self, cte: &Cte) -> bool {
+        referenced_tables(&cte.query.body).contains(&cte.alias.name.value)
+    }
+
+    pub(crate) fn execute_recursive_cte(&mut self, cte: &Cte) -> PolarsResult<LazyFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left: anchor_term,
+            right: recursive_term,
+        } = cte.query.body.as_ref()
+        else {
+            polars_bail!(SQLSyntax: "recursive CTE '{}' must have the form `<anchor> UNION [ALL] <recursive term>`", cte_name)
+        };
+        let distinct = match set_quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => {
+                polars_bail!(SQLInterface: "'UNION {}' is not supported in recursive CTEs", set_quantifier)
+            },
+        };
+        polars_ensure!(
+            !referenced_tables(anchor_term).contains(&cte.alias.name.value),
+            SQLSyntax: "the anchor term of recursive CTE '{}' cannot refer to the CTE itself", cte_name
+        );
+
+        // ORDER BY and LIMIT apply to the result of the fixpoint, not to each iteration.
+        let mut term_query = (*cte.query).clone();
+        term_query.order_by = None;
+        term_query.limit_clause = None;
+        term_query.fetch = None;
+
+        let mut anchor = self.execute_isolated(|ctx| {
+            ctx.register_ctes(&term_query)?;
+            ctx.process_query(anchor_term, &term_query)
+        })?;
+        anchor = self.rename_columns_from_table_alias(anchor, &cte.alias)?;
+        let schema = self.get_frame_schema(&mut anchor)?;
+
+        // Resolve the recursive term once so that invalid queries are rejected up-front.
+        let mut validated = self.execute_recursive_term(
+            cte_name,
+            anchor.clone(),
+            recursive_term,
+            &term_query,
+            &schema,
+        )?;
+        self.get_frame_schema(&mut validated)?;
+
+        let ctx = self.isolated();
+        let name = cte_name.to_string();
+        let recursive_term = recursive_term.as_ref().clone();
+        let limit = self.recursion_limit;
+        let lf = anchor.map(
+            move |anchor_df| {
+                let dedup = |lf: LazyFrame| lf.unique_stable(None, UniqueKeepStrategy::First);
+                let mut result = if distinct {
+                    dedup(anchor_df.lazy()).collect()?
+                } else {
+                    anchor_df
+                };
+                let mut working = result.clone();
+                let mut n_iterations = 0;
+                while working.height() > 0 {
+                    polars_ensure!(
+                        n_iterations < limit,
+                        SQLInterface: "recursive CTE '{}' did not complete within the recursion limit ({} iterations); use `SQLContext::with_recursion_limit` to raise it",
+                        name, limit
+                    );
+                    n_iterations += 1;
+
+                    let step = ctx
+                        .isolated()
+                        .execute_recursive_term(
+                            &name,
+                            working.lazy(),
+                            &recursive_term,
+                            &term_query,
+                            &schema,
+                        )?
+                        .collect()?;
+                    if distinct {
+                        // Rows that were already produced are not fed back into the next
+                        // iteration, so the recursion stops once no new rows are found.
+                        let n_seen = result.height();
+                        let combined = concat([result.lazy(), step.lazy()], UnionArgs::default())?;
+                        result = dedup(combined).collect()?;
+                        working = result.slice(n_seen as i64, usize::MAX);
+                    } else {
+                        result.vstack_mut(&step)?;
+                        working = step;
+                    }
+                }
+                result.rechunk_mut();
+                Ok(result)
+            },
+            AllowedOptimizations::empty(),
+            None,
+            Some("RECURSIVE CTE"),
+        );
+
+        let lf = self.process_order_by(lf, &cte.query.order_by, None)?;
+        self.process_limit_offset(lf, &cte.query.limit_clause, &cte.query.fetch)
+    }
+
+    /// Evaluate the recursive term against the rows of the previous iteration, matching
+    /// its columns (by position) to the names and types of the anchor term.
+    fn execute_recursive_term(
+        &mut self,
+        cte_name: &str,
+        working: LazyFrame,
+        recursive_term: &SetExpr,
+        query: &Query,
+        schema: &Schema,
+    ) -> PolarsResult<LazyFrame> {
+        let mut lf = self.execute_isolated(|ctx| {
+            ctx.register_ctes(query)?;
+            ctx.cte_map.insert(cte_name.to_string(), working);
+            ctx.process_query(recursive_term, query)
+        })?;
+        let term_schema = self.get_frame_schema(&mut lf)?;
+        polars_ensure!(
+            term_schema.len() == schema.len(),
+            SQLSyntax: "recursive CTE '{}' has {} columns in its anchor term but {} in its recursive term",
+            cte_name, schema.len(), term_schema.len()
+        );
+        let exprs: Vec<_> = schema
+            .iter()
+            .zip(term_schema.iter_names())
+            .map(|((name, dtype), term_name)| {
+                col(term_name.clone())
+                    .cast(dtype.clone())
+                    .alias(name.clone())
+            })
+            .collect();
+        Ok(lf.select(exprs))
+    }
+}
+
+fn referenced_tables(expr: &SetExpr) -> Vec<String> {
+    let mut collector = TableIdentifierCollector::default();
+    let _ = expr.visit(&mut collector);
+    collector.tables
+}
@@ -6,7 +6,11 @@
 use std::ops::ControlFlow;
 
 use polars_core::prelude::*;
-use sqlparser::ast::{Expr as SQLExpr, ObjectName, Query, SetExpr, Visit, Visitor as SQLVisitor};
+use polars_utils::unique_column_name;
+use sqlparser::ast::{
+    Expr as SQLExpr, Ident, ObjectName, Query, SetExpr, Visit, VisitMut, Visitor as SQLVisitor,
+    VisitorMut as SQLVisitorMut,
+};
 use sqlparser::keywords::ALL_KEYWORDS;
 
 // ---------------------------------------------------------------------------
@@ -263,3 +267,48 @@ impl SQLVisitor for WindowFunctionFinder {
 pub(crate) fn expr_has_window_functions(expr: &SQLExpr) -> bool {
     expr.visit(&mut WindowFunctionFinder).is_break()
 }
+
+// ---------------------------------------------------------------------------
+// ScalarSubqueryExtractor
+// ---------------------------------------------------------------------------
+
+/// Visitor that replaces the scalar subqueries of an expression (but not those
+/// nested inside other subqueries) with references to temporary columns.
+#[derive(Default)]
+struct ScalarSubqueryExtractor {
+    query_depth: usize,
+    subqueries: Vec<(PlSmallStr, Query)>,
+}
+
+impl SQLVisitorMut for ScalarSubqueryExtractor {
+    type Break = ();
+
+    fn pre_visit_query(&mut self, _query: &mut Query) -> ControlFlow<Self::Break> {
+        self.query_depth += 1;
+        ControlFlow::Continue(())
+    }
+
+    fn post_visit_query(&mut self, _query: &mut Query) -> ControlFlow<Self::Break> {
+        self.query_depth -= 1;
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_expr(&mut self, expr: &mut SQLExpr) -> ControlFlow<Self::Break> {
+        if self.query_depth == 0 && matches!(expr, SQLExpr::Subquery(_)) {
+            let name = unique_column_name();
+            let column = SQLExpr::Identifier(Ident::new(name.as_str()));
+            if let SQLExpr::Subquery(query) = std::mem::replace(expr, column) {
+                self.subqueries.push((name, *query));
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Replace the scalar subqueries of a SQL expression with references to temporary
+/// columns, returning the column names along with the subqueries they stand for.
+pub(crate) fn extract_scalar_subqueries(expr: &mut SQLExpr) -> Vec<(PlSmallStr, Query)> {
+    let mut extractor = ScalarSubqueryExtractor::default();
+    let _ = VisitMut::visit(expr, &mut extractor);
+    extractor.subqueries
+}
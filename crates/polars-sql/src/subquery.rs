@@ -1,23 +1,48 @@
-//! Rewrites of `[NOT] EXISTS` / `[NOT] IN (subquery)` predicates into semi /
-//! anti joins, decorrelating equi-correlation predicates into join keys.
-//! Subquery shapes the rewrites can't soundly express return `None` so the
-//! caller falls back to the generic filter path.
+//! Decorrelation of subqueries into joins:
+//!
+//! * `[NOT] EXISTS` / `[NOT] IN (subquery)` predicates become semi / anti joins, with
+//!   equi-correlation predicates as join keys. Subquery shapes these rewrites can't
+//!   soundly express return `None` so the caller falls back to the generic filter path.
+//! * Scalar subqueries in SELECT and WHERE become left joins against the outer frame.
+//! * `LATERAL` derived tables become (left) joins on their correlation keys.
+//!
+//! A subquery correlated by equalities is executed once for all outer rows, with its
+//! inner correlation keys added to the projection (and to the grouping, window
+//! partitions and LIMIT, which then apply per key). Other correlations join each outer
+//! row (by row index) with the inner rows satisfying the subquery's WHERE clause.
+use std::ops::ControlFlow;
+
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
+use polars_ops::frame::{JoinCoalesce, JoinValidation, MaintainOrderJoin};
+use polars_plan::dsl::functions::{int_range, len};
 #[cfg(feature = "semi_anti_join")]
-use polars_plan::utils::{expr_to_leaf_column_names_iter, has_expr};
-#[cfg(feature = "semi_anti_join")]
+use polars_plan::utils::expr_to_leaf_column_names_iter;
+use polars_plan::utils::{expr_output_name, has_expr};
 use polars_utils::aliases::PlHashSet;
-use sqlparser::ast::{BinaryOperator as SQLBinaryOperator, Expr as SQLExpr, Query};
-#[cfg(feature = "semi_anti_join")]
-use sqlparser::ast::{Distinct, GroupByExpr, Select, SelectItem, SetExpr, TableWithJoins};
+use polars_utils::format_pl_smallstr;
+use sqlparser::ast::{
+    BinaryOperator as SQLBinaryOperator, Distinct, Expr as SQLExpr, Function, GroupByExpr, Ident,
+    JoinConstraint, JoinOperator, NamedWindowDefinition, NamedWindowExpr, Query, Select,
+    SelectItem, SetExpr, TableAlias, TableWithJoins, Value as SQLValue, ValueWithSpan, Visit,
+    WindowSpec, WindowType, visit_expressions, visit_expressions_mut,
+};
 
 use crate::SQLContext;
-use crate::context::FilterMode;
-#[cfg(feature = "semi_anti_join")]
-use crate::context::get_table_name;
-#[cfg(feature = "semi_anti_join")]
+use crate::context::{FilterMode, get_table_name, parse_limit_offset};
 use crate::sql_expr::parse_sql_expr;
+use crate::sql_visitors::extract_scalar_subqueries;
+
+/// Prefix of the inner correlation key columns of a decorrelated subquery.
+const CORRELATION_KEY_PREFIX: &str = "__POLARS_CORRELATION_KEY_";
+/// Constant join key for uncorrelated subqueries.
+const CONSTANT_KEY_NAME: &str = "__POLARS_CONSTANT_KEY";
+/// Prefix of the inner columns of a subquery joined row by row with the outer frame.
+const INNER_COLUMN_PREFIX: &str = "__POLARS_INNER_";
+/// Row index of the outer frame of a subquery joined row by row.
+const OUTER_ROW_NAME: &str = "__POLARS_OUTER_ROW";
+/// HAVING predicate of an aggregate subquery evaluated over no rows.
+const HAVING_NAME: &str = "__POLARS_HAVING";
 
 impl SQLContext {
     // Entry point: offer each WHERE conjunct to the rewrite, returning the
@@ -98,12 +123,59 @@ impl SQLContext {
         }
     }
 
+    // Lower `[NOT] EXISTS (subquery)` to a semi / anti join. Simple subqueries
+    // join directly on their equi-correlation keys, others are decorrelated as a
+    // whole (equi-correlations) or joined row by row (any other correlation).
+    #[cfg(feature = "semi_anti_join")]
+    fn try_rewrite_exists_as_join(
+        &mut self,
+        lf: &LazyFrame,
+        subquery: &Query,
+        negated: bool,
+        outer_schema: &Schema,
+    ) -> PolarsResult<Option<LazyFrame>> {
+        if let Some(joined) =
+            self.try_rewrite_simple_exists_as_join(lf, subquery, negated, outer_schema)?
+        {
+            return Ok(Some(joined));
+        }
+        let how = if negated {
+            JoinType::Anti
+        } else {
+            JoinType::Semi
+        };
+        let Some(decorrelated) = self.decorrelate_subquery(subquery, outer_schema)? else {
+            let lf = lf.clone().with_row_index(OUTER_ROW_NAME, None);
+            let (pairs, _) = self.join_correlated_rows(&lf, subquery, outer_schema, false)?;
+            return Ok(Some(
+                lf.join_builder()
+                    .with(pairs.select([col(OUTER_ROW_NAME)]))
+                    .on([col(OUTER_ROW_NAME)])
+                    .how(how)
+                    .finish()
+                    .drop(cols([OUTER_ROW_NAME])),
+            ));
+        };
+        // An aggregate without GROUP BY yields exactly one row, unless it is then
+        // filtered by HAVING, QUALIFY, LIMIT or OFFSET.
+        if decorrelated.empty_group.is_some() && !filters_aggregated_rows(subquery) {
+            return Ok(Some(if negated {
+                lf.clone().clear()
+            } else {
+                lf.clone()
+            }));
+        }
+        decorrelated
+            .join(lf.clone(), how, JoinValidation::ManyToMany, None)
+            .map(Some)
+    }
+
     // Lower `[NOT] EXISTS (SELECT ... FROM rel WHERE rel.k = outer.k ...)` to a
     // semi / anti join by decorrelating the equi-correlation predicate(s) into
     // join keys. DISTINCT is ignored: existence is invariant under
     // deduplication.
     #[cfg(feature = "semi_anti_join")]
-    fn try_rewrite_exists_as_join(
+    fn try_rewrite_simple_exists_as_join(
         &mut self,
         lf: &LazyFrame,
         subquery: &Query,
@@ -261,7 +333,6 @@ impl SQLContext {
     // Resolve the subquery's FROM (a single relation, possibly with joins) into
     // the inner LazyFrame, its schema, and the set of relation names/aliases
     // used to classify qualified correlation columns.
-    #[cfg(feature = "semi_anti_join")]
     fn resolve_subquery_from(
         &mut self,
         tbl_expr: &TableWithJoins,
@@ -293,11 +364,9 @@ impl SQLContext {
         let mut right_on = Vec::new();
         let mut local_filters = Vec::new();
         for conj in MintermIter::new(selection) {
-            if let Some((outer_key, inner_key)) =
-                correlation_key_pair(conj, inner_names, inner_schema, outer_schema)
-            {
-                left_on.push(col(outer_key));
-                right_on.push(col(inner_key));
+            if let Some(key) = correlation_key_pair(conj, inner_names, inner_schema, outer_schema) {
+                left_on.push(col(key.outer));
+                right_on.push(col(key.inner));
                 continue;
             }
             let Some(filter) = self.try_parse_inner_only_expr(conj, inner_schema, outer_schema)?
@@ -361,6 +430,727 @@ impl SQLContext {
     }
 }
 
+impl SQLContext {
+    // Join the values of the scalar subqueries in the given expressions to the
+    // frame, replacing each subquery with a reference to its (temporary) column.
+    // Returns the temporary column names along with the names of the values.
+    pub(crate) fn rewrite_scalar_subqueries<'a>(
+        &mut self,
+        mut lf: LazyFrame,
+        exprs: impl IntoIterator<Item = &'a mut SQLExpr>,
+        outer_schema: &Schema,
+    ) -> PolarsResult<(LazyFrame, Vec<(PlSmallStr, PlSmallStr)>)> {
+        let mut columns = Vec::new();
+        for expr in exprs {
+            for (name, subquery) in extract_scalar_subqueries(expr) {
+                let value_name;
+                (lf, value_name) = self.join_scalar_subquery(lf, &name, &subquery, outer_schema)?;
+                columns.push((name, value_name));
+            }
+        }
+        Ok((lf, columns))
+    }
+
+    // Rewrite the scalar subqueries in the projections of a SELECT as with
+    // `rewrite_scalar_subqueries`, returning the rewritten SELECT (if there were
+    // any). A bare subquery projection is named after the subquery's value.
+    pub(crate) fn rewrite_projection_subqueries(
+        &mut self,
+        lf: LazyFrame,
+        select: &Select,
+        outer_schema: &Schema,
+    ) -> PolarsResult<(LazyFrame, Vec<(PlSmallStr, PlSmallStr)>, Option<Select>)> {
+        let mut projection = select.projection.clone();
+        let (lf, columns) = self.rewrite_scalar_subqueries(
+            lf,
+            projection.iter_mut().filter_map(|item| match item {
+                SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                    Some(expr)
+                },
+                _ => None,
+            }),
+            outer_schema,
+        )?;
+        if columns.is_empty() {
+            return Ok((lf, columns, None));
+        }
+        for item in projection.iter_mut() {
+            let SelectItem::UnnamedExpr(SQLExpr::Identifier(ident)) = item else {
+                continue;
+            };
+            if let Some((_, value_name)) = columns
+                .iter()
+                .find(|(name, _)| name.as_str() == ident.value)
+            {
+                *item = SelectItem::ExprWithAlias {
+                    expr: SQLExpr::Identifier(ident.clone()),
+                    alias: Ident::new(value_name.as_str()),
+                };
+            }
+        }
+        let select = Select {
+            projection,
+            ..select.clone()
+        };
+        Ok((lf, columns, Some(select)))
+    }
+
+    // Left join the value of a scalar subquery to the frame as column `name`,
+    // returning the joined frame and the name of the subquery's value.
+    fn join_scalar_subquery(
+        &mut self,
+        lf: LazyFrame,
+        name: &PlSmallStr,
+        subquery: &Query,
+        outer_schema: &Schema,
+    ) -> PolarsResult<(LazyFrame, PlSmallStr)> {
+        let (lf, value_name) = match self.decorrelate_subquery(subquery, outer_schema)? {
+            Some(mut decorrelated) => {
+                let schema = self.get_frame_schema(&mut decorrelated.lf)?;
+                let values: Vec<&PlSmallStr> = schema
+                    .iter_names()
+                    .filter(|n| !decorrelated.right_on.contains(*n))
+                    .collect();
+                let [value_name] = values.as_slice() else {
+                    polars_bail!(SQLSyntax: "SQL subquery returns more than one column")
+                };
+                let value_name = (*value_name).clone();
+                decorrelated.rename_values(
+                    std::slice::from_ref(&value_name),
+                    std::slice::from_ref(name),
+                );
+                // A scalar subquery may yield at most one row for each outer row.
+                let lf = decorrelated.join(lf, JoinType::Left, JoinValidation::ManyToOne, None)?;
+                (lf, value_name)
+            },
+            None => {
+                let lf = lf.with_row_index(OUTER_ROW_NAME, None);
+                let (pairs, projection) =
+                    self.join_correlated_rows(&lf, subquery, outer_schema, true)?;
+                let [value] = projection.as_slice() else {
+                    polars_bail!(SQLSyntax: "SQL subquery returns more than one column")
+                };
+                polars_ensure!(
+                    has_expr(value, |e| matches!(e, Expr::Agg(_) | Expr::Len)),
+                    SQLInterface: "scalar subqueries correlated by non-equality predicates must be aggregates; found {}", subquery
+                );
+                let value_name = expr_output_name(value)?;
+                let value_name = value_name
+                    .strip_prefix(INNER_COLUMN_PREFIX)
+                    .map_or_else(|| value_name.clone(), PlSmallStr::from_str);
+                let values = pairs
+                    .clone()
+                    .group_by([col(OUTER_ROW_NAME)])
+                    .agg([value.clone().alias(name.clone())]);
+                // Outer rows without inner rows get the value of the aggregate over no rows.
+                let empty_values = lf
+                    .clone()
+                    .select([col(OUTER_ROW_NAME)])
+                    .join_builder()
+                    .with(pairs.clone().select([col(OUTER_ROW_NAME)]))
+                    .on([col(OUTER_ROW_NAME)])
+                    .how(JoinType::Anti)
+                    .finish()
+                    .cross_join(
+                        pairs.clear().select([value.clone().alias(name.clone())]),
+                        None,
+                    );
+                let union_args = UnionArgs {
+                    to_supertypes: true,
+                    ..Default::default()
+                };
+                let values = concat([values, empty_values], union_args)?;
+                let lf = lf
+                    .join_builder()
+                    .with(values)
+                    .on([col(OUTER_ROW_NAME)])
+                    .how(JoinType::Left)
+                    .maintain_order(MaintainOrderJoin::Left)
+                    .finish()
+                    .drop(cols([OUTER_ROW_NAME]));
+                (lf, value_name)
+            },
+        };
+        Ok((lf, value_name))
+    }
+
+    // Join a `LATERAL` derived table (a subquery that may reference the columns
+    // of the relations to its left) to the frame. Returns the joined frame along
+    // with the name and schema of the derived table.
+    pub(crate) fn process_lateral_join(
+        &mut self,
+        lf: LazyFrame,
+        left_schema: &Schema,
+        join_operator: &JoinOperator,
+        subquery: &Query,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(LazyFrame, String, SchemaRef)> {
+        let how = match join_operator {
+            JoinOperator::CrossJoin(JoinConstraint::None) => JoinType::Inner,
+            JoinOperator::Join(c) | JoinOperator::Inner(c) if is_trivial_constraint(c) => {
+                JoinType::Inner
+            },
+            JoinOperator::Left(c) | JoinOperator::LeftOuter(c) if is_trivial_constraint(c) => {
+                JoinType::Left
+            },
+            _ => polars_bail!(
+                SQLInterface: "LATERAL subqueries only support CROSS JOIN and [LEFT] JOIN ... ON TRUE; found {:?}", join_operator
+            ),
+        };
+        let Some(alias) = alias else {
+            polars_bail!(SQLInterface: "cannot JOIN on unnamed relation; please provide an alias")
+        };
+        let Some(mut decorrelated) = self.decorrelate_subquery(subquery, left_schema)? else {
+            polars_bail!(
+                SQLInterface: "LATERAL subqueries can only be correlated by equality predicates; found {}", subquery
+            )
+        };
+        let schema = self.get_frame_schema(&mut decorrelated.lf)?;
+        let mut names: Vec<PlSmallStr> = schema
+            .iter_names()
+            .filter(|n| !decorrelated.right_on.contains(*n))
+            .cloned()
+            .collect();
+        if !alias.columns.is_empty() {
+            polars_ensure!(
+                alias.columns.len() == names.len(),
+                SQLSyntax: "number of columns ({}) in alias '{}' does not match the number of columns in the table/query ({})",
+                alias.columns.len(), alias.name.value, names.len()
+            );
+            let aliases: Vec<PlSmallStr> = alias
+                .columns
+                .iter()
+                .map(|c| PlSmallStr::from_str(&c.name.value))
+                .collect();
+            decorrelated.rename_values(&names, &aliases);
+            names = aliases;
+        }
+
+        // Register the derived table (without its keys) for qualified references.
+        let r_name = alias.name.value.clone();
+        let mut rf = decorrelated
+            .lf
+            .clone()
+            .select(names.into_iter().map(col).collect::<Vec<_>>());
+        let right_schema = self.get_frame_schema(&mut rf)?;
+        self.table_map.write().unwrap().insert(r_name.clone(), rf);
+
+        let suffix = format_pl_smallstr!(":{}", r_name);
+        let lf = decorrelated.join(lf, how, JoinValidation::ManyToMany, Some(suffix))?;
+        Ok((lf, r_name, right_schema))
+    }
+
+    // Decorrelate a subquery over its equi-correlation keys, so that it runs once
+    // for all outer rows. Returns `None` if it is correlated by any other predicate.
+    fn decorrelate_subquery(
+        &mut self,
+        subquery: &Query,
+        outer_schema: &Schema,
+    ) -> PolarsResult<Option<DecorrelatedSubquery>> {
+        let uncorrelated = |ctx: &mut Self| -> PolarsResult<Option<DecorrelatedSubquery>> {
+            let lf = ctx.execute_isolated(|ctx| ctx.execute_query(subquery))?;
+            Ok(Some(DecorrelatedSubquery {
+                lf,
+                left_on: vec![],
+                right_on: vec![],
+                empty_group: None,
+            }))
+        };
+        let (SetExpr::Select(select), None) = (subquery.body.as_ref(), &subquery.with) else {
+            return uncorrelated(self);
+        };
+        let [from] = select.from.as_slice() else {
+            return uncorrelated(self);
+        };
+        let mut ctx = self.isolated();
+        let Some((inner_names, _, inner_schema)) = ctx.resolve_subquery_from(from)? else {
+            return uncorrelated(self);
+        };
+
+        let mut keys = Vec::new();
+        let mut local_filters = Vec::new();
+        for conj in select.selection.iter().flat_map(MintermIter::new) {
+            if let Some(key) = correlation_key_pair(conj, &inner_names, &inner_schema, outer_schema)
+            {
+                keys.push(key);
+            } else if references_outer_columns(conj, &inner_names, &inner_schema, outer_schema) {
+                return Ok(None);
+            } else {
+                local_filters.push(conj.clone());
+            }
+        }
+        if keys.is_empty() {
+            return uncorrelated(self);
+        }
+        // Outer columns are only resolved in the WHERE clause (as keys above).
+        let mut select = select.as_ref().clone();
+        select.selection = None;
+        let from = std::mem::take(&mut select.from);
+        polars_ensure!(
+            !references_outer_columns(&select, &inner_names, &inner_schema, outer_schema)
+                && !references_outer_columns(&subquery.order_by, &inner_names, &inner_schema, outer_schema),
+            SQLInterface: "correlated subqueries can only reference outer columns in their WHERE clause; found {}", subquery
+        );
+        polars_ensure!(
+            !matches!(select.distinct, Some(Distinct::On(_))),
+            SQLInterface: "DISTINCT ON is not supported in correlated subqueries"
+        );
+
+        // Evaluate the subquery for each key: as additional GROUP BY keys (also of
+        // an aggregate without GROUP BY), window partitions and projections.
+        let key_exprs: Vec<SQLExpr> = keys.iter().map(|k| k.inner_expr.clone()).collect();
+        let key_names: Vec<PlSmallStr> = (0..keys.len())
+            .map(|i| format_pl_smallstr!("{CORRELATION_KEY_PREFIX}{i}"))
+            .collect();
+        select.from = from;
+        select.selection = local_filters
+            .into_iter()
+            .reduce(|left, right| SQLExpr::BinaryOp {
+                left: Box::new(left),
+                op: SQLBinaryOperator::And,
+                right: Box::new(right),
+            });
+        let key_items: Vec<SelectItem> = key_exprs
+            .iter()
+            .zip(&key_names)
+            .map(|(expr, name)| SelectItem::ExprWithAlias {
+                expr: expr.clone(),
+                alias: Ident::new(name.as_str()),
+            })
+            .collect();
+        let mut empty_group = None;
+        if let GroupByExpr::Expressions(exprs, modifiers) = &select.group_by {
+            polars_ensure!(
+                modifiers.is_empty(),
+                SQLInterface: "GROUP BY modifiers are not supported in correlated subqueries"
+            );
+            if exprs.is_empty() && ctx.is_aggregate_projection(&select.projection, &inner_schema)? {
+                empty_group = Some(self.execute_empty_group(subquery, &select, &key_items)?);
+            }
+        }
+        if let GroupByExpr::Expressions(exprs, _) = &mut select.group_by
+            && (!exprs.is_empty() || empty_group.is_some())
+        {
+            exprs.extend(key_exprs.iter().cloned());
+        }
+        partition_windows_by(&mut select, &key_exprs, &key_names);
+        select.projection.extend(key_items);
+
+        // LIMIT and OFFSET also apply to the rows of each key.
+        let mut query = subquery.clone();
+        query.body = Box::new(SetExpr::Select(Box::new(select)));
+        let (offset, limit) = parse_limit_offset(&query.limit_clause, &query.fetch)?;
+        query.limit_clause = None;
+        query.fetch = None;
+        let mut lf = self.execute_isolated(|ctx| ctx.execute_query_no_ctes(&query))?;
+        if offset.is_some() || limit.is_some() {
+            let offset = offset.unwrap_or(0).max(0) as IdxSize;
+            let row = int_range(lit(0), len(), 1, IDX_DTYPE)
+                .over(key_names.iter().cloned().map(col).collect::<Vec<_>>());
+            let mut keep = row.clone().gt_eq(lit(offset));
+            if let Some(limit) = limit {
+                keep = keep.and(row.lt(lit(offset.saturating_add(limit))));
+            }
+            lf = lf.filter(keep);
+        }
+        Ok(Some(DecorrelatedSubquery {
+            lf,
+            left_on: keys.into_iter().map(|k| col(k.outer)).collect(),
+            right_on: key_names,
+            empty_group,
+        }))
+    }
+
+    // Execute what an aggregate subquery without GROUP BY (given as its SELECT
+    // without correlation predicates) yields for the outer rows without inner rows,
+    // which are missing from the decorrelated subquery grouped by its keys.
+    fn execute_empty_group(
+        &mut self,
+        subquery: &Query,
+        select: &Select,
+        key_items: &[SelectItem],
+    ) -> PolarsResult<EmptyGroup> {
+        // HAVING is only valid with GROUP BY, so it is projected and applied after
+        // the query (over a single row, it commutes with QUALIFY, LIMIT and OFFSET).
+        let mut empty = select.clone();
+        empty.selection = Some(SQLExpr::Value(SQLValue::Boolean(false).into()));
+        if let Some(having) = empty.having.take() {
+            empty.projection.push(SelectItem::ExprWithAlias {
+                expr: having,
+                alias: Ident::new(HAVING_NAME),
+            });
+        }
+        let query = Query {
+            body: Box::new(SetExpr::Select(Box::new(empty))),
+            ..subquery.clone()
+        };
+        let mut lf = self.execute_isolated(|ctx| ctx.execute_query_no_ctes(&query))?;
+        if select.having.is_some() {
+            lf = lf.filter(col(HAVING_NAME)).drop(cols([HAVING_NAME]));
+        }
+
+        let keys = Select {
+            distinct: Some(Distinct::Distinct),
+            projection: key_items.to_vec(),
+            group_by: GroupByExpr::Expressions(vec![], vec![]),
+            having: None,
+            named_window: vec![],
+            qualify: None,
+            ..select.clone()
+        };
+        let query = Query {
+            body: Box::new(SetExpr::Select(Box::new(keys))),
+            order_by: None,
+            limit_clause: None,
+            fetch: None,
+            ..subquery.clone()
+        };
+        let keys = self.execute_isolated(|ctx| ctx.execute_query_no_ctes(&query))?;
+        Ok(EmptyGroup {
+            lf,
+            keys: keys.drop_nulls(None),
+        })
+    }
+
+    // Check whether the projections of a SELECT (without GROUP BY) aggregate its rows.
+    fn is_aggregate_projection(
+        &mut self,
+        projection: &[SelectItem],
+        schema: &Schema,
+    ) -> PolarsResult<bool> {
+        for item in projection {
+            if let SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } = item {
+                let expr = parse_sql_expr(expr, self, Some(schema))?;
+                if has_expr(&expr, |e| matches!(e, Expr::Agg(_) | Expr::Len))
+                    && !has_expr(&expr, |e| matches!(e, Expr::Over { .. }))
+                {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    // Join each outer row (of a frame with an `OUTER_ROW_NAME` row index) with the
+    // inner rows satisfying the WHERE clause of a simple subquery, with the inner
+    // columns prefixed. If `with_projection`, also parse the subquery's projections
+    // over these pairs of rows.
+    fn join_correlated_rows(
+        &mut self,
+        lf: &LazyFrame,
+        subquery: &Query,
+        outer_schema: &Schema,
+        with_projection: bool,
+    ) -> PolarsResult<(LazyFrame, Vec<Expr>)> {
+        let select = eligible_subquery_select(subquery)
+            .filter(|select| select.from[0].joins.is_empty())
+            .ok_or_else(|| polars_err!(
+                SQLInterface: "subqueries correlated by non-equality predicates must select from a single table (without GROUP BY, HAVING, QUALIFY or LIMIT); found {}", subquery
+            ))?;
+        let mut ctx = self.isolated();
+        let Some((inner_names, inner_lf, inner_schema)) =
+            ctx.resolve_subquery_from(&select.from[0])?
+        else {
+            polars_bail!(SQLInterface: "cannot correlate a subquery with an unnamed relation; found {}", subquery)
+        };
+        let inner_name = |name: &str| format_pl_smallstr!("{INNER_COLUMN_PREFIX}{name}");
+        let mut pairs_schema = outer_schema.clone();
+        pairs_schema.with_column(OUTER_ROW_NAME.into(), IDX_DTYPE);
+        for (name, dtype) in inner_schema.iter() {
+            pairs_schema.with_column(inner_name(name), dtype.clone());
+        }
+        let inner_lf = inner_lf.select(
+            inner_schema
+                .iter_names()
+                .map(|name| col(name.clone()).alias(inner_name(name)))
+                .collect::<Vec<_>>(),
+        );
+
+        let mut left_on = Vec::new();
+        let mut right_on = Vec::new();
+        let mut local_filters = Vec::new();
+        let mut correlated_filters = Vec::new();
+        for conj in select.selection.iter().flat_map(MintermIter::new) {
+            if let Some(key) = correlation_key_pair(conj, &inner_names, &inner_schema, outer_schema)
+            {
+                left_on.push(col(key.outer));
+                right_on.push(col(inner_name(&key.inner)));
+                continue;
+            }
+            let filter =
+                rewrite_correlated_columns(conj, &inner_names, &inner_schema, outer_schema);
+            let filter = parse_sql_expr(&filter, &mut ctx, Some(&pairs_schema))?;
+            if references_outer_columns(conj, &inner_names, &inner_schema, outer_schema) {
+                correlated_filters.push(filter);
+            } else {
+                local_filters.push(filter);
+            }
+        }
+        let projection = if with_projection {
+            select
+                .projection
+                .iter()
+                .map(|item| match item {
+                    SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                        let expr = rewrite_correlated_columns(
+                            expr,
+                            &inner_names,
+                            &inner_schema,
+                            outer_schema,
+                        );
+                        parse_sql_expr(&expr, &mut ctx, Some(&pairs_schema))
+                    },
+                    _ => polars_bail!(SQLSyntax: "SQL subquery returns more than one column"),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?
+        } else {
+            vec![]
+        };
+
+        let inner_lf = local_filters.into_iter().fold(inner_lf, LazyFrame::filter);
+        inner_lf.set_cached_arena(ctx.lp_arena, ctx.expr_arena);
+        let pairs = if left_on.is_empty() {
+            lf.clone().cross_join(inner_lf, None)
+        } else {
+            lf.clone()
+                .join_builder()
+                .with(inner_lf)
+                .left_on(left_on)
+                .right_on(right_on)
+                .how(JoinType::Inner)
+                .coalesce(JoinCoalesce::KeepColumns)
+                .finish()
+        };
+        let pairs = correlated_filters
+            .into_iter()
+            .fold(pairs, LazyFrame::filter);
+        Ok((pairs, projection))
+    }
+}
+
+// A subquery decorrelated over its equi-correlation keys: its result for all
+// outer rows at once, with the inner correlation keys as additional columns.
+struct DecorrelatedSubquery {
+    lf: LazyFrame,
+    // The outer key columns, matching the inner key columns `right_on` of `lf`.
+    left_on: Vec<Expr>,
+    right_on: Vec<PlSmallStr>,
+    // Set if the keys are the only GROUP BY keys of an aggregate, which then yields
+    // no group for the outer rows without inner rows.
+    empty_group: Option<EmptyGroup>,
+}
+
+// The result of an aggregate subquery without GROUP BY for outer rows without inner rows.
+struct EmptyGroup {
+    // The subquery over no rows, which yields (at most) one row.
+    lf: LazyFrame,
+    // The distinct non-null correlation keys of the inner rows, named as the
+    // `right_on` keys of the decorrelated subquery.
+    keys: LazyFrame,
+}
+
+impl DecorrelatedSubquery {
+    // Rename the (non-key) value columns of the subquery, keeping their order.
+    fn rename_values(&mut self, existing: &[PlSmallStr], new: &[PlSmallStr]) {
+        self.lf = self.lf.clone().rename(existing, new, true);
+        if let Some(empty_group) = &mut self.empty_group {
+            empty_group.lf = empty_group.lf.clone().rename(existing, new, true);
+        }
+    }
+
+    // Join the subquery to the outer frame on its correlation keys, or on a
+    // constant key if it is uncorrelated.
+    fn join(
+        self,
+        lf: LazyFrame,
+        how: JoinType,
+        validation: JoinValidation,
+        suffix: Option<PlSmallStr>,
+    ) -> PolarsResult<LazyFrame> {
+        let Self {
+            lf: rf,
+            left_on,
+            right_on,
+            empty_group,
+        } = self;
+        let uncorrelated = left_on.is_empty();
+        let nulls_equal = empty_group.is_some();
+        let (lf, rf, left_on, right_on) = if uncorrelated {
+            let key = lit(true).alias(CONSTANT_KEY_NAME);
+            (
+                lf.with_column(key.clone()),
+                rf.with_column(key),
+                vec![col(CONSTANT_KEY_NAME)],
+                vec![col(CONSTANT_KEY_NAME)],
+            )
+        } else {
+            let right_keys: Vec<Expr> = right_on.iter().cloned().map(col).collect();
+            let rf = match empty_group {
+                // Add the result over no rows for the keys of the outer rows without
+                // inner rows. A null key matches no inner row, so it is joined to
+                // these rows only (as nulls are equal in this join). Both frames
+                // hold the value columns followed by the keys, so they align by
+                // position.
+                Some(empty_group) => {
+                    let missing_keys = lf
+                        .clone()
+                        .select(
+                            left_on
+                                .iter()
+                                .zip(&right_on)
+                                .map(|(key, name)| key.clone().alias(name.clone()))
+                                .collect::<Vec<_>>(),
+                        )
+                        .unique(None, UniqueKeepStrategy::Any)
+                        .join_builder()
+                        .with(empty_group.keys)
+                        .on(&right_keys)
+                        .how(JoinType::Anti)
+                        .finish();
+                    let union_args = UnionArgs {
+                        to_supertypes: true,
+                        ..Default::default()
+                    };
+                    concat(
+                        [
+                            rf.drop_nulls(Some(cols(right_on.clone()))),
+                            empty_group.lf.cross_join(missing_keys, None),
+                        ],
+                        union_args,
+                    )?
+                },
+                None => rf,
+            };
+            (lf, rf, left_on, right_keys)
+        };
+        let mut builder = lf
+            .join_builder()
+            .with(rf)
+            .left_on(left_on)
+            .right_on(right_on)
+            .how(how)
+            .validate(validation)
+            .join_nulls(nulls_equal)
+            .coalesce(JoinCoalesce::CoalesceColumns)
+            .maintain_order(MaintainOrderJoin::Left);
+        if let Some(suffix) = suffix {
+            builder = builder.suffix(suffix);
+        }
+        let joined = builder.finish();
+        Ok(if uncorrelated {
+            joined.drop(cols([CONSTANT_KEY_NAME]))
+        } else {
+            joined
+        })
+    }
+}
+
+// Check whether (a clause of) a subquery references a column of the outer query.
+fn references_outer_columns<V: Visit>(
+    node: &V,
+    inner_names: &PlHashSet<String>,
+    inner_schema: &Schema,
+    outer_schema: &Schema,
+) -> bool {
+    visit_expressions(node, |expr| {
+        match classify_correlation_column(expr, inner_names, inner_schema, outer_schema) {
+            Some((CorrelationSide::Outer, _)) => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    })
+    .is_break()
+}
+
+// Rewrite the column references of a subquery expression to the columns of the
+// joined pairs of outer and (prefixed) inner rows. As in SQL, unqualified names
+// resolve to the inner relation first.
+fn rewrite_correlated_columns(
+    expr: &SQLExpr,
+    inner_names: &PlHashSet<String>,
+    inner_schema: &Schema,
+    outer_schema: &Schema,
+) -> SQLExpr {
+    let mut expr = expr.clone();
+    let _ = visit_expressions_mut(&mut expr, |e| {
+        let column = match e {
+            SQLExpr::Identifier(ident) if inner_schema.contains(ident.value.as_str()) => {
+                Some((CorrelationSide::Inner, PlSmallStr::from_str(&ident.value)))
+            },
+            _ => classify_correlation_column(e, inner_names, inner_schema, outer_schema),
+        };
+        match column {
+            Some((CorrelationSide::Inner, name)) => {
+                *e = SQLExpr::Identifier(Ident::new(format!("{INNER_COLUMN_PREFIX}{name}")))
+            },
+            Some((CorrelationSide::Outer, name)) => {
+                *e = SQLExpr::Identifier(Ident::new(name.as_str()))
+            },
+            None => {},
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    expr
+}
+
+// Add the correlation keys to the partitions of the window functions of a SELECT,
+// so that they are evaluated separately for each key. QUALIFY is evaluated over
+// the projection, so its windows are partitioned by the projected keys `key_names`.
+fn partition_windows_by(select: &mut Select, keys: &[SQLExpr], key_names: &[PlSmallStr]) {
+    fn add_keys(spec: &mut WindowSpec, keys: &[SQLExpr]) {
+        // a spec extending a named window inherits its partitions
+        if spec.window_name.is_none() {
+            spec.partition_by.extend(keys.iter().cloned());
+        }
+    }
+    fn visit(keys: &[SQLExpr]) -> impl FnMut(&mut SQLExpr) -> ControlFlow<()> {
+        move |expr| {
+            if let SQLExpr::Function(Function {
+                over: Some(WindowType::WindowSpec(spec)),
+                ..
+            }) = expr
+            {
+                add_keys(spec, keys);
+            }
+            ControlFlow::Continue(())
+        }
+    }
+    let projected_keys: Vec<SQLExpr> = key_names
+        .iter()
+        .map(|name| SQLExpr::Identifier(Ident::new(name.as_str())))
+        .collect();
+    let _ = visit_expressions_mut(&mut select.projection, visit(keys));
+    let _ = visit_expressions_mut(&mut select.qualify, visit(&projected_keys));
+    for NamedWindowDefinition(_, named) in &mut select.named_window {
+        if let NamedWindowExpr::WindowSpec(spec) = named {
+            add_keys(spec, keys);
+        }
+    }
+}
+
+// Check whether a subquery may filter the rows of its aggregation (with HAVING,
+// QUALIFY, LIMIT or OFFSET).
+fn filters_aggregated_rows(subquery: &Query) -> bool {
+    subquery.limit_clause.is_some()
+        || subquery.fetch.is_some()
+        || matches!(
+            subquery.body.as_ref(),
+            SetExpr::Select(select)
+                if select.having.is_some() || select.qualify.is_some() || select.top.is_some()
+        )
+}
+
+// Check whether a join constraint is absent or `ON TRUE`.
+fn is_trivial_constraint(constraint: &JoinConstraint) -> bool {
+    matches!(
+        constraint,
+        JoinConstraint::None
+            | JoinConstraint::On(SQLExpr::Value(ValueWithSpan {
+                value: SQLValue::Boolean(true),
+                ..
+            }))
+    )
+}
+
 /// An iterator over all the minterms in an SQL boolean expression: the terms
 /// that `AND` together to form it, descending through parenthesized `Nested`
 /// expressions. The SQL-AST analogue of the `AExpr`-level
@@ -397,7 +1187,6 @@ impl<'a> MintermIter<'a> {
     }
 }
 
-#[cfg(feature = "semi_anti_join")]
 enum CorrelationSide {
     Inner,
     Outer,
@@ -413,17 +1202,23 @@ struct SubqueryConjuncts {
     local_filters: Vec<Expr>,
 }
 
+// An equi-correlation key: the outer and inner column names, and the inner
+// operand as written in the subquery.
+struct CorrelationKey<'a> {
+    outer: PlSmallStr,
+    inner: PlSmallStr,
+    inner_expr: &'a SQLExpr,
+}
+
 // An equi-correlation conjunct `inner.col = outer.col` (either way round) as a
-// `(outer key, inner key)` column-name pair, or `None` when the conjunct is
-// anything else (non-equality, unresolvable names, both columns on the same
-// side).
-#[cfg(feature = "semi_anti_join")]
-fn correlation_key_pair(
-    conj: &SQLExpr,
+// `CorrelationKey`, or `None` when the conjunct is anything else (non-equality,
+// unresolvable names, both columns on the same side).
+fn correlation_key_pair<'a>(
+    conj: &'a SQLExpr,
     inner_names: &PlHashSet<String>,
     inner_schema: &Schema,
     outer_schema: &Schema,
-) -> Option<(PlSmallStr, PlSmallStr)> {
+) -> Option<CorrelationKey<'a>> {
     let SQLExpr::BinaryOp {
         left,
         op: SQLBinaryOperator::Eq,
@@ -437,8 +1232,16 @@ fn correlation_key_pair(
     let (rside, rname) =
         classify_correlation_column(right, inner_names, inner_schema, outer_schema)?;
     match (lside, rside) {
-        (CorrelationSide::Outer, CorrelationSide::Inner) => Some((lname, rname)),
-        (CorrelationSide::Inner, CorrelationSide::Outer) => Some((rname, lname)),
+        (CorrelationSide::Outer, CorrelationSide::Inner) => Some(CorrelationKey {
+            outer: lname,
+            inner: rname,
+            inner_expr: right,
+        }),
+        (CorrelationSide::Inner, CorrelationSide::Outer) => Some(CorrelationKey {
+            outer: rname,
+            inner: lname,
+            inner_expr: left,
+        }),
         _ => None,
     }
 }
@@ -449,7 +1252,6 @@ fn correlation_key_pair(
 // same-named columns like `o.id = c.id` resolve). An unqualified identifier
 // resolves by schema membership. `None` for non-identifiers or names that can't
 // be placed (in neither schema, or ambiguous).
-#[cfg(feature = "semi_anti_join")]
 fn classify_correlation_column(
     expr: &SQLExpr,
     inner_names: &PlHashSet<String>,
@@ -494,7 +1296,6 @@ fn classify_correlation_column(
 // which rows the subquery yields. Exhaustive destructuring (no `..`) is on
 // purpose: a new sqlparser clause must not compile until it gets an explicit
 // keep-or-bail decision here.
-#[cfg(feature = "semi_anti_join")]
 fn eligible_subquery_select(subquery: &Query) -> Option<&Select> {
     let Query {
         with, // CTEs aren't resolved inside the rewrite: bail
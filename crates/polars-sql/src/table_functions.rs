@@ -1,34 +1,33 @@
 use std::str::FromStr;
 
-#[cfg(any(
-    feature = "csv",
-    feature = "parquet",
-    feature = "ipc",
-    feature = "json"
-))]
-use polars_core::prelude::polars_ensure;
 use polars_core::prelude::{PolarsError, PolarsResult, polars_bail};
 #[cfg(feature = "csv")]
-use polars_lazy::prelude::LazyCsvReader;
-use polars_lazy::prelude::LazyFrame;
+use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "ipc")]
+use polars_io::ipc::IpcScanOptions;
+use polars_lazy::prelude::*;
+#[cfg(any(feature = "ipc", feature = "avro", feature = "scan_lines"))]
+use polars_plan::prelude::ScanSources;
 use polars_utils::pl_path::PlRefPath;
-use sqlparser::ast::{
-    Expr as SQLExpr, FunctionArg as SQLFunctionArg, FunctionArgExpr as SQLFunctionArgExpr,
-    Value as SQLValue, ValueWithSpan as SQLValueWithSpan,
-};
+use sqlparser::ast::FunctionArg as SQLFunctionArg;
 
-/// Table functions that are supported by Polars
+/// Table functions that are supported by Polars.
+///
+/// Each function takes a file path (which may be a glob pattern, or an array of paths)
+/// followed by named options, which map onto the options of the equivalent scan.
 #[allow(clippy::enum_variant_names)]
 pub(crate) enum PolarsTableFunctions {
     /// SQL 'read_csv' function.
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/*.csv', separator => ';', has_header => false)
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
     /// SQL 'read_parquet' function.
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('s3://bucket/**/*.parquet', hive_partitioning => true)
     /// ```
     #[cfg(feature = "parquet")]
     ReadParquet,
@@ -44,6 +43,18 @@ pub(crate) enum PolarsTableFunctions {
     /// ```
     #[cfg(feature = "json")]
     ReadJson,
+    /// SQL 'read_avro' function.
+    /// ```sql
+    /// SELECT * FROM read_avro('path/to/file.avro')
+    /// ```
+    #[cfg(feature = "avro")]
+    ReadAvro,
+    /// SQL 'read_lines' function; reads each line of a text file into a string column.
+    /// ```sql
+    /// SELECT * FROM read_lines('path/to/file.txt', name => 'line')
+    /// ```
+    #[cfg(feature = "scan_lines")]
+    ReadLines,
 }
 
 impl FromStr for PolarsTableFunctions {
@@ -60,6 +71,10 @@ impl FromStr for PolarsTableFunctions {
             "read_ipc" => PolarsTableFunctions::ReadIpc,
             #[cfg(feature = "json")]
             "read_json" => PolarsTableFunctions::ReadJson,
+            #[cfg(feature = "avro")]
+            "read_avro" => PolarsTableFunctions::ReadAvro,
+            #[cfg(feature = "scan_lines")]
+            "read_lines" => PolarsTableFunctions::ReadLines,
             _ => polars_bail!(SQLInterface: "'{}' is not a supported table function", s),
         })
     }
@@ -77,67 +92,490 @@ impl PolarsTableFunctions {
             PolarsTableFunctions::ReadIpc => self.read_ipc(args),
             #[cfg(feature = "json")]
             PolarsTableFunctions::ReadJson => self.read_ndjson(args),
+            #[cfg(feature = "avro")]
+            PolarsTableFunctions::ReadAvro => self.read_avro(args),
+            #[cfg(feature = "scan_lines")]
+            PolarsTableFunctions::ReadLines => self.read_lines(args),
             _ => unreachable!(),
         }
     }
 
     #[cfg(feature = "csv")]
     fn read_csv(&self, args: &[SQLFunctionArg]) -> PolarsResult<(PlRefPath, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_csv` expects a single file path; found {:?} arguments", args.len());
-
-        use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(path.clone())
-            .with_try_parse_dates(true)
-            .with_missing_is_null(true)
-            .finish()?;
-        Ok((path, lf))
+        let mut args = function_args::TableFunctionArgs::new("read_csv", args)?;
+        let defaults = CsvReadOptions::default();
+        let mut reader = LazyCsvReader::new_paths(args.paths_buffer())
+            .with_try_parse_dates(args.bool("try_parse_dates")?.unwrap_or(true))
+            .with_missing_is_null(args.bool("missing_is_null")?.unwrap_or(true))
+            .with_has_header(args.bool("has_header")?.unwrap_or(defaults.has_header))
+            .with_separator(
+                args.char("separator")?
+                    .unwrap_or(defaults.parse_options.separator),
+            )
+            .with_skip_rows(args.usize("skip_rows")?.unwrap_or(defaults.skip_rows))
+            .with_skip_rows_after_header(
+                args.usize("skip_rows_after_header")?
+                    .unwrap_or(defaults.skip_rows_after_header),
+            )
+            .with_skip_lines(args.usize("skip_lines")?.unwrap_or(defaults.skip_lines))
+            .with_infer_schema_length(
+                args.usize("infer_schema_length")?
+                    .or(defaults.infer_schema_length),
+            )
+            .with_ignore_errors(
+                args.bool("ignore_errors")?
+                    .unwrap_or(defaults.ignore_errors),
+            )
+            .with_truncate_ragged_lines(
+                args.bool("truncate_ragged_lines")?
+                    .unwrap_or(defaults.parse_options.truncate_ragged_lines),
+            )
+            .with_decimal_comma(
+                args.bool("decimal_comma")?
+                    .unwrap_or(defaults.parse_options.decimal_comma),
+            )
+            .with_low_memory(args.bool("low_memory")?.unwrap_or(defaults.low_memory))
+            .with_rechunk(args.bool("rechunk")?.unwrap_or(defaults.rechunk))
+            .with_cache(args.bool("cache")?.unwrap_or(true))
+            .with_glob(args.bool("glob")?.unwrap_or(true))
+            .with_comment_prefix(args.str("comment_prefix")?)
+            .with_schema(args.schema("schema")?)
+            .with_dtype_overwrite(args.schema("schema_overrides")?)
+            .with_null_values(args.null_values("null_values")?)
+            .with_include_file_paths(args.str("include_file_paths")?)
+            .with_n_rows(args.usize("n_rows")?)
+            .with_row_index(args.row_index()?);
+        if let Some(quote_char) = args.str("quote_char")? {
+            // An empty `quote_char` disables quoting.
+            reader = reader.with_quote_char(function_args::single_byte(
+                "quote_char",
+                &quote_char,
+                true,
+            )?);
+        }
+        args.finish()?;
+
+        Ok((args.first_path(), reader.finish()?))
     }
 
     #[cfg(feature = "parquet")]
     fn read_parquet(&self, args: &[SQLFunctionArg]) -> PolarsResult<(PlRefPath, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_parquet` expects a single file path; found {:?} arguments", args.len());
+        let mut args = function_args::TableFunctionArgs::new("read_parquet", args)?;
+        let defaults = ScanArgsParquet::default();
+        let scan_args = ScanArgsParquet {
+            n_rows: args.usize("n_rows")?,
+            parallel: args
+                .parallel_strategy("parallel")?
+                .unwrap_or(defaults.parallel),
+            row_index: args.row_index()?,
+            hive_options: args.hive_options()?,
+            use_statistics: args
+                .bool("use_statistics")?
+                .unwrap_or(defaults.use_statistics),
+            schema: args.schema("schema")?,
+            low_memory: args.bool("low_memory")?.unwrap_or(defaults.low_memory),
+            rechunk: args.bool("rechunk")?.unwrap_or(defaults.rechunk),
+            cache: args.bool("cache")?.unwrap_or(defaults.cache),
+            glob: args.bool("glob")?.unwrap_or(defaults.glob),
+            include_file_paths: args.str("include_file_paths")?,
+            allow_missing_columns: args
+                .bool("allow_missing_columns")?
+                .unwrap_or(defaults.allow_missing_columns),
+            ..defaults
+        };
+        args.finish()?;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(path.clone(), Default::default())?;
-        Ok((path, lf))
+        let lf = LazyFrame::scan_parquet_files(args.paths_buffer(), scan_args)?;
+        Ok((args.first_path(), lf))
     }
 
     #[cfg(feature = "ipc")]
     fn read_ipc(&self, args: &[SQLFunctionArg]) -> PolarsResult<(PlRefPath, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ipc` expects a single file path; found {:?} arguments", args.len());
+        let mut args = function_args::TableFunctionArgs::new("read_ipc", args)?;
+        let scan_args = args.unified_scan_args()?;
+        args.finish()?;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(path.clone(), Default::default(), Default::default())?;
-        Ok((path, lf))
+        let lf = LazyFrame::scan_ipc_sources(
+            ScanSources::Paths(args.paths_buffer()),
+            IpcScanOptions::default(),
+            scan_args,
+        )?;
+        Ok((args.first_path(), lf))
     }
+
     #[cfg(feature = "json")]
     fn read_ndjson(&self, args: &[SQLFunctionArg]) -> PolarsResult<(PlRefPath, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ndjson` expects a single file path; found {:?} arguments", args.len());
+        let mut args = function_args::TableFunctionArgs::new("read_json", args)?;
+        let mut reader = LazyJsonLineReader::new_paths(args.paths_buffer())
+            .with_schema(args.schema("schema")?)
+            .with_schema_overwrite(args.schema("schema_overrides")?)
+            .with_ignore_errors(args.bool("ignore_errors")?.unwrap_or(false))
+            .low_memory(args.bool("low_memory")?.unwrap_or(false))
+            .with_rechunk(args.bool("rechunk")?.unwrap_or(false))
+            .with_include_file_paths(args.str("include_file_paths")?)
+            .with_n_rows(args.usize("n_rows")?)
+            .with_row_index(args.row_index()?);
+        if let Some(n) = args.usize("infer_schema_length")? {
+            // `infer_schema_length => 0` infers the schema from all rows.
+            reader = reader.with_infer_schema_length(std::num::NonZeroUsize::new(n));
+        }
+        args.finish()?;
+
+        Ok((args.first_path(), reader.finish()?))
+    }
+
+    #[cfg(feature = "avro")]
+    fn read_avro(&self, args: &[SQLFunctionArg]) -> PolarsResult<(PlRefPath, LazyFrame)> {
+        let mut args = function_args::TableFunctionArgs::new("read_avro", args)?;
+        let scan_args = args.unified_scan_args()?;
+        args.finish()?;
+
+        let lf = LazyFrame::scan_avro_sources(ScanSources::Paths(args.paths_buffer()), scan_args)?;
+        Ok((args.first_path(), lf))
+    }
+
+    #[cfg(feature = "scan_lines")]
+    fn read_lines(&self, args: &[SQLFunctionArg]) -> PolarsResult<(PlRefPath, LazyFrame)> {
+        use polars_plan::prelude::DslBuilder;
+
+        let mut args = function_args::TableFunctionArgs::new("read_lines", args)?;
+        let name = args.str("name")?.unwrap_or_else(|| "lines".into());
+        let scan_args = args.unified_scan_args()?;
+        args.finish()?;
 
-        use polars_lazy::frame::LazyFileListReader;
-        use polars_lazy::prelude::LazyJsonLineReader;
+        let lf: LazyFrame =
+            DslBuilder::scan_lines(ScanSources::Paths(args.paths_buffer()), scan_args, name)?
+                .build()
+                .into();
+        Ok((args.first_path(), lf))
+    }
+}
+
+/// Parsing of the arguments of table functions.
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json",
+    feature = "avro",
+    feature = "scan_lines"
+))]
+mod function_args {
+    use polars_buffer::Buffer;
+    use polars_core::prelude::{
+        DataType, IdxSize, PlSmallStr, PolarsResult, Schema, SchemaRef, polars_bail, polars_ensure,
+    };
+    #[cfg(any(
+        feature = "parquet",
+        feature = "ipc",
+        feature = "avro",
+        feature = "scan_lines"
+    ))]
+    use polars_io::HiveOptions;
+    use polars_io::RowIndex;
+    #[cfg(feature = "csv")]
+    use polars_io::csv::read::NullValues;
+    #[cfg(feature = "parquet")]
+    use polars_io::parquet::read::ParallelStrategy;
+    #[cfg(any(feature = "ipc", feature = "avro", feature = "scan_lines"))]
+    use polars_plan::prelude::UnifiedScanArgs;
+    use polars_utils::aliases::PlIndexMap;
+    use polars_utils::pl_path::PlRefPath;
+    #[cfg(any(feature = "ipc", feature = "avro", feature = "scan_lines"))]
+    use polars_utils::slice_enum::Slice;
+    use sqlparser::ast::{
+        Expr as SQLExpr, FunctionArg as SQLFunctionArg, FunctionArgExpr as SQLFunctionArgExpr,
+        Value as SQLValue, ValueWithSpan as SQLValueWithSpan,
+    };
+    use sqlparser::dialect::GenericDialect;
+    use sqlparser::parser::Parser;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
-        Ok((path, lf))
+    use crate::sql_expr::to_sql_interface_err;
+    use crate::types::map_sql_dtype_to_polars;
+
+    /// The arguments of a table function call: the source path(s), followed by named options.
+    /// ```sql
+    /// SELECT * FROM read_parquet(['a.parquet', 'b.parquet'], n_rows => 100)
+    /// ```
+    /// Options are consumed as they are read; any that remain are reported as unsupported.
+    pub(super) struct TableFunctionArgs<'a> {
+        func: &'static str,
+        paths: Vec<PlRefPath>,
+        options: PlIndexMap<String, &'a SQLExpr>,
     }
 
-    #[allow(dead_code)]
-    fn get_file_path_from_arg(&self, arg: &SQLFunctionArg) -> PolarsResult<PlRefPath> {
-        match arg {
-            SQLFunctionArg::Unnamed(SQLFunctionArgExpr::Expr(SQLExpr::Value(
-                SQLValueWithSpan {
-                    value: SQLValue::SingleQuotedString(s),
+    impl<'a> TableFunctionArgs<'a> {
+        pub(super) fn new(func: &'static str, args: &'a [SQLFunctionArg]) -> PolarsResult<Self> {
+            let Some((source, options)) = args.split_first() else {
+                polars_bail!(SQLSyntax: "`{}` expects a file path; found no arguments", func)
+            };
+            let paths = match source {
+                SQLFunctionArg::Unnamed(SQLFunctionArgExpr::Expr(SQLExpr::Array(arr)))
+                    if !arr.elem.is_empty() =>
+                {
+                    arr.elem
+                        .iter()
+                        .map(file_path)
+                        .collect::<PolarsResult<_>>()?
+                },
+                SQLFunctionArg::Unnamed(SQLFunctionArgExpr::Expr(expr)) => vec![file_path(expr)?],
+                _ => polars_bail!(
+                    SQLSyntax: "`{}` expects a file path (or array of file paths) as its first argument; found: {}", func, source,
+                ),
+            };
+
+            let mut named_options = PlIndexMap::with_capacity(options.len());
+            for arg in options {
+                let SQLFunctionArg::Named {
+                    name,
+                    arg: SQLFunctionArgExpr::Expr(value),
                     ..
+                } = arg
+                else {
+                    polars_bail!(
+                        SQLSyntax: "`{}` expects named options (eg: `option => value`) after the file path; found: {}", func, arg,
+                    )
+                };
+                let key = name.value.to_lowercase();
+                polars_ensure!(
+                    named_options.insert(key, value).is_none(),
+                    SQLSyntax: "`{}` option '{}' is given more than once", func, name.value
+                );
+            }
+
+            Ok(Self {
+                func,
+                paths,
+                options: named_options,
+            })
+        }
+
+        pub(super) fn first_path(&self) -> PlRefPath {
+            self.paths[0].clone()
+        }
+
+        pub(super) fn paths_buffer(&self) -> Buffer<PlRefPath> {
+            Buffer::from_iter(self.paths.iter().cloned())
+        }
+
+        /// Error on any options that were not consumed by the table function.
+        pub(super) fn finish(&self) -> PolarsResult<()> {
+            if let Some(key) = self.options.keys().next() {
+                polars_bail!(SQLSyntax: "`{}` does not support the '{}' option", self.func, key);
+            }
+            Ok(())
+        }
+
+        fn value(&mut self, key: &str) -> Option<(&'a SQLExpr, Option<&'a SQLValue>)> {
+            self.options.shift_remove(key).map(|expr| match expr {
+                SQLExpr::Value(SQLValueWithSpan { value, .. }) => (expr, Some(value)),
+                _ => (expr, None),
+            })
+        }
+
+        pub(super) fn bool(&mut self, key: &str) -> PolarsResult<Option<bool>> {
+            match self.value(key) {
+                None => Ok(None),
+                Some((_, Some(SQLValue::Boolean(b)))) => Ok(Some(*b)),
+                Some((expr, _)) => self.invalid(key, "a boolean", expr),
+            }
+        }
+
+        pub(super) fn usize(&mut self, key: &str) -> PolarsResult<Option<usize>> {
+            match self.value(key) {
+                None => Ok(None),
+                Some((expr, Some(SQLValue::Number(n, _)))) => match n.parse::<usize>() {
+                    Ok(n) => Ok(Some(n)),
+                    Err(_) => self.invalid(key, "a non-negative integer", expr),
+                },
+                Some((expr, _)) => self.invalid(key, "a non-negative integer", expr),
+            }
+        }
+
+        pub(super) fn str(&mut self, key: &str) -> PolarsResult<Option<PlSmallStr>> {
+            match self.value(key) {
+                None => Ok(None),
+                Some((_, Some(SQLValue::SingleQuotedString(s)))) => Ok(Some(s.as_str().into())),
+                Some((expr, _)) => self.invalid(key, "a single-quoted string", expr),
+            }
+        }
+
+        #[cfg(feature = "csv")]
+        pub(super) fn char(&mut self, key: &str) -> PolarsResult<Option<u8>> {
+            self.str(key)?
+                .map(|s| single_byte(key, &s, false).map(|c| c.unwrap()))
+                .transpose()
+        }
+
+        /// A schema given as a dictionary of column names and SQL types, eg:
+        /// `{'a': 'INT', 'b': 'VARCHAR'}`.
+        pub(super) fn schema(&mut self, key: &str) -> PolarsResult<Option<SchemaRef>> {
+            let Some((expr, _)) = self.value(key) else {
+                return Ok(None);
+            };
+            let SQLExpr::Dictionary(fields) = expr else {
+                return self.invalid(key, "a dictionary of column names and types", expr);
+            };
+            let mut schema = Schema::with_capacity(fields.len());
+            for field in fields {
+                let SQLExpr::Value(SQLValueWithSpan {
+                    value: SQLValue::SingleQuotedString(sql_dtype),
+                    ..
+                }) = &*field.value
+                else {
+                    return self.invalid(key, "a dictionary of column names and types", expr);
+                };
+                let dtype = parse_sql_dtype(sql_dtype)?;
+                schema.insert_at_index(schema.len(), field.key.value.as_str().into(), dtype)?;
+            }
+            Ok(Some(SchemaRef::new(schema)))
+        }
+
+        pub(super) fn row_index(&mut self) -> PolarsResult<Option<RowIndex>> {
+            let name = self.str("row_index_name")?;
+            let offset = self.usize("row_index_offset")?;
+            match name {
+                Some(name) => Ok(Some(RowIndex {
+                    name,
+                    offset: offset.unwrap_or(0) as IdxSize,
+                })),
+                None => {
+                    polars_ensure!(
+                        offset.is_none(),
+                        SQLSyntax: "`{}` option 'row_index_offset' requires 'row_index_name'", self.func
+                    );
+                    Ok(None)
                 },
-            ))) => Ok(PlRefPath::new(s)),
+            }
+        }
+
+        #[cfg(any(
+            feature = "parquet",
+            feature = "ipc",
+            feature = "avro",
+            feature = "scan_lines"
+        ))]
+        pub(super) fn hive_options(&mut self) -> PolarsResult<HiveOptions> {
+            let mut hive_options = HiveOptions::default();
+            if let Some(enabled) = self.bool("hive_partitioning")? {
+                hive_options.enabled = Some(enabled);
+            }
+            if let Some(try_parse_dates) = self.bool("try_parse_hive_dates")? {
+                hive_options.try_parse_dates = try_parse_dates;
+            }
+            hive_options.schema = self.schema("hive_schema")?;
+            Ok(hive_options)
+        }
+
+        /// Options shared by the scans that take [`UnifiedScanArgs`].
+        #[cfg(any(feature = "ipc", feature = "avro", feature = "scan_lines"))]
+        pub(super) fn unified_scan_args(&mut self) -> PolarsResult<UnifiedScanArgs> {
+            let defaults = UnifiedScanArgs::default();
+            Ok(UnifiedScanArgs {
+                schema: self.schema("schema")?,
+                hive_options: self.hive_options()?,
+                rechunk: self.bool("rechunk")?.unwrap_or(defaults.rechunk),
+                cache: self.bool("cache")?.unwrap_or(defaults.cache),
+                glob: self.bool("glob")?.unwrap_or(defaults.glob),
+                row_index: self.row_index()?,
+                pre_slice: self
+                    .usize("n_rows")?
+                    .map(|len| Slice::Positive { offset: 0, len }),
+                include_file_paths: self.str("include_file_paths")?,
+                ..defaults
+            })
+        }
+
+        #[cfg(feature = "parquet")]
+        pub(super) fn parallel_strategy(
+            &mut self,
+            key: &str,
+        ) -> PolarsResult<Option<ParallelStrategy>> {
+            let Some(strategy) = self.str(key)? else {
+                return Ok(None);
+            };
+            Ok(Some(match strategy.to_lowercase().as_str() {
+                "auto" => ParallelStrategy::Auto,
+                "columns" => ParallelStrategy::Columns,
+                "row_groups" => ParallelStrategy::RowGroups,
+                "prefiltered" => ParallelStrategy::Prefiltered,
+                "none" => ParallelStrategy::None,
+                _ => polars_bail!(
+                    SQLSyntax: "`{}` option '{}' expects one of 'auto', 'columns', 'row_groups', 'prefiltered' or 'none'; found '{}'", self.func, key, strategy
+                ),
+            }))
+        }
+
+        #[cfg(feature = "csv")]
+        pub(super) fn null_values(&mut self, key: &str) -> PolarsResult<Option<NullValues>> {
+            let Some((expr, value)) = self.value(key) else {
+                return Ok(None);
+            };
+            match (expr, value) {
+                (_, Some(SQLValue::SingleQuotedString(s))) => {
+                    Ok(Some(NullValues::AllColumnsSingle(s.as_str().into())))
+                },
+                (SQLExpr::Array(arr), _) => {
+                    let values = arr
+                        .elem
+                        .iter()
+                        .map(|e| match e {
+                            SQLExpr::Value(SQLValueWithSpan {
+                                value: SQLValue::SingleQuotedString(s),
+                                ..
+                            }) => Ok(s.as_str().into()),
+                            _ => self.invalid(key, "a string or array of strings", expr),
+                        })
+                        .collect::<PolarsResult<_>>()?;
+                    Ok(Some(NullValues::AllColumns(values)))
+                },
+                _ => self.invalid(key, "a string or array of strings", expr),
+            }
+        }
+
+        fn invalid<T>(&self, key: &str, expected: &str, found: &SQLExpr) -> PolarsResult<T> {
+            polars_bail!(
+                SQLSyntax: "`{}` option '{}' expects {}; found: {}", self.func, key, expected, found
+            )
+        }
+    }
+
+    fn file_path(expr: &SQLExpr) -> PolarsResult<PlRefPath> {
+        match expr {
+            SQLExpr::Value(SQLValueWithSpan {
+                value: SQLValue::SingleQuotedString(s),
+                ..
+            }) => Ok(PlRefPath::new(s)),
             _ => polars_bail!(
                 SQLSyntax:
-                "expected a valid file path as a single-quoted string; found: {}", arg,
+                "expected a valid file path as a single-quoted string; found: {}", expr,
             ),
         }
     }
+
+    /// Parse a single-byte option value (eg: a separator); optionally allow an empty string.
+    #[cfg(feature = "csv")]
+    pub(super) fn single_byte(
+        key: &str,
+        value: &str,
+        allow_empty: bool,
+    ) -> PolarsResult<Option<u8>> {
+        match value.as_bytes() {
+            [] if allow_empty => Ok(None),
+            [b] => Ok(Some(*b)),
+            _ => {
+                polars_bail!(SQLSyntax: "option '{}' expects a single-byte character; found '{}'", key, value)
+            },
+        }
+    }
+
+    fn parse_sql_dtype(sql_dtype: &str) -> PolarsResult<DataType> {
+        let dtype = Parser::new(&GenericDialect)
+            .try_with_sql(sql_dtype)
+            .and_then(|mut parser| parser.parse_data_type())
+            .map_err(to_sql_interface_err)?;
+        map_sql_dtype_to_polars(&dtype)
+    }
 }
 
 impl PolarsTableFunctions {
@@ -152,6 +590,10 @@ impl PolarsTableFunctions {
             "read_ipc",
             #[cfg(feature = "json")]
             "read_json",
+            #[cfg(feature = "avro")]
+            "read_avro",
+            #[cfg(feature = "scan_lines")]
+            "read_lines",
         ]
     }
 }
@@ -223,3 +223,115 @@ fn test_lead_lag_without_order_by() {
         );
     }
 }
+
+#[test]
+fn test_rows_window_frames() {
+    let df = df! {
+        "k" => ["a", "a", "a", "a", "b", "b"],
+        "idx" => [1, 2, 3, 4, 1, 2],
+        "x" => [1, 2, 3, 4, 10, 20],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+
+    let actual = ctx
+        .execute(
+            r#"
+            SELECT
+              k,
+              SUM(x) OVER (
+                PARTITION BY k ORDER BY idx ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+              ) AS sum_x,
+              AVG(x) OVER (PARTITION BY k ORDER BY idx ROWS 2 PRECEDING) AS avg_x,
+              COUNT(*) OVER (
+                PARTITION BY k ORDER BY idx ROWS BETWEEN CURRENT ROW AND 2 FOLLOWING
+              ) AS n,
+              MAX(x) OVER (
+                PARTITION BY k ORDER BY idx ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING
+              ) AS max_x
+            FROM df
+            ORDER BY k, idx
+            "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    let expected = df! {
+        "k" => ["a", "a", "a", "a", "b", "b"],
+        "sum_x" => [3, 6, 9, 7, 30, 30],
+        "avg_x" => [1.0, 1.5, 2.0, 3.0, 10.0, 15.0],
+        "n" => [3 as IdxSize, 3, 2, 1, 2, 1],
+        "max_x" => [None, Some(1), Some(2), Some(3), None, Some(10)],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual}");
+}
+
+#[test]
+fn test_range_window_frames() {
+    let df = df! {
+        "n" => [1, 2, 5, 8, 9],
+        "v" => [1, 2, 3, 4, 5],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("n").cast(DataType::Date).alias("dt"));
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+
+    let actual = ctx
+        .execute(
+            r#"
+            SELECT
+              SUM(v) OVER (
+                ORDER BY dt RANGE BETWEEN INTERVAL '3 days' PRECEDING AND CURRENT ROW
+              ) AS sum_dt,
+              SUM(v) OVER (ORDER BY n RANGE BETWEEN 3 PRECEDING AND CURRENT ROW) AS sum_n
+            FROM df
+            ORDER BY n
+            "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    let expected = df! {
+        "sum_dt" => [1, 3, 5, 7, 9],
+        "sum_n" => [1, 3, 5, 7, 9],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_unsupported_window_frames() {
+    for (frame, expected_error) in [
+        (
+            "ROWS BETWEEN 1 PRECEDING AND UNBOUNDED FOLLOWING",
+            "only 'ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW' is supported",
+        ),
+        (
+            "ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING",
+            "cannot start after it ends",
+        ),
+        (
+            "RANGE BETWEEN 1 PRECEDING AND 1 FOLLOWING",
+            "only 'RANGE BETWEEN <offset> PRECEDING AND CURRENT ROW' is supported",
+        ),
+        (
+            "GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW",
+            "GROUPS-based window frames are not supported",
+        ),
+    ] {
+        ensure_error(
+            &format!("SUM(a) OVER (ORDER BY b {frame}) AS c"),
+            expected_error,
+        );
+    }
+    ensure_error(
+        "ROW_NUMBER() OVER (ORDER BY b ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS c",
+        "window frame 'ROWS BETWEEN 1 PRECEDING AND CURRENT ROW' is only supported for",
+    );
+}
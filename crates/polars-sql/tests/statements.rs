@@ -654,3 +654,205 @@ fn test_join_non_equi_range() {
         "expected = {expected:?}\nactual={actual:?}"
     );
 }
+
+#[test]
+fn test_recursive_cte() {
+    let mut ctx = SQLContext::new();
+    let sql = r#"
+        WITH RECURSIVE seq(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM seq WHERE n < 5
+        )
+        SELECT n FROM seq ORDER BY n
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "n" => [1i32, 2, 3, 4, 5] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // the fixpoint is bounded by a configurable number of iterations
+    let mut ctx = SQLContext::new().with_recursion_limit(3);
+    let err = ctx
+        .execute(sql)
+        .and_then(|lf| lf.collect())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("recursion limit (3 iterations)"), "{err}");
+
+    let sql = r#"
+        WITH RECURSIVE seq(n) AS (SELECT n FROM seq UNION SELECT 1)
+        SELECT * FROM seq
+    "#;
+    assert!(matches!(ctx.execute(sql), Err(PolarsError::SQLSyntax(_))));
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_asof_join() {
+    let trades = df! {
+        "sym" => ["a", "a", "b", "b"],
+        "ts" => [2i64, 5, 1, 4],
+        "qty" => [10i64, 20, 30, 40],
+    }
+    .unwrap();
+    let quotes = df! {
+        "sym" => ["a", "a", "b"],
+        "ts" => [1i64, 4, 3],
+        "px" => [1.5, 2.5, 3.5],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("trades", trades.lazy());
+    ctx.register("quotes", quotes.lazy());
+
+    // the trade at b/1 has no earlier quote, so it is dropped
+    let expected = df! {
+        "sym" => ["a", "a", "b"],
+        "ts" => [2i64, 5, 4],
+        "px" => [1.5, 2.5, 3.5],
+    }
+    .unwrap();
+    for sql in [
+        "SELECT t.sym, t.ts, q.px FROM trades t ASOF JOIN quotes q \
+         ON t.sym = q.sym AND t.ts >= q.ts ORDER BY t.sym, t.ts",
+        "SELECT t.sym, t.ts, q.px FROM trades t ASOF JOIN quotes q \
+         ON q.sym = t.sym AND q.ts <= t.ts ORDER BY t.sym, t.ts",
+        "SELECT t.sym, t.ts, q.px FROM trades t ASOF JOIN quotes q \
+         MATCH_CONDITION (t.ts >= q.ts) ON t.sym = q.sym ORDER BY t.sym, t.ts",
+        "SELECT sym, ts, px FROM trades ASOF JOIN quotes USING (sym, ts) ORDER BY sym, ts",
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        assert!(
+            actual.equals(&expected),
+            "{sql}\nexpected = {expected:?}\nactual={actual:?}"
+        );
+    }
+
+    let sql = "SELECT * FROM trades t ASOF JOIN quotes q ON t.sym = q.sym";
+    let err = ctx.execute(sql).unwrap_err().to_string();
+    assert!(err.contains("requires an inequality condition"), "{err}");
+
+    // `asof` is not a keyword when used as a table name or alias
+    let asof_alias = "SELECT asof.sym, asof.ts, q.px FROM trades AS asof ASOF JOIN quotes q \
+         ON asof.sym = q.sym AND asof.ts >= q.ts ORDER BY asof.sym, asof.ts";
+    let actual = ctx.execute(asof_alias).unwrap().collect().unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    let asof = ctx.execute("SELECT * FROM trades").unwrap();
+    ctx.register("asof", asof);
+    let expected = df! { "n" => [6 as IdxSize] }.unwrap();
+    for sql in [
+        "SELECT COUNT(*) AS n FROM trades AS asof JOIN quotes q ON asof.sym = q.sym",
+        "SELECT COUNT(*) AS n FROM asof JOIN quotes q ON asof.sym = q.sym",
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        assert!(actual.equals(&expected), "{sql}\n{actual:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_pivot_unpivot() {
+    let sales = df! {
+        "region" => ["east", "east", "west", "west", "east"],
+        "quarter" => ["Q1", "Q2", "Q1", "Q2", "Q1"],
+        "amount" => [10i64, 20, 30, 40, 50],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales.lazy());
+
+    let sql = r#"
+        SELECT * FROM sales
+        PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2'))
+        ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => ["east", "west"],
+        "Q1" => [60i64, 30],
+        "Q2" => [20i64, 40],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    ctx.register("quarterly", expected.lazy());
+    let sql = r#"
+        SELECT * FROM quarterly
+        UNPIVOT (amount FOR quarter IN (Q1, Q2 AS second))
+        ORDER BY region, quarter
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => ["east", "east", "west", "west"],
+        "quarter" => ["Q1", "second", "Q1", "second"],
+        "amount" => [60i64, 20, 30, 40],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_correlated_subqueries() {
+    let customers = df! {
+        "id" => [1i64, 2, 3],
+        "credit" => [50i64, 10, 30],
+    }
+    .unwrap();
+    let orders = df! {
+        "customer_id" => [1i64, 1, 2, 3, 3],
+        "amount" => [40i64, 20, 5, 35, 25],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+
+    // scalar subqueries (correlated by equality) in SELECT and WHERE
+    let sql = r#"
+        SELECT id, (SELECT MAX(amount) FROM orders o WHERE o.customer_id = c.id) AS top
+        FROM customers c
+        WHERE credit >= (SELECT SUM(amount) FROM orders o WHERE o.customer_id = c.id) - 10
+        ORDER BY id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1i64, 2],
+        "top" => [40i64, 5],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // top-n rows per customer with a LATERAL join
+    let sql = r#"
+        SELECT c.id, t.amount
+        FROM customers c
+        JOIN LATERAL (
+          SELECT amount FROM orders o WHERE o.customer_id = c.id
+          ORDER BY amount DESC LIMIT 1
+        ) t ON TRUE
+        ORDER BY c.id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1i64, 2, 3],
+        "amount" => [40i64, 5, 35],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
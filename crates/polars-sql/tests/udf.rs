@@ -1,9 +1,9 @@
 use polars_core::prelude::*;
-use polars_lazy::prelude::IntoLazy;
-use polars_plan::dsl::BaseColumnUdf;
+use polars_lazy::prelude::{IntoLazy, lit};
+use polars_plan::dsl::{BaseColumnUdf, DataTypeSelector};
 use polars_plan::prelude::{FunctionOptions, UserDefinedFunction};
 use polars_sql::SQLContext;
-use polars_sql::function_registry::FunctionRegistry;
+use polars_sql::function_registry::{FunctionRegistry, SQLFunctionRegistry, SQLFunctionSignature};
 
 struct MyFunctionRegistry {
     functions: PlHashMap<String, UserDefinedFunction>,
@@ -156,3 +156,85 @@ fn test_group_by_aggregate_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_sql_function_registry() -> PolarsResult<()> {
+    let mut registry = SQLFunctionRegistry::new();
+    registry.register_scalar(
+        "Scale",
+        SQLFunctionSignature::new([DataTypeSelector::Numeric, DataTypeSelector::Integer]),
+        |args| Ok(args[0].clone() * args[1].clone() + lit(1)),
+    )?;
+    registry.register_aggregate(
+        "str_total_len",
+        SQLFunctionSignature::new([DataTypeSelector::AnyOf([DataType::String].into())]),
+        DataType::UInt64,
+        |c: &[Column]| {
+            let ca = c[0].str()?;
+            let total: u64 = ca.into_iter().flatten().map(|s| s.len() as u64).sum();
+            Ok(Scalar::from(total))
+        },
+    )?;
+    assert!(matches!(
+        registry.register_scalar("scale", SQLFunctionSignature::any(), |args| Ok(
+            args[0].clone()
+        )),
+        Err(PolarsError::SQLInterface(_))
+    ));
+
+    let mut ctx = SQLContext::new().with_function_registry(Arc::new(registry));
+    let df = df! {
+        "g" => &["x", "x", "y"],
+        "s" => &["ab", "c", "def"],
+        "v" => &[1i64, 2, 3],
+    }?
+    .lazy();
+    ctx.register("foo", df);
+
+    // scalar function (names are case-insensitive)
+    let res = ctx
+        .execute("SELECT SCALE(v, 10) AS scaled FROM foo")?
+        .collect()?;
+    let expected = df! { "scaled" => &[11i64, 21, 31] }?;
+    assert!(expected.equals_missing(&res));
+
+    // aggregate function, over the whole frame and per group
+    let res = ctx
+        .execute("SELECT str_total_len(s) AS n FROM foo")?
+        .collect()?;
+    let expected = df! { "n" => &[6u64] }?;
+    assert!(expected.equals_missing(&res));
+
+    let res = ctx
+        .execute("SELECT g, str_total_len(s) AS n FROM foo GROUP BY g ORDER BY g")?
+        .collect()?;
+    let expected = df! {
+        "g" => &["x", "y"],
+        "n" => &[3u64, 3],
+    }?;
+    assert!(expected.equals_missing(&res));
+
+    // registered functions are also available in subqueries
+    let res = ctx
+        .execute("SELECT * FROM (SELECT scale(v, 2) AS x FROM foo) WHERE x > 4")?
+        .collect()?;
+    let expected = df! { "x" => &[5i64, 7] }?;
+    assert!(expected.equals_missing(&res));
+
+    // argument count and type checks
+    assert!(matches!(
+        ctx.execute("SELECT scale(v) FROM foo"),
+        Err(PolarsError::SQLSyntax(_))
+    ));
+    assert!(matches!(
+        ctx.execute("SELECT scale(v, 1.5) FROM foo"),
+        Err(PolarsError::SchemaMismatch(_))
+    ));
+    let err = ctx
+        .execute("SELECT str_total_len(v) FROM foo")
+        .and_then(|lf| lf.collect())
+        .unwrap_err();
+    assert!(err.to_string().contains("str_total_len argument 1 expects"));
+
+    Ok(())
+}
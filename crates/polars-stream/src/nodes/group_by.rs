@@ -9,7 +9,7 @@ use polars_expr::groups::Grouper;
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
-use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ooc::{MostRecentSpillContext, SpillFrame, memory_manager};
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
@@ -213,6 +213,8 @@ impl GroupBySinkState {
                         in_cols.clear();
                         in_cols = in_cols.into_iter().map(|_| unreachable!()).collect(); // Clear lifetimes.
                     }
+                    // The reductions may have registered spillable state.
+                    memory_manager().spill().await;
 
                     // Store cold keys.
                     // TODO: don't always gather, if majority cold simply store all and remember offsets into it.
@@ -446,6 +448,11 @@ impl GroupBySinkState {
                         }
                     }
 
+                    // Load the spilled reduction state before it gets finalized.
+                    for r in &mut p_reductions {
+                        r.load_spilled().await;
+                    }
+
                     // We're done, help others out by doing drops.
                     drop(drop_q_send); // So we don't deadlock trying to receive from ourselves.
                     while let Ok(to_drop) = drop_q_recv.recv().await {
@@ -540,12 +547,16 @@ impl GroupByNode {
         grouper: Box<dyn Grouper>,
         // grouped_reductions[k] is passed input cols grouped_reduction_cols[k].
         grouped_reduction_cols: Vec<Vec<PlSmallStr>>,
-        grouped_reductions: Vec<Box<dyn GroupedReduction>>,
+        mut grouped_reductions: Vec<Box<dyn GroupedReduction>>,
         output_schema: Arc<Schema>,
         random_state: PlRandomState,
         num_pipelines: usize,
         has_order_sensitive_agg: bool,
     ) -> Self {
+        let spill_ctx = MostRecentSpillContext::new("group-by".into());
+        for gr in &mut grouped_reductions {
+            gr.set_spill_context(&spill_ctx);
+        }
         let hot_table_size = std::env::var("POLARS_HOT_TABLE_SIZE")
             .map(|sz| sz.parse::<usize>().unwrap())
             .unwrap_or(DEFAULT_HOT_TABLE_SIZE);
@@ -592,7 +603,7 @@ impl GroupByNode {
             num_inputs,
             num_pipelines,
             output_schema,
-            spill_ctx: MostRecentSpillContext::new("group-by".into()),
+            spill_ctx,
         }
     }
 }
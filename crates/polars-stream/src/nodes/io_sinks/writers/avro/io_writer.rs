@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use arrow::io::avro::avro_schema;
+use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::schema::Record;
+use polars_async::executor;
+use polars_error::{PolarsResult, to_compute_err};
+use tokio::io::AsyncWriteExt as _;
+
+use crate::nodes::io_sinks::components::sink_morsel::SinkMorselPermit;
+use crate::nodes::io_sinks::writers::avro::morsel_serializer::MorselSerializer;
+use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
+
+pub struct IOWriter {
+    pub file: FileOpenTaskHandle,
+    pub filled_serializer_rx: tokio::sync::mpsc::Receiver<(
+        executor::AbortOnDropHandle<PolarsResult<MorselSerializer>>,
+        SinkMorselPermit,
+    )>,
+    pub reuse_serializer_tx: tokio::sync::mpsc::Sender<MorselSerializer>,
+    pub record: Arc<Record>,
+    pub compression: Option<Compression>,
+}
+
+impl IOWriter {
+    pub async fn run(self) -> PolarsResult<()> {
+        let IOWriter {
+            file,
+            mut filled_serializer_rx,
+            reuse_serializer_tx,
+            record,
+            compression,
+        } = self;
+
+        let (writable, sync_on_close) = file.await?;
+        let mut writer = writable.try_into_async_writeable()?;
+
+        // The container header is written once. Every serialized morsel is a self-contained block
+        // terminated by the sync marker.
+        let mut header = vec![];
+        avro_schema::write::write_metadata(&mut header, Record::clone(&record), compression)
+            .map_err(to_compute_err)?;
+        writer.write_all(&header).await?;
+
+        while let Some((handle, permit)) = filled_serializer_rx.recv().await {
+            let serializer = handle.await?;
+
+            writer.write_all(&serializer.serialized_data).await?;
+
+            drop(permit);
+
+            let _ = reuse_serializer_tx.send(serializer).await;
+        }
+
+        writer.close(sync_on_close).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use arrow::io::avro::avro_schema::file::{Block, CompressedBlock, Compression};
+use arrow::io::avro::avro_schema::schema::Record;
+use arrow::io::avro::{avro_schema, write};
+use polars_async::executor::{self, TaskPriority};
+use polars_async::primitives::connector;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::CompatLevel;
+use polars_error::{PolarsResult, to_compute_err};
+
+use crate::nodes::io_sinks::components::par_utils::rechunk_par;
+use crate::nodes::io_sinks::components::sink_morsel::{SinkMorsel, SinkMorselPermit};
+
+pub struct MorselSerializerPipeline {
+    pub morsel_rx: connector::Receiver<SinkMorsel>,
+    pub filled_serializer_tx: tokio::sync::mpsc::Sender<(
+        executor::AbortOnDropHandle<PolarsResult<MorselSerializer>>,
+        SinkMorselPermit,
+    )>,
+    pub reuse_serializer_rx: tokio::sync::mpsc::Receiver<MorselSerializer>,
+    pub max_serializers: usize,
+    pub base_allocation_size: usize,
+    pub record: Arc<Record>,
+    pub compression: Option<Compression>,
+}
+
+impl MorselSerializerPipeline {
+    pub async fn run(self) {
+        let MorselSerializerPipeline {
+            mut morsel_rx,
+            filled_serializer_tx,
+            mut reuse_serializer_rx,
+            max_serializers,
+            base_allocation_size,
+            record,
+            compression,
+        } = self;
+
+        let mut num_created_serializers: usize = 0;
+
+        while let Ok(morsel) = morsel_rx.recv().await {
+            let morsel_serializer: MorselSerializer =
+                if let Ok(serializer) = reuse_serializer_rx.try_recv() {
+                    serializer
+                } else if num_created_serializers < max_serializers {
+                    num_created_serializers += 1;
+                    MorselSerializer {
+                        serialized_data: vec![],
+                        block: Block::default(),
+                        compressed_block: CompressedBlock::default(),
+                        allocation_size: base_allocation_size,
+                    }
+                } else if let Some(serializer) = reuse_serializer_rx.recv().await {
+                    serializer
+                } else {
+                    break;
+                };
+
+            let (df, morsel_permit) = morsel.into_inner();
+
+            let handle = executor::AbortOnDropHandle::new(executor::spawn(
+                TaskPriority::High,
+                morsel_serializer.serialize_morsel(df, Arc::clone(&record), compression),
+            ));
+
+            if filled_serializer_tx
+                .send((handle, morsel_permit))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    }
+}
+
+pub struct MorselSerializer {
+    /// A single framed Avro block: row count, byte length, data and sync marker.
+    pub serialized_data: Vec<u8>,
+    block: Block,
+    compressed_block: CompressedBlock,
+    allocation_size: usize,
+}
+
+impl MorselSerializer {
+    pub async fn serialize_morsel(
+        mut self,
+        mut df: DataFrame,
+        record: Arc<Record>,
+        compression: Option<Compression>,
+    ) -> PolarsResult<Self> {
+        let MorselSerializer {
+            serialized_data,
+            block,
+            compressed_block,
+            allocation_size,
+        } = &mut self;
+
+        serialized_data.clear();
+
+        let height = df.height();
+
+        // A block with 0 rows is read as the end of the file.
+        if height == 0 {
+            return Ok(self);
+        }
+
+        rechunk_par(unsafe { df.columns_mut_retain_schema() }).await;
+
+        let arrays = df
+            .into_columns()
+            .into_iter()
+            .map(|c| c.rechunk_to_arrow(CompatLevel::oldest()))
+            .collect::<Vec<_>>();
+
+        let mut serializers = arrays
+            .iter()
+            .zip(record.fields.iter())
+            .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
+            .collect::<Vec<_>>();
+
+        block.number_of_rows = height;
+        block.data.clear();
+        block.data.reserve_exact(*allocation_size);
+        write::serialize(&mut serializers, block);
+
+        avro_schema::write::compress(block, compressed_block, compression)
+            .map_err(to_compute_err)?;
+
+        serialized_data.reserve(compressed_block.data.len() + 32);
+        avro_schema::write::write_block(serialized_data, compressed_block)
+            .map_err(to_compute_err)?;
+
+        *allocation_size = usize::max(*allocation_size, block.data.capacity());
+
+        Ok(self)
+    }
+}
@@ -7,6 +7,8 @@ use polars_utils::IdxSize;
 
 use crate::nodes::io_sinks::writers::interface::FileWriterStarter;
 
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
 pub mod interface;
@@ -82,11 +84,24 @@ pub fn create_file_writer_starter(
                 initialized_state: Default::default(),
             },
         ) as _,
+        #[cfg(feature = "avro")]
+        FileWriteFormat::Avro(options) => {
+            use polars_core::prelude::CompatLevel;
+            use polars_io::schema_to_arrow_checked;
+
+            use crate::nodes::io_sinks::writers::avro::AvroWriterStarter;
+
+            let arrow_schema =
+                schema_to_arrow_checked(file_schema.as_ref(), CompatLevel::oldest(), "avro")?;
+
+            Arc::new(AvroWriterStarter::new(options.clone(), &arrow_schema)?) as _
+        },
         #[cfg(not(any(
             feature = "parquet",
             feature = "ipc",
             feature = "csv",
-            feature = "json"
+            feature = "json",
+            feature = "avro"
         )))]
         _ => panic!("no enum variants on FileType (hint: missing feature flags?)"),
     })
@@ -6,7 +6,9 @@ use polars_buffer::Buffer;
 use polars_error::PolarsResult;
 use polars_io::parquet::write::BatchedWriter;
 use polars_io::prelude::KeyValueMetadata;
-use polars_parquet::write::{Encoding, FileWriter, SchemaDescriptor, WriteOptions};
+use polars_parquet::write::{
+    Encoding, FileEncryptionProperties, FileWriter, SchemaDescriptor, SortingColumn, WriteOptions,
+};
 
 use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
 use crate::nodes::io_sinks::writers::parquet::EncodedRowGroup;
@@ -19,6 +21,8 @@ pub struct IOWriter {
     pub schema_descriptor: Arc<SchemaDescriptor>,
     pub write_options: WriteOptions,
     pub encodings: Buffer<Vec<Encoding>>,
+    pub sorting_columns: Option<Vec<SortingColumn>>,
+    pub encryption: Option<FileEncryptionProperties>,
     pub key_value_metadata: Option<KeyValueMetadata>,
     pub num_leaf_columns: usize,
 }
@@ -32,6 +36,8 @@ impl IOWriter {
             schema_descriptor,
             write_options,
             encodings,
+            sorting_columns,
+            encryption,
             key_value_metadata,
             num_leaf_columns,
         } = self;
@@ -39,13 +45,17 @@ impl IOWriter {
         let (mut file, sync_on_close) = file.await?;
         let mut buffered_file = file.as_buffered();
 
+        let mut file_writer = FileWriter::new_with_parquet_schema(
+            &mut *buffered_file,
+            Arc::unwrap_or_clone(arrow_schema),
+            Arc::unwrap_or_clone(schema_descriptor),
+            write_options,
+        );
+        file_writer.set_sorting_columns(sorting_columns)?;
+        file_writer.set_encryption(encryption)?;
+
         let mut parquet_writer = BatchedWriter::new(
-            std::sync::Mutex::new(FileWriter::new_with_parquet_schema(
-                &mut *buffered_file,
-                Arc::unwrap_or_clone(arrow_schema),
-                Arc::unwrap_or_clone(schema_descriptor),
-                write_options,
-            )),
+            std::sync::Mutex::new(file_writer),
             encodings,
             write_options,
             false,
@@ -56,10 +66,11 @@ impl IOWriter {
             let EncodedRowGroup {
                 num_rows,
                 data,
+                bloom_filters,
                 morsel_permit,
             } = handle.await?;
             assert_eq!(data.len(), num_leaf_columns);
-            parquet_writer.write_row_group(num_rows as u64, &data)?;
+            parquet_writer.write_row_group(num_rows as u64, &data, bloom_filters)?;
             drop(data);
             drop(morsel_permit);
         }
@@ -6,9 +6,12 @@ use polars_async::primitives::connector;
 use polars_buffer::Buffer;
 use polars_core::runtime::ASYNC;
 use polars_error::PolarsResult;
-use polars_io::prelude::{ParquetWriteOptions, get_encodings};
+use polars_io::prelude::{
+    ParquetWriteOptions, get_bloom_filter_options, get_encodings, get_sorting_columns,
+};
 use polars_parquet::write::{
-    CompressedPage, Encoding, SchemaDescriptor, Version, WriteOptions, to_parquet_schema,
+    BloomFilterOptions, CompressedPage, Encoding, SchemaDescriptor, SortingColumn, Version,
+    WriteOptions, to_parquet_schema,
 };
 use polars_utils::IdxSize;
 use polars_utils::index::NonZeroIdxSize;
@@ -35,12 +38,16 @@ pub struct ParquetWriterStarter {
 #[derive(Clone)]
 pub struct InitializedState {
     encodings: Buffer<Vec<Encoding>>,
+    bloom_filters: Buffer<Option<BloomFilterOptions>>,
+    sorting_columns: Option<Vec<SortingColumn>>,
     schema_descriptor: Arc<SchemaDescriptor>,
 }
 
 struct EncodedRowGroup {
     num_rows: usize,
     data: Vec<Vec<CompressedPage>>,
+    /// Empty if no column has bloom filters enabled.
+    bloom_filters: Vec<Option<Vec<u8>>>,
     morsel_permit: SinkMorselPermit,
 }
 
@@ -83,6 +90,8 @@ impl FileWriterStarter for ParquetWriterStarter {
     ) -> PolarsResult<executor::JoinHandle<PolarsResult<()>>> {
         let InitializedState {
             encodings,
+            bloom_filters,
+            sorting_columns,
             schema_descriptor,
         } = {
             let mut initialized_state = self.initialized_state.lock().unwrap();
@@ -90,9 +99,19 @@ impl FileWriterStarter for ParquetWriterStarter {
             if initialized_state.is_none() {
                 let schema_descriptor = Arc::new(to_parquet_schema(&self.arrow_schema)?);
                 let encodings = get_encodings(&self.arrow_schema);
+                let bloom_filters =
+                    get_bloom_filter_options(&self.arrow_schema, &self.options.bloom_filters)?;
+                let sorting_columns = self
+                    .options
+                    .sorting_columns
+                    .as_deref()
+                    .map(|sorting_columns| get_sorting_columns(&schema_descriptor, sorting_columns))
+                    .transpose()?;
 
                 *initialized_state = Some(InitializedState {
                     encodings,
+                    bloom_filters,
+                    sorting_columns,
                     schema_descriptor,
                 })
             };
@@ -105,11 +124,13 @@ impl FileWriterStarter for ParquetWriterStarter {
         >(num_pipelines.get());
 
         let key_value_metadata = self.options.key_value_metadata.clone();
+        let encryption = self.options.encryption.clone();
         let write_options = WriteOptions {
             statistics: self.options.statistics,
             compression: self.options.compression.into(),
             version: Version::V1,
             data_page_size: self.options.data_page_size,
+            write_page_index: self.options.write_page_index,
         };
 
         let arrow_schema = Arc::clone(&self.arrow_schema);
@@ -124,6 +145,8 @@ impl FileWriterStarter for ParquetWriterStarter {
                     schema_descriptor: Arc::clone(&schema_descriptor),
                     write_options,
                     encodings: Buffer::clone(&encodings),
+                    sorting_columns,
+                    encryption,
                     key_value_metadata,
                     num_leaf_columns,
                 }
@@ -141,6 +164,7 @@ impl FileWriterStarter for ParquetWriterStarter {
                 schema_descriptor,
                 write_options,
                 encodings,
+                bloom_filters,
                 num_leaf_columns,
             }
             .run(),
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use polars_core::config;
+use polars_io::cloud::CloudOptions;
+use polars_io::cloud::concurrency_config::FetchConfig;
+use polars_io::utils::byte_source::DynByteSourceBuilder;
+use polars_plan::dsl::ScanSource;
+
+use super::AvroFileReader;
+use crate::metrics::IOMetrics;
+use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+pub struct AvroReaderBuilder {
+    pub io_metrics: std::sync::OnceLock<Arc<IOMetrics>>,
+}
+
+impl std::fmt::Debug for AvroReaderBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AvroReaderBuilder").finish()
+    }
+}
+
+impl FileReaderBuilder for AvroReaderBuilder {
+    fn reader_name(&self) -> &str {
+        "avro"
+    }
+
+    fn reader_capabilities(&self) -> ReaderCapabilities {
+        use ReaderCapabilities as RC;
+
+        RC::ROW_INDEX | RC::PRE_SLICE
+    }
+
+    fn set_io_metrics(&self, io_metrics: Arc<IOMetrics>) {
+        self.io_metrics.set(io_metrics).ok().unwrap()
+    }
+
+    fn build_file_reader(
+        &self,
+        source: ScanSource,
+        cloud_options: Option<Arc<CloudOptions>>,
+        _scan_source_idx: usize,
+    ) -> Box<dyn FileReader> {
+        use crate::metrics::OptIOMetrics;
+
+        let scan_source = source;
+        let verbose = config::verbose();
+
+        // Avro container files can only be walked front to back.
+        let byte_source_builder =
+            if scan_source.is_cloud_url() || polars_config::config().force_async() {
+                DynByteSourceBuilder::ObjectStore(FetchConfig::streaming())
+            } else {
+                DynByteSourceBuilder::Mmap
+            };
+
+        let reader = AvroFileReader {
+            scan_source,
+            cloud_options,
+            byte_source_builder,
+            io_metrics: OptIOMetrics(self.io_metrics.get().cloned()),
+            verbose,
+            init_data: None,
+        };
+
+        Box::new(reader) as Box<dyn FileReader>
+    }
+}
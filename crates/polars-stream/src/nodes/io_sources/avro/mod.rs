@@ -0,0 +1,595 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchemaRef;
+use arrow::io::avro::avro_schema::file::FileMetadata;
+use arrow::io::avro::avro_schema::read::block_iterator;
+use arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use arrow::io::avro::read::{deserialize, infer_schema};
+use async_trait::async_trait;
+use polars_async::executor::{self, JoinHandle, TaskPriority};
+use polars_buffer::Buffer;
+use polars_core::frame::DataFrame;
+use polars_core::runtime::ASYNC;
+use polars_core::schema::{Schema, SchemaRef};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err, to_compute_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource, DynByteSourceBuilder};
+use polars_io::utils::slice::SplitSlicePosition;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::{FileReaderOutputRecv, FileReaderOutputSend};
+use super::multi_scan::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, Projection, calc_row_position_after_slice,
+};
+use crate::metrics::OptIOMetrics;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::utils::tokio_handle_ext::AbortOnDropHandle;
+
+pub mod builder;
+
+const AVRO_SYNC_MARKER_LEN: usize = 16;
+
+struct AvroFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    byte_source_builder: DynByteSourceBuilder,
+    io_metrics: OptIOMetrics,
+    verbose: bool,
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    byte_source: Arc<DynByteSource>,
+    file_metadata: Arc<FileMetadata>,
+    arrow_schema: ArrowSchemaRef,
+    /// Offset of the first data block, i.e. the size of the file header.
+    data_offset: usize,
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let scan_source = self.scan_source.clone();
+        let byte_source_builder = self.byte_source_builder.clone();
+        let cloud_options = self.cloud_options.clone();
+        let io_metrics = self.io_metrics.clone();
+
+        let (byte_source, file_metadata, data_offset) = ASYNC
+            .spawn(async move {
+                let byte_source = scan_source
+                    .as_scan_source_ref()
+                    .to_dyn_byte_source(
+                        &byte_source_builder,
+                        cloud_options.as_deref(),
+                        io_metrics.0,
+                    )
+                    .await?;
+
+                let (file_metadata, data_offset) =
+                    polars_io::avro::read_metadata_from_byte_source(&byte_source).await?;
+
+                PolarsResult::Ok((byte_source, file_metadata, data_offset))
+            })
+            .await
+            .unwrap()?;
+
+        let arrow_schema = Arc::new(infer_schema(&file_metadata.record)?);
+
+        if self.verbose {
+            eprintln!(
+                "[AvroFileReader]: num_columns: {}, compression: {:?}, header size: {}",
+                arrow_schema.len(),
+                file_metadata.compression,
+                data_offset
+            )
+        }
+
+        self.init_data = Some(InitializedState {
+            byte_source: Arc::new(byte_source),
+            file_metadata: Arc::new(file_metadata),
+            arrow_schema,
+            data_offset,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            byte_source,
+            file_metadata,
+            arrow_schema,
+            data_offset,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            disable_morsel_split,
+            last_morsel_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    mut n_rows_in_file_tx,
+                    mut row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        debug_assert!(!matches!(pre_slice_arg, Some(Slice::Negative { .. })));
+
+        if let Some(file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.send(Arc::new(Schema::from_arrow_schema(&arrow_schema)));
+        }
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice_range: Range<usize> = pre_slice_arg
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        // Avro is row-oriented, unprojected fields still need to be skipped over while decoding,
+        // but they are never materialized.
+        let projection: Arc<[bool]> = arrow_schema
+            .iter_names()
+            .map(|name| projected_schema.contains(name))
+            .collect();
+        let projected_names: Arc<[PlSmallStr]> = projected_schema
+            .iter_names()
+            .filter(|name| arrow_schema.contains(name))
+            .cloned()
+            .collect();
+
+        let ideal_morsel_size = get_ideal_morsel_size();
+
+        if verbose {
+            eprintln!(
+                "[AvroFileReader]: \
+                project: {} / {}, \
+                pre_slice: {:?}, \
+                num_pipelines: {}, \
+                ideal_morsel_size: {}\
+                ",
+                projected_names.len(),
+                arrow_schema.len(),
+                pre_slice_arg,
+                num_pipelines,
+                ideal_morsel_size,
+            )
+        }
+
+        let batch_decoder = Arc::new(BlockBatchDecoder {
+            file_metadata: file_metadata.clone(),
+            arrow_schema,
+            projection,
+            projected_names,
+            row_index,
+        });
+
+        let (decode_send, mut decode_recv) = tokio::sync::mpsc::channel(num_pipelines);
+        let (mut morsel_send, morsel_recv) = FileReaderOutputSend::new_serial();
+
+        // Task: Scan.
+        // Walks the block headers front-to-back, and dispatches decoding of the batches of blocks
+        // that overlap with the slice.
+        let scan_task = AbortOnDropHandle(ASYNC.spawn(async move {
+            let file_size = byte_source.get_size().await?;
+            let fetch_size = byte_source.chunk_size().unwrap_or(file_size);
+
+            let mut block_scanner = BlockScanner {
+                byte_source,
+                marker: file_metadata.marker,
+                buf: Buffer::new(),
+                pos: 0,
+                fetched_to: data_offset,
+                file_size,
+                fetch_size,
+            };
+
+            let mut current_row_offset: usize = 0;
+            let mut output_closed = false;
+
+            loop {
+                let read_to_end = n_rows_in_file_tx.is_some();
+
+                if output_closed && !read_to_end {
+                    break;
+                }
+
+                let Some(BlockBatch { bytes, num_rows }) =
+                    block_scanner.next_batch(ideal_morsel_size).await?
+                else {
+                    break;
+                };
+
+                let batch_position = SplitSlicePosition::split_slice_at_file(
+                    current_row_offset,
+                    num_rows,
+                    slice_range.clone(),
+                );
+
+                let batch_row_offset = current_row_offset;
+                current_row_offset = current_row_offset
+                    .checked_add(num_rows)
+                    .ok_or_else(|| polars_err!(ComputeError: "avro row count overflow"))?;
+
+                match batch_position {
+                    SplitSlicePosition::Before => continue,
+                    SplitSlicePosition::Overlapping(rows_offset, rows_len) if !output_closed => {
+                        let batch_decoder = batch_decoder.clone();
+                        let decode_fut = executor::spawn(TaskPriority::High, async move {
+                            batch_decoder.decode(
+                                bytes.as_ref(),
+                                batch_row_offset,
+                                rows_offset,
+                                rows_len,
+                            )
+                        });
+
+                        output_closed = decode_send.send(decode_fut).await.is_err();
+                    },
+                    SplitSlicePosition::Overlapping(..) => {},
+                    SplitSlicePosition::After => {
+                        if !read_to_end {
+                            break;
+                        }
+                    },
+                }
+            }
+
+            drop(decode_send);
+
+            let current_row_offset = IdxSize::try_from(current_row_offset)
+                .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = current_row_offset))?;
+
+            if let Some(n_rows_in_file_tx) = n_rows_in_file_tx.take() {
+                _ = n_rows_in_file_tx.send(current_row_offset);
+            }
+
+            // If we stopped early, `current_row_offset` is past the end of the slice, which is
+            // clamped here.
+            if let Some(row_position_on_end_tx) = row_position_on_end_tx.take() {
+                _ = row_position_on_end_tx.send(calc_row_position_after_slice(
+                    current_row_offset,
+                    pre_slice_arg,
+                ));
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Task: Distributor.
+        let distribute_task = executor::spawn(TaskPriority::High, async move {
+            let mut morsel_seq = MorselSeq::default();
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            let mut next = None;
+
+            loop {
+                let df = match next.take() {
+                    Some(df) => df,
+                    None => match decode_recv.recv().await {
+                        Some(decode_fut) => decode_fut.await?,
+                        None => break,
+                    },
+                };
+
+                if df.height() == 0 {
+                    continue;
+                }
+
+                // Decode the next batch first, so that we know whether this is the last morsel.
+                let is_last_morsel = if disable_morsel_split {
+                    false
+                } else {
+                    loop {
+                        let Some(decode_fut) = decode_recv.recv().await else {
+                            break true;
+                        };
+                        let next_df = decode_fut.await?;
+                        if next_df.height() > 0 {
+                            next = Some(next_df);
+                            break false;
+                        }
+                    }
+                };
+
+                let n_morsels = if is_last_morsel {
+                    last_morsel_pipelines.max(1)
+                } else {
+                    1
+                };
+                let rows_per_morsel = df.height().div_ceil(n_morsels).max(1);
+
+                for offset in (0..df.height()).step_by(rows_per_morsel) {
+                    let morsel_df = df.slice(offset as i64, rows_per_morsel);
+
+                    if morsel_send
+                        .send_morsel(Morsel::new(morsel_df, morsel_seq, source_token.clone()))
+                        .await
+                        .is_err()
+                    {
+                        return Ok(());
+                    }
+
+                    morsel_seq = morsel_seq.successor();
+                }
+            }
+
+            PolarsResult::Ok(())
+        });
+
+        // Orchestration.
+        let join_task = ASYNC.spawn(async move {
+            distribute_task.await?;
+            scan_task.await.unwrap()?;
+            Ok(())
+        });
+
+        let handle = AbortOnDropHandle(join_task);
+
+        Ok((
+            morsel_recv,
+            executor::spawn(TaskPriority::Low, async move { handle.await.unwrap() }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        let arrow_schema = &self.init_data.as_ref().unwrap().arrow_schema;
+        Ok(Arc::new(Schema::from_arrow_schema(arrow_schema)))
+    }
+
+    async fn file_arrow_schema(&mut self) -> PolarsResult<Option<ArrowSchemaRef>> {
+        Ok(Some(self.init_data.as_ref().unwrap().arrow_schema.clone()))
+    }
+}
+
+/// Decodes a run of consecutive data blocks into a [`DataFrame`].
+struct BlockBatchDecoder {
+    file_metadata: Arc<FileMetadata>,
+    arrow_schema: ArrowSchemaRef,
+    projection: Arc<[bool]>,
+    projected_names: Arc<[PlSmallStr]>,
+    row_index: Option<RowIndex>,
+}
+
+impl BlockBatchDecoder {
+    fn decode(
+        &self,
+        bytes: &[u8],
+        batch_row_offset: usize,
+        slice_offset: usize,
+        slice_len: usize,
+    ) -> PolarsResult<DataFrame> {
+        let mut df = if self.projected_names.is_empty() {
+            DataFrame::empty_with_height(slice_len)
+        } else {
+            let mut blocks = block_iterator(
+                bytes,
+                self.file_metadata.compression,
+                self.file_metadata.marker,
+            );
+            let mut dfs = vec![];
+
+            while let Some(block) = blocks.next().map_err(to_compute_err)? {
+                let record_batch = deserialize(
+                    block,
+                    self.arrow_schema.as_ref(),
+                    &self.file_metadata.record.fields,
+                    &self.projection,
+                )?;
+                dfs.push(DataFrame::from(record_batch));
+            }
+
+            let df = accumulate_dataframes_vertical_unchecked(dfs)
+                .slice(i64::try_from(slice_offset).unwrap(), slice_len);
+
+            // Columns are decoded in file order.
+            if df
+                .get_column_names()
+                .into_iter()
+                .eq(self.projected_names.iter())
+            {
+                df
+            } else {
+                df.select(self.projected_names.iter())?
+            }
+        };
+
+        if let Some(RowIndex { name, offset }) = &self.row_index {
+            let row_offset = batch_row_offset + slice_offset;
+            let row_offset = IdxSize::try_from(row_offset)
+                .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = row_offset))?;
+            df = df.with_row_index(name.clone(), Some(row_offset + *offset))?;
+        }
+
+        Ok(df)
+    }
+}
+
+struct BlockBatch {
+    bytes: Buffer<u8>,
+    num_rows: usize,
+}
+
+/// Walks the data blocks of an Avro container file, fetching the file body progressively. Only
+/// the block headers are decoded here, as the row count and byte length of each block are enough
+/// to skip blocks outside of the slice without decompressing them.
+struct BlockScanner {
+    byte_source: Arc<DynByteSource>,
+    marker: [u8; AVRO_SYNC_MARKER_LEN],
+    buf: Buffer<u8>,
+    /// Position in `buf` of the next block header.
+    pos: usize,
+    /// Position in the file of the end of `buf`.
+    fetched_to: usize,
+    file_size: usize,
+    fetch_size: usize,
+}
+
+impl BlockScanner {
+    /// Returns the next run of non-empty blocks, with at least `target_num_rows` rows unless the
+    /// end of the file is reached.
+    async fn next_batch(&mut self, target_num_rows: usize) -> PolarsResult<Option<BlockBatch>> {
+        let mut start = self.pos;
+        let mut num_rows: usize = 0;
+
+        loop {
+            match parse_block_header(&self.buf[self.pos..], &self.marker)? {
+                BlockHeader::Complete { num_rows: 0, len } => {
+                    // The block decoder treats an empty block as the end of the file, so empty
+                    // blocks must not end up in the middle of a batch.
+                    if num_rows > 0 {
+                        break;
+                    }
+
+                    self.pos += len;
+                    start = self.pos;
+                },
+                BlockHeader::Complete {
+                    num_rows: block_num_rows,
+                    len,
+                } => {
+                    self.pos += len;
+                    num_rows += block_num_rows;
+
+                    if num_rows >= target_num_rows {
+                        break;
+                    }
+                },
+                BlockHeader::Incomplete { block_len } => {
+                    if self.fetched_to == self.file_size {
+                        polars_ensure!(
+                            self.pos == self.buf.len(),
+                            ComputeError: "avro file is truncated: incomplete block at end of file"
+                        );
+                        break;
+                    }
+
+                    let available = self.buf.len() - self.pos;
+                    let fetch_size = usize::max(
+                        self.fetch_size,
+                        block_len.unwrap_or(0).saturating_sub(available),
+                    );
+                    let fetch_range =
+                        self.fetched_to..usize::min(self.fetched_to + fetch_size, self.file_size);
+
+                    let fetched = self.byte_source.get_range(fetch_range.clone()).await?;
+                    self.fetched_to = fetch_range.end;
+
+                    // Keep the blocks of the current batch contiguous.
+                    self.buf = if start == self.buf.len() {
+                        fetched
+                    } else {
+                        let mut bytes = Vec::with_capacity(self.buf.len() - start + fetched.len());
+                        bytes.extend_from_slice(&self.buf[start..]);
+                        bytes.extend_from_slice(&fetched);
+                        Buffer::from_vec(bytes)
+                    };
+                    self.pos -= start;
+                    start = 0;
+                },
+            }
+        }
+
+        Ok((num_rows > 0).then(|| BlockBatch {
+            bytes: self.buf.clone().sliced(start..self.pos),
+            num_rows,
+        }))
+    }
+}
+
+enum BlockHeader {
+    Complete {
+        num_rows: usize,
+        /// Length of the block including its header and sync marker.
+        len: usize,
+    },
+    Incomplete {
+        block_len: Option<usize>,
+    },
+}
+
+fn parse_block_header(
+    bytes: &[u8],
+    marker: &[u8; AVRO_SYNC_MARKER_LEN],
+) -> PolarsResult<BlockHeader> {
+    let Some((num_rows, num_rows_len)) = read_zigzag(bytes)? else {
+        return Ok(BlockHeader::Incomplete { block_len: None });
+    };
+    let Some((num_bytes, num_bytes_len)) = read_zigzag(&bytes[num_rows_len..])? else {
+        return Ok(BlockHeader::Incomplete { block_len: None });
+    };
+
+    let (Ok(num_rows), Ok(num_bytes)) = (usize::try_from(num_rows), usize::try_from(num_bytes))
+    else {
+        polars_bail!(ComputeError: "avro block has a negative length - corrupt avro file")
+    };
+
+    let block_len = num_rows_len + num_bytes_len + num_bytes + AVRO_SYNC_MARKER_LEN;
+
+    if bytes.len() < block_len {
+        return Ok(BlockHeader::Incomplete {
+            block_len: Some(block_len),
+        });
+    }
+
+    polars_ensure!(
+        &bytes[block_len - AVRO_SYNC_MARKER_LEN..block_len] == marker,
+        ComputeError: "avro block sync marker does not match the file header - corrupt avro file"
+    );
+
+    Ok(BlockHeader::Complete {
+        num_rows,
+        len: block_len,
+    })
+}
+
+/// Returns the decoded value and the number of bytes read, or `None` if `bytes` ends before the
+/// value does.
+fn read_zigzag(bytes: &[u8]) -> PolarsResult<Option<(i64, usize)>> {
+    let mut z: u64 = 0;
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        polars_ensure!(
+            i < 10,
+            ComputeError: "zigzag decoding failed - corrupt avro file"
+        );
+
+        z |= u64::from(byte & 0x7F) << (i * 7);
+
+        if byte >> 7 == 0 {
+            let value = if z & 0x1 == 0 {
+                (z >> 1) as i64
+            } else {
+                !(z >> 1) as i64
+            };
+
+            return Ok(Some((value, i + 1)));
+        }
+    }
+
+    Ok(None)
+}
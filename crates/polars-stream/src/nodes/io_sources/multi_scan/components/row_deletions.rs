@@ -13,6 +13,8 @@ use polars_core::schema::{Schema, SchemaRef};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_error::{PolarsResult, feature_gated, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
+#[cfg(feature = "delta")]
+use polars_io::delta::DeletionVector;
 use polars_plan::dsl::deletion::DeletionFilesList;
 #[cfg(feature = "python")]
 use polars_plan::dsl::deletion::DeltaDeletionVectorProvider;
@@ -45,6 +47,10 @@ pub enum DeletionFilesProvider {
         selected_paths: Buffer<PlRefPath>,
         cache: Arc<tokio::sync::OnceCell<Option<ListArray<i64>>>>,
     },
+    #[cfg(feature = "delta")]
+    DeltaDeletionVectors {
+        deletion_vectors: Arc<PlIndexMap<usize, DeletionVector>>,
+    },
 }
 
 impl DeletionFilesProvider {
@@ -67,6 +73,7 @@ impl DeletionFilesProvider {
                         parallel: polars_io::prelude::ParallelStrategy::Auto,
                         low_memory: false,
                         use_statistics: false,
+                        decryption: None,
                     }),
                     pipeline_budget: std::sync::OnceLock::new(),
                     shared_prefetch_wait_group_slot: Default::default(),
@@ -96,6 +103,10 @@ impl DeletionFilesProvider {
                     cache: Arc::new(tokio::sync::OnceCell::new()),
                 })
             },
+            #[cfg(feature = "delta")]
+            Some(DeletionFilesList::DeltaDeletionVectors(deletion_vectors)) => {
+                Ok(Self::DeltaDeletionVectors { deletion_vectors })
+            },
             None => Ok(Self::None),
         }
     }
@@ -336,6 +347,31 @@ impl DeletionFilesProvider {
 
                 Some(RowDeletionsInit::Initializing(handle))
             },
+
+            #[cfg(feature = "delta")]
+            Self::DeltaDeletionVectors { deletion_vectors } => {
+                let deletion_vector = deletion_vectors.get(&scan_source_idx)?.clone();
+
+                if verbose {
+                    eprintln!("[DeletionFilesProvider[Delta]]: scan_source_idx: {scan_source_idx}")
+                }
+
+                let handle =
+                    AbortOnDropHandle::new(executor::spawn(TaskPriority::Low, async move {
+                        let bitmap = polars_core::runtime::ASYNC
+                            .spawn_blocking(move || deletion_vector.load_selection_mask())
+                            .await
+                            .unwrap()?;
+
+                        // Also trigger the bitcount to reduce blocking later down.
+                        bitmap.unset_bits();
+
+                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
+                        Ok(ExternalFilterMask::DeltaDeletionVector { mask })
+                    }));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
         }
     }
 }
@@ -476,7 +476,10 @@ impl FileReader for NDJsonFileReader {
             let memslice = self
                 .scan_source
                 .as_scan_source_ref()
-                .to_buffer_async_assume_latest(self.scan_source.run_async())?;
+                .to_buffer_async_assume_latest(
+                    self.scan_source.run_async(),
+                    self.io_metrics.0.clone(),
+                )?;
 
             ReaderSource::Memory(Cursor::new(memslice))
         };
@@ -0,0 +1,201 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+use polars_io::predicates::{ColumnPredicates, ScanIOPredicate, SpecializedColumnPredicate};
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::bloom_filter::{is_in_set, read_from_bytes};
+use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::write::array_to_hashes;
+
+use crate::metrics::OptIOMetrics;
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// A column whose bloom filters can be probed for the values it must equal.
+struct BloomFilterProbe {
+    name: PlSmallStr,
+    hashes: Vec<u64>,
+}
+
+/// Extends `row_group_mask` with the row groups whose bloom filters prove that no row can
+/// satisfy an equality / `is_in` predicate.
+///
+/// Only the bloom filters of row groups that are not already skipped are fetched.
+#[expect(clippy::too_many_arguments)]
+pub(super) async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &Arc<FileMetadata>,
+    projected_arrow_fields: &[ArrowFieldProjection],
+    byte_source: &DynByteSource,
+    row_group_mask: Option<Bitmap>,
+    io_metrics: &OptIOMetrics,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    if !use_statistics || row_group_slice.is_empty() {
+        return Ok(row_group_mask);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(row_group_mask);
+    };
+
+    let probes = build_probes(
+        &predicate.column_predicates,
+        metadata,
+        projected_arrow_fields,
+        row_group_slice.start,
+    )?;
+
+    if probes.is_empty() {
+        return Ok(row_group_mask);
+    }
+
+    let row_groups_slice = &metadata.row_groups[row_group_slice.clone()];
+    let is_skipped = |i: usize| row_group_mask.as_ref().is_some_and(|m| m.get_bit(i));
+
+    let mut ranges = Vec::new();
+
+    for (i, rg) in row_groups_slice.iter().enumerate() {
+        if is_skipped(i) {
+            continue;
+        }
+
+        for probe in probes.iter() {
+            if let Some(range) = bloom_filter_range(rg, &probe.name) {
+                ranges.push(range);
+            }
+        }
+    }
+
+    if ranges.is_empty() {
+        return Ok(row_group_mask);
+    }
+
+    let bloom_filter_bytes = byte_source.get_ranges(&mut ranges).await?;
+
+    let mut skip_mask = match &row_group_mask {
+        Some(mask) => mask.clone().make_mut(),
+        None => MutableBitmap::from_len_zeroed(row_groups_slice.len()),
+    };
+    let mut num_skipped = 0;
+    let mut bitset = Vec::new();
+
+    for (i, rg) in row_groups_slice.iter().enumerate() {
+        if is_skipped(i) {
+            continue;
+        }
+
+        for probe in probes.iter() {
+            let Some(range) = bloom_filter_range(rg, &probe.name) else {
+                continue;
+            };
+
+            read_from_bytes(&bloom_filter_bytes[&range.start], &mut bitset)?;
+
+            // Unsupported bloom filter algorithm.
+            if bitset.is_empty() {
+                continue;
+            }
+
+            if !probe.hashes.iter().any(|hash| is_in_set(&bitset, *hash)) {
+                skip_mask.set(i, true);
+                num_skipped += 1;
+                break;
+            }
+        }
+    }
+
+    io_metrics.add_row_groups_skipped_bloom_filter(num_skipped as u64);
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+            skipped {} / {} row groups",
+            num_skipped,
+            row_groups_slice.len(),
+        );
+    }
+
+    Ok(Some(skip_mask.freeze()))
+}
+
+/// Hashes the values that the equality / `is_in` column predicates compare against.
+///
+/// Columns for which the hashes could differ from the hashes written to the file (e.g. because
+/// the column is casted, nested, stored with a different physical type or holds floats) are not
+/// probed.
+fn build_probes(
+    column_predicates: &ColumnPredicates,
+    metadata: &FileMetadata,
+    projected_arrow_fields: &[ArrowFieldProjection],
+    first_row_group: usize,
+) -> PolarsResult<Vec<BloomFilterProbe>> {
+    let rg = &metadata.row_groups[first_row_group];
+    let mut probes = Vec::new();
+
+    for projection in projected_arrow_fields.iter() {
+        let ArrowFieldProjection::Plain(arrow_field) = projection else {
+            continue;
+        };
+
+        let Some((_, Some(specialized))) = column_predicates.predicates.get(&arrow_field.name)
+        else {
+            continue;
+        };
+
+        let scalars = match specialized {
+            SpecializedColumnPredicate::Equal(scalar) => std::slice::from_ref(scalar),
+            SpecializedColumnPredicate::EqualOneOf(scalars) => scalars.as_ref(),
+            _ => continue,
+        };
+
+        let dtype = DataType::from_arrow_field(arrow_field);
+
+        if scalars.is_empty() || scalars.iter().any(|s| s.is_null() || s.dtype() != &dtype) {
+            continue;
+        }
+
+        let Some(&[leaf_idx]) = rg.columns_idxs_under_root_iter(&arrow_field.name) else {
+            continue;
+        };
+
+        let values = scalars.iter().map(|s| s.as_any_value()).collect::<Vec<_>>();
+        let array = Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, &dtype, true)?
+            .to_arrow(0, CompatLevel::newest());
+
+        if array.dtype() != arrow_field.dtype() {
+            continue;
+        }
+
+        let physical_type = rg.parquet_columns()[leaf_idx].physical_type();
+
+        if let Some(hashes) = array_to_hashes(array.as_ref(), physical_type) {
+            probes.push(BloomFilterProbe {
+                name: arrow_field.name.clone(),
+                hashes,
+            });
+        }
+    }
+
+    Ok(probes)
+}
+
+fn bloom_filter_range(rg: &RowGroupMetadata, name: &str) -> Option<Range<usize>> {
+    let &[leaf_idx] = rg.columns_idxs_under_root_iter(name)? else {
+        return None;
+    };
+
+    let column = &rg.parquet_columns()[leaf_idx];
+    // The bloom filters of encrypted columns are encrypted as well.
+    if column.is_encrypted() {
+        return None;
+    }
+    let offset = usize::try_from(column.bloom_filter_offset()?).ok()?;
+    let length = usize::try_from(column.bloom_filter_length()?).ok()?;
+
+    Some(offset..offset + length)
+}
@@ -9,6 +9,7 @@ use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::ParallelStrategy;
 use polars_utils::IdxSize;
 
+use super::bloom_filter::calculate_row_group_bloom_filter_skip_mask;
 use super::row_group_data_fetch::RowGroupDataFetcher;
 use super::row_group_decode::RowGroupDecoder;
 use super::{AsyncTaskData, ParquetReadImpl};
@@ -62,6 +63,7 @@ impl ParquetReadImpl {
         let metadata = self.metadata.clone();
         let normalized_pre_slice = self.normalized_pre_slice;
         let byte_source = self.byte_source.clone();
+        let io_metrics = self.io_metrics.clone();
 
         // Prefetch loop (spawns prefetches on the tokio scheduler).
 
@@ -153,7 +155,20 @@ impl ParquetReadImpl {
                 predicate.as_ref(),
                 &metadata,
                 projected_arrow_fields.clone(),
-                row_index,
+                row_index.clone(),
+                verbose,
+            )
+            .await?;
+
+            let row_group_mask = calculate_row_group_bloom_filter_skip_mask(
+                row_group_slice.clone(),
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                &projected_arrow_fields,
+                &byte_source,
+                row_group_mask,
+                &io_metrics,
                 verbose,
             )
             .await?;
@@ -162,6 +177,8 @@ impl ParquetReadImpl {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
                 predicate,
+                use_statistics,
+                row_index,
                 slice_range,
                 memory_prefetch_func,
                 metadata,
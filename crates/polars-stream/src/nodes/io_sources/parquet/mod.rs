@@ -33,9 +33,11 @@ use crate::nodes::io_sources::parquet::projection::{
 use crate::nodes::{TaskPriority, io_sources};
 use crate::utils::tokio_handle_ext;
 
+mod bloom_filter;
 pub mod builder;
 pub mod init;
 mod metadata_utils;
+mod page_index;
 mod projection;
 mod row_group_data_fetch;
 mod row_group_decode;
@@ -122,9 +124,12 @@ impl FileReader for ParquetFileReader {
                 byte_source = Arc::new(DynByteSource::Buffer(BufferByteSource(full_bytes)));
             }
 
-            Arc::new(polars_parquet::parquet::read::deserialize_metadata(
-                metadata_bytes,
-            )?)
+            Arc::new(
+                polars_parquet::parquet::read::deserialize_metadata_with_decryption(
+                    metadata_bytes,
+                    self.config.decryption.as_ref(),
+                )?,
+            )
         };
 
         let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
@@ -342,6 +347,7 @@ impl FileReader for ParquetFileReader {
                 target_values_per_thread,
                 last_morsel_pipelines,
             },
+            io_metrics: self.io_metrics.clone(),
             verbose,
             memory_prefetch_func,
             row_index,
@@ -435,6 +441,7 @@ struct ParquetReadImpl {
     metadata: Arc<FileMetadata>,
     // Run-time vars
     config: Config,
+    io_metrics: OptIOMetrics,
     verbose: bool,
     memory_prefetch_func: fn(&[u8]) -> (),
     row_index: Option<RowIndex>,
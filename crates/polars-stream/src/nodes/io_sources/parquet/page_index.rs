@@ -0,0 +1,406 @@
+use std::ops::Range;
+
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+use polars_io::RowIndex;
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::read::PageMetaData;
+use polars_parquet::parquet::read::indexes::{
+    PageLocation, deserialize_column_index, deserialize_offset_index,
+};
+use polars_parquet::read::statistics::deserialize_column_index as deserialize_page_statistics;
+use polars_parquet::read::{ColumnChunkMetadata, RowGroupMetadata};
+
+use super::row_group_data_fetch::FetchedBytes;
+use super::statistics::StatisticsColumns;
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// The rows of a row group that have to be read, as determined from the page index.
+pub(super) struct PageSelection {
+    /// Mask over all rows of the row group.
+    pub(super) rows: Bitmap,
+    /// The selected pages of the projected columns, keyed by parquet leaf column index.
+    columns: PlHashMap<usize, ColumnPageSelection>,
+}
+
+/// The data pages of a column chunk that contain at least one selected row.
+struct ColumnPageSelection {
+    /// Byte ranges of the dictionary page (if any) and of the selected data pages. Adjacent
+    /// pages are merged into a single range.
+    byte_ranges: Vec<Range<usize>>,
+    /// Row ranges of the selected data pages, relative to the start of the row group.
+    row_ranges: Vec<Range<usize>>,
+}
+
+impl PageSelection {
+    /// The byte ranges that have to be fetched to decode the selected pages.
+    pub(super) fn byte_ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.columns
+            .values()
+            .flat_map(|column| column.byte_ranges.iter().cloned())
+    }
+
+    /// Returns the selected pages of the column chunk `col_md`, and the mask of the selected
+    /// rows within those pages.
+    ///
+    /// Returns `None` if no page of the column chunk is selected.
+    pub(super) fn column_chunk(
+        &self,
+        leaf_idx: usize,
+        col_md: &ColumnChunkMetadata,
+        fetched_bytes: &FetchedBytes,
+    ) -> Option<(PageMetaData, Buffer<u8>, Bitmap)> {
+        let column = self.columns.get(&leaf_idx).unwrap();
+
+        if column.row_ranges.is_empty() {
+            return None;
+        }
+
+        let buffer = match column.byte_ranges.as_slice() {
+            [range] => fetched_bytes.get_range(range.clone()),
+            ranges => {
+                let mut buffer = Vec::with_capacity(ranges.iter().map(|r| r.len()).sum());
+                for range in ranges {
+                    buffer.extend_from_slice(&fetched_bytes.get_range(range.clone()));
+                }
+                Buffer::from(buffer)
+            },
+        };
+
+        let num_values = column.row_ranges.iter().map(|r| r.len()).sum::<usize>();
+        let mut mask = MutableBitmap::with_capacity(num_values);
+        for range in column.row_ranges.iter() {
+            mask.extend_from_bitmap(&self.rows.clone().sliced(range.start, range.len()));
+        }
+
+        let page_meta = PageMetaData::new(
+            col_md.byte_range().start,
+            num_values as i64,
+            col_md.compression(),
+            col_md.descriptor().descriptor.clone(),
+        );
+
+        Some((page_meta, buffer, mask.freeze()))
+    }
+}
+
+/// Uses the page index of a row group to select the data pages that can contain rows that
+/// match the predicate and fall within the slice.
+///
+/// Returns `None` if the page index cannot be used (e.g. it was not written or a projected
+/// column is nested) or if every page must be read.
+///
+/// `predicate` should only be passed if statistics may be used. The offset of `row_index` must
+/// be the offset of the first row of the row group.
+pub(super) async fn calculate_page_selection(
+    row_group_metadata: &RowGroupMetadata,
+    projection: &[ArrowFieldProjection],
+    predicate: Option<&ScanIOPredicate>,
+    slice: Option<(usize, usize)>,
+    row_index: Option<&RowIndex>,
+    byte_source: &DynByteSource,
+) -> PolarsResult<Option<PageSelection>> {
+    let num_rows = row_group_metadata.num_rows();
+    let predicate = predicate.filter(|p| p.skip_batch_predicate.is_some());
+    let slice = slice.filter(|&(offset, len)| offset > 0 || len < num_rows);
+
+    if num_rows == 0 || (predicate.is_none() && slice.is_none()) {
+        return Ok(None);
+    }
+
+    let parquet_columns = row_group_metadata.parquet_columns();
+
+    // The leaf column of every projected column that is present in the row group.
+    let mut leaves = Vec::with_capacity(projection.len());
+
+    for p in projection.iter() {
+        let arrow_field = p.arrow_field();
+
+        // This can be None in the allow_missing_columns case.
+        let Some(idxs) = row_group_metadata.columns_idxs_under_root_iter(&arrow_field.name) else {
+            continue;
+        };
+
+        // The pages of nested columns do not start at row boundaries.
+        let &[leaf_idx] = idxs else {
+            return Ok(None);
+        };
+        if arrow_field.dtype().is_nested() {
+            return Ok(None);
+        }
+
+        let col_md = &parquet_columns[leaf_idx];
+        // The page indexes of encrypted columns are encrypted as well.
+        if col_md.is_encrypted() {
+            return Ok(None);
+        }
+        let Some(offset_index_range) =
+            index_range(col_md.offset_index_offset(), col_md.offset_index_length())
+        else {
+            return Ok(None);
+        };
+
+        let column_index_range = predicate
+            .filter(|predicate| predicate.live_columns.contains(p.output_name()))
+            .and_then(|_| index_range(col_md.column_index_offset(), col_md.column_index_length()));
+
+        leaves.push((p, leaf_idx, offset_index_range, column_index_range));
+    }
+
+    if leaves.is_empty() || (slice.is_none() && leaves.iter().all(|(_, _, _, ci)| ci.is_none())) {
+        return Ok(None);
+    }
+
+    let mut ranges = leaves
+        .iter()
+        .flat_map(|(_, _, oi, ci)| [Some(oi.clone()), ci.clone()])
+        .flatten()
+        .collect::<Vec<_>>();
+    let index_bytes = byte_source.get_ranges(&mut ranges).await?;
+
+    let mut page_locations = Vec::with_capacity(leaves.len());
+    let mut page_row_ranges = Vec::with_capacity(leaves.len());
+
+    for (_, _, offset_index_range, _) in leaves.iter() {
+        let offset_index = deserialize_offset_index(&index_bytes[&offset_index_range.start])?;
+
+        let Some(row_ranges) = row_ranges(&offset_index.page_locations, num_rows) else {
+            return Ok(None);
+        };
+
+        page_locations.push(offset_index.page_locations);
+        page_row_ranges.push(row_ranges);
+    }
+
+    let mut rows = match slice {
+        Some((offset, len)) => {
+            let offset = offset.min(num_rows);
+            let len = len.min(num_rows - offset);
+
+            let mut rows = MutableBitmap::with_capacity(num_rows);
+            rows.extend_constant(offset, false);
+            rows.extend_constant(len, true);
+            rows.extend_constant(num_rows - offset - len, false);
+            rows.freeze()
+        },
+        None => Bitmap::new_with_value(true, num_rows),
+    };
+
+    if let Some(predicate) = predicate {
+        let sbp = predicate.skip_batch_predicate.as_ref().unwrap();
+
+        for (i, (p, leaf_idx, _, column_index_range)) in leaves.iter().enumerate() {
+            let Some(column_index_range) = column_index_range else {
+                continue;
+            };
+
+            let column_index = deserialize_column_index(&index_bytes[&column_index_range.start])?;
+            let page_num_rows = page_row_ranges[i]
+                .iter()
+                .map(|r| r.len())
+                .collect::<Vec<_>>();
+
+            let Some(statistics) = deserialize_page_statistics(
+                p.arrow_field(),
+                &parquet_columns[*leaf_idx]
+                    .descriptor()
+                    .descriptor
+                    .primitive_type,
+                &column_index,
+                &page_num_rows,
+            )?
+            else {
+                continue;
+            };
+
+            let statistics = StatisticsColumns::from_arrow_statistics(statistics, p.arrow_field())?;
+            let statistics_df = build_page_statistics_df(
+                projection,
+                predicate,
+                (p.output_name(), statistics),
+                &page_row_ranges[i],
+                row_index,
+            )?;
+
+            let skip_page_mask = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+            let mut page_rows_mask = MutableBitmap::with_capacity(num_rows);
+            for (page_rows, skip) in page_row_ranges[i].iter().zip(skip_page_mask.iter()) {
+                page_rows_mask.extend_constant(page_rows.len(), !skip);
+            }
+
+            rows = &rows & &page_rows_mask.freeze();
+        }
+    }
+
+    let mut columns = PlHashMap::with_capacity(leaves.len());
+    let mut skips_pages = false;
+
+    for (i, (_, leaf_idx, _, _)) in leaves.iter().enumerate() {
+        let col_md = &parquet_columns[*leaf_idx];
+        let locations = &page_locations[i];
+
+        let mut byte_ranges: Vec<Range<usize>> = Vec::new();
+        let mut row_ranges = Vec::new();
+
+        let dictionary_range = col_md.byte_range().start as usize..locations[0].offset as usize;
+        if !dictionary_range.is_empty() {
+            byte_ranges.push(dictionary_range);
+        }
+
+        for (location, page_rows) in locations.iter().zip(page_row_ranges[i].iter()) {
+            if rows
+                .clone()
+                .sliced(page_rows.start, page_rows.len())
+                .set_bits()
+                == 0
+            {
+                skips_pages = true;
+                continue;
+            }
+
+            let start = location.offset as usize;
+            let end = start + location.compressed_page_size as usize;
+
+            match byte_ranges.last_mut() {
+                Some(last) if last.end == start => last.end = end,
+                _ => byte_ranges.push(start..end),
+            }
+            row_ranges.push(page_rows.clone());
+        }
+
+        columns.insert(
+            *leaf_idx,
+            ColumnPageSelection {
+                byte_ranges,
+                row_ranges,
+            },
+        );
+    }
+
+    if !skips_pages {
+        return Ok(None);
+    }
+
+    Ok(Some(PageSelection { rows, columns }))
+}
+
+/// Builds the statistics of every page of the column `column_name`, in the layout expected by
+/// the skip batch predicate. The statistics of other columns are unknown.
+fn build_page_statistics_df(
+    projection: &[ArrowFieldProjection],
+    predicate: &ScanIOPredicate,
+    column_statistics: (&PlSmallStr, StatisticsColumns),
+    page_row_ranges: &[Range<usize>],
+    row_index: Option<&RowIndex>,
+) -> PolarsResult<DataFrame> {
+    let num_pages = page_row_ranges.len();
+    let (column_name, statistics) = column_statistics;
+    let mut statistics = Some(statistics);
+    let mut columns = Vec::with_capacity(1 + predicate.live_columns.len() * 3);
+
+    let lengths: Vec<IdxSize> = page_row_ranges.iter().map(|r| r.len() as IdxSize).collect();
+    columns.push(Column::new("len".into(), lengths));
+
+    for p in projection.iter() {
+        let c = p.output_name();
+
+        if !predicate.live_columns.contains(c) {
+            continue;
+        }
+
+        let mut column_statistics = match statistics.take_if(|_| c == column_name) {
+            Some(statistics) => statistics,
+            None => {
+                StatisticsColumns::new_null(&DataType::from_arrow_field(p.arrow_field()), num_pages)
+            },
+        };
+
+        column_statistics.min = p.apply_transform(column_statistics.min)?;
+        column_statistics.max = p.apply_transform(column_statistics.max)?;
+
+        let column_statistics = column_statistics.with_base_column_name(c);
+
+        columns.extend([
+            column_statistics.min,
+            column_statistics.max,
+            column_statistics.null_count,
+        ]);
+    }
+
+    if let Some(row_index) = row_index {
+        let statistics = build_row_index_page_statistics(row_index, page_row_ranges)
+            .with_base_column_name(&row_index.name);
+
+        columns.extend([statistics.min, statistics.max, statistics.null_count]);
+    }
+
+    DataFrame::new(num_pages, columns)
+}
+
+fn build_row_index_page_statistics(
+    row_index: &RowIndex,
+    page_row_ranges: &[Range<usize>],
+) -> StatisticsColumns {
+    let num_pages = page_row_ranges.len();
+
+    let page_row_index = |row: usize| {
+        IdxSize::try_from(row)
+            .ok()
+            .and_then(|row| row_index.offset.checked_add(row))
+    };
+
+    let min_value: PrimitiveArray<IdxSize> = page_row_ranges
+        .iter()
+        .map(|r| page_row_index(r.start))
+        .collect();
+    let max_value: PrimitiveArray<IdxSize> = page_row_ranges
+        .iter()
+        .map(|r| page_row_index(r.end - 1))
+        .collect();
+    let null_count = PrimitiveArray::<IdxSize>::full(num_pages, 0, ArrowDataType::IDX_DTYPE);
+
+    StatisticsColumns {
+        min: Series::from_array(PlSmallStr::EMPTY, min_value).into_column(),
+        max: Series::from_array(PlSmallStr::EMPTY, max_value).into_column(),
+        null_count: Series::from_array(PlSmallStr::EMPTY, null_count).into_column(),
+    }
+}
+
+/// The rows (relative to the row group) of every page in `locations`.
+///
+/// Returns `None` if the locations are inconsistent with the number of rows of the row group.
+fn row_ranges(locations: &[PageLocation], num_rows: usize) -> Option<Vec<Range<usize>>> {
+    let first_rows = locations
+        .iter()
+        .map(|l| usize::try_from(l.first_row_index).ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    if first_rows.first() != Some(&0)
+        || locations
+            .iter()
+            .any(|l| l.offset < 0 || l.compressed_page_size < 0)
+    {
+        return None;
+    }
+
+    let ends = first_rows.iter().skip(1).copied().chain([num_rows]);
+    let ranges = first_rows
+        .iter()
+        .copied()
+        .zip(ends)
+        .map(|(start, end)| start..end)
+        .collect::<Vec<_>>();
+
+    ranges.iter().all(|r| r.start < r.end).then_some(ranges)
+}
+
+fn index_range(offset: Option<i64>, length: Option<i32>) -> Option<Range<usize>> {
+    let offset = usize::try_from(offset?).ok()?;
+    let length = usize::try_from(length?).ok()?;
+
+    (length > 0).then_some(offset..offset + length)
+}
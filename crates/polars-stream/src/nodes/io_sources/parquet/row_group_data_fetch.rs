@@ -7,12 +7,15 @@ use polars_core::runtime::ASYNC;
 use polars_core::series::IsSorted;
 use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_error::PolarsResult;
+use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, create_sorting_map};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
 use polars_parquet::read::RowGroupMetadata;
+use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
+use super::page_index::{PageSelection, calculate_page_selection};
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::utils::tokio_handle_ext;
 
@@ -23,13 +26,16 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: Vec<(usize, IsSorted)>,
+    /// Set if only some pages of the row group were fetched. This also accounts for the slice.
+    pub(super) page_selection: Option<PageSelection>,
 }
 
 pub(super) struct RowGroupDataFetcher {
     pub(super) projection: Arc<[ArrowFieldProjection]>,
     pub(super) is_full_projection: bool,
-    #[allow(unused)] // TODO: Fix!
     pub(super) predicate: Option<ScanIOPredicate>,
+    pub(super) use_statistics: bool,
+    pub(super) row_index: Option<RowIndex>,
     pub(super) slice_range: Option<Range<usize>>,
     pub(super) memory_prefetch_func: fn(&[u8]) -> (),
     pub(super) metadata: Arc<FileMetadata>,
@@ -123,9 +129,27 @@ impl RowGroupDataFetcher {
             let projection = self.projection.clone();
             let is_full_projection = self.is_full_projection;
             let memory_prefetch_func = self.memory_prefetch_func;
+            let predicate = self.predicate.clone().filter(|_| self.use_statistics);
+            let row_index = self.row_index.clone().map(|mut ri| {
+                ri.offset = ri
+                    .offset
+                    .saturating_add(IdxSize::try_from(current_row_offset).unwrap_or(IdxSize::MAX));
+                ri
+            });
 
             let handle = ASYNC.spawn(async move {
                 let row_group_metadata = &metadata.row_groups[idx];
+
+                let page_selection = calculate_page_selection(
+                    row_group_metadata,
+                    &projection,
+                    predicate.as_ref(),
+                    slice,
+                    row_index.as_ref(),
+                    current_byte_source.as_ref(),
+                )
+                .await?;
+
                 let fetched_bytes =
                     if let DynByteSource::Buffer(mem_slice) = current_byte_source.as_ref() {
                         // Skip byte range calculation for `no_prefetch`.
@@ -157,6 +181,16 @@ impl RowGroupDataFetcher {
                             offset: 0,
                             buffer: mem_slice,
                         }
+                    } else if let Some(page_selection) = page_selection.as_ref() {
+                        let mut ranges = page_selection.byte_ranges().collect::<Vec<_>>();
+
+                        let n_ranges = ranges.len();
+
+                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+
+                        assert_eq!(bytes_map.len(), n_ranges);
+
+                        FetchedBytes::BytesMap(bytes_map)
                     } else if !is_full_projection {
                         let mut ranges = get_row_group_byte_ranges_for_projection(
                             row_group_metadata,
@@ -194,10 +228,11 @@ impl RowGroupDataFetcher {
                 PolarsResult::Ok(RowGroupData {
                     fetched_bytes,
                     row_offset: current_row_offset,
-                    slice,
+                    slice: slice.filter(|_| page_selection.is_none()),
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    page_selection,
                 })
             });
 
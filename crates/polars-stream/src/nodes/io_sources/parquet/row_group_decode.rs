@@ -48,6 +48,7 @@ impl RowGroupDecoder {
 
         if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
+            && row_group_data.page_selection.is_none()
             && !self.predicate_field_indices.is_empty()
         {
             self.row_group_data_to_df_prefiltered(row_group_data).await
@@ -73,22 +74,33 @@ impl RowGroupDecoder {
 
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
-        if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
+        // The rows of pages that were not fetched are filtered out while decoding.
+        let (filter, projection_height) = match &row_group_data.page_selection {
+            Some(page_selection) => (
+                Filter::Mask(page_selection.rows.clone()),
+                page_selection.rows.set_bits(),
+            ),
+            None => (Filter::Range(slice_range.clone()), slice_range.len()),
+        };
+
+        if let Some(mut s) =
+            self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())?
+        {
+            if let Filter::Mask(mask) = &filter {
+                s = s.filter(&BooleanChunked::from_bitmap(
+                    PlSmallStr::EMPTY,
+                    mask.clone(),
+                ))?;
+            }
             out_columns.push(s);
         }
 
         let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
+        self.decode_projected_columns(&mut decoded_cols, &row_group_data, Some(filter))
+            .await?;
 
         drop(row_group_data);
 
-        let projection_height = slice_range.len();
-
         out_columns.extend(decoded_cols);
 
         let df = unsafe { DataFrame::new_unchecked(projection_height, out_columns) };
@@ -251,26 +263,56 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
+    let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
 
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
+    let (arrays, pred_true_mask) = if let Some(page_selection) = &row_group_data.page_selection {
+        // Only the pages containing selected rows were fetched. These are decoded as if they
+        // were the entire column chunk.
+        let col_idxs = row_group_data
+            .row_group_metadata
+            .columns_idxs_under_root_iter(&arrow_field.name)
+            .unwrap();
+        let leaf_idx = col_idxs[0];
+        let col_md = &row_group_data.row_group_metadata.parquet_columns()[leaf_idx];
+
+        let Some((page_meta, chunk, mask)) =
+            page_selection.column_chunk(leaf_idx, col_md, &row_group_data.fetched_bytes)
+        else {
+            return Ok((
+                Column::full_null(
+                    arrow_field.name.clone(),
+                    expected_num_rows,
+                    &DataType::from_arrow_field(arrow_field),
+                ),
+                Bitmap::default(),
+            ));
+        };
 
-    let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
+        polars_io::prelude::_internal::to_deserializer_with_page_meta(
+            vec![(page_meta, chunk)],
+            arrow_field.clone(),
+            Some(Filter::Mask(mask)),
+        )?
+    } else {
+        let columns_to_deserialize = iter
+            .map(|col_md| {
+                let byte_range = col_md.byte_range();
 
-    let (arrays, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
-        filter,
-    )?;
+                (
+                    col_md,
+                    row_group_data
+                        .fetched_bytes
+                        .get_range(byte_range.start as usize..byte_range.end as usize),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        polars_io::prelude::_internal::to_deserializer(
+            columns_to_deserialize,
+            arrow_field.clone(),
+            filter,
+        )?
+    };
 
     if !skip_num_rows_check {
         let num_rows = arrays.iter().map(|array| array.len()).sum::<usize>();
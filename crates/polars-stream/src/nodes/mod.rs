@@ -41,8 +41,11 @@ pub mod rolling_group_by;
 pub mod select;
 pub mod shift;
 pub mod simple_projection;
+pub mod sort;
 pub mod sorted_group_by;
+mod sorted_partitions;
 pub mod sorted_unique;
+pub mod sorted_window;
 pub mod streaming_slice;
 #[cfg(any(
     feature = "dtype-date",
@@ -4,6 +4,7 @@ use polars_core::frame::column::ScalarColumn;
 use polars_core::prelude::Column;
 use polars_core::schema::{Schema, SchemaExt};
 use polars_expr::reduce::GroupedReduction;
+use polars_ooc::{MostRecentSpillContext, memory_manager};
 use polars_utils::itertools::Itertools;
 
 use super::compute_node_prelude::*;
@@ -27,9 +28,13 @@ pub struct ReduceNode {
 impl ReduceNode {
     pub fn new(
         selectors: Vec<Vec<StreamExpr>>,
-        reductions: Vec<Box<dyn GroupedReduction>>,
+        mut reductions: Vec<Box<dyn GroupedReduction>>,
         output_schema: Arc<Schema>,
     ) -> Self {
+        let spill_ctx = MostRecentSpillContext::new("reduce".into());
+        for r in &mut reductions {
+            r.set_spill_context(&spill_ctx);
+        }
         Self {
             state: ReduceState::Sink {
                 selectors,
@@ -80,6 +85,8 @@ impl ReduceNode {
                                 in_column_refs.into_iter().map(|_| unreachable!()).collect(); // Clear lifetimes.
                             in_columns.clear();
                         }
+                        // The reductions may have registered spillable state.
+                        memory_manager().spill().await;
                     }
 
                     PolarsResult::Ok(local_reducers)
@@ -97,6 +104,10 @@ impl ReduceNode {
                     }
                 }
             }
+            // Load the spilled reduction state before it gets finalized.
+            for r in reductions.iter_mut() {
+                r.load_spilled().await;
+            }
 
             Ok(())
         }));
@@ -0,0 +1,577 @@
+//! Out-of-core sort.
+//!
+//! While receiving, each pipeline buffers its morsels (with the sort keys appended as
+//! extra columns) and sorts them into a run once it holds enough data. A run is stored
+//! as a sequence of sorted chunks in [`SpillFrame`]s, so that the memory manager can
+//! spill it to disk. Once the input is exhausted the runs are merged on their
+//! row-encoded keys, first into fewer (longer) runs if there are more than the maximum
+//! merge fan-in of them, and then into the output.
+//!
+//! The run size and the maximum merge fan-in are configured by
+//! `POLARS_OOC_SORT_RUN_SIZE_BYTES` and `POLARS_OOC_SORT_MAX_MERGE_FAN_IN`.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use parking_lot::Mutex;
+use polars_async::primitives::wait_group::WaitGroup;
+use polars_core::prelude::row_encode::_get_rows_encoded;
+use polars_core::prelude::*;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, slice_offsets};
+use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_utils::IdxSize;
+use polars_utils::pl_str::unique_column_name;
+
+use super::compute_node_prelude::*;
+use crate::expression::StreamExpr;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+
+/// A sorted run: consecutive sorted chunks, each of which may be spilled.
+type Run = VecDeque<SpillFrame>;
+
+/// The sort keys, stored as the trailing columns of the frames being sorted.
+struct SortKeys {
+    /// The index of the first key column.
+    offset: usize,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+}
+
+impl SortKeys {
+    fn encode(&self, df: &DataFrame) -> PolarsResult<BinaryArray<i64>> {
+        let rows = _get_rows_encoded(
+            &df.columns()[self.offset..],
+            &self.descending,
+            &self.nulls_last,
+        )?;
+        Ok(rows.into_array())
+    }
+
+    /// Remove the key columns from a frame.
+    fn strip(&self, df: DataFrame) -> DataFrame {
+        let height = df.height();
+        let mut columns = df.into_columns();
+        columns.truncate(self.offset);
+        unsafe { DataFrame::new_unchecked(height, columns) }
+    }
+}
+
+/// Sort a frame into a run, keeping only its first `limit` rows.
+async fn sort_into_run(
+    df: &DataFrame,
+    keys: &SortKeys,
+    limit: Option<usize>,
+    spill_ctx: &MostRecentSpillContext,
+) -> PolarsResult<Run> {
+    let rows = keys.encode(df)?;
+    let mut idx: Vec<IdxSize> = (0..df.height() as IdxSize).collect();
+    idx.sort_by(|&a, &b| rows.value(a as usize).cmp(rows.value(b as usize)));
+    if let Some(limit) = limit {
+        idx.truncate(limit);
+    }
+    let sorted = unsafe { df.take_slice_unchecked_impl(&idx, false) };
+
+    let mut run = Run::new();
+    push_chunks(&mut run, sorted, spill_ctx).await;
+    Ok(run)
+}
+
+/// Append a sorted frame to a run, in chunks of the ideal morsel size.
+async fn push_chunks(run: &mut Run, df: DataFrame, spill_ctx: &MostRecentSpillContext) {
+    let chunk_size = get_ideal_morsel_size().max(1);
+    let mut offset = 0;
+    while offset < df.height() {
+        let chunk = df.slice(offset as i64, chunk_size);
+        offset += chunk.height();
+        run.push_back(SpillFrame::new(chunk, spill_ctx).await);
+    }
+}
+
+/// A cursor over the rows of a run.
+struct RunCursor {
+    run: Run,
+    /// The current (unspilled) chunk along with its encoded keys.
+    chunk: Option<(DataFrame, BinaryArray<i64>)>,
+    /// The position of the next row in the current chunk.
+    pos: usize,
+}
+
+impl RunCursor {
+    /// Ensure the current chunk has rows left, loading the next chunk if needed.
+    /// Returns false if the run is exhausted.
+    async fn fill(&mut self, keys: &SortKeys) -> PolarsResult<bool> {
+        while self
+            .chunk
+            .as_ref()
+            .is_none_or(|(df, _)| self.pos >= df.height())
+        {
+            let Some(sf) = self.run.pop_front() else {
+                self.chunk = None;
+                return Ok(false);
+            };
+            let df = sf.into_df().await;
+            let rows = keys.encode(&df)?;
+            self.chunk = Some((df, rows));
+            self.pos = 0;
+        }
+        Ok(true)
+    }
+}
+
+/// A k-way merge of sorted runs.
+struct RunMerger {
+    cursors: Vec<RunCursor>,
+}
+
+impl RunMerger {
+    fn new(runs: Vec<Run>) -> Self {
+        let cursors = runs
+            .into_iter()
+            .map(|run| RunCursor {
+                run,
+                chunk: None,
+                pos: 0,
+            })
+            .collect();
+        Self { cursors }
+    }
+
+    /// Merge the next batch of rows, returning `None` once all runs are exhausted.
+    ///
+    /// A batch holds all remaining rows up to the smallest of the last keys of the
+    /// current chunks, so that each batch consumes at least one chunk while holding
+    /// at most one chunk of each run.
+    async fn next_batch(&mut self, keys: &SortKeys) -> PolarsResult<Option<DataFrame>> {
+        let mut active = Vec::with_capacity(self.cursors.len());
+        for (i, cursor) in self.cursors.iter_mut().enumerate() {
+            if cursor.fill(keys).await? {
+                active.push(i);
+            }
+        }
+        if active.is_empty() {
+            return Ok(None);
+        }
+
+        let rows_of = |i: usize| &self.cursors[i].chunk.as_ref().unwrap().1;
+        let last_key = |i: usize| {
+            let rows = rows_of(i);
+            rows.value(rows.len() - 1)
+        };
+        let bound_idx = active
+            .iter()
+            .copied()
+            .min_by(|&a, &b| last_key(a).cmp(last_key(b)))
+            .unwrap();
+        let bound = last_key(bound_idx);
+
+        // The rows of each run up to (and including) the bound.
+        let parts: Vec<(usize, usize, usize)> = active
+            .iter()
+            .map(|&i| {
+                let start = self.cursors[i].pos;
+                let rows = rows_of(i);
+                let mut end = rows.len();
+                if i != bound_idx {
+                    let mut lo = start;
+                    while lo < end {
+                        let mid = lo + (end - lo) / 2;
+                        if rows.value(mid) <= bound {
+                            lo = mid + 1;
+                        } else {
+                            end = mid;
+                        }
+                    }
+                }
+                (i, start, end)
+            })
+            .filter(|(_, start, end)| start < end)
+            .collect();
+
+        let dfs = parts
+            .iter()
+            .map(|&(i, start, end)| {
+                let df = &self.cursors[i].chunk.as_ref().unwrap().0;
+                df.slice(start as i64, end - start)
+            })
+            .collect::<Vec<_>>();
+        let df = accumulate_dataframes_vertical_unchecked(dfs);
+        let df = if parts.len() > 1 {
+            // Merge the parts (ties are taken from the earlier runs first).
+            let mut idx = Vec::with_capacity(df.height());
+            let mut heap = BinaryHeap::with_capacity(parts.len());
+            let mut part_offset = 0;
+            for (p, &(i, start, end)) in parts.iter().enumerate() {
+                heap.push(Reverse((rows_of(i).value(start), p, start, part_offset)));
+                part_offset += end - start;
+            }
+            while let Some(Reverse((_, p, row, part_offset))) = heap.pop() {
+                let (i, start, end) = parts[p];
+                idx.push((part_offset + row - start) as IdxSize);
+                if row + 1 < end {
+                    heap.push(Reverse((
+                        rows_of(i).value(row + 1),
+                        p,
+                        row + 1,
+                        part_offset,
+                    )));
+                }
+            }
+            unsafe { df.take_slice_unchecked_impl(&idx, false) }
+        } else {
+            df
+        };
+
+        for (i, _, end) in parts {
+            self.cursors[i].pos = end;
+        }
+        Ok(Some(df))
+    }
+}
+
+/// Merge runs until at most `max_fan_in` of them remain.
+async fn reduce_runs(
+    mut runs: Vec<Run>,
+    keys: &SortKeys,
+    max_fan_in: usize,
+    spill_ctx: &MostRecentSpillContext,
+) -> PolarsResult<Vec<Run>> {
+    while runs.len() > max_fan_in {
+        let mut merged_runs = Vec::with_capacity(runs.len().div_ceil(max_fan_in));
+        let mut runs_iter = runs.into_iter().peekable();
+        while runs_iter.peek().is_some() {
+            let group = runs_iter.by_ref().take(max_fan_in).collect();
+            let mut merger = RunMerger::new(group);
+            let mut run = Run::new();
+            while let Some(df) = merger.next_batch(keys).await? {
+                push_chunks(&mut run, df, spill_ctx).await;
+            }
+            merged_runs.push(run);
+        }
+        runs = merged_runs;
+    }
+    Ok(runs)
+}
+
+/// The merge of the sorted runs into the output.
+struct SortSource {
+    /// The runs to merge, along with the (unsorted) leftovers of the pipelines,
+    /// until the merge is started.
+    runs: Vec<Run>,
+    leftovers: Vec<DataFrame>,
+    merger: Option<RunMerger>,
+    /// Merged rows not yet sent.
+    buffer: DataFrame,
+    /// The number of rows to skip, and the number of rows left to send.
+    skip: usize,
+    len: usize,
+    seq: MorselSeq,
+}
+
+impl SortSource {
+    /// Sort the leftovers, reduce the runs and set up their final merge.
+    async fn start_merge(
+        &mut self,
+        keys: &SortKeys,
+        run_limit: Option<usize>,
+        max_fan_in: usize,
+        spill_ctx: &MostRecentSpillContext,
+    ) -> PolarsResult<()> {
+        if !self.leftovers.is_empty() {
+            let df = accumulate_dataframes_vertical_unchecked(std::mem::take(&mut self.leftovers));
+            self.runs
+                .push(sort_into_run(&df, keys, run_limit, spill_ctx).await?);
+        }
+        let runs = reduce_runs(std::mem::take(&mut self.runs), keys, max_fan_in, spill_ctx).await?;
+        self.merger = Some(RunMerger::new(runs));
+        Ok(())
+    }
+}
+
+enum SortState {
+    Sink {
+        /// The buffered morsels of each pipeline, along with their estimated size.
+        buffers: Vec<(Vec<DataFrame>, usize)>,
+        runs: Mutex<Vec<Run>>,
+    },
+    Source(SortSource),
+    Done,
+}
+
+pub struct SortNode {
+    key_selectors: Vec<StreamExpr>,
+    /// The (hidden) names of the evaluated key columns.
+    key_names: Vec<PlSmallStr>,
+    keys: SortKeys,
+    /// The names of the morsel sequence and row index key columns that make the sort
+    /// stable, if it should maintain the order of equal rows.
+    order_keys: Option<(PlSmallStr, PlSmallStr)>,
+    slice: Option<(i64, usize)>,
+    limit: Option<usize>,
+    /// The (estimated) number of bytes each pipeline buffers before sorting them into
+    /// a run.
+    run_size_bytes: usize,
+    /// The maximum number of runs that are merged at once.
+    max_merge_fan_in: usize,
+    state: SortState,
+    spill_ctx: Arc<MostRecentSpillContext>,
+}
+
+impl SortNode {
+    pub fn new(
+        input_schema: Arc<Schema>,
+        key_selectors: Vec<StreamExpr>,
+        slice: Option<(i64, usize)>,
+        sort_options: SortMultipleOptions,
+    ) -> Self {
+        let num_keys = key_selectors.len();
+        let broadcast = |v: Vec<bool>| {
+            if v.len() == 1 && num_keys > 1 {
+                vec![v[0]; num_keys]
+            } else {
+                v
+            }
+        };
+        let mut descending = broadcast(sort_options.descending);
+        let mut nulls_last = broadcast(sort_options.nulls_last);
+        let order_keys = sort_options.maintain_order.then(|| {
+            descending.extend([false, false]);
+            nulls_last.extend([false, false]);
+            (unique_column_name(), unique_column_name())
+        });
+
+        Self {
+            keys: SortKeys {
+                offset: input_schema.len(),
+                descending,
+                nulls_last,
+            },
+            key_names: (0..num_keys).map(|_| unique_column_name()).collect(),
+            key_selectors,
+            order_keys,
+            slice,
+            limit: sort_options.limit.map(|l| l as usize),
+            run_size_bytes: polars_config::config().ooc_sort_run_size_bytes() as usize,
+            max_merge_fan_in: polars_config::config().ooc_sort_max_merge_fan_in() as usize,
+            state: SortState::Sink {
+                buffers: Vec::new(),
+                runs: Mutex::default(),
+            },
+            spill_ctx: MostRecentSpillContext::new("sort".into()),
+        }
+    }
+
+    /// The number of rows each run has to keep.
+    fn run_limit(&self) -> Option<usize> {
+        match self.slice {
+            Some((offset, len)) if offset >= 0 => {
+                let end = (offset as usize).saturating_add(len);
+                Some(self.limit.map_or(end, |l| l.min(end)))
+            },
+            _ => self.limit,
+        }
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done {
+            self.state = SortState::Done;
+        }
+
+        // If the input is done, transition to being a source.
+        if let SortState::Sink { buffers, runs } = &mut self.state {
+            if buffers.is_empty() {
+                buffers.resize_with(state.num_pipelines, Default::default);
+            }
+            if recv[0] == PortState::Done {
+                let runs = std::mem::take(runs.get_mut());
+                let leftovers: Vec<DataFrame> = buffers
+                    .drain(..)
+                    .flat_map(|(dfs, _)| dfs)
+                    .filter(|df| df.height() > 0)
+                    .collect();
+                let total_len = runs
+                    .iter()
+                    .flatten()
+                    .map(|sf| sf.height())
+                    .chain(leftovers.iter().map(|df| df.height()))
+                    .sum::<usize>();
+                let total_len = self.limit.map_or(total_len, |l| l.min(total_len));
+                let (skip, len) = match self.slice {
+                    Some((offset, len)) => slice_offsets(offset, len, total_len),
+                    None => (0, total_len),
+                };
+                self.state = SortState::Source(SortSource {
+                    runs,
+                    leftovers,
+                    merger: None,
+                    buffer: DataFrame::empty(),
+                    skip,
+                    len,
+                    seq: MorselSeq::default(),
+                });
+            }
+        }
+
+        match &mut self.state {
+            SortState::Sink { .. } => {
+                recv[0] = PortState::Ready;
+                send[0] = PortState::Blocked;
+            },
+            SortState::Source(src) => {
+                recv[0] = PortState::Done;
+                send[0] = if src.len > 0 {
+                    PortState::Ready
+                } else {
+                    PortState::Done
+                };
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink { .. })
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let run_limit = self.run_limit();
+        let run_size_bytes = self.run_size_bytes;
+        let max_merge_fan_in = self.max_merge_fan_in;
+        match &mut self.state {
+            SortState::Sink { buffers, runs } => {
+                assert!(send_ports[0].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let runs = &*runs;
+                let key_selectors = &self.key_selectors;
+                let key_names = &self.key_names;
+                let keys = &self.keys;
+                let order_keys = &self.order_keys;
+                let spill_ctx = &*self.spill_ctx;
+                for (mut recv, (buffer, buffer_size)) in receivers.into_iter().zip(buffers) {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(morsel) = recv.recv().await {
+                            let seq = morsel.seq();
+                            let df = morsel.into_df();
+                            let height = df.height();
+                            let mut keys_columns = Vec::with_capacity(keys.descending.len());
+                            for (selector, name) in key_selectors.iter().zip(key_names) {
+                                let key = selector
+                                    .evaluate_preserve_len_broadcast(
+                                        &df,
+                                        &state.in_memory_exec_state,
+                                    )
+                                    .await?;
+                                keys_columns.push(key.with_name(name.clone()));
+                            }
+                            if let Some((seq_name, idx_name)) = order_keys {
+                                keys_columns.push(Column::new_scalar(
+                                    seq_name.clone(),
+                                    Scalar::from(seq.to_u64()),
+                                    height,
+                                ));
+                                keys_columns.push(
+                                    IdxCa::from_vec(
+                                        idx_name.clone(),
+                                        (0..height as IdxSize).collect(),
+                                    )
+                                    .into_column(),
+                                );
+                            }
+                            let mut columns = df.into_columns();
+                            columns.extend(keys_columns);
+                            let df = unsafe { DataFrame::new_unchecked(height, columns) };
+
+                            *buffer_size += df.estimated_size();
+                            buffer.push(df);
+                            if *buffer_size >= run_size_bytes {
+                                let df = accumulate_dataframes_vertical_unchecked(std::mem::take(
+                                    buffer,
+                                ));
+                                *buffer_size = 0;
+                                let run = sort_into_run(&df, keys, run_limit, spill_ctx).await?;
+                                runs.lock().push(run);
+                            }
+                        }
+                        Ok(())
+                    }));
+                }
+            },
+
+            SortState::Source(src) => {
+                assert!(recv_ports[0].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+                let keys = &self.keys;
+                let spill_ctx = &*self.spill_ctx;
+                join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                    if src.merger.is_none() {
+                        src.start_merge(keys, run_limit, max_merge_fan_in, spill_ctx)
+                            .await?;
+                    }
+
+                    let morsel_size = get_ideal_morsel_size().max(1);
+                    let source_token = SourceToken::new();
+                    let wait_group = WaitGroup::default();
+                    while src.len > 0 && !source_token.stop_requested() {
+                        if src.buffer.height() == 0 {
+                            match src.merger.as_mut().unwrap().next_batch(keys).await? {
+                                Some(df) => src.buffer = df,
+                                None => {
+                                    src.len = 0;
+                                    break;
+                                },
+                            }
+                        }
+                        if src.skip > 0 {
+                            let n = src.skip.min(src.buffer.height());
+                            src.buffer = src.buffer.slice(n as i64, usize::MAX);
+                            src.skip -= n;
+                            continue;
+                        }
+
+                        let height = src.buffer.height().min(morsel_size).min(src.len);
+                        let df = keys.strip(src.buffer.slice(0, height));
+                        src.buffer = src.buffer.slice(height as i64, usize::MAX);
+                        src.len -= height;
+
+                        let mut morsel = Morsel::new(df, src.seq, source_token.clone());
+                        morsel.set_consume_token(wait_group.token());
+                        src.seq = src.seq.successor();
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+                        wait_group.wait().await;
+                    }
+                    Ok(())
+                }));
+            },
+
+            SortState::Done => unreachable!(),
+        }
+    }
+}
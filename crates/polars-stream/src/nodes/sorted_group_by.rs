@@ -5,15 +5,14 @@ use polars_async::primitives::distributor_channel::distributor_channel;
 use polars_async::primitives::wait_group::WaitGroup;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::GroupsType;
-use polars_core::schema::Schema;
-use polars_core::series::IsSorted;
 use polars_error::{PolarsError, PolarsResult};
 use polars_expr::state::ExecutionState;
-use polars_ops::series::{SearchSortedSide, rle_lengths, search_sorted};
+use polars_ops::series::rle_lengths;
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::ComputeNode;
+use super::sorted_partitions::SortedPartitionBuffer;
 use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
 use crate::execute::StreamingExecutionState;
 use crate::expression::StreamExpr;
@@ -22,7 +21,7 @@ use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::pipe::{RecvPort, SendPort};
 
 pub struct SortedGroupBy {
-    buf_df: DataFrame,
+    buffer: SortedPartitionBuffer,
 
     seq: MorselSeq,
 
@@ -36,11 +35,9 @@ impl SortedGroupBy {
         key: PlSmallStr,
         aggs: Arc<[(PlSmallStr, StreamExpr)]>,
         slice: Option<(IdxSize, IdxSize)>,
-        input_schema: Arc<Schema>,
     ) -> Self {
-        let buf_df = DataFrame::empty_with_arc_schema(input_schema.clone());
         Self {
-            buf_df,
+            buffer: SortedPartitionBuffer::new(key.clone(), "sorted-group-by".into()),
             seq: MorselSeq::default(),
             key,
             aggs,
@@ -119,21 +116,8 @@ impl ComputeNode for SortedGroupBy {
         if self.slice.is_some_and(|(_, l)| l == 0) {
             recv[0] = PortState::Done;
             send[0] = PortState::Done;
-            std::mem::take(&mut self.buf_df);
-        }
-
-        if send[0] == PortState::Done {
-            recv[0] = PortState::Done;
-            std::mem::take(&mut self.buf_df);
-        } else if recv[0] == PortState::Done {
-            if self.buf_df.height() == 0 {
-                send[0] = PortState::Done;
-            } else {
-                send[0] = PortState::Ready;
-            }
-        } else {
-            recv.swap_with_slice(send);
         }
+        self.buffer.update_state(recv, send);
 
         Ok(())
     }
@@ -150,7 +134,7 @@ impl ComputeNode for SortedGroupBy {
 
         let Some(recv) = recv_ports[0].take() else {
             // We no longer have to receive data. Finalize and send all remaining data.
-            assert!(self.buf_df.height() > 0);
+            assert!(!self.buffer.is_empty());
             assert!(self.slice.is_none_or(|(_, l)| l > 0));
             let mut send = send_ports[0].take().unwrap().serial();
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
@@ -159,7 +143,7 @@ impl ComputeNode for SortedGroupBy {
                     &self.aggs,
                     &state.in_memory_exec_state,
                     &mut Vec::new(),
-                    std::mem::take(&mut self.buf_df),
+                    self.buffer.take().await,
                     self.slice.unwrap_or((0, IdxSize::MAX)),
                 )
                 .await?;
@@ -214,6 +198,7 @@ impl ComputeNode for SortedGroupBy {
         //
         // This finds boundaries to distribute to worker threads over.
         join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            let mut idxs = Vec::<IdxSize>::new();
             while let Ok(morsel) = recv.recv().await
                 && self.slice.is_none_or(|(_, l)| l > 0)
             {
@@ -221,44 +206,15 @@ impl ComputeNode for SortedGroupBy {
                 self.seq = seq;
                 drop(wait_token);
 
-                if df.height() == 0 {
-                    continue;
-                }
-
-                self.buf_df.vstack_mut_owned(df).unwrap();
-
-                let buf_key_column = self.buf_df.column(&self.key).unwrap();
-                let fst = buf_key_column.get(0).unwrap();
-                let lst = buf_key_column.get(buf_key_column.len() - 1).unwrap();
-
-                if fst == lst {
+                let Some((df, num_uniq_values)) = self.buffer.push(df, &mut idxs).await? else {
                     continue;
-                }
-
-                let mut buf_key_column = buf_key_column.as_materialized_series().clone();
-                buf_key_column.set_sorted_flag(IsSorted::Ascending);
-
-                let descending = fst > lst;
-                let num_flushable = search_sorted(
-                    &buf_key_column,
-                    &buf_key_column.tail(Some(1)),
-                    SearchSortedSide::Left,
-                    descending,
-                )
-                .unwrap();
-                let num_flushable = unsafe { num_flushable.get_unchecked(0) }.unwrap();
-
-                let df;
-                (df, self.buf_df) = self.buf_df.split_at(num_flushable as i64);
+                };
 
                 let mut windows_offset = 0;
                 let mut windows_length = IdxSize::MAX;
 
                 if let Some((offset, length)) = self.slice.as_mut() {
-                    let buf_key_column = buf_key_column.head(Some(num_flushable as usize));
-
-                    // Since `buf_key_column` is flagged as sorted, this is simply a linear scan.
-                    let num_uniq_values = buf_key_column.n_unique()? as IdxSize;
+                    let num_uniq_values = num_uniq_values as IdxSize;
 
                     // Fast path: Slice allows skipping the entire morsel.
                     if *offset >= num_uniq_values {
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use polars_core::frame::DataFrame;
+use polars_core::prelude::AnyValue;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::PolarsResult;
+use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ops::series::rle_lengths;
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::graph::PortState;
+
+/// Buffers input that is sorted by a key column, splitting off its partitions (runs of
+/// equal keys) once they are complete.
+///
+/// The rows of the last, possibly incomplete, partition are kept as spillable frames,
+/// so that a partition spanning many morsels need not stay in memory until it is
+/// complete. It is loaded in full once complete, so memory usage is bounded by the
+/// size of the largest partition.
+pub struct SortedPartitionBuffer {
+    key: PlSmallStr,
+    /// The frames holding the rows of the last partition.
+    frames: Vec<SpillFrame>,
+    /// The key of the last partition.
+    last_key: Option<AnyValue<'static>>,
+    spill_ctx: Arc<MostRecentSpillContext>,
+}
+
+impl SortedPartitionBuffer {
+    pub fn new(key: PlSmallStr, name: PlSmallStr) -> Self {
+        Self {
+            key,
+            frames: Vec::new(),
+            last_key: None,
+            spill_ctx: MostRecentSpillContext::new(name),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.last_key = None;
+    }
+
+    /// Update the port states of a node that passes its input through this buffer.
+    pub fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) {
+        if send[0] == PortState::Done {
+            recv[0] = PortState::Done;
+            self.clear();
+        } else if recv[0] == PortState::Done {
+            send[0] = if self.is_empty() {
+                PortState::Done
+            } else {
+                PortState::Ready
+            };
+        } else {
+            recv.swap_with_slice(send);
+        }
+    }
+
+    /// Buffer a frame, returning the partitions it completes along with their count.
+    pub async fn push(
+        &mut self,
+        df: DataFrame,
+        idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<Option<(DataFrame, usize)>> {
+        if df.height() == 0 {
+            return Ok(None);
+        }
+
+        let key_column = df.column(&self.key).unwrap();
+        let fst = key_column.get(0).unwrap().into_static();
+        let lst = key_column.get(key_column.len() - 1).unwrap().into_static();
+        if fst == lst && self.last_key.as_ref().is_none_or(|k| *k == fst) {
+            self.frames
+                .push(SpillFrame::new(df, &*self.spill_ctx).await);
+            self.last_key = Some(lst);
+            return Ok(None);
+        }
+
+        rle_lengths(key_column, idxs)?;
+        let num_flushable = df.height() - *idxs.last().unwrap() as usize;
+        let (head, tail) = df.split_at(num_flushable as i64);
+
+        let mut dfs = Vec::with_capacity(self.frames.len() + 1);
+        for sf in std::mem::take(&mut self.frames) {
+            dfs.push(sf.into_df().await);
+        }
+        dfs.push(head);
+        let complete = accumulate_dataframes_vertical_unchecked(dfs);
+
+        self.frames
+            .push(SpillFrame::new(tail, &*self.spill_ctx).await);
+        self.last_key = Some(lst);
+
+        rle_lengths(complete.column(&self.key).unwrap(), idxs)?;
+        Ok(Some((complete, idxs.len())))
+    }
+
+    /// Take the last partition, once the input is exhausted.
+    pub async fn take(&mut self) -> DataFrame {
+        let mut dfs = Vec::with_capacity(self.frames.len());
+        for sf in std::mem::take(&mut self.frames) {
+            dfs.push(sf.into_df().await);
+        }
+        self.last_key = None;
+        accumulate_dataframes_vertical_unchecked(dfs)
+    }
+}
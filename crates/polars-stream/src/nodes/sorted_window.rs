@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use polars_async::executor::{JoinHandle, TaskPriority, TaskScope};
+use polars_async::primitives::distributor_channel::distributor_channel;
+use polars_async::primitives::wait_group::WaitGroup;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{ExplodeOptions, GroupsType};
+use polars_error::{PolarsError, PolarsResult, polars_ensure};
+use polars_expr::prelude::AggState;
+use polars_expr::state::ExecutionState;
+use polars_ops::series::rle_lengths;
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::ComputeNode;
+use super::sorted_partitions::SortedPartitionBuffer;
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::execute::StreamingExecutionState;
+use crate::expression::StreamExpr;
+use crate::graph::PortState;
+use crate::morsel::{Morsel, MorselSeq, SourceToken};
+use crate::pipe::{RecvPort, SendPort};
+
+/// Evaluates a window function on input that is sorted by its partition key.
+///
+/// Each partition is evaluated as one group, and the results are mapped back to its
+/// rows. The output consists of the row index column and the window function result.
+///
+/// A partition is evaluated at once, so it has to fit in memory; the rows of the
+/// partition still being received are spillable.
+pub struct SortedWindow {
+    buffer: SortedPartitionBuffer,
+
+    seq: MorselSeq,
+
+    key: PlSmallStr,
+    row_idx: PlSmallStr,
+    function: Arc<(PlSmallStr, StreamExpr)>,
+}
+
+impl SortedWindow {
+    pub fn new(key: PlSmallStr, row_idx: PlSmallStr, function: (PlSmallStr, StreamExpr)) -> Self {
+        Self {
+            buffer: SortedPartitionBuffer::new(key.clone(), "sorted-window".into()),
+            seq: MorselSeq::default(),
+            key,
+            row_idx,
+            function: Arc::new(function),
+        }
+    }
+
+    async fn evaluate_one(
+        key: &str,
+        row_idx: &str,
+        function: &(PlSmallStr, StreamExpr),
+        state: &ExecutionState,
+        idxs: &mut Vec<IdxSize>,
+        df: DataFrame,
+    ) -> PolarsResult<DataFrame> {
+        let column = df.column(key).unwrap();
+        rle_lengths(column, idxs).unwrap();
+
+        let mut offset = 0;
+        let groups = idxs
+            .iter()
+            .map(|i| {
+                let start = offset;
+                offset += i;
+                [start, *i]
+            })
+            .collect();
+        let groups = GroupsType::new_slice(groups, false, true).into_sliceable();
+
+        let (name, function) = function;
+        let mut ac = function.evaluate_on_groups(&df, &groups, state).await?;
+        let is_scalar = matches!(
+            ac.agg_state(),
+            AggState::AggregatedScalar(_) | AggState::LiteralScalar(_)
+        );
+        let out = if is_scalar {
+            // Broadcast the value of each partition to its rows.
+            let values = ac.finalize();
+            let gather = idxs
+                .iter()
+                .enumerate()
+                .flat_map(|(g, len)| std::iter::repeat_n(g as IdxSize, *len as usize))
+                .collect::<Vec<_>>();
+            unsafe { values.take_slice_unchecked(&gather) }
+        } else {
+            let values = ac.aggregated();
+            let lists = values.list()?.rechunk();
+            let lengths = lists.downcast_as_array().offsets().lengths();
+            polars_ensure!(
+                lengths.zip(idxs.iter()).all(|(l, len)| l == *len as usize),
+                ShapeMismatch: "the length of the window expression did not match that of the group"
+            );
+            values.explode(ExplodeOptions {
+                empty_as_null: false,
+                keep_nulls: true,
+            })?
+        };
+
+        let columns = vec![
+            df.column(row_idx).unwrap().clone(),
+            out.with_name(name.clone()),
+        ];
+        Ok(unsafe { DataFrame::new_unchecked(df.height(), columns) })
+    }
+}
+
+impl ComputeNode for SortedWindow {
+    fn name(&self) -> &str {
+        "sorted-window"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+        self.buffer.update_state(recv, send);
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+
+        let Some(recv) = recv_ports[0].take() else {
+            // We no longer have to receive data. Evaluate the last partition.
+            assert!(!self.buffer.is_empty());
+            let mut send = send_ports[0].take().unwrap().serial();
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let df = Self::evaluate_one(
+                    &self.key,
+                    &self.row_idx,
+                    &self.function,
+                    &state.in_memory_exec_state,
+                    &mut Vec::new(),
+                    self.buffer.take().await,
+                )
+                .await?;
+
+                _ = send
+                    .send(Morsel::new(df, self.seq.successor(), SourceToken::new()))
+                    .await;
+
+                Ok(())
+            }));
+            return;
+        };
+
+        let mut recv = recv.serial();
+        let send = send_ports[0].take().unwrap().parallel();
+
+        let (mut distributor, rxs) =
+            distributor_channel::<Morsel>(send.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        // Worker tasks.
+        //
+        // These evaluate the window function on complete partitions.
+        join_handles.extend(rxs.into_iter().zip(send).map(|(mut rx, mut tx)| {
+            let wg = WaitGroup::default();
+            let key = self.key.clone();
+            let row_idx = self.row_idx.clone();
+            let function = self.function.clone();
+            let state = state.in_memory_exec_state.split();
+            let mut idxs = Vec::<IdxSize>::new();
+            scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(mut morsel) = rx.recv().await {
+                    morsel = morsel
+                        .async_try_map::<PolarsError, _, _>(async |df| {
+                            Self::evaluate_one(&key, &row_idx, &function, &state, &mut idxs, df)
+                                .await
+                        })
+                        .await?;
+                    morsel.set_consume_token(wg.token());
+
+                    if tx.send(morsel).await.is_err() {
+                        break;
+                    }
+                    wg.wait().await;
+                }
+
+                Ok(())
+            })
+        }));
+
+        // Distributor task.
+        //
+        // This splits off the complete partitions, keeping the last (possibly
+        // incomplete) one buffered.
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            let mut idxs = Vec::<IdxSize>::new();
+            while let Ok(morsel) = recv.recv().await {
+                let (df, seq, source_token, wait_token) = morsel.into_inner();
+                self.seq = seq;
+                drop(wait_token);
+
+                let Some((df, _)) = self.buffer.push(df, &mut idxs).await? else {
+                    continue;
+                };
+
+                if distributor
+                    .send(Morsel::new(df, seq, source_token))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+
+            Ok(())
+        }));
+    }
+}
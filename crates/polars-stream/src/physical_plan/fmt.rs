@@ -314,6 +314,8 @@ fn visualize_plan_rec(
             FileWriteFormat::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileWriteFormat::NDJson(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileWriteFormat::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
         },
         PhysNodeKind::PartitionedSink { input, options } => {
             let variant = match options.partition_strategy {
@@ -330,6 +332,8 @@ fn visualize_plan_rec(
                 FileWriteFormat::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileWriteFormat::NDJson(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileWriteFormat::Avro(_) => (format!("{variant}[avro]"), from_ref(input)),
             }
         },
         PhysNodeKind::InMemoryMap {
@@ -401,6 +405,25 @@ fn visualize_plan_rec(
 
             (s, from_ref(input))
         },
+        PhysNodeKind::SortedWindow {
+            input,
+            key,
+            row_idx: _,
+            function,
+        } => {
+            let mut s = String::new();
+            s.push_str("sorted-window\\n");
+            let f = &mut s;
+            write!(f, "key: {key}\\n").unwrap();
+            write!(
+                f,
+                "function:\\n{}",
+                fmt_exprs_to_label(from_ref(function), expr_arena, FormatExprStyle::Select)
+            )
+            .unwrap();
+
+            (s, from_ref(input))
+        },
         PhysNodeKind::Sort {
             input,
             by_column,
@@ -30,7 +30,7 @@ use super::fmt::fmt_exprs;
 use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream, StreamingLowerIRContext};
 use crate::physical_plan::ZipBehavior;
 use crate::physical_plan::lower_group_by::{
-    GroupByLowerKind, build_group_by_stream, try_build_streaming_group_by,
+    GroupByLowerKind, build_group_by_stream, try_build_sorted_window, try_build_streaming_group_by,
 };
 use crate::physical_plan::lower_ir::{build_filter_stream, build_row_idx_stream};
 
@@ -79,6 +79,19 @@ impl<'a> From<&LowerExprContext<'a>> for StreamingLowerIRContext<'a> {
     }
 }
 
+/// Whether a quantile argument is a scalar literal, as required by the quantile reduction.
+pub(crate) fn is_literal_quantile(quantile: Node, expr_arena: &Arena<AExpr>) -> bool {
+    match expr_arena.get(quantile) {
+        AExpr::Literal(lv) => {
+            lv.is_scalar()
+                && lv
+                    .to_any_value()
+                    .is_some_and(|av| av.extract::<f64>().is_some())
+        },
+        _ => false,
+    }
+}
+
 pub(crate) fn is_fake_elementwise_function(expr: &AExpr) -> bool {
     // The in-memory engine treats ApplyList as elementwise but this is not actually
     // the case. It doesn't cause any problems for the in-memory engine because of
@@ -533,7 +546,17 @@ fn lower_reduce_node(
     agg_aexpr.inputs_rev(&mut agg_input);
     agg_input.reverse();
 
-    let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &agg_input, ctx)?;
+    // The quantile reduction takes its quantile as a literal, so only lower the values.
+    let num_lowered = match agg_aexpr {
+        AExpr::Function {
+            function: IRFunctionExpr::Quantile { .. },
+            ..
+        } => 1,
+        _ => agg_input.len(),
+    };
+    let (trans_input, mut trans_exprs) =
+        lower_exprs_with_ctx(input, &agg_input[..num_lowered], ctx)?;
+    trans_exprs.extend_from_slice(&agg_input[num_lowered..]);
     let trans_agg_node = ctx.expr_arena.add(agg_aexpr.replace_inputs(&trans_exprs));
 
     let out_name = unique_column_name();
@@ -2094,7 +2117,8 @@ fn lower_exprs_with_ctx(
                 | IRAggExpr::Mean(_)
                 | IRAggExpr::Var { .. }
                 | IRAggExpr::Std { .. }
-                | IRAggExpr::Count { .. } => {
+                | IRAggExpr::Count { .. }
+                | IRAggExpr::Median(_) => {
                     let (trans_stream, trans_expr) = lower_reduce_node(input, expr, ctx)?;
                     input_streams.insert(trans_stream);
                     transformed_exprs.push(trans_expr);
@@ -2142,7 +2166,7 @@ fn lower_exprs_with_ctx(
                     input_streams.insert(PhysStream::first(reduce_node_key));
                     transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(tmp_name)));
                 },
-                IRAggExpr::Median(_) | IRAggExpr::Implode { .. } | IRAggExpr::AggGroups(_) => {
+                IRAggExpr::Implode { .. } | IRAggExpr::AggGroups(_) => {
                     let out_name = unique_column_name();
                     fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
                     transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
@@ -2162,9 +2186,30 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(trans_expr);
             },
 
+            AExpr::Function {
+                input: ref inner_exprs,
+                function: IRFunctionExpr::Quantile { .. },
+                ..
+            } if is_literal_quantile(inner_exprs[1].node(), ctx.expr_arena) => {
+                let (trans_stream, trans_expr) = lower_reduce_node(input, expr, ctx)?;
+                input_streams.insert(trans_stream);
+                transformed_exprs.push(trans_expr);
+            },
+
             #[cfg(feature = "approx_unique")]
             AExpr::Function {
-                function: IRFunctionExpr::ApproxNUnique,
+                function:
+                    IRFunctionExpr::ApproxNUnique | IRFunctionExpr::HllSketch | IRFunctionExpr::HllMerge,
+                ..
+            } => {
+                let (trans_stream, trans_expr) = lower_reduce_node(input, expr, ctx)?;
+                input_streams.insert(trans_stream);
+                transformed_exprs.push(trans_expr);
+            },
+
+            #[cfg(feature = "approx_quantile")]
+            AExpr::Function {
+                function: IRFunctionExpr::ApproxQuantile { .. } | IRFunctionExpr::QuantileSketch,
                 ..
             } => {
                 let (trans_stream, trans_expr) = lower_reduce_node(input, expr, ctx)?;
@@ -2578,35 +2623,54 @@ fn lower_exprs_with_ctx(
             AExpr::Over {
                 function,
                 partition_by,
-                order_by: None,
+                order_by,
                 mapping: WindowMapping::GroupsToRows,
             } => {
                 let out_name = unique_column_name();
-                let function_ir = AExprBuilder::new_from_node(function).expr_ir(out_name.clone());
-                let key_ir = partition_by
-                    .iter()
-                    .map(|n| AExprBuilder::new_from_node(*n).expr_ir(unique_column_name()))
-                    .collect_vec();
+                let mut stream = None;
+                if order_by.is_none() {
+                    let function_ir =
+                        AExprBuilder::new_from_node(function).expr_ir(out_name.clone());
+                    let key_ir = partition_by
+                        .iter()
+                        .map(|n| AExprBuilder::new_from_node(*n).expr_ir(unique_column_name()))
+                        .collect_vec();
 
-                if let Some(gb) = try_build_streaming_group_by(
-                    input,
-                    &key_ir,
-                    &[function_ir],
-                    false,
-                    Arc::new(GroupbyOptions::default()),
-                    None,
-                    GroupByLowerKind::Over,
-                    ctx.expr_arena,
-                    ctx.phys_sm,
-                    ctx.cache,
-                    StreamingLowerIRContext::from(&*ctx),
-                )? {
-                    input_streams.insert(gb);
-                    transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+                    stream = try_build_streaming_group_by(
+                        input,
+                        &key_ir,
+                        &[function_ir],
+                        false,
+                        Arc::new(GroupbyOptions::default()),
+                        None,
+                        GroupByLowerKind::Over,
+                        ctx.expr_arena,
+                        ctx.phys_sm,
+                        ctx.cache,
+                        StreamingLowerIRContext::from(&*ctx),
+                    )?;
+                }
+
+                // Window functions that aren't (elementwise) aggregations, or that have an
+                // `order_by`, are evaluated per partition on sorted input.
+                if stream.is_none() {
+                    stream = try_build_sorted_window(
+                        input,
+                        expr,
+                        out_name.clone(),
+                        ctx.expr_arena,
+                        ctx.phys_sm,
+                        ctx.cache,
+                        StreamingLowerIRContext::from(&*ctx),
+                    )?;
+                }
+
+                if let Some(stream) = stream {
+                    input_streams.insert(stream);
                 } else {
                     fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
-                    transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
                 }
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
 
             // Generic fallback for column-based functions/UDFs.
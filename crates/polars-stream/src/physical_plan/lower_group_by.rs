@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use parking_lot::Mutex;
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{Field, InitHashMaps, PlIndexMap, PlIndexSet, SortMultipleOptions};
+use polars_core::prelude::{
+    Field, IDX_DTYPE, InitHashMaps, PlIndexMap, PlIndexSet, SortMultipleOptions,
+};
 use polars_core::scalar::Scalar;
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_err};
@@ -21,7 +23,7 @@ use slotmap::SlotMap;
 use super::{ExprCache, PhysNode, PhysNodeKey, PhysNodeKind, PhysStream, StreamingLowerIRContext};
 use crate::physical_plan::lower_expr::{
     build_hstack_stream, build_select_stream, compute_output_schema, is_elementwise_rec_cached,
-    is_fake_elementwise_function, is_input_independent,
+    is_fake_elementwise_function, is_input_independent, is_literal_quantile,
 };
 use crate::physical_plan::lower_ir::{
     build_filter_stream, build_row_idx_stream, build_slice_stream,
@@ -113,9 +115,21 @@ fn replace_agg_uniq(
         .entry(agg_id)
         .or_insert_with(|| {
             let mut input_ids = Vec::new();
+            let is_quantile = matches!(
+                aexpr,
+                AExpr::Function {
+                    function: IRFunctionExpr::Quantile { .. },
+                    ..
+                }
+            );
             let input_cols = inputs
                 .iter()
-                .map(|input| {
+                .enumerate()
+                .map(|(i, input)| {
+                    // The quantile reduction takes its quantile as a literal.
+                    if is_quantile && i == 1 {
+                        return *input;
+                    }
                     let (input_id, node) = replace_elementwise_components(
                         *input,
                         expr_merger,
@@ -139,7 +153,14 @@ fn replace_agg_uniq(
                     }
                 })
                 .collect::<Vec<_>>();
-            let trans_agg_node = expr_arena.add(aexpr.replace_inputs(&input_cols));
+            let mut trans_agg_node = expr_arena.add(aexpr.replace_inputs(&input_cols));
+            if is_unordered_mode(&aexpr) {
+                // The mode reduction directly produces the implicit implode of the modes.
+                trans_agg_node = expr_arena.add(AExpr::Agg(IRAggExpr::Implode {
+                    input: trans_agg_node,
+                    maintain_order: false,
+                }));
+            }
 
             // Add to aggregation expressions and replace with a reference to its output.
             let agg_expr = ExprIR::new(trans_agg_node, OutputName::Alias(unique_column_name()));
@@ -152,6 +173,28 @@ fn replace_agg_uniq(
     expr_arena.add(AExpr::Column(name))
 }
 
+/// Whether this is a mode that does not maintain order, which a reduction can compute in a
+/// group_by.
+fn is_unordered_mode(aexpr: &AExpr) -> bool {
+    #[cfg(feature = "mode")]
+    {
+        matches!(
+            aexpr,
+            AExpr::Function {
+                function: IRFunctionExpr::Mode {
+                    maintain_order: false
+                },
+                ..
+            }
+        )
+    }
+    #[cfg(not(feature = "mode"))]
+    {
+        let _ = aexpr;
+        false
+    }
+}
+
 /// Replaces all elementwise subexpressions with column references, storing the elementwise
 /// expressions uniquely in expr_merger/uniq_elementwise_exprs keys.
 #[recursive]
@@ -380,7 +423,14 @@ fn try_lower_elementwise_scalar_agg_expr(
 
         #[cfg(feature = "approx_unique")]
         AExpr::Function {
-            function: IRFunctionExpr::ApproxNUnique,
+            function:
+                IRFunctionExpr::ApproxNUnique | IRFunctionExpr::HllSketch | IRFunctionExpr::HllMerge,
+            ..
+        } => Some(replace_agg_uniq!(expr)),
+
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            function: IRFunctionExpr::ApproxQuantile { .. } | IRFunctionExpr::QuantileSketch,
             ..
         } => Some(replace_agg_uniq!(expr)),
 
@@ -417,6 +467,12 @@ fn try_lower_elementwise_scalar_agg_expr(
 
         AExpr::AnonymousAgg { .. } => Some(replace_agg_uniq!(expr)),
 
+        AExpr::Function {
+            input,
+            function: IRFunctionExpr::Quantile { .. },
+            ..
+        } if is_literal_quantile(input[1].node(), expr_arena) => Some(replace_agg_uniq!(expr)),
+
         node @ AExpr::Function { input, options, .. }
         | node @ AExpr::AnonymousFunction { input, options, .. }
             if options.is_elementwise() && !is_fake_elementwise_function(node) =>
@@ -510,6 +566,7 @@ fn try_lower_elementwise_scalar_agg_expr(
                 | IRAggExpr::Var(..)
                 | IRAggExpr::Std(..)
                 | IRAggExpr::Count { .. }
+                | IRAggExpr::Median(_)
                 | IRAggExpr::Implode {
                     maintain_order: false,
                     ..
@@ -531,8 +588,7 @@ fn try_lower_elementwise_scalar_agg_expr(
                     expr_merger.add_expr(count_node, expr_arena);
                     Some(replace_agg_uniq!(count_node))
                 },
-                IRAggExpr::Median(..)
-                | IRAggExpr::Implode {
+                IRAggExpr::Implode {
                     maintain_order: true,
                     ..
                 }
@@ -808,17 +864,35 @@ pub fn try_build_streaming_group_by(
     let mut uniq_elementwise_exprs = PlIndexMap::new();
 
     for agg in aggs {
-        let Some(trans_node) = try_lower_elementwise_scalar_agg_expr(
-            agg.node(),
-            gbl_kind,
-            &mut expr_merger,
-            expr_cache,
-            expr_arena,
-            &mut trans_agg_exprs,
-            &mut uniq_input_names,
-            &mut uniq_agg_exprs,
-            &mut uniq_elementwise_exprs,
-        ) else {
+        // A mode is not scalar, but as a whole aggregation it is imploded per group, which the
+        // mode reduction computes directly.
+        let trans_node = if matches!(gbl_kind, GroupByLowerKind::Groups)
+            && is_unordered_mode(expr_arena.get(agg.node()))
+        {
+            Some(replace_agg_uniq(
+                agg.node(),
+                &mut expr_merger,
+                expr_cache,
+                expr_arena,
+                &mut trans_agg_exprs,
+                &mut uniq_input_names,
+                &mut uniq_agg_exprs,
+                &mut uniq_elementwise_exprs,
+            ))
+        } else {
+            try_lower_elementwise_scalar_agg_expr(
+                agg.node(),
+                gbl_kind,
+                &mut expr_merger,
+                expr_cache,
+                expr_arena,
+                &mut trans_agg_exprs,
+                &mut uniq_input_names,
+                &mut uniq_agg_exprs,
+                &mut uniq_elementwise_exprs,
+            )
+        };
+        let Some(trans_node) = trans_node else {
             return Ok(None);
         };
         let output_name = OutputName::Alias(agg.output_name().clone());
@@ -1200,6 +1274,143 @@ pub fn try_build_sorted_group_by(
     Ok(Some(input))
 }
 
+/// Evaluates a `GroupsToRows` window expression by sorting the input on its partition
+/// keys (and `order_by`), evaluating each partition as it completes and restoring the
+/// original row order afterwards.
+///
+/// The resulting stream has a single column named `out_name`.
+pub fn try_build_sorted_window(
+    input: PhysStream,
+    over: Node,
+    out_name: PlSmallStr,
+    expr_arena: &mut Arena<AExpr>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+    expr_cache: &mut ExprCache,
+    ctx: StreamingLowerIRContext<'_>,
+) -> PolarsResult<Option<PhysStream>> {
+    let AExpr::Over {
+        function,
+        partition_by,
+        order_by,
+        mapping: WindowMapping::GroupsToRows,
+    } = expr_arena.get(over).clone()
+    else {
+        return Ok(None);
+    };
+
+    let input_schema = input.output_schema(phys_sm).clone();
+    if partition_by.is_empty()
+        || partition_by
+            .iter()
+            .chain(order_by.as_ref().map(|(n, _)| n))
+            .any(|n| !is_elementwise_rec_cached(*n, expr_arena, expr_cache))
+    {
+        return Ok(None);
+    }
+
+    let out_field =
+        ExprIR::new(over, OutputName::Alias(out_name.clone())).field(&input_schema, expr_arena)?;
+    let key_fields = partition_by
+        .iter()
+        .map(|n| AExprBuilder::new_from_node(*n).expr_ir(unique_column_name()))
+        .map(|k| k.field(&input_schema, expr_arena).map(|f| (k, f)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    if out_field.dtype.contains_unknown()
+        || key_fields.iter().any(|(_, f)| f.dtype.contains_unknown())
+    {
+        return Ok(None);
+    }
+
+    // Only keep the columns the window expression needs, as these get sorted twice.
+    let row_idx_name = unique_column_name();
+    let mut input = build_row_idx_stream(input, row_idx_name.clone(), None, phys_sm);
+    let columns = std::iter::once(function)
+        .chain(partition_by.iter().copied())
+        .chain(order_by.as_ref().map(|(n, _)| *n))
+        .flat_map(|n| polars_plan::utils::aexpr_to_leaf_names_iter(n, expr_arena).cloned())
+        .chain(std::iter::once(row_idx_name.clone()))
+        .collect::<PlIndexSet<_>>()
+        .into_iter()
+        .map(|name| AExprBuilder::col(name.clone(), expr_arena).expr_ir(name))
+        .collect::<Vec<_>>();
+    input = build_select_stream(input, &columns, expr_arena, phys_sm, expr_cache, ctx)?;
+
+    let key_name = unique_column_name();
+    let key = if key_fields.len() > 1 || key_fields[0].1.dtype.is_nested() {
+        let (keys, fields): (Vec<_>, Vec<_>) = key_fields.into_iter().unzip();
+        AExprBuilder::function(
+            keys,
+            IRFunctionExpr::RowEncode(
+                fields.into_iter().map(|f| f.dtype).collect(),
+                RowEncodingVariant::Ordered {
+                    descending: None,
+                    nulls_last: None,
+                    broadcast_nulls: None,
+                },
+            ),
+            expr_arena,
+        )
+        .expr_ir(key_name.clone())
+    } else {
+        key_fields[0].0.with_alias(key_name.clone())
+    };
+    input = build_hstack_stream(input, &[key], expr_arena, phys_sm, expr_cache, ctx)?;
+
+    let row_idx = AExprBuilder::col(row_idx_name.clone(), expr_arena).expr_ir(row_idx_name.clone());
+    let mut by_column =
+        vec![AExprBuilder::col(key_name.clone(), expr_arena).expr_ir(key_name.clone())];
+    let mut descending = vec![false];
+    let mut nulls_last = vec![false];
+    if let Some((node, options)) = order_by {
+        by_column.push(AExprBuilder::new_from_node(node).expr_ir(unique_column_name()));
+        descending.push(options.descending);
+        nulls_last.push(options.nulls_last);
+    }
+    by_column.push(row_idx.clone());
+    descending.push(false);
+    nulls_last.push(false);
+
+    input = PhysStream::first(phys_sm.insert(PhysNode::new(
+        input.output_schema(phys_sm).clone(),
+        PhysNodeKind::Sort {
+            input,
+            by_column,
+            slice: None,
+            sort_options: SortMultipleOptions {
+                descending,
+                nulls_last,
+                ..Default::default()
+            },
+        },
+    )));
+
+    let mut window_output_schema = Schema::with_capacity(2);
+    window_output_schema.insert(row_idx_name.clone(), IDX_DTYPE);
+    window_output_schema.insert(out_name.clone(), out_field.dtype);
+    input = PhysStream::first(phys_sm.insert(PhysNode::new(
+        Arc::new(window_output_schema),
+        PhysNodeKind::SortedWindow {
+            input,
+            key: key_name,
+            row_idx: row_idx_name,
+            function: AExprBuilder::new_from_node(function).expr_ir(out_name.clone()),
+        },
+    )));
+
+    // Restore the original row order.
+    input = PhysStream::first(phys_sm.insert(PhysNode::new(
+        input.output_schema(phys_sm).clone(),
+        PhysNodeKind::Sort {
+            input,
+            by_column: vec![row_idx],
+            slice: None,
+            sort_options: SortMultipleOptions::default(),
+        },
+    )));
+    let out = AExprBuilder::col(out_name.clone(), expr_arena).expr_ir(out_name);
+    build_select_stream(input, &[out], expr_arena, phys_sm, expr_cache, ctx).map(Some)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn build_group_by_stream(
     input: PhysStream,
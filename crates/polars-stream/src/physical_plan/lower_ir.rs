@@ -493,8 +493,8 @@ pub fn lower_ir(
             let mut stream = phys_input;
 
             // If we need to maintain order augment with row index. This is
-            // not yet necessary for the non-limiting case as that one
-            // dispatches to in-memory.
+            // not necessary for the non-limiting case as the sort node keeps
+            // equal rows in order itself.
             if sort_options.maintain_order && limit < u64::MAX {
                 let row_idx_name = unique_column_name();
                 stream = build_row_idx_stream(stream, row_idx_name.clone(), None, phys_sm);
@@ -814,8 +814,17 @@ pub fn lower_ir(
                         }) as _
                     },
 
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro => {
+                        Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder {
+                            io_metrics: std::sync::OnceLock::new(),
+                        }) as _
+                    },
+
                     FileScanIR::ExpandedPaths { name: _ } => unreachable!(),
 
+                    FileScanIR::Dataset { .. } => unreachable!("dataset should be resolved"),
+
                     FileScanIR::Anonymous { .. } => todo!("unimplemented: AnonymousScan"),
                 };
 
@@ -841,10 +850,11 @@ pub fn lower_ir(
                     let file_projection_builder = ProjectionBuilder::new(
                         projected_schema,
                         unified_scan_args.column_mapping.as_ref(),
-                        unified_scan_args
-                            .default_values
-                            .filter(|DefaultFieldValues::Iceberg(v)| !v.is_empty())
-                            .map(|DefaultFieldValues::Iceberg(v)| v),
+                        unified_scan_args.default_values.and_then(|x| match x {
+                            DefaultFieldValues::Iceberg(v) if !v.is_empty() => Some(v),
+                            // Delta partition values are resolved to hive partitions.
+                            _ => None,
+                        }),
                     );
 
                     // TODO: We ignore the parameter for some scan types to maintain old behavior,
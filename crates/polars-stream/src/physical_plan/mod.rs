@@ -292,6 +292,15 @@ pub enum PhysNodeKind {
         slice: Option<(IdxSize, IdxSize)>,
     },
 
+    /// Evaluates a window function on input sorted by the `key` column, outputting the
+    /// `row_idx` column and the function result.
+    SortedWindow {
+        input: PhysStream,
+        key: PlSmallStr,
+        row_idx: PlSmallStr,
+        function: ExprIR,
+    },
+
     Sort {
         input: PhysStream,
         by_column: Vec<ExprIR>,
@@ -583,6 +592,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::PartitionedSink { input, .. }
             | PhysNodeKind::InMemoryMap { input, .. }
             | PhysNodeKind::SortedGroupBy { input, .. }
+            | PhysNodeKind::SortedWindow { input, .. }
             | PhysNodeKind::Map { input, .. }
             | PhysNodeKind::Sort { input, .. }
             | PhysNodeKind::Multiplexer { input }
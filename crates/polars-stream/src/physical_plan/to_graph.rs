@@ -581,7 +581,26 @@ fn to_graph_rec<'a>(
                 .collect::<PolarsResult<Arc<[_]>>>()?;
 
             ctx.graph.add_node(
-                nodes::sorted_group_by::SortedGroupBy::new(key.clone(), aggs, *slice, input_schema),
+                nodes::sorted_group_by::SortedGroupBy::new(key.clone(), aggs, *slice),
+                [(input_key, input.port)],
+            )
+        },
+
+        SortedWindow {
+            input,
+            key,
+            row_idx,
+            function,
+        } => {
+            let input_schema = input.output_schema(ctx.phys_sm).clone();
+            let input_key = to_graph_rec(input.node, ctx)?;
+            let function = (
+                function.output_name().clone(),
+                create_stream_expr(function, ctx, &input_schema)?,
+            );
+
+            ctx.graph.add_node(
+                nodes::sorted_window::SortedWindow::new(key.clone(), row_idx.clone(), function),
                 [(input_key, input.port)],
             )
         },
@@ -593,31 +612,18 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = input.output_schema(ctx.phys_sm).clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: slice.map(|t| (t.0, t.1, None)),
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                Some(crate::dispatch::build_streaming_query_executor),
-            )?);
+            let key_selectors = by_column
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &input_schema))
+                .try_collect_vec()?;
 
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
+                nodes::sort::SortNode::new(
                     input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
+                    key_selectors,
+                    *slice,
+                    sort_options.clone(),
                 ),
                 [(input_key, input.port)],
             )
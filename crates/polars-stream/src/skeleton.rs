@@ -198,6 +198,12 @@ impl StreamingQuery {
                 let io_total_bytes_requested = node_metrics.io_total_bytes_requested;
                 let io_total_bytes_received = node_metrics.io_total_bytes_received;
                 let io_total_bytes_sent = node_metrics.io_total_bytes_sent;
+                let io_total_row_groups_skipped_bloom_filter =
+                    node_metrics.io_total_row_groups_skipped_bloom_filter;
+                let io_total_file_cache_hits = node_metrics.io_total_file_cache_hits;
+                let io_total_file_cache_misses = node_metrics.io_total_file_cache_misses;
+                let io_total_file_cache_bytes_evicted =
+                    node_metrics.io_total_file_cache_bytes_evicted;
 
                 lines.push(
                     (total_time, format!(
@@ -210,7 +216,12 @@ impl StreamingQuery {
                                     total_active_time={io_total_active_time:.2?}, \
                                     total_bytes_requested={io_total_bytes_requested}, \
                                     total_bytes_received={io_total_bytes_received}, \
-                                    total_bytes_sent={io_total_bytes_sent})"))
+                                    total_bytes_sent={io_total_bytes_sent}, \
+                                    total_row_groups_skipped_bloom_filter={io_total_row_groups_skipped_bloom_filter}, \
+                                    file_cache(\
+                                        hits={io_total_file_cache_hits}, \
+                                        misses={io_total_file_cache_misses}, \
+                                        bytes_evicted={io_total_file_cache_bytes_evicted}))"))
                 );
 
                 total_query_ns += total_ns;
@@ -0,0 +1,226 @@
+use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::write::{write_block, write_metadata};
+use arrow::io::avro::write;
+use polars::io::SerReader;
+use polars::io::avro::AvroReader;
+use polars::prelude::*;
+
+use super::write::serialize_to_block;
+
+fn scan_df() -> PolarsResult<DataFrame> {
+    df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| (i % 7 != 0).then(|| format!("value-{i}"))).collect::<Vec<_>>(),
+        "c" => (0..1000).map(|i| i as f64 / 4.0).collect::<Vec<_>>(),
+    )
+}
+
+/// Writes `df` as an avro container file with a data block per `rows_per_block` rows.
+fn write_blocks(
+    df: &DataFrame,
+    rows_per_block: usize,
+    compression: Option<Compression>,
+) -> PolarsResult<Vec<u8>> {
+    let schema = df.schema().to_arrow(CompatLevel::oldest());
+    let record = write::to_record(&schema, "".to_string())?;
+
+    let mut file = vec![];
+    write_metadata(&mut file, record, compression)?;
+
+    let mut offset = 0;
+    while offset < df.height() {
+        let mut chunk = df.slice(offset as i64, rows_per_block);
+        chunk.rechunk_mut_par();
+
+        for batch in chunk.iter_chunks(CompatLevel::oldest(), false) {
+            write_block(
+                &mut file,
+                &serialize_to_block(&batch, &schema, compression)?,
+            )?;
+        }
+
+        offset += rows_per_block;
+    }
+
+    Ok(file)
+}
+
+fn scan(buf: Vec<u8>, args: UnifiedScanArgs) -> PolarsResult<LazyFrame> {
+    LazyFrame::scan_avro_sources(ScanSources::Buffers([buf.into()].into()), args)
+}
+
+#[test]
+fn scan_avro_roundtrip() -> PolarsResult<()> {
+    let df = scan_df()?;
+
+    for compression in [None, Some(Compression::Snappy), Some(Compression::Deflate)] {
+        let buf = write_blocks(&df, 128, compression)?;
+
+        let out = scan(buf.clone(), Default::default())?.collect()?;
+        assert!(out.equals_missing(&df));
+
+        let out = scan(buf, Default::default())?.select([len()]).collect()?;
+        assert_eq!(out.column("len")?.idx()?.get(0), Some(1000));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn scan_avro_projection_slice_row_index() -> PolarsResult<()> {
+    let df = scan_df()?;
+    let buf = write_blocks(&df, 100, Some(Compression::Snappy))?;
+
+    let args = UnifiedScanArgs {
+        row_index: Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }),
+        ..Default::default()
+    };
+
+    // The slice starts and ends in the middle of a block.
+    let out = scan(buf, args)?
+        .select([col("c"), col("index"), col("a")])
+        .slice(250, 420)
+        .collect()?;
+    let expected = df
+        .with_row_index("index".into(), Some(10))?
+        .select(["c", "index", "a"])?
+        .slice(250, 420);
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn scan_avro_multiple_files() -> PolarsResult<()> {
+    let df = scan_df()?;
+    let sources = ScanSources::Buffers(
+        [
+            write_blocks(&df.slice(0, 300), 64, None)?.into(),
+            write_blocks(&df.slice(300, 700), 256, None)?.into(),
+        ]
+        .into(),
+    );
+
+    let out = LazyFrame::scan_avro_sources(sources.clone(), Default::default())?.collect()?;
+    assert!(out.equals_missing(&df));
+
+    let out = LazyFrame::scan_avro_sources(sources, Default::default())?
+        .filter(col("a").gt_eq(lit(250i64)))
+        .select([col("b")])
+        .slice(0, 100)
+        .collect()?;
+    assert!(out.equals_missing(&df.select(["b"])?.slice(250, 100)));
+
+    Ok(())
+}
+
+#[test]
+fn scan_avro_hive() -> PolarsResult<()> {
+    let df = scan_df()?;
+    let dir = tempfile::tempdir()?;
+
+    let parts = [(1i64, df.slice(0, 300)), (2, df.slice(300, 700))];
+    let mut expected = Vec::new();
+    for (k, part) in parts {
+        let part_dir = dir.path().join(format!("k={k}"));
+        std::fs::create_dir(&part_dir)?;
+        std::fs::write(part_dir.join("0.avro"), write_blocks(&part, 128, None)?)?;
+
+        let mut part = part;
+        part.with_column(Column::new("k".into(), vec![k; part.height()]))?;
+        expected.push(part.lazy());
+    }
+    let expected = concat(expected, Default::default())?.collect()?;
+
+    let path = PlRefPath::new(dir.path().to_str().unwrap());
+    let out = LazyFrame::scan_avro(path.clone(), Default::default())?.collect()?;
+    assert!(out.equals_missing(&expected));
+
+    // The predicate on the hive column only selects the files of one partition.
+    let out = LazyFrame::scan_avro(path, Default::default())?
+        .filter(col("k").eq(lit(2i64)))
+        .select([col("a"), col("k")])
+        .collect()?;
+    assert!(out.equals(&expected.select(["a", "k"])?.slice(300, 700)));
+
+    Ok(())
+}
+
+#[test]
+fn sink_avro_roundtrip() -> PolarsResult<()> {
+    let df = scan_df()?;
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("out.avro");
+    let target = SinkTarget::Path(PlRefPath::new(path.to_str().unwrap()));
+
+    for compression in [None, Some(AvroCodec::Snappy), Some(AvroCodec::Deflate)] {
+        df.clone()
+            .lazy()
+            .sink(
+                SinkDestination::File {
+                    target: target.clone(),
+                },
+                FileWriteFormat::Avro(AvroWriterOptions {
+                    compression,
+                    ..Default::default()
+                }),
+                Default::default(),
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+
+        let buf = std::fs::read(&path)?;
+        let out = scan(buf.clone(), Default::default())?.collect()?;
+        assert!(out.equals_missing(&df));
+
+        // The eager reader reads the sinked file as well.
+        let out = AvroReader::new(std::io::Cursor::new(buf)).finish()?;
+        assert!(out.equals_missing(&df));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn sink_avro_partitioned() -> PolarsResult<()> {
+    let mut df = scan_df()?;
+    df.with_column(Column::new(
+        "k".into(),
+        (0..1000i64).map(|i| i % 3).collect::<Vec<_>>(),
+    ))?;
+    let dir = tempfile::tempdir()?;
+    let base_path = PlRefPath::new(dir.path().to_str().unwrap());
+
+    df.clone()
+        .lazy()
+        .sink(
+            SinkDestination::Partitioned {
+                base_path: base_path.clone(),
+                file_path_provider: None,
+                partition_strategy: PartitionStrategy::Keyed {
+                    keys: vec![col("k")],
+                    include_keys: false,
+                    keys_pre_grouped: false,
+                },
+                max_rows_per_file: IdxSize::MAX,
+                approximate_bytes_per_file: u64::MAX,
+            },
+            FileWriteFormat::Avro(AvroWriterOptions::default()),
+            Default::default(),
+        )?
+        .collect_with_engine(Engine::Streaming)?;
+
+    for k in 0..3 {
+        assert!(dir.path().join(format!("k={k}")).is_dir());
+    }
+
+    // The keys are restored from the hive partitions.
+    let out = LazyFrame::scan_avro(base_path, Default::default())?
+        .sort(["a"], Default::default())
+        .collect()?;
+    assert!(out.equals_missing(&df));
+
+    Ok(())
+}
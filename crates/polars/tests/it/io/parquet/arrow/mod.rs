@@ -673,6 +673,7 @@ fn integration_write(
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_page_size: None,
+        write_page_index: true,
     };
 
     let encodings = get_encodings(schema);
@@ -37,6 +37,7 @@ fn round_trip_opt_stats(
         compression,
         version,
         data_page_size: None,
+        write_page_index: true,
     };
 
     let iter = vec![RecordBatchT::try_new(
@@ -28,6 +28,7 @@ fn round_trip(
         compression,
         version,
         data_page_size: None,
+        write_page_index: true,
     };
 
     let iter = vec![RecordBatchT::try_new(
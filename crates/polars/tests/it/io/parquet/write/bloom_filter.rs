@@ -0,0 +1,159 @@
+use std::io::Cursor;
+
+use polars::io::SerReader;
+use polars::io::parquet::read::ParquetReader;
+use polars::io::parquet::write::{BloomFilterOptions, ParquetWriter};
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_parquet::parquet::bloom_filter::{hash_byte, hash_native, is_in_set, read};
+use polars_parquet::read::read_metadata;
+
+#[test]
+fn write_bloom_filters() -> PolarsResult<()> {
+    let mut df = df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("key-{i}")).collect::<Vec<_>>(),
+        "c" => (0..1000i32).collect::<Vec<_>>(),
+    )?;
+
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_bloom_filters(vec![
+            (
+                "a".into(),
+                BloomFilterOptions {
+                    fpp: 0.01,
+                    ndv: None,
+                },
+            ),
+            ("b".into(), BloomFilterOptions::default()),
+        ])
+        .finish(&mut df)?;
+
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].parquet_columns();
+
+    let mut bitset = vec![];
+
+    read(&columns[0], &mut buf, &mut bitset)?;
+    assert!(!bitset.is_empty());
+    assert!((0..1000i64).all(|v| is_in_set(&bitset, hash_native(v))));
+    let false_positives = (1000..11000i64)
+        .filter(|v| is_in_set(&bitset, hash_native(*v)))
+        .count();
+    assert!(false_positives < 500);
+
+    read(&columns[1], &mut buf, &mut bitset)?;
+    assert!(!bitset.is_empty());
+    assert!((0..1000).all(|i| is_in_set(&bitset, hash_byte(format!("key-{i}")))));
+
+    assert!(columns[2].bloom_filter_offset().is_none());
+    read(&columns[2], &mut buf, &mut bitset)?;
+    assert!(bitset.is_empty());
+
+    // The data is still readable.
+    buf.set_position(0);
+    let out = ParquetReader::new(buf).finish()?;
+    assert!(out.equals(&df));
+
+    Ok(())
+}
+
+#[test]
+fn write_bloom_filters_unknown_column() {
+    let mut df = df!("a" => [1i64, 2, 3]).unwrap();
+
+    let mut buf = Cursor::new(vec![]);
+    let result = ParquetWriter::new(&mut buf)
+        .with_bloom_filters(vec![("x".into(), BloomFilterOptions::default())])
+        .finish(&mut df);
+    assert!(matches!(result, Err(PolarsError::ColumnNotFound(_))));
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn scan_with_bloom_filters() -> PolarsResult<()> {
+    use polars::prelude::*;
+
+    // Every row group spans (almost) the full value range, so min/max statistics cannot skip any.
+    // Row group `i` holds the values `4 * k + i`.
+    let a = (0..1000i64)
+        .map(|i| (i % 250) * 4 + i / 250)
+        .collect::<Vec<_>>();
+    let b = a.iter().map(|v| format!("key-{v}")).collect::<Vec<_>>();
+    let mut df = df!("a" => a, "b" => b)?;
+
+    let options = BloomFilterOptions {
+        fpp: 0.001,
+        ndv: None,
+    };
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(250))
+        .with_bloom_filters(vec![("a".into(), options), ("b".into(), options)])
+        .finish(&mut df)?;
+    let metadata = read_metadata(&mut buf)?;
+    let file = buf.into_inner();
+
+    // Overwrites the data of the row groups that are not in `keep`, so that the scan fails unless
+    // the bloom filters skip them.
+    let scan = |predicate: Expr, keep: &[usize]| {
+        let mut file = file.clone();
+        for (i, rg) in metadata.row_groups.iter().enumerate() {
+            if keep.contains(&i) {
+                continue;
+            }
+            for column in rg.parquet_columns() {
+                let range = column.byte_range();
+                file[range.start as usize..range.end as usize].fill(0xFF);
+            }
+        }
+
+        let sources = ScanSources::Buffers([file.into()].into());
+        LazyFrame::scan_parquet_sources(sources, Default::default())?
+            .filter(predicate)
+            .collect()
+    };
+
+    for (predicate, keep) in [
+        (col("a").eq(lit(5i64)), &[1][..]),
+        (col("a").eq(lit(1000i64)), &[][..]),
+        (col("b").eq(lit("key-998")), &[2][..]),
+        (col("b").eq(lit("missing")), &[][..]),
+    ] {
+        let out = scan(predicate.clone(), keep)?;
+        let expected = df.clone().lazy().filter(predicate).collect()?;
+        assert!(out.equals(&expected));
+    }
+
+    // The row groups that are not skipped are read.
+    assert!(scan(col("a").eq(lit(5i64)), &[0]).is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn scan_with_bloom_filters_float() -> PolarsResult<()> {
+    use polars::prelude::*;
+
+    // Values that compare equal to the predicate but have a different bit pattern.
+    let mut df = df!("f" => [-0.0f64, 1.0, f64::NAN])?;
+
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_bloom_filters(vec![("f".into(), BloomFilterOptions::default())])
+        .finish(&mut df)?;
+    let sources = ScanSources::Buffers([buf.into_inner().into()].into());
+
+    for predicate in [col("f").eq(lit(0.0f64)), col("f").eq(lit(f64::NAN))] {
+        let out = LazyFrame::scan_parquet_sources(sources.clone(), Default::default())?
+            .filter(predicate.clone())
+            .collect()?;
+        let expected = df.clone().lazy().filter(predicate).collect()?;
+        // NaN does not compare equal to itself in `equals`.
+        assert_eq!(out.height(), expected.height());
+    }
+
+    Ok(())
+}
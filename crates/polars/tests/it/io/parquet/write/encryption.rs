@@ -0,0 +1,242 @@
+use std::io::Cursor;
+
+use polars::io::SerReader;
+use polars::io::parquet::read::{FileDecryptionProperties, KeyRetriever, ParquetReader};
+use polars::io::parquet::write::{EncryptionAlgorithm, FileEncryptionProperties, ParquetWriter};
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_parquet::parquet::error::{ParquetError, ParquetResult};
+use polars_parquet::read::{read_metadata, read_metadata_with_decryption};
+
+const FOOTER_KEY: &[u8; 16] = b"0123456789012345";
+const COLUMN_KEY: &[u8; 16] = b"1234567890123450";
+
+fn encryption_df() -> PolarsResult<DataFrame> {
+    df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| (i % 5 != 0).then(|| format!("secret-{i}"))).collect::<Vec<_>>(),
+        "c" => (0..1000).map(|i| i as f64 / 3.0).collect::<Vec<_>>(),
+    )
+}
+
+fn write_encrypted(
+    df: &mut DataFrame,
+    encryption: FileEncryptionProperties,
+) -> PolarsResult<Vec<u8>> {
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(300))
+        .with_data_page_size(Some(1024))
+        .with_encryption(Some(encryption))
+        .finish(df)?;
+    Ok(buf.into_inner())
+}
+
+fn read_decrypted(
+    buf: &[u8],
+    decryption: Option<FileDecryptionProperties>,
+    columns: Option<Vec<String>>,
+) -> PolarsResult<DataFrame> {
+    ParquetReader::new(Cursor::new(buf.to_vec()))
+        .with_decryption(decryption)
+        .with_columns(columns)
+        .finish()
+}
+
+fn decryption() -> FileDecryptionProperties {
+    FileDecryptionProperties::default()
+        .with_footer_key(FOOTER_KEY.to_vec())
+        .with_column_key("b", COLUMN_KEY.to_vec())
+}
+
+#[test]
+fn encrypted_footer_roundtrip() -> PolarsResult<()> {
+    let mut df = encryption_df()?;
+
+    for algorithm in [
+        EncryptionAlgorithm::AesGcmV1,
+        EncryptionAlgorithm::AesGcmCtrV1,
+    ] {
+        let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec())
+            .with_algorithm(algorithm)
+            .with_column_key("b", COLUMN_KEY.to_vec(), None);
+        let buf = write_encrypted(&mut df, encryption)?;
+
+        assert_eq!(&buf[buf.len() - 4..], b"PARE");
+        // The plaintext never ends up in the file.
+        assert!(!buf.windows(10).any(|w| w == b"secret-123"));
+
+        let out = read_decrypted(&buf, Some(decryption()), None)?;
+        assert!(out.equals_missing(&df));
+
+        // The footer cannot be read without keys.
+        assert!(read_metadata(&mut Cursor::new(buf.clone())).is_err());
+        assert!(read_decrypted(&buf, None, None).is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn plaintext_footer_roundtrip() -> PolarsResult<()> {
+    let mut df = encryption_df()?;
+    let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec())
+        .with_column_key("b", COLUMN_KEY.to_vec(), None)
+        .with_plaintext_footer(true);
+    let buf = write_encrypted(&mut df, encryption)?;
+
+    assert_eq!(&buf[buf.len() - 4..], b"PAR1");
+
+    let out = read_decrypted(&buf, Some(decryption()), None)?;
+    assert!(out.equals_missing(&df));
+
+    // Without keys, the metadata and the unencrypted columns can still be read.
+    let metadata = read_metadata(&mut Cursor::new(buf.clone()))?;
+    assert_eq!(metadata.num_rows, df.height());
+    let columns = metadata.row_groups[0].parquet_columns();
+    assert!(!columns[0].is_encrypted());
+    assert!(columns[1].is_encrypted());
+    assert!(columns[1].statistics(&metadata.footer_buf).is_none());
+
+    let out = read_decrypted(&buf, None, Some(vec!["a".into(), "c".into()]))?;
+    assert!(out.equals(&df.select(["a", "c"])?));
+    assert!(read_decrypted(&buf, None, None).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn decrypt_with_wrong_key() -> PolarsResult<()> {
+    let mut df = encryption_df()?;
+    let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec()).with_column_key(
+        "b",
+        COLUMN_KEY.to_vec(),
+        None,
+    );
+    let buf = write_encrypted(&mut df, encryption)?;
+
+    let wrong_footer_key = FileDecryptionProperties::default()
+        .with_footer_key(COLUMN_KEY.to_vec())
+        .with_column_key("b", COLUMN_KEY.to_vec());
+    assert!(read_decrypted(&buf, Some(wrong_footer_key), None).is_err());
+
+    let wrong_column_key = FileDecryptionProperties::default()
+        .with_footer_key(FOOTER_KEY.to_vec())
+        .with_column_key("b", FOOTER_KEY.to_vec());
+    assert!(read_decrypted(&buf, Some(wrong_column_key), None).is_err());
+
+    // Columns encrypted with a missing key can be listed, but not read.
+    let missing_column_key =
+        FileDecryptionProperties::default().with_footer_key(FOOTER_KEY.to_vec());
+    let metadata =
+        read_metadata_with_decryption(&mut Cursor::new(buf.clone()), Some(&missing_column_key))?;
+    assert!(metadata.row_groups[0].parquet_columns()[1].is_encrypted());
+
+    let out = read_decrypted(
+        &buf,
+        Some(missing_column_key.clone()),
+        Some(vec!["a".into(), "c".into()]),
+    )?;
+    assert!(out.equals(&df.select(["a", "c"])?));
+    assert!(read_decrypted(&buf, Some(missing_column_key), None).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn decrypt_with_aad_prefix() -> PolarsResult<()> {
+    let mut df = encryption_df()?;
+    let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec())
+        .with_aad_prefix(b"table".to_vec(), false);
+    let buf = write_encrypted(&mut df, encryption)?;
+
+    let decryption = FileDecryptionProperties::default().with_footer_key(FOOTER_KEY.to_vec());
+    assert!(read_decrypted(&buf, Some(decryption.clone()), None).is_err());
+
+    let out = read_decrypted(
+        &buf,
+        Some(decryption.with_aad_prefix(b"table".to_vec())),
+        None,
+    )?;
+    assert!(out.equals_missing(&df));
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct StaticKeyRetriever;
+
+impl KeyRetriever for StaticKeyRetriever {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>> {
+        match key_metadata {
+            b"footer" => Ok(FOOTER_KEY.to_vec()),
+            b"column-b" => Ok(COLUMN_KEY.to_vec()),
+            _ => Err(ParquetError::InvalidParameter("unknown key".to_string())),
+        }
+    }
+}
+
+#[test]
+fn decrypt_with_key_retriever() -> PolarsResult<()> {
+    let mut df = encryption_df()?;
+    let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec())
+        .with_footer_key_metadata(b"footer".to_vec())
+        .with_column_key("b", COLUMN_KEY.to_vec(), Some(b"column-b".to_vec()));
+    let buf = write_encrypted(&mut df, encryption)?;
+
+    let decryption = FileDecryptionProperties::default().with_key_retriever(StaticKeyRetriever);
+    let out = read_decrypted(&buf, Some(decryption), None)?;
+    assert!(out.equals_missing(&df));
+
+    Ok(())
+}
+
+#[test]
+fn encryption_key_for_unknown_column() -> PolarsResult<()> {
+    let mut df = encryption_df()?;
+    let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec()).with_column_key(
+        "d",
+        COLUMN_KEY.to_vec(),
+        None,
+    );
+    assert!(write_encrypted(&mut df, encryption).is_err());
+
+    let encryption = FileEncryptionProperties::new(b"too short".to_vec());
+    assert!(write_encrypted(&mut df, encryption).is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn scan_encrypted() -> PolarsResult<()> {
+    use polars::prelude::*;
+
+    let mut df = encryption_df()?;
+    let encryption = FileEncryptionProperties::new(FOOTER_KEY.to_vec()).with_column_key(
+        "b",
+        COLUMN_KEY.to_vec(),
+        None,
+    );
+    let buf = write_encrypted(&mut df, encryption)?;
+    let sources = ScanSources::Buffers([buf.into()].into());
+    let args = ScanArgsParquet {
+        decryption: Some(decryption()),
+        ..Default::default()
+    };
+
+    let predicate = col("a").gt_eq(lit(500i64)).and(col("b").is_not_null());
+    let out = LazyFrame::scan_parquet_sources(sources.clone(), args.clone())?
+        .filter(predicate.clone())
+        .collect()?;
+    let expected = df.clone().lazy().filter(predicate).collect()?;
+    assert!(out.equals_missing(&expected));
+
+    assert!(
+        LazyFrame::scan_parquet_sources(sources, Default::default())?
+            .collect()
+            .is_err()
+    );
+
+    Ok(())
+}
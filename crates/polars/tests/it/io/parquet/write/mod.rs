@@ -1,6 +1,11 @@
 mod binary;
+mod bloom_filter;
+#[cfg(feature = "parquet_encryption")]
+mod encryption;
+mod page_index;
 mod primitive;
 mod sidecar;
+mod sorting_columns;
 
 use std::io::{Cursor, Read, Seek};
 
@@ -54,6 +59,7 @@ fn test_column(column: &str, compression: CompressionOptions) -> ParquetResult<(
 
     let options = WriteOptions {
         write_statistics: true,
+        write_page_index: true,
         version: Version::V1,
     };
 
@@ -179,6 +185,7 @@ fn basic() -> ParquetResult<()> {
 
     let options = WriteOptions {
         write_statistics: false,
+        write_page_index: true,
         version: Version::V1,
     };
 
@@ -0,0 +1,181 @@
+use std::io::Cursor;
+
+use polars::io::parquet::write::ParquetWriter;
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_parquet::read::read_metadata;
+
+fn page_index_df() -> PolarsResult<DataFrame> {
+    df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("key-{i:04}")).collect::<Vec<_>>(),
+        "c" => (0..1000).map(|i| (i % 7 != 0).then_some(i as i32)).collect::<Vec<_>>(),
+    )
+}
+
+fn write_page_index_df(df: &mut DataFrame, write_page_index: bool) -> PolarsResult<Vec<u8>> {
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(500))
+        .with_data_page_size(Some(256))
+        .with_page_index(write_page_index)
+        .finish(df)?;
+    Ok(buf.into_inner())
+}
+
+#[test]
+fn write_page_index() -> PolarsResult<()> {
+    let mut df = page_index_df()?;
+
+    for write_page_index in [true, false] {
+        let buf = write_page_index_df(&mut df, write_page_index)?;
+        let metadata = read_metadata(&mut Cursor::new(buf))?;
+
+        for rg in metadata.row_groups.iter() {
+            for column in rg.parquet_columns() {
+                assert_eq!(column.offset_index_offset().is_some(), write_page_index);
+                assert_eq!(column.column_index_offset().is_some(), write_page_index);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn scan_with_page_index() -> PolarsResult<()> {
+    use polars::prelude::*;
+
+    let mut df = page_index_df()?;
+    let buf = write_page_index_df(&mut df, true)?;
+    let sources = ScanSources::Buffers([buf.into()].into());
+    let scan = || LazyFrame::scan_parquet_sources(sources.clone(), Default::default());
+
+    for predicate in [
+        col("a").gt_eq(lit(300i64)).and(col("a").lt(lit(320i64))),
+        col("a").eq(lit(999i64)),
+        col("a").lt(lit(0i64)),
+        col("b").eq(lit("key-0742")),
+        col("c").is_null().and(col("a").gt(lit(900i64))),
+        col("c").gt(lit(480)).or(col("a").lt(lit(20i64))),
+    ] {
+        let out = scan()?.filter(predicate.clone()).collect()?;
+        let expected = df.clone().lazy().filter(predicate.clone()).collect()?;
+        assert!(out.equals_missing(&expected));
+
+        // The predicate column is not part of the output.
+        let out = scan()?
+            .filter(predicate.clone())
+            .select([col("b")])
+            .collect()?;
+        let expected = df
+            .clone()
+            .lazy()
+            .filter(predicate)
+            .select([col("b")])
+            .collect()?;
+        assert!(out.equals(&expected));
+    }
+
+    let predicate = col("index").gt_eq(lit(610)).and(col("index").lt(lit(640)));
+    let out = scan()?
+        .with_row_index("index", None)
+        .filter(predicate.clone())
+        .collect()?;
+    let expected = df
+        .clone()
+        .lazy()
+        .with_row_index("index", None)
+        .filter(predicate)
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    for (offset, len) in [(130, 50), (480, 40), (990, 100)] {
+        let out = scan()?.slice(offset, len).collect()?;
+        let expected = df.slice(offset, len as usize);
+        assert!(out.equals_missing(&expected));
+    }
+
+    Ok(())
+}
+
+/// Overwrites the data pages that contain none of the rows in `keep`, so that a scan fails
+/// unless it skips these pages.
+#[cfg(feature = "lazy")]
+fn corrupt_pages(file: &mut [u8], keep: impl Fn(usize, std::ops::Range<usize>) -> bool) {
+    use polars_parquet::parquet::read::indexes::deserialize_offset_index;
+
+    let metadata = read_metadata(&mut Cursor::new(&*file)).unwrap();
+    for (rg_idx, rg) in metadata.row_groups.iter().enumerate() {
+        for column in rg.parquet_columns() {
+            let start = column.offset_index_offset().unwrap() as usize;
+            let len = column.offset_index_length().unwrap() as usize;
+            let pages = deserialize_offset_index(&file[start..start + len])
+                .unwrap()
+                .page_locations;
+
+            for (i, page) in pages.iter().enumerate() {
+                let end_row = pages
+                    .get(i + 1)
+                    .map_or(rg.num_rows(), |next| next.first_row_index as usize);
+                if !keep(rg_idx, page.first_row_index as usize..end_row) {
+                    let offset = page.offset as usize;
+                    file[offset..offset + page.compressed_page_size as usize].fill(0xFF);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn scan_with_page_index_skips_pages() -> PolarsResult<()> {
+    use polars::prelude::*;
+    use polars_parquet::parquet::read::indexes::deserialize_offset_index;
+
+    let mut df = page_index_df()?;
+    let file = write_page_index_df(&mut df, true)?;
+
+    // Column `a` is sorted, so the pages of `a` that can match `300 <= a < 320` are the ones
+    // holding rows `300..320` of the first row group. The pages of the other columns are read
+    // for the rows of these pages only.
+    let metadata = read_metadata(&mut Cursor::new(&file))?;
+    let a = &metadata.row_groups[0].parquet_columns()[0];
+    let start = a.offset_index_offset().unwrap() as usize;
+    let len = a.offset_index_length().unwrap() as usize;
+    let first_rows = deserialize_offset_index(&file[start..start + len])
+        .unwrap()
+        .page_locations
+        .iter()
+        .map(|page| page.first_row_index as usize)
+        .collect::<Vec<_>>();
+    assert!(first_rows.len() > 4);
+    let first_page = first_rows.partition_point(|&row| row <= 300) - 1;
+    let last_page = first_rows.partition_point(|&row| row < 320) - 1;
+    let selected_rows =
+        first_rows[first_page]..first_rows.get(last_page + 1).copied().unwrap_or(500);
+
+    let scan = |file: Vec<u8>| {
+        let sources = ScanSources::Buffers([file.into()].into());
+        LazyFrame::scan_parquet_sources(sources, Default::default())?
+            .filter(col("a").gt_eq(lit(300i64)).and(col("a").lt(lit(320i64))))
+            .collect()
+    };
+
+    let mut corrupted = file.clone();
+    corrupt_pages(&mut corrupted, |rg_idx, rows| {
+        rg_idx == 0 && rows.start < selected_rows.end && selected_rows.start < rows.end
+    });
+    let out = scan(corrupted)?;
+    assert!(out.equals_missing(&df.slice(300, 20)));
+
+    // The selected pages are read.
+    let mut corrupted = file;
+    corrupt_pages(&mut corrupted, |_, rows| {
+        rows.end <= 300 || rows.start >= 320
+    });
+    assert!(scan(corrupted).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use std::io::Cursor;
+
+use polars::io::parquet::read::file_sorting_columns;
+use polars::io::parquet::write::{ParquetSortingColumn, ParquetWriter};
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_parquet::parquet::metadata::SortingColumn;
+use polars_parquet::read::{infer_schema, read_metadata};
+
+fn sorted_df() -> PolarsResult<DataFrame> {
+    let mut df = df!(
+        "b" => (0..1000).map(|i| (i * 7919) % 1000).collect::<Vec<i32>>(),
+    )?;
+
+    let mut a = Series::new("a".into(), (0..1000i64).collect::<Vec<_>>());
+    a.set_sorted_flag(IsSorted::Ascending);
+    df.with_column(a.into())?;
+
+    let mut c = Series::new(
+        "c".into(),
+        (0..1000)
+            .map(|i| (i < 900).then(|| format!("{:04}", 1000 - i)))
+            .collect::<Vec<_>>(),
+    );
+    c.set_sorted_flag(IsSorted::Descending);
+    df.with_column(c.into())?;
+
+    Ok(df)
+}
+
+fn write(df: &mut DataFrame, sorting_columns: Option<Vec<ParquetSortingColumn>>) -> Vec<u8> {
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(250))
+        .with_sorting_columns(sorting_columns)
+        .finish(df)
+        .unwrap();
+    buf.into_inner()
+}
+
+#[test]
+fn write_sorting_columns_from_flags() -> PolarsResult<()> {
+    let mut df = sorted_df()?;
+    let buf = write(&mut df, None);
+    let metadata = read_metadata(&mut Cursor::new(buf))?;
+
+    // Columns are ordered as "b", "a", "c".
+    let expected = [
+        SortingColumn {
+            column_idx: 1,
+            descending: false,
+            nulls_first: true,
+        },
+        SortingColumn {
+            column_idx: 2,
+            descending: true,
+            nulls_first: false,
+        },
+    ];
+
+    assert_eq!(metadata.row_groups.len(), 4);
+    for rg in metadata.row_groups.iter() {
+        assert_eq!(rg.sorting_columns(), Some(expected.as_slice()));
+    }
+
+    let schema = infer_schema(&metadata)?;
+    let sorting_columns = file_sorting_columns(&metadata, &schema)?;
+    assert_eq!(
+        sorting_columns,
+        [
+            ParquetSortingColumn {
+                name: "a".into(),
+                descending: false,
+                nulls_last: false,
+            },
+            ParquetSortingColumn {
+                name: "c".into(),
+                descending: true,
+                nulls_last: true,
+            },
+        ]
+    );
+
+    // Without sortedness flags nothing is written.
+    let mut df = df!("a" => (0..1000i64).collect::<Vec<_>>())?;
+    let buf = write(&mut df, None);
+    let metadata = read_metadata(&mut Cursor::new(buf))?;
+    for rg in metadata.row_groups.iter() {
+        assert_eq!(rg.sorting_columns(), None);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn write_explicit_sorting_columns() -> PolarsResult<()> {
+    let mut df = sorted_df()?;
+    let buf = write(
+        &mut df,
+        Some(vec![ParquetSortingColumn {
+            name: "b".into(),
+            descending: true,
+            nulls_last: true,
+        }]),
+    );
+    let metadata = read_metadata(&mut Cursor::new(buf))?;
+
+    let expected = [SortingColumn {
+        column_idx: 0,
+        descending: true,
+        nulls_first: false,
+    }];
+    for rg in metadata.row_groups.iter() {
+        assert_eq!(rg.sorting_columns(), Some(expected.as_slice()));
+    }
+
+    // The statistics show that the row groups are not in order.
+    let schema = infer_schema(&metadata)?;
+    assert!(file_sorting_columns(&metadata, &schema)?.is_empty());
+
+    let mut buf = Cursor::new(vec![]);
+    let result = ParquetWriter::new(&mut buf)
+        .with_sorting_columns(Some(vec![ParquetSortingColumn {
+            name: "x".into(),
+            descending: false,
+            nulls_last: false,
+        }]))
+        .finish(&mut df);
+    assert!(matches!(result, Err(PolarsError::ColumnNotFound(_))));
+
+    Ok(())
+}
+
+#[test]
+fn file_sorting_columns_across_batches() -> PolarsResult<()> {
+    let batch = |range: std::ops::Range<i64>| {
+        let mut a = Series::new("a".into(), range.collect::<Vec<_>>());
+        a.set_sorted_flag(IsSorted::Ascending);
+        DataFrame::new_infer_height(vec![a.into()])
+    };
+
+    for (ranges, is_sorted) in [([0..100, 100..200], true), ([0..100, 50..150], false)] {
+        let mut buf = Cursor::new(vec![]);
+        let mut writer = ParquetWriter::new(&mut buf).batched(batch(0..0)?.schema())?;
+        for range in ranges {
+            writer.write_batch(&batch(range)?)?;
+        }
+        writer.finish()?;
+
+        let metadata = read_metadata(&mut Cursor::new(buf.into_inner()))?;
+        let schema = infer_schema(&metadata)?;
+        assert_eq!(metadata.row_groups.len(), 2);
+        assert_eq!(
+            !file_sorting_columns(&metadata, &schema)?.is_empty(),
+            is_sorted
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn scan_sorted_parquet() -> PolarsResult<()> {
+    use polars::prelude::*;
+
+    let mut df = sorted_df()?;
+    let buf = write(&mut df, None);
+    let sources = ScanSources::Buffers([buf.into()].into());
+    let scan = || LazyFrame::scan_parquet_sources(sources.clone(), Default::default());
+
+    let out = scan()?.sort(["a"], Default::default()).collect()?;
+    assert!(out.equals_missing(&df));
+
+    let out = scan()?
+        .filter(col("a").gt(lit(500i64)))
+        .sort(
+            ["c"],
+            SortMultipleOptions::default()
+                .with_order_descending(true)
+                .with_nulls_last(true),
+        )
+        .collect()?;
+    let expected = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(500i64)))
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
@@ -2428,6 +2428,16 @@ class PyCatalogClient:
         cloud_options: dict[str, str] | None,
         credential_provider: Any | None,
     ) -> PyLazyFrame: ...
+    def sink_table(
+        self,
+        lf: PyLazyFrame,
+        catalog_name: str,
+        namespace: str,
+        table_name: str,
+        mode: str,
+        cloud_options: dict[str, Any] | None,
+        credential_provider: Any | None,
+    ) -> PyLazyFrame: ...
     def create_catalog(
         self, catalog_name: str, comment: str | None, storage_root: str | None
     ) -> Any: ...
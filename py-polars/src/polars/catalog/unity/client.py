@@ -337,6 +337,9 @@ class Catalog:
             - If 'ignore', will not write anything if table already exists.
             - If 'merge', return a `TableMerger` object to merge data from the DataFrame
               with the existing data.
+
+            Delta tables are written natively, except when merging or when
+            `delta_write_options` are given, which requires `deltalake`.
         delta_write_options
             (For delta tables) Additional keyword arguments while writing a
             Delta lake Table.
@@ -381,6 +384,24 @@ class Catalog:
             caller_name="Catalog.write_table",
         )
 
+        if (
+            data_source_format == "DELTA"
+            and delta_mode != "merge"
+            and delta_write_options is None
+        ):
+            wrap_ldf(
+                self._client.sink_table(
+                    df.lazy()._ldf,
+                    catalog_name,
+                    namespace,
+                    table_name,
+                    mode=delta_mode,
+                    cloud_options=storage_options,
+                    credential_provider=credential_provider,
+                )
+            ).collect(engine="streaming")
+            return None
+
         if data_source_format in ["DELTA", "DELTASHARING"]:
             return df.write_delta(  # type: ignore[misc]
                 storage_location,
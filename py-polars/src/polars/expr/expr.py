@@ -3708,6 +3708,220 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.approx_n_unique())
 
+    @unstable()
+    def hll_sketch(self) -> Expr:
+        """
+        Aggregate into a serialized HyperLogLog sketch of the unique values.
+
+        Null values are ignored. Sketches can be stored (for example in Parquet),
+        combined with :meth:`hll_merge` and counted with :meth:`hll_estimate`.
+        Only sketches of columns with the same data type can be combined.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        approx_n_unique
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "day": [1, 1, 1, 2, 2, 3],
+        ...         "user": ["a", "b", "a", "b", "c", "d"],
+        ...     }
+        ... )
+        >>> daily = df.group_by("day").agg(pl.col("user").hll_sketch())
+        >>> daily.filter(pl.col("day") <= 2).select(
+        ...     pl.col("user").hll_merge().hll_estimate()
+        ... )
+        shape: (1, 1)
+        ┌──────┐
+        │ user │
+        │ ---  │
+        │ u64  │
+        ╞══════╡
+        │ 3    │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.hll_sketch())
+
+    @unstable()
+    def hll_merge(self) -> Expr:
+        """
+        Merge a binary column of HyperLogLog sketches into a single sketch.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        hll_sketch
+        hll_estimate
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4]})
+        >>> sketches = pl.concat(
+        ...     [
+        ...         df.head(3).select(pl.col("a").hll_sketch()),
+        ...         df.tail(3).select(pl.col("a").hll_sketch()),
+        ...     ]
+        ... )
+        >>> sketches.select(pl.col("a").hll_merge().hll_estimate())
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ u64 │
+        ╞═════╡
+        │ 4   │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.hll_merge())
+
+    @unstable()
+    def hll_estimate(self) -> Expr:
+        """
+        Estimate the number of unique values of each HyperLogLog sketch.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        hll_sketch
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"g": [1, 1, 2], "a": [1, 2, 2]})
+        >>> (
+        ...     df.group_by("g")
+        ...     .agg(pl.col("a").hll_sketch())
+        ...     .select("g", pl.col("a").hll_estimate())
+        ...     .sort("g")
+        ... )
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ g   ┆ a   │
+        │ --- ┆ --- │
+        │ i64 ┆ u64 │
+        ╞═════╪═════╡
+        │ 1   ┆ 2   │
+        │ 2   ┆ 1   │
+        └─────┴─────┘
+        """
+        return wrap_expr(self._pyexpr.hll_estimate())
+
+    @unstable()
+    def approx_quantile(self, quantile: float) -> Expr:
+        """
+        Approximate quantile value, using a mergeable t-digest sketch.
+
+        The input is either numeric, or a binary column of sketches created by
+        :meth:`quantile_sketch`, in which case the sketches are merged.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        See Also
+        --------
+        quantile_sketch
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 3.0 │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.approx_quantile(quantile))
+
+    @unstable()
+    def approx_median(self) -> Expr:
+        """
+        Approximate median value, using a mergeable t-digest sketch.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        approx_quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_median())
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 3.0 │
+        └─────┘
+        """
+        return self.approx_quantile(0.5)
+
+    @unstable()
+    def quantile_sketch(self) -> Expr:
+        """
+        Aggregate into a serialized t-digest quantile sketch.
+
+        The input is either numeric, or a binary column of sketches which are
+        merged. This allows sketches to be stored and later rolled up into coarser
+        groups, for example daily sketches into monthly ones, and evaluated with
+        :meth:`approx_quantile`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "month": [1, 1, 1, 1, 2, 2],
+        ...         "day": [1, 1, 2, 2, 1, 1],
+        ...         "a": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> daily = df.group_by("month", "day").agg(pl.col("a").quantile_sketch())
+        >>> (
+        ...     daily.group_by("month")
+        ...     .agg(pl.col("a").approx_median())
+        ...     .sort("month")
+        ... )
+        shape: (2, 2)
+        ┌───────┬─────┐
+        │ month ┆ a   │
+        │ ---   ┆ --- │
+        │ i64   ┆ f64 │
+        ╞═══════╪═════╡
+        │ 1     ┆ 2.5 │
+        │ 2     ┆ 5.5 │
+        └───────┴─────┘
+        """
+        return wrap_expr(self._pyexpr.quantile_sketch())
+
     def null_count(self) -> Expr:
         """
         Count null values.
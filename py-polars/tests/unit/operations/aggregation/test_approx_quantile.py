@@ -0,0 +1,91 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal
+
+
+@pytest.mark.parametrize("quantile", [0.0, 0.01, 0.25, 0.5, 0.9, 0.999, 1.0])
+def test_approx_quantile_accuracy(quantile: float) -> None:
+    df = pl.DataFrame({"a": [(i * 7919) % 100_000 for i in range(100_000)]})
+    result = df.select(pl.col("a").approx_quantile(quantile)).item()
+    expected = df.select(pl.col("a").quantile(quantile)).item()
+    assert result == pytest.approx(expected, abs=500)
+
+
+def test_approx_quantile_small() -> None:
+    df = pl.DataFrame({"a": [5, None, 1, 4, 2, 3], "b": [None] * 6})
+    result = df.select(
+        median=pl.col("a").approx_median(),
+        min=pl.col("a").approx_quantile(0.0),
+        max=pl.col("a").approx_quantile(1.0),
+        null=pl.col("b").approx_median(),
+    )
+    expected = pl.DataFrame(
+        {"median": [3.0], "min": [1.0], "max": [5.0], "null": [None]},
+        schema_overrides={"null": pl.Float64},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_approx_quantile_group_by_streaming() -> None:
+    lf = pl.LazyFrame(
+        {"g": [i % 7 for i in range(10_000)], "a": [float(i) for i in range(10_000)]}
+    )
+    q = lf.group_by("g").agg(
+        median=pl.col("a").approx_median(),
+        p90=pl.col("a").approx_quantile(0.9),
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+        abs_tol=20.0,
+    )
+
+
+def test_quantile_sketch_roll_up() -> None:
+    df = pl.DataFrame(
+        {
+            "month": [i // 3_000 for i in range(9_000)],
+            "day": [i // 100 for i in range(9_000)],
+            "a": [float((i * 7919) % 9_000) for i in range(9_000)],
+        }
+    )
+    daily = df.group_by("month", "day").agg(pl.col("a").quantile_sketch())
+    assert daily.schema["a"] == pl.Binary
+
+    # Roll daily sketches up into monthly sketches, and evaluate them.
+    monthly = daily.group_by("month").agg(pl.col("a").quantile_sketch())
+    result = monthly.select("month", pl.col("a").approx_median()).sort("month")
+    direct = daily.group_by("month").agg(pl.col("a").approx_median()).sort("month")
+    expected = df.group_by("month").agg(pl.col("a").median()).sort("month")
+    assert_frame_equal(result, expected, abs_tol=50.0)
+    assert_frame_equal(direct, expected, abs_tol=50.0)
+
+    streaming = (
+        daily.lazy()
+        .group_by("month")
+        .agg(pl.col("a").approx_median())
+        .sort("month")
+        .collect(engine="streaming")
+    )
+    assert_frame_equal(streaming, expected, abs_tol=50.0)
+
+
+def test_quantile_sketch_invalid() -> None:
+    df = pl.DataFrame({"a": [b"not a sketch"]})
+    with pytest.raises(ComputeError, match="invalid quantile sketch"):
+        df.select(pl.col("a").approx_median())
+    with pytest.raises(ComputeError, match="invalid quantile sketch"):
+        df.lazy().select(pl.col("a").approx_median()).collect(engine="streaming")
+
+
+def test_approx_quantile_invalid() -> None:
+    df = pl.DataFrame({"a": ["x"], "b": [1.0]})
+    with pytest.raises(InvalidOperationError, match="not supported for dtype"):
+        df.select(pl.col("a").approx_quantile(0.5))
+    with pytest.raises(ComputeError, match="quantile should be between"):
+        df.select(pl.col("b").approx_quantile(1.5))
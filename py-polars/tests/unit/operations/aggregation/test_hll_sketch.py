@@ -0,0 +1,110 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def test_hll_sketch_roll_up() -> None:
+    df = pl.DataFrame(
+        {
+            "month": [i // 30_000 for i in range(90_000)],
+            "day": [i // 1_000 for i in range(90_000)],
+            "user": [(i * 7919) % 20_000 for i in range(90_000)],
+        }
+    )
+    daily = df.group_by("month", "day").agg(pl.col("user").hll_sketch())
+    assert daily.schema["user"] == pl.Binary
+
+    # Roll daily sketches up into monthly sketches, and estimate them.
+    monthly = daily.group_by("month").agg(pl.col("user").hll_merge().hll_estimate())
+    result = monthly.sort("month")["user"].to_list()
+    expected = df.group_by("month").agg(pl.col("user").n_unique()).sort("month")
+    for r, e in zip(result, expected["user"].to_list()):
+        assert r == pytest.approx(e, rel=0.05)
+
+    total = daily.select(pl.col("user").hll_merge().hll_estimate()).item()
+    assert total == pytest.approx(20_000, rel=0.05)
+
+
+def test_hll_sketch_streaming() -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": [i % 7 for i in range(10_000)],
+            "a": [str(i % 1_234) for i in range(10_000)],
+        }
+    )
+    q = lf.group_by("g").agg(pl.col("a").hll_sketch()).sort("g")
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    q = lf.select(pl.col("a").hll_sketch())
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    q = q.select(pl.col("a").hll_merge().hll_estimate())
+    assert q.collect(engine="streaming").item() == q.collect(engine="in-memory").item()
+
+
+def test_hll_sketch_format() -> None:
+    # The hash of the values is part of the serialized format, so it must not change.
+    df = pl.DataFrame({"a": [1], "b": ["a"]})
+    sketches = df.select(pl.all().hll_sketch()).row(0)
+    for sketch, (index, rank) in zip(sketches, [(6549, 2), (11867, 1)]):
+        assert len(sketch) == 1 + 2**14
+        assert sketch[0] == 1
+        assert sketch[1 + index] == rank
+        assert sum(sketch[1:]) == rank
+
+    # Floats are compared by their total order.
+    s = pl.Series([0.0, -0.0, float("nan"), -float("nan")])
+    assert s.to_frame().select(pl.all().hll_sketch().hll_estimate()).item() == 2
+
+
+def test_hll_sketch_nulls() -> None:
+    df = pl.DataFrame({"a": [1, None, 2, 2, None], "b": [None] * 5})
+    result = df.select(
+        a=pl.col("a").hll_sketch().hll_estimate(),
+        b=pl.col("b").cast(pl.Int64).hll_sketch().hll_estimate(),
+    )
+    assert result.row(0) == (2, 0)
+
+    # Null sketches are ignored when merging, and estimated as null.
+    sketches = pl.DataFrame({"a": [None, df.select(pl.col("a").hll_sketch()).item()]})
+    assert sketches.select(pl.col("a").hll_merge().hll_estimate()).item() == 2
+    assert sketches.select(pl.col("a").hll_estimate())["a"].to_list() == [None, 2]
+
+
+def test_hll_sketch_categorical() -> None:
+    s = pl.Series("a", ["x", "y", "x", "z"])
+    assert (
+        s.cast(pl.Categorical).to_frame().select(pl.col("a").hll_sketch()).item()
+        == s.to_frame().select(pl.col("a").hll_sketch()).item()
+    )
+
+
+def test_hll_sketch_parquet_round_trip(tmp_path: Path) -> None:
+    path = tmp_path / "sketches.parquet"
+    df = pl.DataFrame({"day": [i // 100 for i in range(1_000)], "a": range(1_000)})
+    df.group_by("day").agg(pl.col("a").hll_sketch()).write_parquet(path)
+
+    result = pl.scan_parquet(path).select(pl.col("a").hll_merge().hll_estimate())
+    assert result.collect().item() == pytest.approx(1_000, rel=0.05)
+
+
+def test_hll_sketch_invalid() -> None:
+    df = pl.DataFrame({"a": [b"not a sketch"]})
+    with pytest.raises(ComputeError, match="invalid HyperLogLog sketch"):
+        df.select(pl.col("a").hll_merge())
+    with pytest.raises(ComputeError, match="invalid HyperLogLog sketch"):
+        df.lazy().select(pl.col("a").hll_merge()).collect(engine="streaming")
+    with pytest.raises(ComputeError, match="invalid HyperLogLog sketch"):
+        df.select(pl.col("a").hll_estimate())
+
+    with pytest.raises(InvalidOperationError, match="expects a binary column"):
+        pl.DataFrame({"a": [1]}).select(pl.col("a").hll_merge())
@@ -683,3 +683,66 @@ def test_group_by_empty_or_scalar_key_exprs_23397() -> None:
         q.collect(),
         pl.DataFrame({"len": pl.Series([5], dtype=pl.get_index_type())}),
     )
+
+
+def test_group_by_grouping_sets() -> None:
+    df = pl.DataFrame(
+        {
+            "region": ["eu", "eu", "us", "us"],
+            "product": ["a", "b", "a", "a"],
+            "sales": [10, 20, 30, 40],
+        }
+    )
+    with pl.SQLContext(df=df, eager=True) as ctx:
+        res = ctx.execute(
+            """
+            SELECT region, product, GROUPING(region, product) AS g, SUM(sales) AS total
+            FROM df
+            GROUP BY ROLLUP(region, product)
+            ORDER BY g, region, product
+            """
+        )
+        assert res.rows() == [
+            ("eu", "a", 0, 10),
+            ("eu", "b", 0, 20),
+            ("us", "a", 0, 70),
+            ("eu", None, 1, 30),
+            ("us", None, 1, 70),
+            (None, None, 3, 100),
+        ]
+
+        # CUBE also produces the per-product subtotals
+        res = ctx.execute(
+            """
+            SELECT product, SUM(sales) AS total
+            FROM df
+            GROUP BY CUBE(region, product)
+            HAVING GROUPING(region) = 1 AND GROUPING(product) = 0
+            ORDER BY product
+            """
+        )
+        assert res.rows() == [("a", 80), ("b", 20)]
+
+        for group_by in (
+            "GROUPING SETS ((region), ())",
+            "region WITH ROLLUP",
+        ):
+            res = ctx.execute(
+                f"""
+                SELECT region, COUNT(*) AS n FROM df
+                GROUP BY {group_by}
+                ORDER BY region NULLS LAST
+                """
+            )
+            assert res.rows() == [("eu", 2), ("us", 2), (None, 4)]
+
+        # GROUPING is zero for a plain GROUP BY
+        res = ctx.execute(
+            "SELECT region, GROUPING(region) AS g FROM df GROUP BY region ORDER BY 1"
+        )
+        assert res.rows() == [("eu", 0), ("us", 0)]
+
+    with pytest.raises(SQLSyntaxError, match="must be GROUP BY expressions"):
+        pl.sql("SELECT GROUPING(sales) FROM df GROUP BY ROLLUP(region)", eager=True)
+    with pytest.raises(SQLSyntaxError, match="only be used in a query with a GROUP BY"):
+        pl.sql("SELECT GROUPING(sales) FROM df", eager=True)
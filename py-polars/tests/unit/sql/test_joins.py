@@ -1502,3 +1502,57 @@ def test_qualified_wildcard_combinations(query: str, expected: dict[str, Any]) -
         right=pl.sql(query).collect(),
         check_row_order=False,
     )
+
+
+@pytest.mark.parametrize(
+    "join_clause",
+    [
+        "ASOF JOIN quotes q ON t.sym = q.sym AND t.ts >= q.ts",
+        "ASOF JOIN quotes q ON q.ts <= t.ts AND q.sym = t.sym",
+        "ASOF JOIN quotes q MATCH_CONDITION (t.ts >= q.ts) ON t.sym = q.sym",
+        "ASOF JOIN quotes q USING (sym, ts)",
+    ],
+)
+def test_asof_join(join_clause: str) -> None:
+    trades = pl.DataFrame(  # noqa: F841
+        {"sym": ["a", "a", "b", "b"], "ts": [2, 5, 1, 4], "qty": [10, 20, 30, 40]}
+    )
+    quotes = pl.DataFrame(  # noqa: F841
+        {"sym": ["a", "a", "b"], "ts": [1, 4, 3], "px": [1.5, 2.5, 3.5]}
+    )
+    res = pl.sql(
+        f"SELECT t.sym, t.ts, t.qty, q.px FROM trades t {join_clause} ORDER BY qty",
+        eager=True,
+    )
+    # trades without an earlier quote are dropped (as with an inner join)
+    assert res.to_dict(as_series=False) == {
+        "sym": ["a", "a", "b"],
+        "ts": [2, 5, 4],
+        "qty": [10, 20, 40],
+        "px": [1.5, 2.5, 3.5],
+    }
+
+
+def test_asof_join_forward() -> None:
+    df1 = pl.DataFrame({"id": [1, 2, 3], "ts": [1, 5, 9]})  # noqa: F841
+    df2 = pl.DataFrame({"ts": [2, 5, 7], "val": ["x", "y", "z"]})  # noqa: F841
+    res = pl.sql(
+        "SELECT df1.id, df2.val FROM df1 ASOF JOIN df2 ON df1.ts < df2.ts ORDER BY id",
+        eager=True,
+    )
+    assert res.to_dict(as_series=False) == {"id": [1, 2], "val": ["x", "z"]}
+
+
+@pytest.mark.parametrize(
+    ("join_clause", "error_msg"),
+    [
+        ("ON t.sym = q.sym", "requires an inequality condition"),
+        ("ON t.ts >= q.ts AND t.ts < q.ts", "supports only one inequality"),
+        ("ON t.ts + 1 >= q.ts", "must compare columns"),
+    ],
+)
+def test_asof_join_errors(join_clause: str, error_msg: str) -> None:
+    trades = pl.DataFrame({"sym": ["a"], "ts": [1]})  # noqa: F841
+    quotes = pl.DataFrame({"sym": ["a"], "ts": [1]})  # noqa: F841
+    with pytest.raises((SQLInterfaceError, SQLSyntaxError), match=error_msg):
+        pl.sql(f"SELECT * FROM trades t ASOF JOIN quotes q {join_clause}").collect()
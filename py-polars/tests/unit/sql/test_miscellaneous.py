@@ -361,6 +361,65 @@ def test_nested_cte_column_aliasing() -> None:
     }
 
 
+def test_recursive_cte() -> None:
+    employees = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5],
+            "name": ["ann", "bob", "cat", "dan", "eve"],
+            "manager_id": [None, 1, 1, 2, 4],
+        }
+    )
+    with pl.SQLContext(employees=employees) as ctx:
+        res = ctx.execute(
+            """
+            WITH RECURSIVE chain(id, name, depth) AS (
+              SELECT id, name, 0 FROM employees WHERE manager_id IS NULL
+              UNION ALL
+              SELECT e.id, e.name, c.depth + 1
+              FROM employees e JOIN chain c ON e.manager_id = c.id
+            )
+            SELECT name, depth FROM chain ORDER BY depth, name
+            """,
+            eager=True,
+        )
+        assert res.rows() == [
+            ("ann", 0),
+            ("bob", 1),
+            ("cat", 1),
+            ("dan", 2),
+            ("eve", 3),
+        ]
+
+    # UNION discards duplicate rows, which also ends the recursion on cycles
+    edges = pl.DataFrame({"src": [1, 2, 3], "dst": [2, 3, 1]})
+    with pl.SQLContext(edges=edges) as ctx:
+        res = ctx.execute(
+            """
+            WITH RECURSIVE reachable(node) AS (
+              SELECT 1
+              UNION
+              SELECT dst FROM edges JOIN reachable ON src = node
+            )
+            SELECT node FROM reachable ORDER BY node
+            """,
+            eager=True,
+        )
+        assert res["node"].to_list() == [1, 2, 3]
+
+        with pytest.raises(SQLInterfaceError, match="recursion limit"):
+            ctx.execute(
+                """
+                WITH RECURSIVE walk(node) AS (
+                  SELECT 1
+                  UNION ALL
+                  SELECT dst FROM edges JOIN walk ON src = node
+                )
+                SELECT * FROM walk
+                """,
+                eager=True,
+            )
+
+
 def test_invalid_derived_table_column_aliases() -> None:
     values_query = "SELECT * FROM (VALUES (1,2), (3,4))"
 
@@ -410,11 +469,90 @@ def test_read_csv(tmp_path: Path) -> None:
 
     with pytest.raises(
         SQLSyntaxError,
-        match="`read_csv` expects a single file path; found 3 arguments",
+        match="`read_csv` expects named options",
     ):
         pl.sql("SELECT * FROM read_csv('a','b','c')")
 
 
+def test_read_csv_options(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": ["1", "2", "3", "NA"], "b": ["x", "y", "z", "w"]})
+    for name in ("one", "two"):
+        df.write_csv(tmp_path / f"{name}.csv", separator=";", include_header=False)
+
+    res = pl.sql(
+        f"""
+        SELECT * FROM read_csv(
+            '{tmp_path}/*.csv',
+            separator => ';',
+            has_header => false,
+            schema => {{'n': 'INT', 's': 'VARCHAR'}},
+            null_values => 'NA',
+            row_index_name => 'idx',
+            row_index_offset => 10,
+            n_rows => 6
+        )
+        """
+    ).collect()
+    assert res.schema == pl.Schema({"idx": pl.UInt32, "n": pl.Int32, "s": pl.String})
+    assert res.rows() == [
+        (10, 1, "x"),
+        (11, 2, "y"),
+        (12, 3, "z"),
+        (13, None, "w"),
+        (14, 1, "x"),
+        (15, 2, "y"),
+    ]
+
+    paths = ", ".join(f"'{tmp_path / name}.csv'" for name in ("two", "one"))
+    res = pl.sql(
+        f"SELECT COUNT(*) FROM read_csv([{paths}], "
+        "has_header => false, separator => ';')"
+    ).collect()
+    assert res.item() == 8
+
+    for options, err in (
+        ("sep => ';'", "does not support the 'sep' option"),
+        ("separator => 1", "'separator' expects a single-quoted string"),
+        ("glob => true, glob => false", "'glob' is given more than once"),
+        ("row_index_offset => 1", "requires 'row_index_name'"),
+    ):
+        with pytest.raises(SQLSyntaxError, match=err):
+            pl.sql(f"SELECT * FROM read_csv('{tmp_path}/one.csv', {options})")
+
+
+def test_read_parquet_hive_partitioned(tmp_path: Path) -> None:
+    df = pl.DataFrame({"part": [1, 1, 2], "value": ["a", "b", "c"]})
+    df.write_parquet(tmp_path, partition_by="part")
+
+    res = pl.sql(
+        f"""
+        SELECT value, part FROM read_parquet(
+            '{tmp_path}/**/*.parquet',
+            hive_partitioning => true,
+            include_file_paths => 'path'
+        )
+        WHERE part = 2
+        """
+    ).collect()
+    assert res.rows() == [("c", 2)]
+
+    res = pl.sql(
+        f"SELECT * FROM read_parquet('{tmp_path}/**/*.parquet', "
+        "hive_partitioning => false)"
+    ).collect()
+    assert res.columns == ["value"]
+
+
+def test_read_lines(tmp_path: Path) -> None:
+    (tmp_path / "lines.txt").write_text("alpha\nbeta\ngamma\n")
+
+    res = pl.sql(
+        f"SELECT line FROM read_lines('{tmp_path}/lines.txt', name => 'line') "
+        "WHERE line <> 'beta'"
+    ).collect()
+    assert res.to_series().to_list() == ["alpha", "gamma"]
+
+
 def test_global_variable_inference_17398() -> None:
     users = pl.DataFrame({"id": "1"})
 
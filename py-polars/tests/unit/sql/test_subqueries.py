@@ -1,3 +1,7 @@
+from __future__ import annotations
+
+from typing import Any
+
 import pytest
 
 import polars as pl
@@ -157,29 +161,20 @@ def test_subquery_20732() -> None:
     assert res.to_dict(as_series=False) == {"id": [2], "s": ["b"]}
 
 
-def test_unsupported_subquery_comparisons() -> None:
-    """Test that using = with a subquery gives a helpful error message."""
-    df = pl.DataFrame({"value": [2000, 2000]})
-
-    for op, suggestion in (("=", "IN"), ("!=", "NOT IN")):
-        with pytest.raises(
-            expected_exception=SQLSyntaxError,
-            match=rf"subquery comparisons with '{op}' are not supported; use '{suggestion}' instead",
-        ):
-            pl.sql(f"SELECT * FROM df WHERE value {op} (SELECT MAX(e) FROM df)")
-
-    for op in ("<", "<=", ">", ">="):
-        with pytest.raises(
-            expected_exception=SQLSyntaxError,
-            match=rf"subquery comparisons with '{op}' are not supported",
-        ):
-            pl.sql(f"SELECT * FROM df WHERE (SELECT MAX(e) FROM df) {op} value")
-
-        with pytest.raises(
-            expected_exception=SQLSyntaxError,
-            match=rf"subquery comparisons with '{op}' are not supported",
-        ):
-            pl.sql(f"SELECT * FROM df WHERE value {op} (SELECT MAX(value) FROM df)")
+@pytest.mark.parametrize(
+    ("predicate", "expected"),
+    [
+        ("value = (SELECT MAX(value) FROM df)", [3000]),
+        ("value != (SELECT MAX(value) FROM df)", [1000, 2000]),
+        ("(SELECT MIN(value) FROM df) < value", [2000, 3000]),
+        ("value <= (SELECT AVG(value) FROM df)", [1000, 2000]),
+        ("value > (SELECT MIN(value) FROM df) + 1000", [3000]),
+    ],
+)
+def test_scalar_subquery_comparisons(predicate: str, expected: list[int]) -> None:
+    df = pl.DataFrame({"value": [1000, 2000, 3000]})
+    res = pl.sql(f"SELECT * FROM df WHERE {predicate}", eager=True)
+    assert res["value"].to_list() == expected
 
 
 def test_derived_table_without_alias() -> None:
@@ -418,22 +413,160 @@ def test_sql_subquery_to_join(
 
 
 @pytest.mark.parametrize(
-    "subquery",
+    ("subquery", "expected"),
+    [
+        # A nested subquery can't join directly; the subquery is decorrelated
+        # as a whole instead (the nested subquery then being uncorrelated).
+        (
+            "SELECT 1 FROM orders WHERE o_custkey = c_custkey"
+            " AND o_custkey IN (SELECT l_okey FROM lineitem)",
+            [1, 3, 4, None],
+        ),
+        # Clauses that change which rows the subquery yields must be applied
+        # per correlation key, never be silently ignored.
+        (
+            "SELECT 1 FROM orders WHERE o_custkey = c_custkey LIMIT 0",
+            [1, 2, 3, 4, 5, None],
+        ),
+        (
+            "SELECT 1 FROM orders WHERE o_custkey = c_custkey"
+            " QUALIFY ROW_NUMBER() OVER (ORDER BY o_amt) > 1",
+            [1, 2, 3, 4, 5, None],
+        ),
+        # An aggregate without GROUP BY yields a row for every outer row (even
+        # without inner rows) unless HAVING, OFFSET or QUALIFY drops it.
+        (
+            "SELECT COUNT(*) FROM orders WHERE o_custkey = c_custkey",
+            [],
+        ),
+        (
+            "SELECT COUNT(*) FROM orders WHERE o_custkey = c_custkey"
+            " HAVING COUNT(*) > 0",
+            [1, 4, None],
+        ),
+        (
+            "SELECT COUNT(*) FROM orders WHERE o_custkey = c_custkey"
+            " HAVING COUNT(*) = 0",
+            [2, 3, 5],
+        ),
+        (
+            "SELECT COUNT(*) FROM orders WHERE o_custkey = c_custkey OFFSET 1",
+            [1, 2, 3, 4, 5, None],
+        ),
+        (
+            "SELECT COUNT(*) FROM orders WHERE o_custkey = c_custkey"
+            " QUALIFY ROW_NUMBER() OVER () > 1",
+            [1, 2, 3, 4, 5, None],
+        ),
+    ],
+)
+def test_sql_subquery_decorrelated(subquery: str, expected: list[int | None]) -> None:
+    sql = f"SELECT c_custkey FROM customer WHERE NOT EXISTS ({subquery})"
+    res = _subquery_ctx().execute(sql, eager=True)
+    assert sorted(res["c_custkey"].to_list(), key=lambda k: (k is None, k)) == expected
+
+
+@pytest.mark.parametrize(
+    ("subquery", "exception", "error"),
     [
-        # A nested subquery must not rewrite (its SubPlan node is only valid
-        # after subquery lowering, not inside a plain filter expression).
-        "SELECT 1 FROM orders WHERE o_custkey = c_custkey"
-        " AND o_custkey IN (SELECT l_okey FROM lineitem)",
-        # Clauses that change which rows the subquery yields must make the
-        # rewrite bail, never be silently ignored. Each clause here empties
-        # the subquery, so a rewrite that ignored it would get every row's
-        # NOT EXISTS wrong.
-        "SELECT 1 FROM orders WHERE o_custkey = c_custkey LIMIT 0",
-        "SELECT TOP 0 1 FROM orders WHERE o_custkey = c_custkey",
-        "SELECT 1 FROM orders WHERE o_custkey = c_custkey QUALIFY FALSE",
+        (
+            "SELECT TOP 0 1 FROM orders WHERE o_custkey = c_custkey",
+            SQLInterfaceError,
+            "`TOP` clause",
+        ),
+        (
+            "SELECT 1 FROM orders WHERE o_custkey = c_custkey"
+            " ORDER BY c_acctbal LIMIT 1",
+            SQLInterfaceError,
+            "only reference outer columns in their WHERE clause",
+        ),
+        (
+            "SELECT 1 FROM orders WHERE o_custkey = c_custkey QUALIFY FALSE",
+            SQLSyntaxError,
+            "QUALIFY clause must reference window functions",
+        ),
     ],
 )
-def test_sql_subquery_not_rewritten(subquery: str) -> None:
+def test_sql_subquery_not_rewritten(
+    subquery: str, exception: type[Exception], error: str
+) -> None:
     sql = f"SELECT c_custkey FROM customer WHERE NOT EXISTS ({subquery})"
-    with pytest.raises(SQLInterfaceError, match="not currently supported"):
+    with pytest.raises(exception, match=error):
         _subquery_ctx().execute(sql, eager=True)
+
+
+@pytest.mark.parametrize(
+    ("query", "expected"),
+    [
+        # Correlated scalar subqueries in SELECT; COUNT over no rows is 0.
+        (
+            "SELECT c_custkey, (SELECT COUNT(*) FROM orders"
+            " WHERE o_custkey = c_custkey) AS n FROM customer",
+            {"c_custkey": [1, 2, 3, 4, 5, None], "n": [0, 1, 1, 0, 1, 0]},
+        ),
+        # Any aggregate without GROUP BY yields its value over no rows.
+        (
+            "SELECT c_custkey, (SELECT COUNT(*) + 1 FROM orders"
+            " WHERE o_custkey = c_custkey) AS n FROM customer",
+            {"c_custkey": [1, 2, 3, 4, 5, None], "n": [1, 2, 2, 1, 2, 1]},
+        ),
+        (
+            "SELECT c_custkey, t.n FROM customer, LATERAL (SELECT COUNT(*)"
+            " FROM orders WHERE o_custkey = c_custkey) t(n)",
+            {"c_custkey": [1, 2, 3, 4, 5, None], "n": [0, 1, 1, 0, 1, 0]},
+        ),
+        (
+            "SELECT c_custkey, (SELECT MAX(o_amt) FROM orders"
+            " WHERE o_custkey = c_custkey) FROM customer",
+            {
+                "c_custkey": [1, 2, 3, 4, 5, None],
+                "o_amt": [None, 20, 99, None, 99, None],
+            },
+        ),
+        # ...and in WHERE.
+        (
+            "SELECT c_custkey FROM customer WHERE c_acctbal <"
+            " (SELECT SUM(o_amt) FROM orders WHERE o_custkey = c_custkey)",
+            {"c_custkey": [3, 5]},
+        ),
+        # Correlation by non-equality predicates.
+        (
+            "SELECT c_custkey FROM customer"
+            " WHERE EXISTS (SELECT 1 FROM orders WHERE o_amt < c_acctbal)",
+            {"c_custkey": [3, 4, 5, None]},
+        ),
+        (
+            "SELECT c_custkey FROM customer"
+            " WHERE NOT EXISTS (SELECT 1 FROM orders WHERE o_amt < c_acctbal)",
+            {"c_custkey": [1, 2]},
+        ),
+        (
+            "SELECT c_custkey, (SELECT COUNT(*) FROM orders"
+            " WHERE o_amt < c_acctbal) AS n FROM customer",
+            {"c_custkey": [1, 2, 3, 4, 5, None], "n": [0, 0, 1, 1, 1, 1]},
+        ),
+        (
+            "SELECT c_custkey, (SELECT COUNT(*) + 1 FROM orders"
+            " WHERE o_amt < c_acctbal) AS n FROM customer",
+            {"c_custkey": [1, 2, 3, 4, 5, None], "n": [1, 1, 2, 2, 2, 2]},
+        ),
+        # Top-n rows per group with LATERAL joins.
+        (
+            "SELECT c_custkey, t.o_amt FROM customer LEFT JOIN LATERAL"
+            " (SELECT o_amt FROM orders WHERE o_custkey = c_custkey"
+            " ORDER BY o_amt LIMIT 1) t ON TRUE",
+            {
+                "c_custkey": [1, 2, 3, 4, 5, None],
+                "o_amt": [None, 20, 99, None, 99, None],
+            },
+        ),
+        (
+            "SELECT c.a, t.b AS b FROM customer c, LATERAL (SELECT b FROM orders o"
+            " WHERE o.a = c.a ORDER BY b DESC LIMIT 1) t",
+            {"a": [1, 1, 2, 1, 2, 2], "b": [20, 20, 30, 20, 30, 30]},
+        ),
+    ],
+)
+def test_sql_correlated_subqueries(query: str, expected: dict[str, Any]) -> None:
+    res = _subquery_ctx().execute(query, eager=True)
+    assert res.to_dict(as_series=False) == expected
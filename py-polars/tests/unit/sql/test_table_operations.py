@@ -2,7 +2,7 @@ from __future__ import annotations
 
 import re
 from datetime import date
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
@@ -64,6 +64,67 @@ def test_create_table_from_file_io(io_files_path: Path) -> None:
         assert df.shape == (135, 4)
 
 
+def test_create_view() -> None:
+    with pl.SQLContext(eager=True) as ctx:
+        ctx.register("tbl", pl.LazyFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]}))
+        ctx.execute("CREATE VIEW v1 (n, s) AS SELECT a * 10, b FROM tbl WHERE a > 1")
+        ctx.execute("CREATE TEMPORARY VIEW v2 AS SELECT MAX(n) AS n FROM v1")
+
+        assert ctx.execute("SHOW VIEWS").to_series().to_list() == ["v1", "v2"]
+        assert ctx.execute("SHOW TABLES").to_series().to_list() == ["tbl"]
+        assert ctx.execute("SELECT * FROM v1").rows() == [(20, "y"), (30, "z")]
+
+        # views re-resolve the tables they reference each time they are used
+        ctx.register("tbl", pl.LazyFrame({"a": [5], "b": ["w"]}))
+        assert ctx.execute("SELECT s FROM v1").rows() == [("w",)]
+        assert ctx.execute("SELECT v.n FROM v2 AS v").item() == 50
+
+        # a CTE of the same name takes precedence over the view
+        res = ctx.execute("WITH v1 AS (SELECT 0 AS n) SELECT n FROM v1")
+        assert res.item() == 0
+
+        with pytest.raises(SQLInterfaceError, match="'v1' already exists"):
+            ctx.execute("CREATE VIEW v1 AS SELECT 1 AS n")
+        with pytest.raises(SQLInterfaceError, match="cannot reference itself"):
+            ctx.execute("CREATE OR REPLACE VIEW v1 AS SELECT n AS a, 'x' AS b FROM v2")
+
+        ctx.execute("CREATE OR REPLACE VIEW v1 (n, s) AS SELECT a, b FROM tbl")
+        ctx.execute("CREATE VIEW IF NOT EXISTS v1 AS SELECT 1 AS n")
+        assert ctx.execute("SELECT * FROM v2").item() == 5
+
+        ctx.execute("DROP VIEW v2")
+        assert ctx.execute("SHOW VIEWS").to_series().to_list() == ["v1"]
+        with pytest.raises(SQLInterfaceError, match="view 'v2' does not exist"):
+            ctx.execute("DROP VIEW v2")
+        with pytest.raises(SQLInterfaceError, match="'tbl' already exists as a table"):
+            ctx.execute("CREATE VIEW tbl AS SELECT 1 AS n")
+
+
+@pytest.mark.parametrize(
+    "describe_sql",
+    [
+        "DESCRIBE {}",
+        "DESC {}",
+        "SHOW COLUMNS FROM {}",
+        "SHOW COLUMNS IN {}",
+    ],
+)
+def test_describe_relation(describe_sql: str, test_frame: pl.LazyFrame) -> None:
+    expected = pl.DataFrame(
+        {
+            "column_name": ["x", "y", "z"],
+            "data_type": ["u8", "str", "date"],
+        }
+    )
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        ctx.execute("CREATE VIEW v AS SELECT * FROM frame")
+        for name in ("frame", "v"):
+            assert_frame_equal(ctx.execute(describe_sql.format(name)), expected)
+
+        with pytest.raises(SQLInterfaceError, match="'missing' was not found"):
+            ctx.execute(describe_sql.format("missing"))
+
+
 @pytest.mark.parametrize(
     ("delete_constraint", "expected_ids"),
     [
@@ -131,6 +192,93 @@ def test_explain_query(test_frame: pl.LazyFrame) -> None:
         )
 
 
+def test_insert_into(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute(
+            "INSERT INTO frame (y, x) VALUES ('ddd', 4), ('eee', 5)",
+        )
+        assert res.schema == test_frame.collect_schema()
+        assert res.rows() == [
+            (1, "aaa", date(2000, 12, 31)),
+            (2, "bbb", date(1978, 11, 15)),
+            (3, "ccc", date(2077, 10, 20)),
+            (4, "ddd", None),
+            (5, "eee", None),
+        ]
+
+        # the registered table is replaced by the result
+        res = ctx.execute("INSERT INTO frame SELECT * FROM frame WHERE x > 4")
+        assert res["x"].to_list() == [1, 2, 3, 4, 5, 5]
+        assert_frame_equal(ctx.execute("SELECT * FROM frame"), res)
+
+        res = ctx.execute(
+            "INSERT OVERWRITE TABLE frame SELECT * FROM frame WHERE x < 2",
+        )
+        assert res.rows() == [(1, "aaa", date(2000, 12, 31))]
+
+        for sql, err in (
+            ("INSERT INTO frame VALUES (1, 'aaa')", "3 target column"),
+            ("INSERT INTO frame (x, x) VALUES (1, 2)", "more than once"),
+            ("INSERT INTO frame (w) VALUES (1)", "column 'w' not found"),
+            ("INSERT INTO missing VALUES (1)", "'missing' does not exist"),
+        ):
+            with pytest.raises(SQLInterfaceError, match=err):
+                ctx.execute(sql)
+
+
+def test_merge_into() -> None:
+    target = pl.LazyFrame(
+        {"id": [1, 2, 3, 4], "qty": [10, 20, 30, 40], "note": ["a", "b", "c", "d"]}
+    )
+    source = pl.LazyFrame({"id": [2, 4, 5, 6], "qty": [-1, 400, 500, 600]})
+
+    with pl.SQLContext(target=target, source=source, eager=True) as ctx:
+        res = ctx.execute(
+            """
+            MERGE INTO target t
+            USING source s ON t.id = s.id
+            WHEN MATCHED AND s.qty < 0 THEN DELETE
+            WHEN MATCHED THEN UPDATE SET qty = s.qty, note = t.note || '!'
+            WHEN NOT MATCHED AND s.id < 6 THEN INSERT (id, qty) VALUES (s.id, s.qty)
+            WHEN NOT MATCHED BY SOURCE AND t.id = 3 THEN UPDATE SET note = 'gone'
+            """
+        )
+        assert res.rows() == [
+            (1, 10, "a"),
+            (3, 30, "gone"),
+            (4, 400, "d!"),
+            (5, 500, None),
+        ]
+        assert_frame_equal(ctx.execute("SELECT * FROM target"), res)
+
+        # 'INSERT ROW' inserts the source columns positionally
+        ctx.register("source", pl.LazyFrame({"id": [7], "qty": [700], "note": ["g"]}))
+        res = ctx.execute(
+            """
+            MERGE INTO target USING source ON target.id = source.id
+            WHEN NOT MATCHED THEN INSERT ROW
+            """
+        )
+        assert res["id"].to_list() == [1, 3, 4, 5, 7]
+
+        with pytest.raises(SQLInterfaceError, match="provide an alias"):
+            ctx.execute(
+                "MERGE INTO target USING (SELECT 1 AS id) ON target.id = id "
+                "WHEN MATCHED THEN DELETE"
+            )
+
+        # a target row may not be matched by more than one source row
+        ctx.register("source", pl.LazyFrame({"id": [4, 4], "qty": [1, 2]}))
+        with pytest.raises(SQLInterfaceError, match="more than one source row"):
+            ctx.execute(
+                """
+                MERGE INTO target t USING source s ON t.id = s.id
+                WHEN MATCHED THEN UPDATE SET qty = s.qty
+                WHEN NOT MATCHED THEN INSERT (id, qty) VALUES (s.id, s.qty)
+                """
+            )
+
+
 def test_show_tables(test_frame: pl.LazyFrame) -> None:
     # 'show tables' lists all tables registered with the sql context in sorted order
     with pl.SQLContext(
@@ -159,3 +307,99 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_update(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute(
+            "UPDATE frame SET y = UPPER(y), x = x * 10 WHERE x <> 2",
+        )
+        assert res.schema == test_frame.collect_schema()
+        assert res.rows() == [
+            (10, "AAA", date(2000, 12, 31)),
+            (2, "bbb", date(1978, 11, 15)),
+            (30, "CCC", date(2077, 10, 20)),
+        ]
+
+        # subqueries are evaluated against the updated table
+        res = ctx.execute("UPDATE frame f SET x = (SELECT MAX(x) FROM frame) - f.x")
+        assert res["x"].to_list() == [20, 28, 0]
+        assert_frame_equal(ctx.execute("SELECT * FROM frame"), res)
+
+        for sql, err in (
+            ("UPDATE frame SET w = 1", "column 'w' not found"),
+            ("UPDATE frame SET x = 1, x = 2", "more than once"),
+            ("UPDATE missing SET x = 1", "'missing' does not exist"),
+        ):
+            with pytest.raises(SQLInterfaceError, match=err):
+                ctx.execute(sql)
+
+
+def test_pivot() -> None:
+    sales = pl.DataFrame(  # noqa: F841
+        {
+            "region": ["east", "east", "west", "west", "east"],
+            "quarter": ["Q1", "Q2", "Q1", "Q2", "Q1"],
+            "amount": [10, 20, 30, 40, 50],
+        }
+    )
+    res = pl.sql(
+        """
+        SELECT * FROM sales
+        PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2' AS second))
+        ORDER BY region
+        """,
+        eager=True,
+    )
+    assert res.to_dict(as_series=False) == {
+        "region": ["east", "west"],
+        "Q1": [60, 30],
+        "second": [20, 40],
+    }
+
+    # multiple aggregates are suffixed with their alias
+    res = pl.sql(
+        """
+        SELECT * FROM sales
+        PIVOT (SUM(amount) AS total, MAX(amount) AS top FOR quarter IN ('Q1'))
+        AS p ORDER BY p.region
+        """,
+        eager=True,
+    )
+    assert res.to_dict(as_series=False) == {
+        "region": ["east", "west"],
+        "Q1_total": [60, 30],
+        "Q1_top": [50, 30],
+    }
+
+
+@pytest.mark.parametrize(
+    ("null_clause", "expected"),
+    [
+        ("", {"id": [1, 1, 2], "key": ["a", "b", "a"], "val": [10, 20, 30]}),
+        (
+            "INCLUDE NULLS",
+            {
+                "id": [1, 1, 2, 2],
+                "key": ["a", "b", "a", "b"],
+                "val": [10, 20, 30, None],
+            },
+        ),
+    ],
+)
+def test_unpivot(null_clause: str, expected: dict[str, list[Any]]) -> None:
+    df = pl.DataFrame({"id": [1, 2], "a": [10, 30], "b": [20, None]})  # noqa: F841
+    res = pl.sql(
+        f"""
+        SELECT * FROM df UNPIVOT {null_clause} (val FOR key IN (a, b))
+        ORDER BY id, key
+        """,
+        eager=True,
+    )
+    assert res.to_dict(as_series=False) == expected
+
+
+def test_pivot_errors() -> None:
+    df = pl.DataFrame({"k": ["x"], "v": [1]})  # noqa: F841
+    with pytest.raises(SQLInterfaceError, match="explicit list of values"):
+        pl.sql("SELECT * FROM df PIVOT (SUM(v) FOR k IN (ANY))").collect()
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+from datetime import date
+
 import pytest
 
 import polars as pl
@@ -561,7 +563,7 @@ def test_window_frame_validation() -> None:
         assert df.sql(query).rows() == [("aa", 50), ("bb", -50), ("cc", 25)]
         assert_sql_matches(df, query=query, compare_with="sqlite")
 
-    # Rejected: RANGE frame (peer group semantics not supported)
+    # Rejected: unbounded RANGE frame (peer group semantics not supported)
     query = """
         SELECT lbl, SUM(value) OVER (
             ORDER BY lbl
@@ -571,7 +573,10 @@ def test_window_frame_validation() -> None:
     """
     with pytest.raises(
         SQLInterfaceError,
-        match="RANGE-based window frames are not supported",
+        match=(
+            "only 'RANGE BETWEEN <offset> PRECEDING AND CURRENT ROW' is supported "
+            "for RANGE-based window frames"
+        ),
     ):
         df.sql(query)
 
@@ -589,19 +594,89 @@ def test_window_frame_validation() -> None:
     ):
         df.sql(query)
 
-    # Rejected: ROWS with incompatible bounds
+    # Rejected: ROWS with an unbounded end
     query = """
         SELECT lbl, SUM(value) OVER (
             ORDER BY lbl
-            ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+            ROWS BETWEEN 1 PRECEDING AND UNBOUNDED FOLLOWING
         ) AS sum_value
         FROM self
     """
     with pytest.raises(
         SQLInterfaceError,
         match=(
-            "only 'ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW' is currently "
-            "supported; found 'ROWS BETWEEN 1 PRECEDING AND CURRENT ROW'"
+            "only 'ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW' is supported for "
+            "unbounded ROWS-based window frames; "
+            "found 'ROWS BETWEEN 1 PRECEDING AND UNBOUNDED FOLLOWING'"
         ),
     ):
         df.sql(query)
+
+    # Rejected: sliding frame for a function without a rolling equivalent
+    query = """
+        SELECT lbl, ROW_NUMBER() OVER (
+            ORDER BY lbl
+            ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+        ) AS n
+        FROM self
+    """
+    with pytest.raises(
+        SQLInterfaceError,
+        match=(
+            "window frame 'ROWS BETWEEN 1 PRECEDING AND CURRENT ROW' is only supported"
+        ),
+    ):
+        df.sql(query)
+
+
+def test_window_frame_sliding(df_test: pl.DataFrame) -> None:
+    query = """
+        SELECT
+            id,
+            SUM(value) OVER (
+                PARTITION BY category ORDER BY id
+                ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+            ) AS sum_value,
+            AVG(value) OVER (ORDER BY id ROWS 2 PRECEDING) AS avg_value,
+            COUNT(*) OVER (
+                ORDER BY id ROWS BETWEEN CURRENT ROW AND 2 FOLLOWING
+            ) AS n_rows,
+            MIN(value) OVER (
+                ORDER BY id ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING
+            ) AS min_value,
+            MAX(value) OVER (
+                ORDER BY id RANGE BETWEEN 2 PRECEDING AND CURRENT ROW
+            ) AS max_value
+        FROM self
+        ORDER BY id
+    """
+    res = df_test.sql(query)
+    assert res.to_dict(as_series=False) == {
+        "id": [1, 2, 3, 4, 5, 6, 7],
+        "sum_value": [30, 60, 40, 55, 80, 65, 35],
+        "avg_value": [20.0, 15.0, 20.0, 55 / 3, 85 / 3, 80 / 3, 100 / 3],
+        "n_rows": [3, 3, 3, 3, 3, 2, 1],
+        "min_value": [None, 20, 10, 10, 15, 15, 25],
+        "max_value": [20, 20, 30, 30, 40, 40, 40],
+    }
+    assert_sql_matches(df_test, query=query, compare_with="sqlite")
+
+
+def test_window_frame_range_interval() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [date(2024, 1, d) for d in (1, 2, 5, 8, 9)],
+            "value": [1, 2, 3, 4, 5],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT dt, SUM(value) OVER (
+            ORDER BY dt
+            RANGE BETWEEN INTERVAL '3 days' PRECEDING AND CURRENT ROW
+        ) AS sum_value
+        FROM self
+        ORDER BY dt
+        """
+    )
+    assert res["sum_value"].to_list() == [1, 3, 5, 7, 9]
@@ -475,3 +475,60 @@ def test_streaming_hconcat_strict_27372() -> None:
     result = lf.collect(engine="streaming")
     expected = lf.collect(engine="in-memory")
     assert_frame_equal(result, expected)
+
+
+def test_streaming_window_functions(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "7")
+    lf = pl.LazyFrame(
+        {
+            "g": [1, 2, None, 1, 2, 3, 1, None, 2, 1] * 10,
+            "h": ["a", "b"] * 50,
+            "t": [(i * 37) % 100 for i in range(100)],
+            "x": list(range(100)),
+        }
+    )
+    lf = lf.select(
+        pl.col("x").cum_sum().over("g").alias("cum_sum"),
+        pl.col("x").cum_sum().over("g", order_by="t").alias("cum_sum_ordered"),
+        pl.col("x").last().over("g", "h", order_by="t").alias("last_ordered"),
+        pl.col("x").diff().over(pl.col("g") % 2, order_by="t").alias("diff"),
+        pl.col("x").rank().over(["g", "h"]).alias("rank"),
+        pl.col("x"),
+    )
+    assert_frame_equal(lf.collect(engine="streaming"), lf.collect(engine="in-memory"))
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("spill", [False, True])
+def test_streaming_window_functions_many_morsels(
+    tmp_path: Path, plmonkeypatch: PlMonkeyPatch, spill: bool
+) -> None:
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "7")
+    if spill:
+        plmonkeypatch.setenv("POLARS_OOC_SPILL_POLICY", "spill")
+        plmonkeypatch.setenv("POLARS_OOC_MEMORY_BUDGET_FRACTION", "0.000000001")
+        plmonkeypatch.setenv("POLARS_OOC_SPILL_DIR", str(tmp_path))
+        plmonkeypatch.setenv("POLARS_OOC_DRIFT_THRESHOLD", "1")
+        plmonkeypatch.setenv("POLARS_OOC_SPILL_MIN_BYTES", "1")
+
+    # Partitions of a single row up to partitions spanning hundreds of morsels.
+    n = 20_000
+    g = [
+        i if i >= n - 50 else i % 500 if i % 3 else i % 7 if i % 2 else None
+        for i in range(n)
+    ]
+    lf = pl.LazyFrame(
+        {
+            "g": g,
+            "t": [(i * 7919) % n for i in range(n)],
+            "x": list(range(n)),
+        }
+    )
+    lf = lf.select(
+        pl.col("x").cum_sum().over("g").alias("cum_sum"),
+        pl.col("x").cum_sum().over("g", order_by="t").alias("cum_sum_ordered"),
+        pl.col("x").shift().over("g", order_by="t").alias("shift_ordered"),
+        pl.col("x").rank().over("g").alias("rank"),
+        pl.col("x"),
+    )
+    assert_frame_equal(lf.collect(engine="streaming"), lf.collect(engine="in-memory"))
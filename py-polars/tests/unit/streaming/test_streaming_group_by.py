@@ -530,3 +530,68 @@ def test_streaming_group_by_nested_agg_fallback() -> None:
     )
     expected = {("aaa", n // 3), ("bbb", n - n // 3)}
     assert expected == set(res.rows())
+
+
+@pytest.mark.parametrize("morsel_size", ["1", "100000"])
+def test_streaming_group_by_holistic_aggs(
+    plmonkeypatch: PlMonkeyPatch, morsel_size: str
+) -> None:
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", morsel_size)
+    df = pl.DataFrame(
+        {
+            "g": [i % 7 for i in range(200)],
+            "x": [None if i % 11 == 0 else (i * 37) % 101 for i in range(200)],
+            "s": [str(i % 5) for i in range(200)],
+        }
+    )
+    q = df.lazy().group_by("g").agg(
+        pl.col("x").median().alias("median"),
+        pl.col("x").quantile(0.9, "linear").alias("q90"),
+        pl.col("x").quantile(0.25, "nearest").alias("q25"),
+        pl.col("x").n_unique().alias("n_unique"),
+        pl.col("s").n_unique().alias("s_n_unique"),
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+    q = df.lazy().select(
+        pl.col("x").median(), pl.col("x").quantile(0.5, "higher").alias("q")
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    # Multiple modes are returned in an unspecified order.
+    q = df.lazy().group_by("g").agg(pl.col("x").mode(), pl.col("s").mode())
+    assert_frame_equal(
+        q.collect(engine="streaming").with_columns(pl.col("x", "s").list.sort()),
+        q.collect(engine="in-memory").with_columns(pl.col("x", "s").list.sort()),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.write_disk
+def test_streaming_group_by_holistic_aggs_spill(
+    tmp_path: Path, plmonkeypatch: PlMonkeyPatch
+) -> None:
+    # Flush the collected values after every morsel, and spill them all.
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "10")
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_POLICY", "spill")
+    plmonkeypatch.setenv("POLARS_OOC_MEMORY_BUDGET_FRACTION", "0.000000001")
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_DIR", str(tmp_path))
+    plmonkeypatch.setenv("POLARS_OOC_DRIFT_THRESHOLD", "1")
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_MIN_BYTES", "1")
+
+    df = pl.DataFrame({"g": [i % 3 for i in range(300)], "x": list(range(300))})
+    q = df.lazy().group_by("g").agg(
+        pl.col("x").median().alias("median"),
+        pl.col("x").quantile(0.1, "lower").alias("q10"),
+        pl.col("x").n_unique().alias("n_unique"),
+        (pl.col("x") % 4).mode().alias("mode"),
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming").with_columns(pl.col("mode").list.sort()),
+        q.collect(engine="in-memory").with_columns(pl.col("mode").list.sort()),
+        check_row_order=False,
+    )
@@ -13,6 +13,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from tests.conftest import PlMonkeyPatch
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -183,3 +185,86 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.parametrize("maintain_order", [True, False])
+@pytest.mark.parametrize(
+    "slice", [None, (0, 10), (25, 50), (-30, 20), (990, 100), (2000, 5)]
+)
+def test_streaming_sort_slice_many_morsels(
+    monkeypatch: pytest.MonkeyPatch,
+    maintain_order: bool,
+    slice: tuple[int, int] | None,
+) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "7")
+    df = pl.DataFrame(
+        {
+            "a": [i % 13 if i % 5 else None for i in range(1000)],
+            "b": [str(i % 3) for i in range(1000)],
+            "c": list(range(1000)),
+        }
+    )
+    q = df.lazy().sort(
+        pl.col("a") * 2,
+        "b",
+        descending=[True, False],
+        nulls_last=True,
+        maintain_order=maintain_order,
+    )
+    if slice is not None:
+        q = q.slice(*slice)
+
+    out = q.collect(engine="streaming")
+    expected = q.collect(engine="in-memory")
+    if maintain_order:
+        assert_frame_equal(out, expected)
+    else:
+        assert_frame_equal(out.select("a", "b"), expected.select("a", "b"))
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("descending", [True, False])
+@pytest.mark.parametrize("nulls_last", [True, False])
+def test_streaming_sort_many_runs_spill(
+    tmp_path: Path,
+    plmonkeypatch: PlMonkeyPatch,
+    descending: bool,
+    nulls_last: bool,
+) -> None:
+    # Sort every morsel into its own run, merge them two at a time (so that the
+    # runs are merged over several rounds) and spill them all.
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "7")
+    plmonkeypatch.setenv("POLARS_OOC_SORT_RUN_SIZE_BYTES", "1")
+    plmonkeypatch.setenv("POLARS_OOC_SORT_MAX_MERGE_FAN_IN", "2")
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_POLICY", "spill")
+    plmonkeypatch.setenv("POLARS_OOC_MEMORY_BUDGET_FRACTION", "0.000000001")
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_DIR", str(tmp_path))
+    plmonkeypatch.setenv("POLARS_OOC_DRIFT_THRESHOLD", "1")
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_MIN_BYTES", "1")
+
+    n = 1000
+    a = [i % 13 if i % 5 else None for i in range(n)]
+    b = [(i * 7) % 3 if i % 11 else None for i in range(n)]
+    df = pl.DataFrame({"a": a, "b": b, "c": list(range(n))})
+
+    def sort_key(i: int) -> tuple[tuple[bool, int], ...]:
+        def key(v: int | None, desc: bool) -> tuple[bool, int]:
+            if v is None:
+                return (nulls_last, 0)
+            return (not nulls_last, -v if desc else v)
+
+        return (key(a[i], descending), key(b[i], True))
+
+    # A stable multi-key sort, with the second key always descending.
+    expected = df[sorted(range(n), key=sort_key)]
+    q = df.lazy().sort(
+        "a",
+        "b",
+        descending=[descending, True],
+        nulls_last=nulls_last,
+        maintain_order=True,
+    )
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert_frame_equal(
+        q.slice(100, 250).collect(engine="streaming"), expected.slice(100, 250)
+    )